@@ -68,6 +68,26 @@ async fn main() {
                          start_time.elapsed().as_secs_f64(), 
                          ip);
             }
+            MonitorEvent::MinerMoved { id, from_ip, to_ip } => {
+                println!("[{:>6.1}s] ↪ Moved: {} {} -> {}", 
+                         start_time.elapsed().as_secs_f64(), 
+                         id, from_ip, to_ip);
+            }
+            MonitorEvent::IpTakenOver { ip, previous, current } => {
+                println!("[{:>6.1}s] ⚠ Taken over: {} now {} (was {})", 
+                         start_time.elapsed().as_secs_f64(), 
+                         ip, current, previous);
+            }
+            MonitorEvent::DriftDetected(report) => {
+                println!("[{:>6.1}s] ≠ Drift: {} from '{}' ({} setting(s))", 
+                         start_time.elapsed().as_secs_f64(), 
+                         report.ip, report.policy, report.drifts.len());
+            }
+            MonitorEvent::DriftResolved { ip, .. } => {
+                println!("[{:>6.1}s] = In line again: {}", 
+                         start_time.elapsed().as_secs_f64(), 
+                         ip);
+            }
             MonitorEvent::FullSnapshot(miners) => {
                 println!("\n[{:>6.1}s] 📊 Status Snapshot ({} updates so far):", 
                          start_time.elapsed().as_secs_f64(),
@@ -26,7 +26,7 @@ async fn main() {
 
     // Step 2: Send reboot command (now uses HTTP Digest Auth)
     println!("\n🔄 Sending Reboot command to {} (via HTTP Digest Auth)...", ip);
-    let results = execute_batch_command(vec![ip.clone()], MinerCommand::Reboot, None).await;
+    let results = execute_batch_command(vec![ip.clone()], MinerCommand::Reboot, None, None).await;
 
     for result in &results {
         if result.success {
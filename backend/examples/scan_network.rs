@@ -61,6 +61,29 @@ async fn main() {
                     }
                 }
             }
+            ScanEvent::Cancelled { scanned, found } => {
+                println!("\n\n=== Scan Cancelled ===");
+                println!("Scanned: {} IP(s), found {} miner(s)", scanned, found);
+            }
+            ScanEvent::MinerMoved { id, from_ip, to_ip } => {
+                println!("\n↪ {} moved from {} to {}", id, from_ip, to_ip);
+            }
+            ScanEvent::IpTakenOver { ip, previous, current } => {
+                println!("\n⚠ {} now answers at {} (was {})", current, ip, previous);
+            }
+            ScanEvent::NonMiner(device) => {
+                let name = device.sys_name.as_deref().or(device.vendor_hint.as_deref()).unwrap_or("unknown vendor");
+                println!("\n· {} is a {:?}, not a miner ({})", device.ip, device.kind, name);
+            }
+            ScanEvent::DuplicateIp { ip, mac_addresses, serial_numbers } => {
+                println!("\n⚠ {} is shared by several devices: {:?} {:?}", ip, mac_addresses, serial_numbers);
+            }
+            ScanEvent::FactoryDefaultIp { ip, mac_address, .. } => {
+                println!("\n⚠ {} is a factory-default address (MAC {})", ip, mac_address.as_deref().unwrap_or("unknown"));
+            }
+            ScanEvent::NetworkMismatch { ip, reason, .. } => {
+                println!("\n⚠ {}: {}", ip, reason);
+            }
         }
     }
 }
@@ -24,6 +24,7 @@ async fn main() {
         vec![ip.clone()],
         MinerCommand::BlinkLed,
         Some(auth),
+        None,
     ).await;
 
     for result in results {
@@ -25,6 +25,7 @@ async fn main() {
         vec![ip.clone()],
         MinerCommand::BlinkLed,
         Some(auth.clone()),
+        None,
     ).await;
 
     for result in results {
@@ -43,6 +44,7 @@ async fn main() {
         vec![ip.clone()],
         MinerCommand::StopBlink,
         Some(auth),
+        None,
     ).await;
 
     for result in results {
@@ -4,7 +4,8 @@
 /// Usage:
 ///   cargo run --example test_set_pools -- <ip>
 ///   cargo run --example test_set_pools -- <ip> --write
-use rust_lib_frontend::client::antminer_web::{AntminerWebClient, AntminerPool};
+use rust_lib_frontend::api::models::PoolConfig;
+use rust_lib_frontend::client::antminer_web::AntminerWebClient;
 use std::env;
 
 #[tokio::main]
@@ -48,15 +49,15 @@ async fn main() {
         println!();
         println!("✏️  Writing test pools (will trigger automatic reboot)...");
         let test_pools = vec![
-            AntminerPool {
+            PoolConfig {
                 url: "stratum+tcp://pool1.example.com:3333".to_string(),
-                user: "test_wallet.worker1".to_string(),
-                pass: "x".to_string(),
+                worker: "test_wallet.worker1".to_string(),
+                password: "x".to_string(),
             },
-            AntminerPool {
+            PoolConfig {
                 url: "stratum+tcp://pool2.example.com:3333".to_string(),
-                user: "test_wallet.worker2".to_string(),
-                pass: "x".to_string(),
+                worker: "test_wallet.worker2".to_string(),
+                password: "x".to_string(),
             },
         ];
         match AntminerWebClient::set_pools(&ip, user, pass, test_pools).await {
@@ -0,0 +1,111 @@
+use crate::api::commands::execute_batch_command;
+use crate::api::models::{CommandResult, MinerCommand};
+use crate::backup::{self, BackupStore, ConfigChange, SnapshotInfo};
+use crate::core::{MinerError, MinerId};
+use crate::identity;
+
+/// Outcome of backing up one miner
+#[derive(Debug, Clone)]
+pub struct BackupResult {
+    pub ip: String,
+    pub miner_id: Option<MinerId>,
+    pub snapshot: Option<SnapshotInfo>,
+    pub error: Option<String>,
+}
+
+/// Snapshot the full config of every miner in `target_ips` into the local
+/// backup store.  `note` is kept with each snapshot, e.g. "before firmware
+/// update".
+pub async fn backup_miners(target_ips: Vec<String>, note: Option<String>) -> Vec<BackupResult> {
+    let store = match BackupStore::open() {
+        Ok(store) => std::sync::Arc::new(store),
+        Err(e) => {
+            return target_ips
+                .into_iter()
+                .map(|ip| BackupResult { miner_id: identity::ADDRESSES.id_at(&ip), ip, snapshot: None, error: Some(e.to_string()) })
+                .collect();
+        }
+    };
+
+    let tasks: Vec<_> = target_ips
+        .into_iter()
+        .map(|ip| {
+            let store = store.clone();
+            let note = note.clone();
+            tokio::spawn(async move {
+                match backup::backup(&store, &ip, note).await {
+                    Ok(info) => BackupResult { ip, miner_id: Some(info.miner_id.clone()), snapshot: Some(info), error: None },
+                    Err(e) => {
+                        println!("[backup] {} FAILED: {}", ip, e);
+                        BackupResult { miner_id: identity::ADDRESSES.id_at(&ip), ip, snapshot: None, error: Some(e.to_string()) }
+                    }
+                }
+            })
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for task in tasks {
+        if let Ok(result) = task.await {
+            results.push(result);
+        }
+    }
+    results
+}
+
+/// Stored snapshots of one miner, or of all miners; newest first
+pub fn list_config_snapshots(miner_id: Option<MinerId>) -> Result<Vec<SnapshotInfo>, String> {
+    BackupStore::open().and_then(|store| store.list(miner_id.as_ref())).map_err(|e| e.to_string())
+}
+
+pub fn delete_config_snapshot(snapshot_id: String) -> Result<(), String> {
+    BackupStore::open().and_then(|store| store.delete(&snapshot_id)).map_err(|e| e.to_string())
+}
+
+/// Settings that changed from snapshot `before_id` to `after_id`
+pub fn diff_config_snapshots(before_id: String, after_id: String) -> Result<Vec<ConfigChange>, String> {
+    let store = BackupStore::open().map_err(|e| e.to_string())?;
+    let before = store.load(&before_id).map_err(|e| e.to_string())?;
+    let after = store.load(&after_id).map_err(|e| e.to_string())?;
+    Ok(backup::diff(&before.content, &after.content))
+}
+
+/// What changed on the miner at `ip` since snapshot `snapshot_id`, read
+/// live without storing anything
+pub async fn diff_config_with_miner(snapshot_id: String, ip: String) -> Result<Vec<ConfigChange>, String> {
+    let snapshot = BackupStore::open().and_then(|store| store.load(&snapshot_id)).map_err(|e| e.to_string())?;
+    let current = backup::capture(&ip, None).await.map_err(|e| e.to_string())?;
+    Ok(backup::diff(&snapshot.content, &current.content))
+}
+
+/// Write snapshot `snapshot_id` back to every miner in `target_ips`, e.g.
+/// to roll back a config broken by hand, or copy one across miners of the
+/// same model (each keeps its own pool workers).  Each miner's current config is
+/// backed up first; a miner that can't be backed up isn't touched.  The
+/// restore itself runs through the batch executor with its retries.
+pub async fn restore_config_snapshot(snapshot_id: String, target_ips: Vec<String>) -> Vec<CommandResult> {
+    if let Err(e) = BackupStore::open().and_then(|store| store.load(&snapshot_id)) {
+        let message = match e {
+            MinerError::Backup(message) => message,
+            other => other.to_string(),
+        };
+        return target_ips.into_iter().map(|ip| CommandResult::failed(ip, MinerError::Backup(message.clone()))).collect();
+    }
+
+    let note = Some(format!("before restoring {}", snapshot_id));
+    let mut results = Vec::new();
+    let mut ready = Vec::new();
+    for backed_up in backup_miners(target_ips, note).await {
+        match backed_up.error {
+            None => ready.push(backed_up.ip),
+            Some(e) => results.push(CommandResult::failed(
+                backed_up.ip,
+                MinerError::Backup(format!("no safety backup, not restored: {}", e)),
+            )),
+        }
+    }
+
+    let command = MinerCommand::RestoreConfig { snapshot_id };
+    results.extend(execute_batch_command(ready, command, None, None).await);
+    results
+}
@@ -1,36 +1,48 @@
 use crate::api::models::{MinerCommand, CommandResult};
+use crate::backup::{self, BackupStore, SnapshotContent};
 
-use crate::core::MinerCredentials;
+use crate::core::{MinerCredentials, MinerError, MinerVendor, Result};
 use crate::client::{
-    get_summary,
-    antminer_web::AntminerWebClient,
+    self,
+    antminer_web::{AntminerWebClient, ConfChange},
+    credentials,
+    retry::{self, PreCheck, RetryPolicy},
     whatsminer_web::WhatsminerWebClient,
+    pools,
 };
+use crate::identity;
+use std::time::Instant;
 
 
 /// Execute a command on multiple miners in parallel.
 /// Returns results for each IP (success/failure).
+///
+/// Each miner is logged in to with the credential that worked for it last,
+/// falling back through the matching credential sets (see
+/// `client::credentials`).  With the vault locked every miner fails.
+/// Explicit `credentials` are used as given on every miner instead.
+///
+/// Transient failures are retried per `retry_policy`, or the command's
+/// default policy if `None`; every attempt is listed in the result.
 pub async fn execute_batch_command(
     target_ips: Vec<String>,
     command: MinerCommand,
     credentials: Option<MinerCredentials>,
+    retry_policy: Option<RetryPolicy>,
 ) -> Vec<CommandResult> {
     let mut results = Vec::new();
-    
-    let settings = AppSettings::load();
-    let antminer_creds = settings.antminer_credentials;
-    let whatsminer_creds = settings.whatsminer_credentials;
+    let policy = retry_policy.unwrap_or_else(|| command.default_retry_policy());
     
     // Execute commands concurrently
     let tasks: Vec<_> = target_ips
         .into_iter()
         .map(|ip| {
             let cmd = command.clone();
-            let a_creds = antminer_creds.clone();
-            let w_creds = whatsminer_creds.clone();
+            let policy = policy.clone();
+            let given = credentials.clone();
             
             tokio::spawn(async move {
-                execute_single_command(ip, cmd, a_creds, w_creds).await
+                execute_single_command(ip, cmd, given, policy).await
             })
         })
         .collect();
@@ -45,35 +57,109 @@ pub async fn execute_batch_command(
     results
 }
 
-use crate::api::settings::get_app_settings;
-use crate::core::config::AppSettings;
-
 /// Execute a command on a single miner.
 async fn execute_single_command(
     ip: String,
     command: MinerCommand,
-    antminer_creds: MinerCredentials,
-    whatsminer_creds: MinerCredentials,
+    given: Option<MinerCredentials>,
+    policy: RetryPolicy,
 ) -> CommandResult {
     println!("Executing command {:?} for {}...", command, ip);
     
-    // Detect miner type by querying CGMiner summary
-    let is_whatsminer = if let Ok(stats) = get_summary(&ip, 4028, 500).await {
-         let model = stats.model.as_ref().map(|m| m.to_lowercase()).unwrap_or_default();
-         let firmware = stats.firmware.as_ref().map(|f| f.to_lowercase()).unwrap_or_default();
-         println!("Miner Detection - IP: {}, Model: {}, Firmware: {}", ip, model, firmware);
-         model.contains("whatsminer") || firmware.contains("whatsminer")
-    } else {
-        println!("Miner Detection - Failed to get summary for {}. Defaulting to Antminer.", ip);
-        false
+    // Vendor comes from the identity cache (filled by scan/monitor);
+    // only an unknown IP is probed, and a failed probe is an error, not a guess.
+    let identity = match identity::resolve(&ip).await {
+        Ok(identity) => identity,
+        Err(e) => {
+            println!("Miner Detection FAILED for {}: {}", ip, e);
+            return CommandResult::failed(ip, e);
+        }
     };
 
-    if is_whatsminer {
-        println!("Detected Whatsminer for {}", ip);
-        execute_whatsminer_command(ip, command, whatsminer_creds).await
-    } else {
-        println!("Detected Antminer for {}", ip);
-        execute_antminer_command(ip, command, antminer_creds).await
+    match identity.vendor {
+        MinerVendor::Whatsminer => println!("Detected Whatsminer for {}", ip),
+        MinerVendor::Antminer => println!("Detected Antminer for {}", ip),
+    }
+
+    // Pools are checked and worker templates expanded once, before the
+    // first attempt
+    let command = match command {
+        MinerCommand::SetPools { pools } => match pools::prepare(&ip, &pools).await {
+            Ok(pools) => MinerCommand::SetPools { pools },
+            Err(e) => {
+                println!("Pool settings INVALID for {}: {}", ip, e);
+                return CommandResult::failed(ip, e);
+            }
+        },
+        MinerCommand::ApplyConfig { pools: Some(pools), power_mode, fan_mode, freq_level } => {
+            match pools::prepare(&ip, &pools).await {
+                Ok(pools) => MinerCommand::ApplyConfig { pools: Some(pools), power_mode, fan_mode, freq_level },
+                Err(e) => {
+                    println!("Pool settings INVALID for {}: {}", ip, e);
+                    return CommandResult::failed(ip, e);
+                }
+            }
+        }
+        command => command,
+    };
+
+    let first_sent = Instant::now();
+    let outcome = retry::run(
+        &policy,
+        |attempt| precheck(&ip, &command, &policy, attempt, first_sent),
+        || async {
+            match identity.vendor {
+                MinerVendor::Whatsminer => execute_whatsminer_command(&ip, &command, given.as_ref()).await,
+                MinerVendor::Antminer => execute_antminer_command(&ip, &command, given.as_ref()).await,
+            }
+        },
+    )
+    .await;
+
+    if identity::IDENTITIES.record_outcome(&ip, outcome.result.is_ok()) {
+        println!("Repeated failures on {}, its vendor will be re-detected", ip);
+    }
+    if let Some(reason) = &outcome.skipped {
+        println!("{:?} not sent to {}: {}", command, ip, reason);
+    }
+    let mut result = match outcome.result {
+        Ok(()) => CommandResult::ok(ip),
+        Err(e) => CommandResult::failed(ip, e),
+    };
+    result.attempts = outcome.attempts;
+    result.skipped = outcome.skipped;
+    result
+}
+
+/// Guard for commands that must not run twice (see
+/// `MinerCommand::is_idempotent`).  The first attempt is skipped if the
+/// miner has been up for less than the policy's `min_uptime_secs`, and goes
+/// out if its uptime can't be read.  A retry is skipped if the mining
+/// daemon has restarted since the first attempt was sent (the reported
+/// uptime is the daemon's, not the system's, so it only means something
+/// relative to our own attempt), and held while the miner doesn't answer,
+/// since the failed attempt may have taken it down.
+async fn precheck(ip: &str, command: &MinerCommand, policy: &RetryPolicy, attempt: u32, first_sent: Instant) -> PreCheck {
+    if command.is_idempotent() {
+        return PreCheck::Proceed;
+    }
+    if attempt == 1 {
+        if policy.min_uptime_secs == 0 {
+            return PreCheck::Proceed;
+        }
+        return match client::get_summary(ip, client::DEFAULT_PORT, client::DEFAULT_TIMEOUT_MS).await {
+            Ok(stats) => policy.check_uptime(stats.uptime),
+            Err(_) => PreCheck::Proceed,
+        };
+    }
+    let since_first = first_sent.elapsed().as_secs();
+    match client::get_summary(ip, client::DEFAULT_PORT, client::DEFAULT_TIMEOUT_MS).await {
+        // Uptime 0 means the firmware didn't report it
+        Ok(stats) if stats.uptime > 0 && stats.uptime <= since_first => {
+            PreCheck::Satisfied(format!("miner restarted {}s ago, after the first attempt", stats.uptime))
+        }
+        Ok(_) => PreCheck::Proceed,
+        Err(e) => PreCheck::Hold(format!("miner not answering after the last attempt ({})", e)),
     }
 }
 
@@ -81,52 +167,85 @@ async fn execute_single_command(
 // Antminer command dispatch
 // ---------------------------------------------------------------------------
 
-async fn execute_antminer_command(
-    ip: String,
-    command: MinerCommand,
-    creds: MinerCredentials,
-) -> CommandResult {
-    let (user, pass) = (creds.username.as_str(), creds.password.as_str());
+async fn execute_antminer_command(ip: &str, command: &MinerCommand, given: Option<&MinerCredentials>) -> Result<()> {
+    let host = ip;
+    let vendor = MinerVendor::Antminer;
 
     match command {
         MinerCommand::Reboot => {
-            match AntminerWebClient::reboot(&ip, user, pass).await {
-                Ok(_) => {
-                    println!("Antminer reboot SUCCESS for {}", ip);
-                    CommandResult { ip, success: true, error: None }
-                }
-                Err(e) => {
-                    println!("Antminer reboot FAILED for {}: {}", ip, e);
-                    CommandResult { ip, success: false, error: Some(e.to_string()) }
-                }
+            let result = credentials::with_given(host, vendor, given, |c| async move {
+                AntminerWebClient::reboot(host, &c.username, &c.password).await
+            });
+            let result = result.await;
+            match &result {
+                Ok(_) => println!("Antminer reboot SUCCESS for {}", ip),
+                Err(e) => println!("Antminer reboot FAILED for {}: {}", ip, e),
             }
+            result
         }
 
         MinerCommand::BlinkLed => {
-            match AntminerWebClient::set_led(&ip, user, pass, true).await {
-                Ok(_) => CommandResult { ip, success: true, error: None },
-                Err(e) => CommandResult { ip, success: false, error: Some(e.to_string()) },
-            }
+            let result = credentials::with_given(host, vendor, given, |c| async move {
+                AntminerWebClient::set_led(host, &c.username, &c.password, true).await
+            });
+            result.await
         }
 
         MinerCommand::StopBlink => {
-            match AntminerWebClient::set_led(&ip, user, pass, false).await {
-                Ok(_) => CommandResult { ip, success: true, error: None },
-                Err(e) => CommandResult { ip, success: false, error: Some(e.to_string()) },
-            }
+            let result = credentials::with_given(host, vendor, given, |c| async move {
+                AntminerWebClient::set_led(host, &c.username, &c.password, false).await
+            });
+            result.await
         }
 
         MinerCommand::SetPools { pools } => {
-            match AntminerWebClient::set_pools(&ip, user, pass, pools).await {
-                Ok(_) => {
-                    println!("Antminer set_pools SUCCESS for {} (will reboot automatically)", ip);
-                    CommandResult { ip, success: true, error: None }
-                }
-                Err(e) => {
-                    println!("Antminer set_pools FAILED for {}: {}", ip, e);
-                    CommandResult { ip, success: false, error: Some(e.to_string()) }
-                }
+            let result = credentials::with_given(host, vendor, given, |c| {
+                let pools = pools.clone();
+                async move { AntminerWebClient::set_pools(host, &c.username, &c.password, pools).await }
+            });
+            let result = result.await;
+            match &result {
+                Ok(_) => println!("Antminer set_pools SUCCESS for {} (will reboot automatically)", ip),
+                Err(e) => println!("Antminer set_pools FAILED for {}: {}", ip, e),
+            }
+            result
+        }
+
+        MinerCommand::ApplyConfig { pools, power_mode, fan_mode, freq_level } => {
+            let change = ConfChange {
+                pools: pools.clone(),
+                power_mode: power_mode.map(|mode| mode.antminer_mode()),
+                fan_mode: *fan_mode,
+                freq_level: *freq_level,
+            };
+            let change = &change;
+            let result = credentials::with_given(host, vendor, given, |c| async move {
+                AntminerWebClient::apply_conf(host, &c.username, &c.password, change).await
+            });
+            let result = result.await;
+            match &result {
+                Ok(_) => println!("Antminer apply_conf SUCCESS for {} (will reboot automatically)", ip),
+                Err(e) => println!("Antminer apply_conf FAILED for {}: {}", ip, e),
             }
+            result
+        }
+
+        MinerCommand::RestoreConfig { snapshot_id } => {
+            let conf = match backup::restore_content(&BackupStore::open()?, snapshot_id, ip, vendor).await? {
+                SnapshotContent::Antminer { conf } => conf,
+                // `restore_content` checked the vendor
+                other => return Err(wrong_vendor(snapshot_id, other.vendor(), vendor)),
+            };
+            let conf = &conf;
+            let result = credentials::with_given(host, vendor, given, |c| async move {
+                AntminerWebClient::set_conf(host, &c.username, &c.password, conf).await
+            });
+            let result = result.await;
+            match &result {
+                Ok(_) => println!("Antminer restore {} SUCCESS for {} (will reboot automatically)", snapshot_id, ip),
+                Err(e) => println!("Antminer restore {} FAILED for {}: {}", snapshot_id, ip, e),
+            }
+            result
         }
     }
 }
@@ -135,61 +254,101 @@ async fn execute_antminer_command(
 // Whatsminer command dispatch
 // ---------------------------------------------------------------------------
 
-async fn execute_whatsminer_command(
-    ip: String,
-    command: MinerCommand,
-    creds: MinerCredentials,
-) -> CommandResult {
-    let (user, pass) = (creds.username.as_str(), creds.password.as_str());
+async fn execute_whatsminer_command(ip: &str, command: &MinerCommand, given: Option<&MinerCredentials>) -> Result<()> {
+    let host = ip;
+    let vendor = MinerVendor::Whatsminer;
 
     match command {
         MinerCommand::Reboot => {
-            match WhatsminerWebClient::reboot(&ip, user, pass).await {
-                Ok(_) => {
-                    println!("Whatsminer reboot SUCCESS for {}", ip);
-                    CommandResult { ip, success: true, error: None }
-                }
-                Err(e) => {
-                    println!("Whatsminer reboot FAILED for {}: {}", ip, e);
-                    CommandResult { ip, success: false, error: Some(e.to_string()) }
-                }
+            let result = credentials::with_given(host, vendor, given, |c| async move {
+                WhatsminerWebClient::reboot(host, &c.username, &c.password).await
+            });
+            let result = result.await;
+            match &result {
+                Ok(_) => println!("Whatsminer reboot SUCCESS for {}", ip),
+                Err(e) => println!("Whatsminer reboot FAILED for {}: {}", ip, e),
             }
+            result
         }
 
         MinerCommand::BlinkLed => {
-            match WhatsminerWebClient::blink_led(&ip, user, pass, true).await {
-                Ok(_) => CommandResult { ip, success: true, error: None },
-                Err(e) => CommandResult { ip, success: false, error: Some(e.to_string()) },
-            }
+            let result = credentials::with_given(host, vendor, given, |c| async move {
+                WhatsminerWebClient::blink_led(host, &c.username, &c.password, true).await
+            });
+            result.await
         }
 
         MinerCommand::StopBlink => {
-            match WhatsminerWebClient::blink_led(&ip, user, pass, false).await {
-                Ok(_) => CommandResult { ip, success: true, error: None },
-                Err(e) => CommandResult { ip, success: false, error: Some(e.to_string()) },
-            }
+            let result = credentials::with_given(host, vendor, given, |c| async move {
+                WhatsminerWebClient::blink_led(host, &c.username, &c.password, false).await
+            });
+            result.await
         }
 
         MinerCommand::SetPools { pools } => {
             use crate::client::whatsminer_web::WhatsminerPool;
-            let wm_pools: Vec<WhatsminerPool> = pools
-                .into_iter()
-                .map(|p| WhatsminerPool { url: p.url, worker: p.worker, password: p.password })
-                .collect();
-            match WhatsminerWebClient::set_pools(&ip, user, pass, wm_pools).await {
-                Ok(_) => {
-                    println!("Whatsminer set_pools SUCCESS for {} (daemon restarted)", ip);
-                    CommandResult { ip, success: true, error: None }
-                }
-                Err(e) => {
-                    println!("Whatsminer set_pools FAILED for {}: {}", ip, e);
-                    CommandResult { ip, success: false, error: Some(e.to_string()) }
+            let result = credentials::with_given(host, vendor, given, |c| {
+                let wm_pools: Vec<WhatsminerPool> = pools
+                    .iter()
+                    .map(|p| WhatsminerPool { url: p.url.clone(), worker: p.worker.clone(), password: p.password.clone() })
+                    .collect();
+                async move { WhatsminerWebClient::set_pools(host, &c.username, &c.password, wm_pools).await }
+            });
+            let result = result.await;
+            match &result {
+                Ok(_) => println!("Whatsminer set_pools SUCCESS for {} (daemon restarted)", ip),
+                Err(e) => println!("Whatsminer set_pools FAILED for {}: {}", ip, e),
+            }
+            result
+        }
+
+        MinerCommand::ApplyConfig { pools, power_mode, fan_mode, freq_level } => {
+            if fan_mode.is_some() || freq_level.is_some() {
+                return Err(MinerError::UnsupportedCommand("fan and frequency settings on Whatsminer".to_string()));
+            }
+            if let Some(pools) = pools {
+                Box::pin(execute_whatsminer_command(ip, &MinerCommand::SetPools { pools: pools.clone() }, given)).await?;
+            }
+            if let Some(mode) = power_mode {
+                let mode = mode.whatsminer_mode();
+                let result = credentials::with_given(host, vendor, given, |c| async move {
+                    WhatsminerWebClient::set_power_mode(host, &c.username, &c.password, mode).await
+                });
+                if let Err(e) = result.await {
+                    println!("Whatsminer set_power_mode FAILED for {}: {}", ip, e);
+                    return Err(match pools {
+                        Some(_) => MinerError::PartialSuccess(format!("pools set, power mode not: {}", e)),
+                        None => e,
+                    });
                 }
             }
+            Ok(())
+        }
+
+        MinerCommand::RestoreConfig { snapshot_id } => {
+            let forms = match backup::restore_content(&BackupStore::open()?, snapshot_id, ip, vendor).await? {
+                SnapshotContent::Whatsminer { forms } => forms,
+                // `restore_content` checked the vendor
+                other => return Err(wrong_vendor(snapshot_id, other.vendor(), vendor)),
+            };
+            let forms = &forms;
+            let result = credentials::with_given(host, vendor, given, |c| async move {
+                WhatsminerWebClient::set_config_forms(host, &c.username, &c.password, forms).await
+            });
+            let result = result.await;
+            match &result {
+                Ok(_) => println!("Whatsminer restore {} SUCCESS for {} (daemon restarted)", snapshot_id, ip),
+                Err(e) => println!("Whatsminer restore {} FAILED for {}: {}", snapshot_id, ip, e),
+            }
+            result
         }
     }
 }
 
+fn wrong_vendor(snapshot_id: &str, snapshot: MinerVendor, miner: MinerVendor) -> MinerError {
+    MinerError::Backup(format!("snapshot {} is of a {:?}, not a {:?}", snapshot_id, snapshot, miner))
+}
+
 /// Test connection to a single miner
 pub fn test_connection(ip: String) -> String {
     format!("Testing connection to {}", ip)
@@ -199,34 +358,48 @@ pub fn test_connection(ip: String) -> String {
 /// Reads the current config first to preserve fan/frequency settings.
 /// The miner will automatically reboot ~2 minutes after applying the change.
 ///
-/// `pools` must have 1–3 entries.
+/// `pools` must have 1–3 valid entries (see `client::pools`).  Worker
+/// templates are expanded as for `MinerCommand::SetPools`.
 pub async fn set_miner_pools(ip: String, pools: Vec<crate::api::models::PoolConfig>) -> CommandResult {
     use crate::client::antminer_web::AntminerWebClient;
-    let settings = AppSettings::load();
-    let creds = settings.antminer_credentials;
+    let pools = match pools::prepare(&ip, &pools).await {
+        Ok(pools) => pools,
+        Err(e) => return CommandResult::failed(ip, e),
+    };
+    let host = ip.as_str();
+    let result = credentials::with_credentials(host, MinerVendor::Antminer, |c| {
+        let pools = pools.clone();
+        async move { AntminerWebClient::set_pools(host, &c.username, &c.password, pools).await }
+    });
 
-    match AntminerWebClient::set_pools(&ip, &creds.username, &creds.password, pools).await {
-        Ok(_) => CommandResult { ip, success: true, error: None },
-        Err(e) => CommandResult { ip, success: false, error: Some(e.to_string()) },
+    match result.await {
+        Ok(_) => CommandResult::ok(ip),
+        Err(e) => CommandResult::failed(ip, e),
     }
 }
 
-/// Read the currently configured pools from a single Antminer.
+/// Read the currently configured pools from a single miner, to pre-fill the
+/// pool editor.  Vendor comes from the identity cache; a miner whose pools
+/// can't be read gets an empty list (the reason is logged).
 pub async fn get_miner_pools(ip: String) -> Vec<crate::api::models::PoolConfig> {
-    use crate::client::antminer_web::AntminerWebClient;
-    let settings = AppSettings::load();
-    let creds = settings.antminer_credentials;
-
-    match AntminerWebClient::get_pools(&ip, &creds.username, &creds.password).await {
-        Ok(pools) => pools
-            .into_iter()
-            .map(|p| crate::api::models::PoolConfig { url: p.url, worker: p.user, password: p.pass })
-            .collect(),
-        Err(_) => Vec::new(),
+    let vendor = match identity::resolve(&ip).await {
+        Ok(identity) => identity.vendor,
+        Err(e) => {
+            println!("get_pools: miner detection FAILED for {}: {}", ip, e);
+            return Vec::new();
+        }
+    };
+
+    match pools::read_pools(&ip, vendor).await {
+        Ok(current) => current.pools,
+        Err(e) => {
+            println!("get_pools FAILED for {}: {}", ip, e);
+            Vec::new()
+        }
     }
 }
 
-/// Set the power mode on a miner. Detects Whatsminer vs Antminer automatically.
+/// Set the power mode on a miner. Whatsminer vs Antminer comes from the identity cache.
 ///
 /// PowerMode mapping:
 ///   Antminer  — Normal=0, Sleep=1, Lpm=3 (via `miner-mode` field in set_miner_conf.cgi)
@@ -234,54 +407,49 @@ pub async fn get_miner_pools(ip: String) -> Vec<crate::api::models::PoolConfig>
 pub async fn set_miner_power_mode(ip: String, mode: crate::api::models::PowerMode) -> CommandResult {
     use crate::client::antminer_web::AntminerWebClient;
     use crate::client::whatsminer_web::WhatsminerWebClient;
-    use crate::api::models::PowerMode;
-    let settings = AppSettings::load();
-
-    // Detect miner type via CGMiner summary (same logic as execute_single_command)
-    let is_whatsminer = if let Ok(stats) = crate::client::get_summary(&ip, 4028, 500).await {
-        let model = stats.model.as_ref().map(|m| m.to_lowercase()).unwrap_or_default();
-        let firmware = stats.firmware.as_ref().map(|f| f.to_lowercase()).unwrap_or_default();
-        model.contains("whatsminer") || firmware.contains("whatsminer")
-    } else {
-        false
+
+    // Same vendor resolution as execute_single_command
+    let is_whatsminer = match identity::resolve(&ip).await {
+        Ok(identity) => identity.vendor == MinerVendor::Whatsminer,
+        Err(e) => {
+            println!("set_power_mode: miner detection FAILED for {}: {}", ip, e);
+            return CommandResult::failed(ip, e);
+        }
     };
 
+    let host = ip.as_str();
     if is_whatsminer {
-        let creds = settings.whatsminer_credentials;
         // Whatsminer LuCI `miner_type` field — no true Sleep mode, map Sleep→Low
-        let mode_str = match mode {
-            PowerMode::Normal => "Normal",
-            PowerMode::Lpm | PowerMode::Sleep => "Low",
-        };
-        match WhatsminerWebClient::set_power_mode(&ip, &creds.username, &creds.password, mode_str).await {
+        let mode_str = mode.whatsminer_mode();
+        let result = credentials::with_credentials(host, MinerVendor::Whatsminer, |c| async move {
+            WhatsminerWebClient::set_power_mode(host, &c.username, &c.password, mode_str).await
+        });
+        match result.await {
             Ok(_) => {
                 println!("Whatsminer set_power_mode({}) SUCCESS for {}", mode_str, ip);
-                CommandResult { ip, success: true, error: None }
+                CommandResult::ok(ip)
             }
             Err(e) => {
                 println!("Whatsminer set_power_mode FAILED for {}: {}", ip, e);
-                CommandResult { ip, success: false, error: Some(e.to_string()) }
+                CommandResult::failed(ip, e)
             }
         }
     } else {
-        let creds = settings.antminer_credentials;
-        // Antminer miner-mode: Normal=0, Sleep=1, LPM=2
-        let mode_u8: u8 = match mode {
-            PowerMode::Normal => 0,
-            PowerMode::Sleep  => 1,
-            PowerMode::Lpm    => 3,
-        };
-        match AntminerWebClient::set_power_mode(&ip, &creds.username, &creds.password, mode_u8).await {
+        let mode_u8 = mode.antminer_mode();
+        let result = credentials::with_credentials(host, MinerVendor::Antminer, |c| async move {
+            AntminerWebClient::set_power_mode(host, &c.username, &c.password, mode_u8).await
+        });
+        match result.await {
             Ok(_) => {
                 println!(
                     "Antminer set_power_mode(mode={}) SUCCESS for {} (will reboot automatically)",
                     mode_u8, ip
                 );
-                CommandResult { ip, success: true, error: None }
+                CommandResult::ok(ip)
             }
             Err(e) => {
                 println!("Antminer set_power_mode FAILED for {}: {}", ip, e);
-                CommandResult { ip, success: false, error: Some(e.to_string()) }
+                CommandResult::failed(ip, e)
             }
         }
     }
@@ -0,0 +1,65 @@
+use flutter_rust_bridge::frb;
+use crate::client::credentials::{self, AuthFailure};
+use crate::client::rotation::{self, PasswordPolicy, RotationResult};
+use crate::core::config::{AppSettings, CredentialScope, CredentialSet};
+use crate::core::vault;
+use crate::core::MinerId;
+use crate::scanner::ScanSpec;
+
+/// Scoped credential sets, tried before the per-brand credentials
+pub fn list_credential_sets() -> Result<Vec<CredentialSet>, String> {
+    vault::credential_sets().map_err(|e| e.to_string())
+}
+
+/// Replace the scoped credential sets.  Names must be unique and ranges
+/// must parse; remembered logins and auth-failure flags are reset.
+pub fn save_credential_sets(sets: Vec<CredentialSet>) -> Result<(), String> {
+    for (i, set) in sets.iter().enumerate() {
+        let name = set.name.trim();
+        if name.is_empty() {
+            return Err("Credential set name is empty".to_string());
+        }
+        if sets[..i].iter().any(|other| other.name.trim().eq_ignore_ascii_case(name)) {
+            return Err(format!("Duplicate credential set '{}'", name));
+        }
+        match &set.scope {
+            CredentialScope::Range(spec) => {
+                ScanSpec::parse(spec).map_err(|e| format!("Credential set '{}': {}", name, e))?;
+            }
+            CredentialScope::Tag(tag) if tag.trim().is_empty() => {
+                return Err(format!("Credential set '{}' has an empty tag", name));
+            }
+            _ => {}
+        }
+    }
+
+    vault::store_credential_sets(sets).map_err(|e| e.to_string())?;
+    credentials::reset();
+    Ok(())
+}
+
+/// Tags used to scope credential sets to a miner
+#[frb(sync)]
+pub fn get_miner_tags(miner_id: MinerId) -> Vec<String> {
+    AppSettings::load().tags_of(&miner_id).to_vec()
+}
+
+#[frb(sync)]
+pub fn set_miner_tags(miner_id: MinerId, tags: Vec<String>) -> Result<(), String> {
+    let mut settings = AppSettings::load();
+    settings.set_tags(miner_id, tags);
+    settings.save()
+}
+
+/// Miners that refused every matching credential, most recent first
+#[frb(sync)]
+pub fn list_auth_failures() -> Vec<AuthFailure> {
+    credentials::auth_failures()
+}
+
+/// Give every miner in `target_ips` a new web password under `policy`,
+/// verifying each and keeping the new secrets in the vault (which must be
+/// unlocked)
+pub async fn rotate_web_passwords(target_ips: Vec<String>, policy: PasswordPolicy) -> Result<Vec<RotationResult>, String> {
+    rotation::rotate_passwords(target_ips, policy).await.map_err(|e| e.to_string())
+}
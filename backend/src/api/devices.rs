@@ -0,0 +1,63 @@
+use flutter_rust_bridge::frb;
+use crate::core::config::{AppSettings, MinerLocation, OutletLink};
+use crate::core::{Device, MinerId, PduOutlet};
+use crate::devices::{self, DeviceProbe};
+use std::net::IpAddr;
+
+/// Identify the non-miner device at `ip` (SNMP system info, web banner, OUI)
+pub async fn probe_device(ip: String) -> Result<Device, String> {
+    ip.parse::<IpAddr>().map_err(|e| format!("Invalid IP address '{}': {}", ip, e))?;
+
+    let settings = AppSettings::load();
+    let probe = DeviceProbe {
+        community: settings.snmp_community,
+        ..DeviceProbe::default()
+    };
+    let mac = crate::client::lookup_mac_address(&ip).await;
+    Ok(devices::describe(&ip, None, mac, None, &probe).await)
+}
+
+/// Record that PDU `pdu_ip`, outlet `outlet` (as printed, from 1) feeds the
+/// miner.  The monitor attaches the outlet to that miner from the next poll.
+#[frb(sync)]
+pub fn link_miner_outlet(miner_id: MinerId, pdu_ip: String, outlet: u32) -> Result<(), String> {
+    let pdu_ip = pdu_ip.trim().to_string();
+    pdu_ip.parse::<IpAddr>().map_err(|e| format!("Invalid PDU address '{}': {}", pdu_ip, e))?;
+    if outlet == 0 {
+        return Err("Outlet numbers start at 1".to_string());
+    }
+
+    let mut settings = AppSettings::load();
+    settings.link_outlet(miner_id, PduOutlet { pdu_ip, outlet });
+    settings.save()
+}
+
+/// Remove the outlet link of a miner. Returns false if it had none.
+#[frb(sync)]
+pub fn unlink_miner_outlet(miner_id: MinerId) -> Result<bool, String> {
+    let mut settings = AppSettings::load();
+    if !settings.unlink_outlet(&miner_id) {
+        return Ok(false);
+    }
+    settings.save()?;
+    Ok(true)
+}
+
+#[frb(sync)]
+pub fn list_outlet_links() -> Vec<OutletLink> {
+    AppSettings::load().outlet_links
+}
+
+/// Record the rack and position of a miner, used in worker names.  Blank
+/// values remove the location.
+#[frb(sync)]
+pub fn set_miner_location(miner_id: MinerId, rack: String, position: String) -> Result<(), String> {
+    let mut settings = AppSettings::load();
+    settings.set_location(miner_id, &rack, &position);
+    settings.save()
+}
+
+#[frb(sync)]
+pub fn list_miner_locations() -> Vec<MinerLocation> {
+    AppSettings::load().miner_locations
+}
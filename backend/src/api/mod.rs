@@ -4,4 +4,10 @@ pub mod monitor;
 pub mod commands;
 pub mod simple;
 pub mod settings;
+pub mod devices;
+pub mod vault;
+pub mod credentials;
+pub mod pools;
 
+pub mod policy;
+pub mod backup;
@@ -1,9 +1,13 @@
 // Re-export core models - they already have Serialize/Deserialize
-pub use crate::core::{Miner, MinerStats, MinerStatus};
+pub use crate::core::{Miner, MinerId, MinerStats, MinerStatus};
+use crate::core::{ErrorKind, FanMode, MinerError};
+use crate::client::retry::{CommandAttempt, RetryPolicy, RECENT_BOOT_SECS};
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
 
 /// A mining pool configuration entry.
 /// This is the FRB-visible version of `AntminerPool`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PoolConfig {
     pub url: String,
     pub worker: String,
@@ -11,12 +15,56 @@ pub struct PoolConfig {
 }
 
 /// Command to execute on miners
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum MinerCommand {
     Reboot,
     BlinkLed,
     StopBlink,
+    /// Workers may be templates, e.g. `acct.{rack}x{position}`; see
+    /// `client::worker_name`
     SetPools { pools: Vec<PoolConfig> },
+    /// Change several settings at once; `None` keeps the current value.
+    /// Antminers get a single conf write (one reboot).  Whatsminers support
+    /// pools and power mode only.
+    ApplyConfig {
+        pools: Option<Vec<PoolConfig>>,
+        power_mode: Option<PowerMode>,
+        fan_mode: Option<FanMode>,
+        freq_level: Option<u32>,
+    },
+    /// Write back a config snapshot (see `backup`); onto other miners than
+    /// the snapshot's own only if they are the same model
+    RestoreConfig { snapshot_id: String },
+}
+
+impl MinerCommand {
+    /// Whether sending the command twice does the same as sending it once.
+    /// Anything that reboots the miner or restarts its mining daemon (a
+    /// reboot, or an Antminer conf write) is not.  Retries of those are
+    /// guarded by a pre-check.
+    #[frb(ignore)]
+    pub fn is_idempotent(&self) -> bool {
+        !matches!(
+            self,
+            MinerCommand::Reboot
+                | MinerCommand::SetPools { .. }
+                | MinerCommand::ApplyConfig { .. }
+                | MinerCommand::RestoreConfig { .. }
+        )
+    }
+
+    /// Retry policy used when the caller doesn't give one
+    #[frb(ignore)]
+    pub fn default_retry_policy(&self) -> RetryPolicy {
+        match self {
+            // A miner that booted minutes ago was most likely rebooted by
+            // an earlier run of the same batch
+            MinerCommand::Reboot => RetryPolicy { max_attempts: 2, min_uptime_secs: RECENT_BOOT_SECS, ..RetryPolicy::default() },
+            // A lost reply usually means the miner is already going down
+            _ if !self.is_idempotent() => RetryPolicy { max_attempts: 2, ..RetryPolicy::default() },
+            _ => RetryPolicy::default(),
+        }
+    }
 }
 
 /// Power mode for a miner.
@@ -30,17 +78,76 @@ pub enum MinerCommand {
 ///   - Normal → "Normal"
 ///   - Lpm    → "Low"
 ///   - Sleep  → "Low"  (no dedicated sleep mode; falls back to Low)
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PowerMode {
     Normal,
     Lpm,
     Sleep,
 }
 
+impl PowerMode {
+    /// Antminer `miner-mode` value
+    #[frb(ignore)]
+    pub fn antminer_mode(&self) -> u8 {
+        match self {
+            PowerMode::Normal => 0,
+            PowerMode::Sleep => 1,
+            PowerMode::Lpm => 3,
+        }
+    }
+
+    /// Whatsminer LuCI `miner_type` value; there is no sleep mode, so Sleep
+    /// falls back to Low
+    #[frb(ignore)]
+    pub fn whatsminer_mode(&self) -> &'static str {
+        match self {
+            PowerMode::Normal => "Normal",
+            PowerMode::Lpm | PowerMode::Sleep => "Low",
+        }
+    }
+}
+
 /// Result of a batch command execution
 #[derive(Debug, Clone)]
 pub struct CommandResult {
     pub ip: String,
+    /// Stable id of the miner last seen at `ip`, so results can be filed
+    /// against the right box even after DHCP moves it
+    pub miner_id: Option<MinerId>,
     pub success: bool,
     pub error: Option<String>,
+    /// What kind of failure `error` is, e.g. to tell auth from network problems
+    pub error_kind: Option<ErrorKind>,
+    /// Whether sending the same command again may succeed
+    pub retryable: bool,
+    pub suggested_action: Option<String>,
+    /// Every attempt made, in order
+    pub attempts: Vec<CommandAttempt>,
+    /// Why the command (or its retry) wasn't sent, e.g. the miner had
+    /// just rebooted
+    pub skipped: Option<String>,
+}
+
+impl CommandResult {
+    #[frb(ignore)]
+    pub fn ok(ip: String) -> Self {
+        let miner_id = crate::identity::ADDRESSES.id_at(&ip);
+        Self { ip, miner_id, success: true, error: None, error_kind: None, retryable: false, suggested_action: None, attempts: Vec::new(), skipped: None }
+    }
+
+    #[frb(ignore)]
+    pub fn failed(ip: String, error: MinerError) -> Self {
+        let miner_id = crate::identity::ADDRESSES.id_at(&ip);
+        Self {
+            ip,
+            miner_id,
+            success: false,
+            error: Some(error.to_string()),
+            error_kind: Some(error.kind()),
+            retryable: error.is_retryable(),
+            suggested_action: Some(error.suggested_action()),
+            attempts: Vec::new(),
+            skipped: None,
+        }
+    }
 }
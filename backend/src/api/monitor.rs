@@ -1,5 +1,7 @@
 use crate::monitor::{self, MonitorConfig as BackendMonitorConfig};
+use crate::monitor::drift::DriftReport;
 use crate::core::Miner;
+use crate::core::config::AppSettings;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -8,14 +10,18 @@ lazy_static::lazy_static! {
     static ref MONITOR_RX: Arc<Mutex<Option<tokio::sync::mpsc::Receiver<monitor::MonitorEvent>>>> = 
         Arc::new(Mutex::new(None));
     static ref CURRENT_MINERS: Arc<Mutex<Vec<Miner>>> = Arc::new(Mutex::new(Vec::new()));
+    /// Latest drift per IP, as reported by the monitor
+    static ref CURRENT_DRIFT: Arc<Mutex<Vec<DriftReport>>> = Arc::new(Mutex::new(Vec::new()));
 }
 
 /// Start monitoring a list of miners
-/// This initializes the background polling loop
+/// This initializes the background polling loop.  The poll interval follows
+/// `AppSettings::monitor_interval`, including changes saved while running.
 pub async fn start_monitoring(miners: Vec<Miner>) -> anyhow::Result<()> {
-    let config = BackendMonitorConfig::default();
+    let settings = AppSettings::subscribe();
+    let config = BackendMonitorConfig::from_settings(&settings.borrow());
     
-    let rx = monitor::start_monitor(miners, config).await;
+    let rx = monitor::start_monitor_with_settings(miners, config, settings).await;
     
     // Store the receiver globally
     let mut guard = MONITOR_RX.lock().await;
@@ -39,7 +45,11 @@ async fn update_miners_loop() {
                 match event {
                     monitor::MonitorEvent::MinerUpdated(miner) => {
                         let mut miners = CURRENT_MINERS.lock().await;
-                        if let Some(existing) = miners.iter_mut().find(|m| m.ip == miner.ip) {
+                        // Match by stable id first so a moved miner keeps its entry
+                        let position = miner.id.as_ref()
+                            .and_then(|id| miners.iter().position(|m| m.id.as_ref() == Some(id)))
+                            .or_else(|| miners.iter().position(|m| m.ip == miner.ip));
+                        if let Some(existing) = position.map(|i| &mut miners[i]) {
                             *existing = miner;
                         } else {
                             miners.push(miner);
@@ -57,6 +67,23 @@ async fn update_miners_loop() {
                         let mut current = CURRENT_MINERS.lock().await;
                         *current = miners;
                     }
+                    monitor::MonitorEvent::MinerMoved { id, from_ip, to_ip } => {
+                        println!("[monitor] {} moved from {} to {}", id, from_ip, to_ip);
+                        let mut miners = CURRENT_MINERS.lock().await;
+                        miners.retain(|m| !(m.ip == from_ip && m.id.as_ref() == Some(&id)));
+                    }
+                    monitor::MonitorEvent::IpTakenOver { ip, previous, current } => {
+                        println!("[monitor] {} now answers at {} (was {})", current, ip, previous);
+                    }
+                    monitor::MonitorEvent::DriftDetected(report) => {
+                        let mut reports = CURRENT_DRIFT.lock().await;
+                        reports.retain(|r| r.ip != report.ip);
+                        reports.push(report);
+                    }
+                    monitor::MonitorEvent::DriftResolved { ip, .. } => {
+                        let mut reports = CURRENT_DRIFT.lock().await;
+                        reports.retain(|r| r.ip != ip);
+                    }
                 }
             } else {
                 // Channel closed
@@ -75,6 +102,12 @@ pub async fn get_current_miners() -> Vec<Miner> {
     miners.clone()
 }
 
+/// Miners that currently differ from their desired state
+pub async fn get_drift_reports() -> Vec<DriftReport> {
+    let reports = CURRENT_DRIFT.lock().await;
+    reports.clone()
+}
+
 /// Stop the monitor
 pub async fn stop_monitoring() {
     let mut guard = MONITOR_RX.lock().await;
@@ -0,0 +1,85 @@
+use flutter_rust_bridge::frb;
+use crate::api::commands::execute_batch_command;
+use crate::api::models::{CommandResult, MinerCommand};
+use crate::api::monitor::get_drift_reports;
+use crate::client::pools;
+use crate::core::config::{AppSettings, CredentialScope, DesiredState};
+use crate::core::FanMode;
+use crate::monitor::drift;
+use crate::scanner::ScanSpec;
+
+/// Desired state per group, compared with what the miners report
+#[frb(sync)]
+pub fn list_desired_states() -> Vec<DesiredState> {
+    AppSettings::load().desired_states
+}
+
+/// Replace the desired states.  Names must be unique, ranges must parse and
+/// pools must pass the same checks as `SetPools`.  A running monitor picks
+/// up the change on its next poll.
+#[frb(sync)]
+pub fn save_desired_states(states: Vec<DesiredState>) -> Result<(), String> {
+    for (i, state) in states.iter().enumerate() {
+        let name = state.name.trim();
+        if name.is_empty() {
+            return Err("Desired state name is empty".to_string());
+        }
+        if states[..i].iter().any(|other| other.name.trim().eq_ignore_ascii_case(name)) {
+            return Err(format!("Duplicate desired state '{}'", name));
+        }
+        match &state.scope {
+            CredentialScope::Range(spec) => {
+                ScanSpec::parse(spec).map_err(|e| format!("Desired state '{}': {}", name, e))?;
+            }
+            CredentialScope::Tag(tag) if tag.trim().is_empty() => {
+                return Err(format!("Desired state '{}' has an empty tag", name));
+            }
+            _ => {}
+        }
+        if let Some(pools) = &state.pools {
+            pools::validate_template_pools(pools).map_err(|e| format!("Desired state '{}': {}", name, e))?;
+        }
+        if let Some(FanMode::Fixed { percent }) = state.fan_mode {
+            if percent > 100 {
+                return Err(format!("Desired state '{}': fan speed {}% is over 100%", name, percent));
+            }
+        }
+    }
+
+    let mut settings = AppSettings::load();
+    settings.desired_states = states;
+    settings.save()
+}
+
+/// Put the drifted settings of `target_ips` back to their desired state.
+/// Works from the monitor's latest drift reports; miners without drift are
+/// left alone.  Miners needing the same correction are sent it as one batch.
+pub async fn reconcile_drift(target_ips: Vec<String>) -> Vec<CommandResult> {
+    let settings = AppSettings::load();
+    let mut batches: Vec<(MinerCommand, Vec<String>)> = Vec::new();
+
+    for report in get_drift_reports().await {
+        if !target_ips.contains(&report.ip) {
+            continue;
+        }
+        // The policy may have been edited or removed since the report
+        let Some(policy) = settings.desired_states.iter().find(|state| state.name == report.policy) else {
+            println!("[policy] {}: desired state '{}' no longer exists", report.ip, report.policy);
+            continue;
+        };
+        let Some(command) = drift::corrective_command(policy, &report.drifts) else {
+            continue;
+        };
+        match batches.iter_mut().find(|(batch, _)| *batch == command) {
+            Some((_, ips)) => ips.push(report.ip),
+            None => batches.push((command, vec![report.ip])),
+        }
+    }
+
+    let mut results = Vec::new();
+    for (command, ips) in batches {
+        println!("[policy] Reconciling {} miner(s): {:?}", ips.len(), command);
+        results.extend(execute_batch_command(ips, command, None, None).await);
+    }
+    results
+}
@@ -0,0 +1,133 @@
+use flutter_rust_bridge::frb;
+use crate::api::models::PoolConfig;
+use crate::client::pools::{self, PoolSlotChange};
+use crate::core::config::{AppSettings, PoolTemplate};
+use crate::core::{MinerId, Result};
+use crate::identity;
+
+/// Worker names one miner would get
+#[derive(Debug, Clone)]
+pub struct WorkerPreview {
+    pub ip: String,
+    pub miner_id: Option<MinerId>,
+    /// Expanded worker of each pool, in order; empty on error
+    pub workers: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// What `SetPools` would change on one miner
+#[derive(Debug, Clone)]
+pub struct PoolDiff {
+    pub ip: String,
+    pub miner_id: Option<MinerId>,
+    /// Slots that would change; empty on error
+    pub changes: Vec<PoolSlotChange>,
+    /// Whether the current passwords could be read and compared
+    pub passwords_compared: bool,
+    /// Why the miner's pools couldn't be read or prepared
+    pub error: Option<String>,
+}
+
+/// Expand the worker templates of `pools` for every miner in `target_ips`
+/// without changing anything, so the names can be checked before sending
+/// `SetPools`
+pub async fn preview_worker_names(target_ips: Vec<String>, pools: Vec<PoolConfig>) -> Vec<WorkerPreview> {
+    let tasks: Vec<_> = target_ips
+        .into_iter()
+        .map(|ip| {
+            let pools = pools.clone();
+            tokio::spawn(async move {
+                let miner_id = identity::ADDRESSES.id_at(&ip);
+                match pools::prepare(&ip, &pools).await {
+                    Ok(expanded) => WorkerPreview {
+                        ip,
+                        miner_id,
+                        workers: expanded.into_iter().map(|pool| pool.worker).collect(),
+                        error: None,
+                    },
+                    Err(e) => WorkerPreview { ip, miner_id, workers: Vec::new(), error: Some(e.to_string()) },
+                }
+            })
+        })
+        .collect();
+
+    let mut previews = Vec::new();
+    for task in tasks {
+        if let Ok(preview) = task.await {
+            previews.push(preview);
+        }
+    }
+    previews
+}
+
+#[frb(sync)]
+pub fn list_pool_templates() -> Vec<PoolTemplate> {
+    AppSettings::load().pool_templates
+}
+
+/// Replace the pool templates.  Names must be unique and every template
+/// must pass the same checks as `SetPools`.
+#[frb(sync)]
+pub fn save_pool_templates(templates: Vec<PoolTemplate>) -> std::result::Result<(), String> {
+    for (i, template) in templates.iter().enumerate() {
+        let name = template.name.trim();
+        if name.is_empty() {
+            return Err("Pool template name is empty".to_string());
+        }
+        if templates[..i].iter().any(|other| other.name.trim().eq_ignore_ascii_case(name)) {
+            return Err(format!("Duplicate pool template '{}'", name));
+        }
+        pools::validate_template_pools(&template.pools).map_err(|e| format!("Pool template '{}': {}", name, e))?;
+    }
+
+    let mut settings = AppSettings::load();
+    settings.pool_templates = templates;
+    settings.save()
+}
+
+/// Show what sending `pools` with `SetPools` would change, without
+/// changing anything.  Miners that already have these pools are left out;
+/// miners whose pools couldn't be read are listed with the error.
+pub async fn dry_run_set_pools(target_ips: Vec<String>, pools: Vec<PoolConfig>) -> Vec<PoolDiff> {
+    let tasks: Vec<_> = target_ips
+        .into_iter()
+        .map(|ip| {
+            let pools = pools.clone();
+            tokio::spawn(async move {
+                let miner_id = identity::ADDRESSES.id_at(&ip);
+                match diff_miner(&ip, &pools).await {
+                    Ok((changes, passwords_compared)) => {
+                        PoolDiff { ip, miner_id, changes, passwords_compared, error: None }
+                    }
+                    Err(e) => PoolDiff { ip, miner_id, changes: Vec::new(), passwords_compared: false, error: Some(e.to_string()) },
+                }
+            })
+        })
+        .collect();
+
+    let mut diffs = Vec::new();
+    for task in tasks {
+        if let Ok(diff) = task.await {
+            if diff.error.is_some() || !diff.changes.is_empty() {
+                diffs.push(diff);
+            }
+        }
+    }
+    diffs
+}
+
+/// `dry_run_set_pools` with a saved template
+pub async fn dry_run_pool_template(target_ips: Vec<String>, template_name: String) -> std::result::Result<Vec<PoolDiff>, String> {
+    let template = AppSettings::load()
+        .pool_template(&template_name)
+        .cloned()
+        .ok_or_else(|| format!("No pool template named '{}'", template_name))?;
+    Ok(dry_run_set_pools(target_ips, template.pools).await)
+}
+
+async fn diff_miner(ip: &str, pools: &[PoolConfig]) -> Result<(Vec<PoolSlotChange>, bool)> {
+    let intended = pools::prepare(ip, pools).await?;
+    let vendor = identity::resolve(ip).await?.vendor;
+    let current = pools::read_pools(ip, vendor).await?;
+    Ok((pools::diff(&current, &intended), current.passwords_known))
+}
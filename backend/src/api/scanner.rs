@@ -1,47 +1,124 @@
-use crate::scanner::{self, ScanConfig};
+use crate::scanner::{self, ScanConfig, ScanEvent, ScanHandle, ScanSpec};
 use crate::core::Miner;
+use crate::core::config::AppSettings;
+use crate::frb_generated::StreamSink;
+use std::sync::Mutex;
 
-/// Start scanning a network range for miners
+lazy_static::lazy_static! {
+    /// Handle of the scan currently running, if any
+    static ref ACTIVE_SCAN: Mutex<Option<ScanHandle>> = Mutex::new(None);
+}
+
+/// Start scanning a network range for miners.
+/// `ip_range` is a scan spec or the name of a saved range.
 /// Returns all discovered miners after scan completes
 pub async fn start_scan(ip_range: String) -> anyhow::Result<Vec<Miner>> {
-    // Validate IP range first
-    let _ips = scanner::parse_ip_range(&ip_range)
-        .map_err(|e| anyhow::anyhow!("Invalid IP range: {}", e))?;
-    
-    // Use default scan configuration
-    let config = ScanConfig::default();
-    
-    // Start the scan
-    let mut rx: tokio::sync::mpsc::Receiver<scanner::ScanEvent> = scanner::scan_range(&ip_range, config).await?;
-
+    let (mut rx, handle) = begin_scan(&ip_range).await?;
     
     // Collect all found miners
     let mut miners = Vec::new();
     
     while let Some(event) = rx.recv().await {
-        if let scanner::ScanEvent::Found(miner) = event {
+        if let ScanEvent::Found(miner) = event {
             miners.push(miner);
         }
     }
     
+    finish_scan(&handle);
     Ok(miners)
 }
 
-/// Validate an IP range string without starting a scan
+/// Start scanning a network range (spec or saved range name), streaming
+/// every `ScanEvent` as it happens.
+/// The stream ends after `Complete`, or `Cancelled` if `stop_scan` was called.
+pub async fn start_scan_stream(ip_range: String, sink: StreamSink<ScanEvent>) -> anyhow::Result<()> {
+    let (mut rx, handle) = begin_scan(&ip_range).await?;
+    
+    while let Some(event) = rx.recv().await {
+        // The Dart side stopped listening: no point probing the rest
+        if sink.add(event).is_err() {
+            handle.cancel();
+        }
+    }
+    
+    finish_scan(&handle);
+    Ok(())
+}
+
+/// Stop the running scan. Returns false if no scan was running.
+#[flutter_rust_bridge::frb(sync)]
+pub fn stop_scan() -> bool {
+    match ACTIVE_SCAN.lock().unwrap().take() {
+        Some(handle) => {
+            handle.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Validate the range and start a scan, replacing (and cancelling) any running one
+async fn begin_scan(ip_range: &str) -> anyhow::Result<(tokio::sync::mpsc::Receiver<ScanEvent>, ScanHandle)> {
+    let settings = AppSettings::load();
+    
+    // Validate IP range first
+    let spec = ScanSpec::parse(resolve_range(&settings, ip_range))
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    
+    // Thread count, timeout and ports come from the saved settings
+    let config = ScanConfig::from_settings(&settings);
+    
+    let (rx, handle) = scanner::scan_spec_cancellable(&spec, config)?;
+    
+    if let Some(previous) = ACTIVE_SCAN.lock().unwrap().replace(handle.clone()) {
+        previous.cancel();
+    }
+    
+    Ok((rx, handle))
+}
+
+/// The spec to scan for `ip_range`: the saved range's spec if it names one,
+/// otherwise `ip_range` itself
+fn resolve_range<'a>(settings: &'a AppSettings, ip_range: &'a str) -> &'a str {
+    settings
+        .named_range(ip_range)
+        .map(|range| range.spec.as_str())
+        .unwrap_or(ip_range)
+}
+
+/// Forget the handle once its scan is over, unless a newer scan took its place
+fn finish_scan(handle: &ScanHandle) {
+    let mut active = ACTIVE_SCAN.lock().unwrap();
+    if active.as_ref().is_some_and(|current| current.same_scan(handle)) {
+        *active = None;
+    }
+}
+
+/// Validate a scan spec (or the name of a saved range) without starting a scan.
+/// Errors name the offending entry and its line/column.
 pub fn validate_ip_range(range: String) -> Result<String, String> {
-    match scanner::parse_ip_range(&range) {
-        Ok(ips) => Ok(format!("Valid range: {} IPs", ips.len() as usize)),
-        Err(e) => Err(format!("Invalid range: {}", e)),
+    match scanner::parse_ip_range(resolve_range(&AppSettings::load(), &range)) {
+        Ok(ips) => Ok(format!("Valid range: {} IPs", ips.len())),
+        Err(e) => Err(e.to_string()),
     }
 }
 
-/// Detect local network interfaces and return their /24 subnet ranges.
+/// A local IPv4 address that scans can be bound to
+#[derive(Debug, Clone)]
+pub struct LocalInterface {
+    /// Interface name, e.g. "eth0" or "en1"
+    pub name: String,
+    pub ip: String,
+    /// The interface's subnet in CIDR notation, e.g. "10.1.0.0/22"
+    pub range: String,
+}
+
+/// Detect local network interfaces with their IPv4 address and subnet.
 /// Filters out loopback (127.x.x.x) and link-local (169.254.x.x) addresses.
-pub fn detect_local_ranges() -> Vec<String> {
+pub fn list_local_interfaces() -> Vec<LocalInterface> {
     use network_interface::{NetworkInterface, NetworkInterfaceConfig};
-    use std::collections::HashSet;
 
-    let mut ranges = HashSet::new();
+    let mut result = Vec::new();
 
     if let Ok(interfaces) = NetworkInterface::show() {
         for iface in interfaces {
@@ -59,18 +136,65 @@ pub fn detect_local_ranges() -> Vec<String> {
                         continue;
                     }
 
-                    let range = format!(
-                        "{}.{}.{}.1-{}.{}.{}.254",
-                        octets[0], octets[1], octets[2],
-                        octets[0], octets[1], octets[2]
-                    );
-                    ranges.insert(range);
+                    result.push(LocalInterface {
+                        name: iface.name.clone(),
+                        ip: ip.to_string(),
+                        range: subnet_of(ip, v4.netmask),
+                    });
                 }
             }
         }
     }
 
-    let mut result: Vec<String> = ranges.into_iter().collect();
+    result.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.ip.cmp(&b.ip)));
+    result
+}
+
+/// Detect local network interfaces and return their subnets in CIDR notation,
+/// using each interface's real prefix length.
+pub fn detect_local_ranges() -> Vec<String> {
+    let mut result: Vec<String> = list_local_interfaces()
+        .into_iter()
+        .map(|iface| iface.range)
+        .collect();
     result.sort();
+    result.dedup();
     result
 }
+
+/// CIDR of the network `ip` belongs to.  Interfaces that report no netmask
+/// are assumed to be on a /24.
+fn subnet_of(ip: std::net::Ipv4Addr, netmask: Option<std::net::Ipv4Addr>) -> String {
+    let prefix = netmask.map(|mask| u32::from(mask).leading_ones()).unwrap_or(24);
+    let network = ipnetwork::Ipv4Network::new(ip, prefix as u8)
+        .map(|net| net.network())
+        .unwrap_or(ip);
+    format!("{}/{}", network, prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subnet_uses_real_prefix() {
+        let ip = "10.1.2.77".parse().unwrap();
+        assert_eq!(subnet_of(ip, Some("255.255.252.0".parse().unwrap())), "10.1.0.0/22");
+        assert_eq!(subnet_of(ip, Some("255.255.255.0".parse().unwrap())), "10.1.2.0/24");
+        assert_eq!(subnet_of(ip, None), "10.1.2.0/24");
+    }
+
+    #[test]
+    fn test_resolve_named_range() {
+        let settings = AppSettings {
+            named_ranges: vec![crate::core::config::NamedRange {
+                name: "Container A".into(),
+                spec: "10.9.0.0/24, !10.9.0.1".into(),
+                source: None,
+            }],
+            ..Default::default()
+        };
+        assert_eq!(resolve_range(&settings, "container a"), "10.9.0.0/24, !10.9.0.1");
+        assert_eq!(resolve_range(&settings, "10.1.0.0/24"), "10.1.0.0/24");
+    }
+}
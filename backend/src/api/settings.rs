@@ -1,12 +1,23 @@
 use flutter_rust_bridge::frb;
 use crate::core::config::AppSettings;
+use crate::core::vault;
 
+/// Saved settings.  Credentials come from the vault if there is one, and
+/// are blank while it is locked.
 #[frb(sync)]
 pub fn get_app_settings() -> AppSettings {
-    AppSettings::load()
+    let mut settings = AppSettings::load();
+    vault::fill_credentials(&mut settings);
+    settings
 }
 
+/// Save settings.  With an unlocked vault the credentials go into it; the
+/// settings file only holds them while no vault exists.
 #[frb(sync)]
 pub fn save_app_settings(settings: AppSettings) -> Result<(), String> {
-    settings.save()
+    vault::store_credentials(&settings).map_err(|e| e.to_string())?;
+    settings.save()?;
+    // Named ranges may have gained or lost a source interface
+    crate::client::source::apply_settings(&settings);
+    Ok(())
 }
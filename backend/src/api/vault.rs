@@ -0,0 +1,29 @@
+use flutter_rust_bridge::frb;
+use crate::core::vault::{self, VaultStatus};
+
+/// Whether a credential vault exists and is unlocked
+#[frb(sync)]
+pub fn get_vault_status() -> VaultStatus {
+    vault::status()
+}
+
+/// Create the credential vault under `passphrase`, moving the credentials
+/// out of the plaintext settings file.  The vault starts unlocked.
+pub fn create_vault(passphrase: String) -> Result<(), String> {
+    vault::create(&passphrase).map_err(|e| e.to_string())
+}
+
+/// Unlock the vault for this session
+pub fn unlock_vault(passphrase: String) -> Result<(), String> {
+    vault::unlock(&passphrase).map_err(|e| e.to_string())
+}
+
+/// Forget the vault key; commands fail until it is unlocked again
+#[frb(sync)]
+pub fn lock_vault() {
+    vault::lock();
+}
+
+pub fn change_vault_passphrase(current: String, new_passphrase: String) -> Result<(), String> {
+    vault::change_passphrase(&current, &new_passphrase).map_err(|e| e.to_string())
+}
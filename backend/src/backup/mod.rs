@@ -0,0 +1,352 @@
+//! Config backups: snapshots of a miner's whole configuration, a diff
+//! between two of them and a restore onto one or many miners.
+//!
+//! A snapshot holds what the web UI would write back: the full
+//! `get_miner_conf.cgi` JSON of an Antminer, or the values of the LuCI pool
+//! and power forms of a Whatsminer.  Snapshots are kept per miner identity
+//! (`MinerId`), so a miner moved by DHCP keeps its history; see `store`.
+//!
+//! A restore goes through the batch executor as `MinerCommand::RestoreConfig`
+//! and only onto miners of the snapshot's vendor.  Network settings aren't
+//! part of a snapshot; restoring onto other miners additionally needs the
+//! same model, and keeps each target's own pool workers.
+
+use crate::client::antminer_web::AntminerWebClient;
+use crate::client::{credentials, pools};
+use crate::client::whatsminer_web::WhatsminerWebClient;
+use crate::client::{identify, DEFAULT_PORT, DEFAULT_TIMEOUT_MS};
+use crate::core::{MinerError, MinerId, MinerVendor, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub mod store;
+
+pub use store::BackupStore;
+
+/// Format of the snapshot files; bumped when it changes incompatibly
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Shown instead of password values in a diff
+const MASKED: &str = "********";
+
+/// A miner's configuration as read from its web UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "vendor")]
+pub enum SnapshotContent {
+    /// `get_miner_conf.cgi`, verbatim
+    Antminer { conf: serde_json::Value },
+    /// Form values per LuCI page, e.g. `network/cgminer`
+    Whatsminer { forms: BTreeMap<String, BTreeMap<String, String>> },
+}
+
+impl SnapshotContent {
+    pub fn vendor(&self) -> MinerVendor {
+        match self {
+            SnapshotContent::Antminer { .. } => MinerVendor::Antminer,
+            SnapshotContent::Whatsminer { .. } => MinerVendor::Whatsminer,
+        }
+    }
+
+    /// Every setting as `key -> value`: JSON paths such as `pools[0].url`
+    /// for Antminers, `page#field` for Whatsminers
+    pub fn settings(&self) -> BTreeMap<String, String> {
+        let mut settings = BTreeMap::new();
+        match self {
+            SnapshotContent::Antminer { conf } => flatten_json("", conf, &mut settings),
+            SnapshotContent::Whatsminer { forms } => {
+                for (page, values) in forms {
+                    for (name, value) in values {
+                        settings.insert(format!("{}#{}", page, name), value.clone());
+                    }
+                }
+            }
+        }
+        settings
+    }
+
+    /// The same configuration with the pool workers replaced by `workers`,
+    /// by pool position.  Pools past the end of `workers` keep theirs.
+    pub fn with_workers(&self, workers: &[String]) -> SnapshotContent {
+        let mut content = self.clone();
+        match &mut content {
+            SnapshotContent::Antminer { conf } => {
+                if let Some(pools) = conf.get_mut("pools").and_then(|pools| pools.as_array_mut()) {
+                    for (pool, worker) in pools.iter_mut().zip(workers) {
+                        if let Some(pool) = pool.as_object_mut() {
+                            pool.insert("user".to_string(), serde_json::Value::String(worker.clone()));
+                        }
+                    }
+                }
+            }
+            SnapshotContent::Whatsminer { forms } => {
+                for values in forms.values_mut() {
+                    for (i, worker) in workers.iter().enumerate() {
+                        if let Some(value) = values.get_mut(&format!("cbid.pools.default.pool{}user", i + 1)) {
+                            *value = worker.clone();
+                        }
+                    }
+                }
+            }
+        }
+        content
+    }
+}
+
+/// One stored configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigSnapshot {
+    pub format: u32,
+    /// Assigned by the store: `<miner>/<unix ms>`
+    #[serde(default)]
+    pub id: String,
+    pub miner_id: MinerId,
+    /// Address the miner had when the snapshot was taken
+    pub ip: String,
+    pub model: Option<String>,
+    /// Unix timestamp in milliseconds
+    pub taken_at: u64,
+    pub note: Option<String>,
+    pub content: SnapshotContent,
+}
+
+impl ConfigSnapshot {
+    pub fn info(&self) -> SnapshotInfo {
+        SnapshotInfo {
+            id: self.id.clone(),
+            miner_id: self.miner_id.clone(),
+            ip: self.ip.clone(),
+            vendor: self.content.vendor(),
+            model: self.model.clone(),
+            taken_at: self.taken_at,
+            note: self.note.clone(),
+        }
+    }
+}
+
+/// A snapshot without its content, for listings
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotInfo {
+    pub id: String,
+    pub miner_id: MinerId,
+    pub ip: String,
+    pub vendor: MinerVendor,
+    pub model: Option<String>,
+    /// Unix timestamp in milliseconds
+    pub taken_at: u64,
+    pub note: Option<String>,
+}
+
+/// One setting that differs between two snapshots; `None` where a side
+/// doesn't have it.  Passwords are masked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    pub key: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Settings that differ from `before` to `after`, by key
+pub fn diff(before: &SnapshotContent, after: &SnapshotContent) -> Vec<ConfigChange> {
+    let before = before.settings();
+    let after = after.settings();
+    let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter(|key| before.get(*key) != after.get(*key))
+        .map(|key| {
+            let shown = |value: Option<&String>| {
+                value.map(|v| if is_secret(key) && !v.is_empty() { MASKED.to_string() } else { v.clone() })
+            };
+            ConfigChange { key: key.clone(), before: shown(before.get(key)), after: shown(after.get(key)) }
+        })
+        .collect()
+}
+
+/// Pool passwords: `pass` in the Antminer conf, `poolNpw` on Whatsminers
+fn is_secret(key: &str) -> bool {
+    let name = key.rsplit(['.', '#']).next().unwrap_or(key).to_ascii_lowercase();
+    name.contains("pass") || name.ends_with("pw")
+}
+
+fn flatten_json(prefix: &str, value: &serde_json::Value, out: &mut BTreeMap<String, String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten_json(&path, value, out);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (i, value) in items.iter().enumerate() {
+                flatten_json(&format!("{}[{}]", prefix, i), value, out);
+            }
+        }
+        serde_json::Value::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
+
+/// Read the whole configuration of the miner at `ip`.  The miner is asked
+/// for its identity first, so the snapshot is filed under the device that
+/// actually answers at `ip` now.
+pub async fn capture(ip: &str, note: Option<String>) -> Result<ConfigSnapshot> {
+    let report = identify(ip, DEFAULT_PORT, DEFAULT_TIMEOUT_MS).await?;
+    crate::identity::record_report(ip, DEFAULT_PORT, &report);
+    let miner_id = MinerId::derive(&report.stats)
+        .ok_or_else(|| MinerError::Backup(format!("{} reports no MAC, serial or hostname to file the backup under", ip)))?;
+
+    let vendor = report.vendor;
+    let content = match vendor {
+        MinerVendor::Antminer => {
+            let conf = credentials::with_credentials(ip, vendor, |c| async move {
+                AntminerWebClient::get_conf_value(ip, &c.username, &c.password).await
+            })
+            .await?;
+            SnapshotContent::Antminer { conf }
+        }
+        MinerVendor::Whatsminer => {
+            let forms = credentials::with_credentials(ip, vendor, |c| async move {
+                WhatsminerWebClient::get_config_forms(ip, &c.username, &c.password).await
+            })
+            .await?;
+            SnapshotContent::Whatsminer { forms }
+        }
+    };
+
+    Ok(ConfigSnapshot {
+        format: FORMAT_VERSION,
+        id: String::new(),
+        miner_id,
+        ip: ip.to_string(),
+        model: report.stats.model,
+        taken_at: store::now_ms(),
+        note: note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty()),
+        content,
+    })
+}
+
+/// What restoring snapshot `snapshot_id` writes to the miner at `ip`, a
+/// `vendor` miner.  Onto the miner it was taken from, the snapshot as is.
+/// Onto another miner only if that is the same model (frequency and voltage
+/// settings don't carry over between models), and with the target's own
+/// pool workers kept.
+pub async fn restore_content(store: &BackupStore, snapshot_id: &str, ip: &str, vendor: MinerVendor) -> Result<SnapshotContent> {
+    let snapshot = store.load(snapshot_id)?;
+    if snapshot.content.vendor() != vendor {
+        return Err(MinerError::Backup(format!(
+            "snapshot {} is of a {:?}, not a {:?}",
+            snapshot_id,
+            snapshot.content.vendor(),
+            vendor
+        )));
+    }
+
+    let report = identify(ip, DEFAULT_PORT, DEFAULT_TIMEOUT_MS).await?;
+    if MinerId::derive(&report.stats).as_ref() == Some(&snapshot.miner_id) {
+        return Ok(snapshot.content);
+    }
+
+    let model = report.stats.model.as_deref().map(str::trim);
+    match (snapshot.model.as_deref().map(str::trim), model) {
+        (Some(theirs), Some(ours)) if theirs.eq_ignore_ascii_case(ours) => {}
+        (theirs, ours) => {
+            return Err(MinerError::Backup(format!(
+                "snapshot {} is of a {}, {} is a {}",
+                snapshot_id,
+                theirs.unwrap_or("miner of unknown model"),
+                ip,
+                ours.unwrap_or("miner of unknown model")
+            )))
+        }
+    }
+
+    let current = pools::read_pools(ip, vendor).await?;
+    let workers: Vec<String> = current.pools.into_iter().map(|pool| pool.worker).collect();
+    Ok(snapshot.content.with_workers(&workers))
+}
+
+/// Take a snapshot of the miner at `ip` and keep it
+pub async fn backup(store: &BackupStore, ip: &str, note: Option<String>) -> Result<SnapshotInfo> {
+    let mut snapshot = capture(ip, note).await?;
+    store.save(&mut snapshot)?;
+    println!("[backup] {} ({}) saved as {}", ip, snapshot.miner_id, snapshot.id);
+    Ok(snapshot.info())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn antminer(fan_pwm: &str, pass: &str) -> SnapshotContent {
+        SnapshotContent::Antminer {
+            conf: serde_json::json!({
+                "pools": [{ "url": "stratum+tcp://pool.example:3333", "user": "acct.1", "pass": pass }],
+                "bitmain-fan-ctrl": true,
+                "bitmain-fan-pwm": fan_pwm,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_with_workers() {
+        let content = antminer("80", "x").with_workers(&["acct.B12x3".to_string()]);
+        assert_eq!(content.settings().get("pools[0].user").map(String::as_str), Some("acct.B12x3"));
+        assert_eq!(content.settings().get("pools[0].pass").map(String::as_str), Some("x"));
+
+        let forms = SnapshotContent::Whatsminer {
+            forms: BTreeMap::from([(
+                "network/btminer".to_string(),
+                BTreeMap::from([
+                    ("cbid.pools.default.pool1user".to_string(), "acct.A1".to_string()),
+                    ("cbid.pools.default.pool2user".to_string(), "acct.A1b".to_string()),
+                ]),
+            )]),
+        };
+        let settings = forms.with_workers(&["acct.C7".to_string()]).settings();
+        assert_eq!(settings["network/btminer#cbid.pools.default.pool1user"], "acct.C7");
+        assert_eq!(settings["network/btminer#cbid.pools.default.pool2user"], "acct.A1b");
+    }
+
+    #[test]
+    fn test_diff() {
+        assert!(diff(&antminer("80", "x"), &antminer("80", "x")).is_empty());
+
+        let changes = diff(&antminer("80", "x"), &antminer("100", "secret"));
+        assert_eq!(
+            changes,
+            vec![
+                ConfigChange { key: "bitmain-fan-pwm".into(), before: Some("80".into()), after: Some("100".into()) },
+                ConfigChange { key: "pools[0].pass".into(), before: Some(MASKED.into()), after: Some(MASKED.into()) },
+            ]
+        );
+
+        let form = |coin: &str| SnapshotContent::Whatsminer {
+            forms: BTreeMap::from([(
+                "network/cgminer".to_string(),
+                BTreeMap::from([
+                    ("cbid.pools.default.coin_type".to_string(), coin.to_string()),
+                    ("cbid.pools.default.pool1pw".to_string(), "x".to_string()),
+                ]),
+            )]),
+        };
+        let changes = diff(&form("BTC"), &form("BCH"));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].key, "network/cgminer#cbid.pools.default.coin_type");
+        assert!(is_secret("network/cgminer#cbid.pools.default.pool1pw"));
+    }
+
+    #[test]
+    fn test_snapshot_format() {
+        // The vendor tag keeps the file self-describing
+        let json = serde_json::to_value(antminer("80", "x")).unwrap();
+        assert_eq!(json["vendor"], "Antminer");
+        let back: SnapshotContent = serde_json::from_value(json).unwrap();
+        assert_eq!(back.vendor(), MinerVendor::Antminer);
+    }
+}
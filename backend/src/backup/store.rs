@@ -0,0 +1,242 @@
+//! Local store for config snapshots.
+//!
+//! One JSON file per snapshot under `<config dir>/backups/<miner>/<unix ms>.json`,
+//! where `<miner>` is the `MinerId` made file-name safe.  The snapshot id is
+//! that relative path without the extension.  Files are written through a
+//! temporary file and are readable by the owner only, since the Antminer
+//! conf carries the pool passwords.  Only the newest
+//! `MAX_SNAPSHOTS_PER_MINER` snapshots of a miner are kept.
+
+use super::{ConfigSnapshot, SnapshotInfo, FORMAT_VERSION};
+use crate::core::config::AppSettings;
+use crate::core::{MinerError, MinerId, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Older snapshots of a miner are pruned beyond this many
+pub const MAX_SNAPSHOTS_PER_MINER: usize = 50;
+
+pub struct BackupStore {
+    root: PathBuf,
+}
+
+impl BackupStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The store next to `app_settings.json`
+    pub fn open() -> Result<Self> {
+        AppSettings::config_dir()
+            .map(|dir| Self::new(dir.join("backups")))
+            .ok_or_else(|| MinerError::Backup("no config directory".to_string()))
+    }
+
+    /// Keep `snapshot`, assigning its id
+    pub fn save(&self, snapshot: &mut ConfigSnapshot) -> Result<()> {
+        let miner_dir = dir_name(&snapshot.miner_id);
+        let dir = self.root.join(&miner_dir);
+        fs::create_dir_all(&dir)?;
+
+        // Two snapshots of a miner within the same millisecond
+        while dir.join(format!("{}.json", snapshot.taken_at)).exists() {
+            snapshot.taken_at += 1;
+        }
+        snapshot.id = format!("{}/{}", miner_dir, snapshot.taken_at);
+        let json = serde_json::to_vec_pretty(snapshot)?;
+        write_private(&dir.join(format!("{}.json", snapshot.taken_at)), &json)?;
+
+        self.prune(&dir);
+        Ok(())
+    }
+
+    pub fn load(&self, id: &str) -> Result<ConfigSnapshot> {
+        let path = self.path_of(id)?;
+        let raw = fs::read(&path).map_err(|_| MinerError::Backup(format!("no snapshot {}", id)))?;
+        let mut snapshot: ConfigSnapshot = serde_json::from_slice(&raw)
+            .map_err(|e| MinerError::Backup(format!("snapshot {} is unreadable: {}", id, e)))?;
+        if snapshot.format > FORMAT_VERSION {
+            return Err(MinerError::Backup(format!("snapshot {} is from a newer version (format {})", id, snapshot.format)));
+        }
+        snapshot.id = id.to_string();
+        Ok(snapshot)
+    }
+
+    /// Snapshots of `miner_id` (or of every miner), newest first
+    pub fn list(&self, miner_id: Option<&MinerId>) -> Result<Vec<SnapshotInfo>> {
+        let dirs = match miner_id {
+            Some(id) => vec![dir_name(id)],
+            None => match fs::read_dir(&self.root) {
+                Ok(entries) => entries
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().is_dir())
+                    .filter_map(|entry| entry.file_name().into_string().ok())
+                    .collect(),
+                Err(_) => Vec::new(),
+            },
+        };
+
+        let mut infos = Vec::new();
+        for dir in dirs {
+            for stamp in stamps(&self.root.join(&dir)) {
+                let id = format!("{}/{}", dir, stamp);
+                match self.load(&id) {
+                    // Ids that sanitise to the same directory are told apart here
+                    Ok(snapshot) if miner_id.is_none_or(|wanted| *wanted == snapshot.miner_id) => infos.push(snapshot.info()),
+                    Ok(_) => {}
+                    Err(e) => eprintln!("[backup] skipping {}: {}", id, e),
+                }
+            }
+        }
+        infos.sort_by_key(|info| std::cmp::Reverse(info.taken_at));
+        Ok(infos)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<()> {
+        fs::remove_file(self.path_of(id)?).map_err(|_| MinerError::Backup(format!("no snapshot {}", id)))
+    }
+
+    /// File of snapshot `id`; rejects anything but `<miner>/<digits>` so an
+    /// id can't point outside the store
+    fn path_of(&self, id: &str) -> Result<PathBuf> {
+        let invalid = || MinerError::Backup(format!("invalid snapshot id '{}'", id));
+        let (dir, stamp) = id.split_once('/').ok_or_else(invalid)?;
+        let dir_ok = !dir.is_empty() && !dir.starts_with('.') && dir.chars().all(is_safe_char);
+        let stamp_ok = !stamp.is_empty() && stamp.chars().all(|c| c.is_ascii_digit());
+        if !dir_ok || !stamp_ok {
+            return Err(invalid());
+        }
+        Ok(self.root.join(dir).join(format!("{}.json", stamp)))
+    }
+
+    fn prune(&self, dir: &Path) {
+        let stamps = stamps(dir);
+        for stamp in stamps.iter().skip(MAX_SNAPSHOTS_PER_MINER) {
+            if let Err(e) = fs::remove_file(dir.join(format!("{}.json", stamp))) {
+                eprintln!("[backup] could not prune {}/{}: {}", dir.display(), stamp, e);
+            }
+        }
+    }
+}
+
+/// Timestamps of the snapshots in `dir`, newest first
+fn stamps(dir: &Path) -> Vec<u64> {
+    let mut stamps: Vec<u64> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter_map(|name| name.strip_suffix(".json").and_then(|stamp| stamp.parse().ok()))
+                .collect()
+        })
+        .unwrap_or_default();
+    stamps.sort_unstable_by(|a, b| b.cmp(a));
+    stamps
+}
+
+fn is_safe_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+}
+
+/// `mac:AA:BB:…` → `mac_AA_BB_…`
+fn dir_name(id: &MinerId) -> String {
+    let name: String = id.as_str().chars().map(|c| if is_safe_char(c) { c } else { '_' }).collect();
+    name.trim_start_matches('.').to_string()
+}
+
+fn write_private(path: &Path, content: &[u8]) -> Result<()> {
+    let tmp = path.with_extension("json.tmp");
+    {
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        use std::io::Write;
+        let mut file = options.open(&tmp)?;
+        file.write_all(content)?;
+        file.sync_all()?;
+    }
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Current Unix time in milliseconds
+pub fn now_ms() -> u64 {
+    SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::SnapshotContent;
+
+    fn store(name: &str) -> BackupStore {
+        let root = std::env::temp_dir().join(format!("backup-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        BackupStore::new(root)
+    }
+
+    fn snapshot(id: &MinerId, taken_at: u64) -> ConfigSnapshot {
+        ConfigSnapshot {
+            format: FORMAT_VERSION,
+            id: String::new(),
+            miner_id: id.clone(),
+            ip: "10.0.0.5".into(),
+            model: Some("Antminer S19".into()),
+            taken_at,
+            note: None,
+            content: SnapshotContent::Antminer { conf: serde_json::json!({ "bitmain-fan-pwm": "100" }) },
+        }
+    }
+
+    #[test]
+    fn test_save_load_list() {
+        let store = store("list");
+        let a = MinerId::from_mac("aa:bb:cc:00:11:22").unwrap();
+        let b = MinerId::from_serial("SN2").unwrap();
+
+        let mut first = snapshot(&a, 1000);
+        store.save(&mut first).unwrap();
+        assert_eq!(first.id, "mac_AA_BB_CC_00_11_22/1000");
+        // Same millisecond: moved on instead of overwritten
+        let mut second = snapshot(&a, 1000);
+        store.save(&mut second).unwrap();
+        assert_eq!(second.id, "mac_AA_BB_CC_00_11_22/1001");
+        store.save(&mut snapshot(&b, 500)).unwrap();
+
+        assert_eq!(store.load(&first.id).unwrap(), first);
+        let listed: Vec<String> = store.list(Some(&a)).unwrap().into_iter().map(|info| info.id).collect();
+        assert_eq!(listed, vec![second.id.clone(), first.id.clone()]);
+        assert_eq!(store.list(None).unwrap().len(), 3);
+
+        store.delete(&first.id).unwrap();
+        assert!(matches!(store.load(&first.id), Err(MinerError::Backup(_))));
+        let _ = fs::remove_dir_all(&store.root);
+    }
+
+    #[test]
+    fn test_ids_stay_inside_store() {
+        let store = store("ids");
+        for id in ["../1000", "mac_AA/../../etc", "mac_AA/1000.json", "mac_AA", "/1000", "a/b/1000"] {
+            assert!(store.path_of(id).is_err(), "{} accepted", id);
+        }
+        assert!(store.path_of("sn_SN1/1000").is_ok());
+    }
+
+    #[test]
+    fn test_prune() {
+        let store = store("prune");
+        let id = MinerId::from_serial("SN1").unwrap();
+        for stamp in 0..(MAX_SNAPSHOTS_PER_MINER as u64 + 3) {
+            store.save(&mut snapshot(&id, stamp)).unwrap();
+        }
+        let listed = store.list(Some(&id)).unwrap();
+        assert_eq!(listed.len(), MAX_SNAPSHOTS_PER_MINER);
+        assert_eq!(listed.last().unwrap().taken_at, 3);
+        let _ = fs::remove_dir_all(&store.root);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::core::Result;
-use reqwest::Client;
+use crate::client::http_pool::{self, WebApi};
+use crate::core::{FanMode, MinerError, Result};
+use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
 
 // ---------------------------------------------------------------------------
 // Public data types
@@ -15,6 +15,36 @@ pub struct AntminerPool {
     pub pass: String,
 }
 
+/// Response from `/cgi-bin/get_system_info.cgi`: device inventory and
+/// network configuration.  Every field is optional — older firmware omits
+/// some of them and names the serial `serial_no` instead of `serinum`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AntminerSystemInfo {
+    #[serde(default)]
+    pub minertype: Option<String>,
+    /// "DHCP" or "Static"
+    #[serde(default)]
+    pub nettype: Option<String>,
+    #[serde(default)]
+    pub macaddr: Option<String>,
+    #[serde(default)]
+    pub hostname: Option<String>,
+    #[serde(default)]
+    pub ipaddress: Option<String>,
+    #[serde(default)]
+    pub netmask: Option<String>,
+    #[serde(default)]
+    pub gateway: Option<String>,
+    /// Space-separated list, often with a trailing space
+    #[serde(default)]
+    pub dnsservers: Option<String>,
+    /// "Release" or a custom/test build marker
+    #[serde(default)]
+    pub firmware_type: Option<String>,
+    #[serde(default, alias = "serial_no")]
+    pub serinum: Option<String>,
+}
+
 // ---------------------------------------------------------------------------
 // Internal response types
 // ---------------------------------------------------------------------------
@@ -26,7 +56,7 @@ pub struct AntminerPool {
 ///
 /// Key observations from real device (Antminer, firmware 2024):
 ///   - Power mode is stored as **`"bitmain-work-mode"`** with a **string** value:
-///       "0" = Normal, "1" = Sleep, "3" = Low Power Mode (LPM)
+///     "0" = Normal, "1" = Sleep, "3" = Low Power Mode (LPM)
 ///   - `"freq-level"` is actually `"bitmain-freq-level"` on real firmware.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct MinerConf {
@@ -72,10 +102,88 @@ fn default_freq_level() -> String {
     "100".to_string()
 }
 
+/// Settings read from the miner conf that the monitor tracks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfSettings {
+    /// 0 = Normal, 1 = Sleep, 3 = LPM
+    pub power_mode: u8,
+    pub fan_mode: FanMode,
+    pub freq_level: Option<u32>,
+}
+
+impl ConfSettings {
+    fn from_conf(conf: &serde_json::Value) -> Self {
+        let number = |key: &str| match &conf[key] {
+            serde_json::Value::String(s) => s.trim().parse::<u32>().ok(),
+            value => value.as_u64().map(|n| n as u32),
+        };
+        let fan_ctrl = match &conf["bitmain-fan-ctrl"] {
+            serde_json::Value::Bool(b) => *b,
+            serde_json::Value::String(s) => s == "true" || s == "1",
+            _ => false,
+        };
+        Self {
+            power_mode: number("bitmain-work-mode").unwrap_or(0) as u8,
+            fan_mode: match number("bitmain-fan-pwm") {
+                Some(percent) if fan_ctrl => FanMode::Fixed { percent },
+                _ => FanMode::Auto,
+            },
+            freq_level: number("bitmain-freq-level"),
+        }
+    }
+}
+
+/// Conf fields to change in one write; `None` keeps the current value
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfChange {
+    pub pools: Option<Vec<crate::api::models::PoolConfig>>,
+    /// 0 = Normal, 1 = Sleep, 3 = LPM
+    pub power_mode: Option<u8>,
+    pub fan_mode: Option<FanMode>,
+    pub freq_level: Option<u32>,
+}
+
+impl ConfChange {
+    fn apply_to(&self, conf: &mut serde_json::Value) {
+        let Some(obj) = conf.as_object_mut() else { return };
+        if let Some(pools) = &self.pools {
+            let pools = pools
+                .iter()
+                .take(3)
+                .map(|p| serde_json::json!({ "url": p.url, "user": p.worker, "pass": p.password }))
+                .collect();
+            obj.insert("pools".to_string(), serde_json::Value::Array(pools));
+        }
+        if let Some(mode) = self.power_mode {
+            // Read as `bitmain-work-mode`, written as `miner-mode` (see set_power_mode)
+            obj.remove("bitmain-work-mode");
+            obj.insert("miner-mode".to_string(), serde_json::Value::String(mode.to_string()));
+        }
+        match self.fan_mode {
+            Some(FanMode::Auto) => {
+                obj.insert("bitmain-fan-ctrl".to_string(), serde_json::Value::Bool(false));
+            }
+            Some(FanMode::Fixed { percent }) => {
+                obj.insert("bitmain-fan-ctrl".to_string(), serde_json::Value::Bool(true));
+                obj.insert("bitmain-fan-pwm".to_string(), serde_json::Value::String(percent.to_string()));
+            }
+            None => {}
+        }
+        if let Some(level) = self.freq_level {
+            obj.insert("bitmain-freq-level".to_string(), serde_json::Value::String(level.to_string()));
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Client
 // ---------------------------------------------------------------------------
 
+lazy_static::lazy_static! {
+    /// Last Digest challenge per host, reused with an incrementing `nc`.
+    static ref DIGEST_CHALLENGES: DashMap<String, digest_auth::WwwAuthenticateHeader> = DashMap::new();
+}
+
 /// HTTP client for the Antminer web API (port 80, HTTP Digest Auth).
 pub struct AntminerWebClient;
 
@@ -84,13 +192,6 @@ impl AntminerWebClient {
     // Private helpers
     // -----------------------------------------------------------------------
 
-    fn build_client() -> Result<Client> {
-        Client::builder()
-            .timeout(Duration::from_secs(8))
-            .build()
-            .map_err(|e| format!("Failed to build HTTP client: {}", e).into())
-    }
-
     /// Perform a GET request with HTTP Digest Auth.
     async fn digest_get(
         ip: &str,
@@ -98,58 +199,7 @@ impl AntminerWebClient {
         username: &str,
         password: &str,
     ) -> Result<String> {
-        let url = format!("http://{}{}", ip, path);
-        let client = Self::build_client()?;
-
-        // Step 1 – unauthenticated probe to get the 401 challenge
-        let resp = client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| format!("GET {} failed: {}", url, e))?;
-
-        if resp.status().is_success() {
-            return Ok(resp.text().await.unwrap_or_default());
-        }
-
-        if resp.status() != reqwest::StatusCode::UNAUTHORIZED {
-            return Err(format!(
-                "Unexpected status {} for GET {}",
-                resp.status(),
-                url
-            )
-            .into());
-        }
-
-        // Step 2 – compute Digest response
-        let www_auth = resp
-            .headers()
-            .get("www-authenticate")
-            .ok_or("Missing WWW-Authenticate header")?
-            .to_str()
-            .map_err(|e| format!("Invalid WWW-Authenticate header: {}", e))?
-            .to_string();
-
-        let context = digest_auth::AuthContext::new(username, password, path);
-        let mut prompt = digest_auth::parse(&www_auth)
-            .map_err(|e| format!("Failed to parse digest challenge: {:?}", e))?;
-        let answer = prompt
-            .respond(&context)
-            .map_err(|e| format!("Failed to compute digest response: {:?}", e))?;
-
-        // Step 3 – authenticated request
-        let resp = client
-            .get(&url)
-            .header("Authorization", answer.to_header_string())
-            .send()
-            .await
-            .map_err(|e| format!("Authenticated GET {} failed: {}", url, e))?;
-
-        if resp.status().is_success() {
-            Ok(resp.text().await.unwrap_or_default())
-        } else {
-            Err(format!("GET {} returned {}", url, resp.status()).into())
-        }
+        Self::digest_request(ip, path, username, password, None, false).await
     }
 
     /// Perform a POST request with HTTP Digest Auth, sending a JSON body.
@@ -163,13 +213,14 @@ impl AntminerWebClient {
         password: &str,
         body: String,
     ) -> Result<String> {
-        Self::_digest_post_inner(ip, path, username, password, body, false).await
+        Self::digest_request(ip, path, username, password, Some(body), false).await
     }
 
-    /// Like `digest_post` but treats connection errors on the *authenticated*
+    /// Like `digest_post` but treats a dropped connection on the *authenticated*
     /// request as success.  Use this for endpoints that trigger an immediate
     /// reboot (set_miner_conf.cgi) where the miner drops the TCP connection
-    /// before it can send an HTTP response.
+    /// before it can send an HTTP response.  A connection that could not be
+    /// made at all is still an error: the miner never got the request.
     async fn digest_post_tolerant(
         ip: &str,
         path: &str,
@@ -177,95 +228,112 @@ impl AntminerWebClient {
         password: &str,
         body: String,
     ) -> Result<String> {
-        Self::_digest_post_inner(ip, path, username, password, body, true).await
+        Self::digest_request(ip, path, username, password, Some(body), true).await
     }
 
-    async fn _digest_post_inner(
+    /// Send a Digest-authenticated GET (`body = None`) or JSON POST.
+    ///
+    /// The last challenge from each host is cached, so the request is normally
+    /// signed up front with the cached nonce and an incremented `nc` — no
+    /// unauthenticated probe.  On a 401 (first contact, expired or stale
+    /// nonce) the new challenge is cached and the request is retried once.
+    async fn digest_request(
         ip: &str,
         path: &str,
         username: &str,
         password: &str,
-        body: String,
+        body: Option<String>,
         tolerate_connection_error: bool,
     ) -> Result<String> {
         let url = format!("http://{}{}", ip, path);
-        let client = Self::build_client()?;
-
-        // Step 1 – unauthenticated probe
-        let resp = client
-            .post(&url)
-            .header("Content-Type", "application/json")
-            .body(body.clone())
-            .send()
-            .await
-            .map_err(|e| format!("POST {} failed: {}", url, e))?;
-
-        if resp.status().is_success() {
-            return Ok(resp.text().await.unwrap_or_default());
-        }
-
-        if resp.status() != reqwest::StatusCode::UNAUTHORIZED {
-            return Err(format!(
-                "Unexpected status {} for POST {}",
-                resp.status(),
-                url
-            )
-            .into());
-        }
+        let method = if body.is_some() { "POST" } else { "GET" };
+        let client = http_pool::client_for(WebApi::Antminer, ip)?;
+
+        let mut authorization = Self::sign_with_cached_challenge(ip, path, username, password, body.is_some());
+
+        let mut retried = false;
+        loop {
+            let mut request = match &body {
+                Some(body) => client
+                    .post(&url)
+                    .header("Content-Type", "application/json")
+                    .body(body.clone()),
+                None => client.get(&url),
+            };
+            let authenticated = authorization.is_some();
+            if let Some(header) = authorization.take() {
+                request = request.header("Authorization", header);
+            }
 
-        // Step 2 – compute Digest response for POST
-        let www_auth = resp
-            .headers()
-            .get("www-authenticate")
-            .ok_or("Missing WWW-Authenticate header")?
-            .to_str()
-            .map_err(|e| format!("Invalid WWW-Authenticate header: {}", e))?
-            .to_string();
+            // Some commands (set_miner_conf) cause the miner to reboot immediately,
+            // dropping the TCP connection before it sends a response.  If the caller
+            // opted in to tolerance, treat a drop on an accepted connection as
+            // success; a refused or unreachable connection never carried the request.
+            let resp = match request.send().await {
+                Ok(r) => r,
+                Err(ref e) if authenticated && tolerate_connection_error && !e.is_connect() && (e.is_request() || e.is_timeout()) => {
+                    println!("[antminer_web] {} {} — connection dropped (miner likely rebooting, treating as success)", method, path);
+                    return Ok(String::new());
+                }
+                Err(e) => {
+                    return Err(MinerError::from_reqwest(format!("{} {}", method, url), e));
+                }
+            };
+
+            if resp.status().is_success() {
+                return Ok(resp.text().await.unwrap_or_default());
+            }
 
-        let mut context = digest_auth::AuthContext::new(username, password, path);
-        context.method = digest_auth::HttpMethod::POST;
-
-        let mut prompt = digest_auth::parse(&www_auth)
-            .map_err(|e| format!("Failed to parse digest challenge: {:?}", e))?;
-        let answer = prompt
-            .respond(&context)
-            .map_err(|e| format!("Failed to compute digest response: {:?}", e))?;
-
-        // Step 3 – authenticated request
-        let resp = client
-            .post(&url)
-            .header("Authorization", answer.to_header_string())
-            .header("Content-Type", "application/json")
-            .body(body)
-            .send()
-            .await;
-
-        // Some commands (set_miner_conf) cause the miner to reboot immediately,
-        // dropping the TCP connection before it sends a response.  If the caller
-        // opted in to tolerance, treat connection errors as success.
-        let resp = match resp {
-            Ok(r) => r,
-            Err(ref e) if tolerate_connection_error && (e.is_connect() || e.is_request() || e.is_timeout()) => {
-                println!("[antminer_web] POST {} — connection dropped (miner likely rebooting, treating as success)", path);
-                return Ok(String::new());
+            if resp.status() == reqwest::StatusCode::UNAUTHORIZED && retried {
+                // Signed with a fresh challenge and still refused: wrong credentials
+                return Err(MinerError::AuthenticationError);
             }
-            Err(e) => {
-                return Err(format!("Authenticated POST {} failed: {}", url, e).into());
+            if resp.status() != reqwest::StatusCode::UNAUTHORIZED {
+                return Err(MinerError::from_status(format!("{} {}", method, url), resp.status().as_u16()));
             }
-        };
 
-        if resp.status().is_success() {
-            Ok(resp.text().await.unwrap_or_default())
-        } else {
-            Err(format!("POST {} returned {}", url, resp.status()).into())
+            // Fresh challenge: cache it and sign the retry with it
+            let www_auth = resp
+                .headers()
+                .get("www-authenticate")
+                .ok_or_else(|| MinerError::MalformedResponse("401 without a WWW-Authenticate header".to_string()))?
+                .to_str()
+                .map_err(|e| MinerError::MalformedResponse(format!("Invalid WWW-Authenticate header: {}", e)))?
+                .to_string();
+            let prompt = digest_auth::parse(&www_auth)
+                .map_err(|e| MinerError::MalformedResponse(format!("Failed to parse digest challenge: {:?}", e)))?;
+            DIGEST_CHALLENGES.insert(ip.to_string(), prompt);
+
+            authorization = Some(
+                Self::sign_with_cached_challenge(ip, path, username, password, body.is_some())
+                    .ok_or_else(|| MinerError::MalformedResponse("Failed to compute digest response".to_string()))?,
+            );
+            retried = true;
+        }
+    }
+
+    /// Compute an `Authorization` header from the cached challenge for `ip`,
+    /// bumping its nonce count.  Returns `None` if there is no usable challenge.
+    fn sign_with_cached_challenge(
+        ip: &str,
+        path: &str,
+        username: &str,
+        password: &str,
+        is_post: bool,
+    ) -> Option<String> {
+        let mut prompt = DIGEST_CHALLENGES.get_mut(ip)?;
+        let mut context = digest_auth::AuthContext::new(username, password, path);
+        if is_post {
+            context.method = digest_auth::HttpMethod::POST;
         }
+        prompt.respond(&context).ok().map(|answer| answer.to_header_string())
     }
 
     /// Read the current miner config (required for read-modify-write).
     async fn get_miner_conf(ip: &str, username: &str, password: &str) -> Result<MinerConf> {
         let raw = Self::digest_get(ip, "/cgi-bin/get_miner_conf.cgi", username, password).await?;
         let conf: MinerConf = serde_json::from_str(&raw)
-            .map_err(|e| format!("Failed to parse miner conf: {} — raw: {}", e, &raw[..raw.len().min(200)]))?;
+            .map_err(|e| MinerError::MalformedResponse(format!("Failed to parse miner conf: {} — raw: {}", e, &raw[..raw.len().min(200)])))?;
         Ok(conf)
     }
 
@@ -295,7 +363,7 @@ impl AntminerWebClient {
         #[derive(Deserialize)]
         struct BlinkResp { blink: bool }
         let resp: BlinkResp = serde_json::from_str(&raw)
-            .map_err(|e| format!("Failed to parse blink status: {}", e))?;
+            .map_err(|e| MinerError::MalformedResponse(format!("Failed to parse blink status: {}", e)))?;
         Ok(resp.blink)
     }
 
@@ -315,7 +383,7 @@ impl AntminerWebClient {
     pub async fn set_power_mode(ip: &str, username: &str, password: &str, mode: u8) -> Result<()> {
         let raw = Self::digest_get(ip, "/cgi-bin/get_miner_conf.cgi", username, password).await?;
         let mut conf: serde_json::Value = serde_json::from_str(raw.trim())
-            .map_err(|e| format!("Failed to parse miner conf for {}: {} — raw: {}", ip, e, &raw[..raw.len().min(300)]))?;
+            .map_err(|e| MinerError::MalformedResponse(format!("Failed to parse miner conf for {}: {} — raw: {}", ip, e, &raw[..raw.len().min(300)])))?;
 
         if let Some(obj) = conf.as_object_mut() {
             obj.remove("bitmain-work-mode"); // Strip the 'read' key
@@ -333,14 +401,61 @@ impl AntminerWebClient {
     /// Read the current power mode without changing anything.
     /// Returns the raw work-mode u8 value (0=Normal, 1=Sleep, 3=LPM).
     pub async fn read_power_mode(ip: &str, username: &str, password: &str) -> Result<u8> {
+        Ok(Self::read_conf_settings(ip, username, password).await?.power_mode)
+    }
+
+    /// Read power mode, fan control and frequency level in one request.
+    pub async fn read_conf_settings(ip: &str, username: &str, password: &str) -> Result<ConfSettings> {
+        let conf = Self::get_conf_value(ip, username, password).await?;
+        Ok(ConfSettings::from_conf(&conf))
+    }
+
+    /// Apply several conf changes with a single `set_miner_conf.cgi` write,
+    /// so the miner reboots once.  Fields left `None` keep their value.
+    ///
+    /// Triggers an automatic reboot.
+    pub async fn apply_conf(ip: &str, username: &str, password: &str, change: &ConfChange) -> Result<()> {
+        if let Some(pools) = &change.pools {
+            crate::client::pools::validate_pools(pools)?;
+        }
+        let mut conf = Self::get_conf_value(ip, username, password).await?;
+        change.apply_to(&mut conf);
+
+        let body = serde_json::to_string(&conf)
+            .map_err(|e| format!("Failed to serialise miner conf: {}", e))?;
+
+        println!("[antminer_web] apply_conf for {} — body: {}", ip, &body[..body.len().min(200)]);
+        Self::digest_post_tolerant(ip, "/cgi-bin/set_miner_conf.cgi", username, password, body).await?;
+        Ok(())
+    }
+
+    /// Write a whole conf back, e.g. from a backup.  The power mode is read
+    /// as `bitmain-work-mode` but only accepted as `miner-mode`, so it is
+    /// moved over.
+    ///
+    /// Triggers an automatic reboot.
+    pub async fn set_conf(ip: &str, username: &str, password: &str, conf: &serde_json::Value) -> Result<()> {
+        let mut conf = conf.clone();
+        let obj = conf
+            .as_object_mut()
+            .ok_or_else(|| MinerError::MalformedResponse(format!("Miner conf for {} is not a JSON object", ip)))?;
+        if let Some(mode) = obj.remove("bitmain-work-mode") {
+            obj.entry("miner-mode").or_insert(mode);
+        }
+
+        let body = serde_json::to_string(&conf)
+            .map_err(|e| format!("Failed to serialise miner conf: {}", e))?;
+
+        println!("[antminer_web] set_conf for {} — {} bytes", ip, body.len());
+        Self::digest_post_tolerant(ip, "/cgi-bin/set_miner_conf.cgi", username, password, body).await?;
+        Ok(())
+    }
+
+    /// The miner conf as raw JSON, as read for read-modify-write and backups
+    pub async fn get_conf_value(ip: &str, username: &str, password: &str) -> Result<serde_json::Value> {
         let raw = Self::digest_get(ip, "/cgi-bin/get_miner_conf.cgi", username, password).await?;
-        let conf: serde_json::Value = serde_json::from_str(raw.trim())
-            .map_err(|e| format!("Failed to parse miner conf for {}: {}", ip, e))?;
-        let mode = conf["bitmain-work-mode"]
-            .as_str()
-            .and_then(|s| s.trim().parse::<u8>().ok())
-            .unwrap_or(0);
-        Ok(mode)
+        serde_json::from_str(raw.trim())
+            .map_err(|e| MinerError::MalformedResponse(format!("Failed to parse miner conf for {}: {}", ip, e)))
     }
 
     /// Configure up to three mining pools.
@@ -349,13 +464,11 @@ impl AntminerWebClient {
     /// `serde_json::Value`, mutates only the `"pools"` array, and POSTs
     /// the entire blob back.
     pub async fn set_pools(ip: &str, username: &str, password: &str, pools: Vec<crate::api::models::PoolConfig>) -> Result<()> {
-        if pools.is_empty() {
-            return Err(crate::core::MinerError::InvalidResponse);
-        }
+        crate::client::pools::validate_pools(&pools)?;
 
         let raw = Self::digest_get(ip, "/cgi-bin/get_miner_conf.cgi", username, password).await?;
         let mut conf: serde_json::Value = serde_json::from_str(raw.trim())
-            .map_err(|e| format!("Failed to parse miner conf for {}: {} — raw: {}", ip, e, &raw[..raw.len().min(300)]))?;
+            .map_err(|e| MinerError::MalformedResponse(format!("Failed to parse miner conf for {}: {} — raw: {}", ip, e, &raw[..raw.len().min(300)])))?;
 
         let mut ant_pools = Vec::new();
         for p in pools.into_iter().take(3) {
@@ -375,6 +488,32 @@ impl AntminerWebClient {
         Ok(())
     }
 
+    /// Read the device inventory: MAC, hostname, serial and network config.
+    pub async fn get_system_info(ip: &str, username: &str, password: &str) -> Result<AntminerSystemInfo> {
+        let raw = Self::digest_get(ip, "/cgi-bin/get_system_info.cgi", username, password).await?;
+        serde_json::from_str(raw.trim())
+            .map_err(|e| MinerError::MalformedResponse(format!("Failed to parse system info for {}: {} — raw: {}", ip, e, &raw[..raw.len().min(200)])))
+    }
+
+    /// Change the web UI password of `username` via `/cgi-bin/passwd.cgi`.
+    /// The miner answers `{"code":"P000"}` on success; any other code means
+    /// the password was not changed.
+    pub async fn change_password(ip: &str, username: &str, current: &str, new: &str) -> Result<()> {
+        let body = serde_json::json!({ "curPwd": current, "newPwd": new, "confirmPwd": new }).to_string();
+        let raw = Self::digest_post(ip, "/cgi-bin/passwd.cgi", username, current, body).await?;
+
+        #[derive(Deserialize)]
+        struct PasswdResp { code: String }
+        let resp: PasswdResp = serde_json::from_str(raw.trim())
+            .map_err(|e| MinerError::MalformedResponse(format!("Failed to parse passwd.cgi response for {}: {} — raw: {}", ip, e, &raw[..raw.len().min(200)])))?;
+        if resp.code != "P000" {
+            let message = format!("passwd.cgi on {} refused the password change", ip);
+            return Err(MinerError::Rejected { code: resp.code, message });
+        }
+        println!("[antminer_web] Changed web password of {} on {}", username, ip);
+        Ok(())
+    }
+
     /// Read the currently configured pools (from the miner config, not live stats).
     pub async fn get_pools(ip: &str, username: &str, password: &str) -> Result<Vec<AntminerPool>> {
         let conf = Self::get_miner_conf(ip, username, password).await?;
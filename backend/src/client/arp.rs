@@ -0,0 +1,300 @@
+//! MAC address lookup through the OS neighbour (ARP) table.
+//!
+//! The whole table is read at once into a shared cache that is refreshed when
+//! it gets stale, instead of spawning `arp`/`ip neigh` per miner.  On Linux
+//! the table is read natively from `/proc/net/arp`; other platforms run one
+//! `arp -a` per refresh.  Every reader only supplies the raw table text and a
+//! parser for it, so parsing is unit-tested against fixture files.
+
+use crate::core::Result;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// How long a table read is trusted before the next lookup refreshes it
+pub const ARP_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Connect timeout used to make the OS resolve a missing entry
+const PRIME_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// A platform source of the neighbour table
+#[async_trait::async_trait]
+pub trait ArpReader: Send + Sync {
+    /// Raw table text, in whatever format `parse` understands
+    async fn read_table(&self) -> Result<String>;
+
+    /// Extract IP -> MAC (`AA:BB:CC:DD:EE:FF`) pairs from `read_table` output.
+    /// Incomplete, all-zero and broadcast entries are skipped.
+    fn parse(&self, table: &str) -> HashMap<IpAddr, String>;
+}
+
+/// Linux: `/proc/net/arp`, no subprocess
+pub struct ProcNetArpReader {
+    pub path: PathBuf,
+}
+
+impl Default for ProcNetArpReader {
+    fn default() -> Self {
+        Self { path: PathBuf::from("/proc/net/arp") }
+    }
+}
+
+#[async_trait::async_trait]
+impl ArpReader for ProcNetArpReader {
+    async fn read_table(&self) -> Result<String> {
+        Ok(tokio::fs::read_to_string(&self.path).await?)
+    }
+
+    fn parse(&self, table: &str) -> HashMap<IpAddr, String> {
+        parse_proc_net_arp(table)
+    }
+}
+
+/// macOS/BSD (`arp -an`) and Windows (`arp -a`): one spawn per refresh
+pub struct ArpCommandReader;
+
+#[async_trait::async_trait]
+impl ArpReader for ArpCommandReader {
+    async fn read_table(&self) -> Result<String> {
+        #[allow(unused_mut)]
+        let mut cmd = tokio::process::Command::new("arp");
+        if cfg!(target_os = "windows") {
+            cmd.arg("-a");
+        } else {
+            cmd.arg("-an");
+        }
+        #[cfg(target_os = "windows")]
+        {
+            // CREATE_NO_WINDOW: no console window flashing on every refresh
+            use std::os::windows::process::CommandExt;
+            cmd.creation_flags(0x08000000);
+        }
+
+        let output = cmd.output().await?;
+        if !output.status.success() {
+            return Err(format!("arp exited with {}", output.status).into());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    fn parse(&self, table: &str) -> HashMap<IpAddr, String> {
+        parse_arp_a(table)
+    }
+}
+
+/// The reader for the platform we are running on
+pub fn platform_reader() -> Box<dyn ArpReader> {
+    if cfg!(target_os = "linux") || cfg!(target_os = "android") {
+        Box::new(ProcNetArpReader::default())
+    } else {
+        Box::new(ArpCommandReader)
+    }
+}
+
+/// Shared, lazily refreshed copy of the neighbour table
+pub struct ArpCache {
+    reader: Box<dyn ArpReader>,
+    entries: RwLock<HashMap<IpAddr, String>>,
+    /// When `entries` was last read; the lock also serialises refreshes
+    refreshed_at: Mutex<Option<Instant>>,
+}
+
+impl ArpCache {
+    pub fn new(reader: Box<dyn ArpReader>) -> Self {
+        Self {
+            reader,
+            entries: RwLock::new(HashMap::new()),
+            refreshed_at: Mutex::new(None),
+        }
+    }
+
+    /// MAC address of `ip`, or `None` if the OS has no entry for it.
+    ///
+    /// A missing entry is primed with a TCP connect on `prime_port` (the
+    /// OS resolves the address to send the SYN) and the table is read again.
+    pub async fn lookup(&self, ip: &str, prime_port: u16) -> Option<String> {
+        let addr: IpAddr = ip.parse().ok()?;
+
+        self.refresh_if_older_than(Instant::now() - ARP_REFRESH_INTERVAL).await;
+        if let Some(mac) = self.get(&addr) {
+            return Some(mac);
+        }
+
+        let primed_at = Instant::now();
+        let _ = tokio::time::timeout(PRIME_TIMEOUT, super::source::connect(ip, prime_port)).await;
+        self.lookup_after(ip, primed_at).await
+    }
+
+    /// MAC address of `ip` from a table read started after `since`, e.g. a
+    /// connect to it we just made.  Never primes; concurrent callers share
+    /// one read.
+    pub async fn lookup_after(&self, ip: &str, since: Instant) -> Option<String> {
+        let addr: IpAddr = ip.parse().ok()?;
+        self.refresh_if_older_than(since).await;
+        self.get(&addr)
+    }
+
+    fn get(&self, addr: &IpAddr) -> Option<String> {
+        self.entries.read().unwrap().get(addr).cloned()
+    }
+
+    /// Re-read the table unless a read started after `threshold` already
+    /// happened.  Concurrent callers wait for one shared read.
+    async fn refresh_if_older_than(&self, threshold: Instant) {
+        let mut refreshed_at = self.refreshed_at.lock().await;
+        if refreshed_at.is_some_and(|at| at >= threshold) {
+            return;
+        }
+
+        let started = Instant::now();
+        match self.reader.read_table().await {
+            Ok(table) => {
+                *self.entries.write().unwrap() = self.reader.parse(&table);
+            }
+            Err(e) => eprintln!("[MAC] Failed to read ARP table: {}", e),
+        }
+        *refreshed_at = Some(started);
+    }
+}
+
+lazy_static::lazy_static! {
+    /// Process-wide ARP cache shared by scanner, monitor and parsers
+    pub static ref ARP_CACHE: ArpCache = ArpCache::new(platform_reader());
+}
+
+/// Parse `/proc/net/arp`:
+/// `IP address  HW type  Flags  HW address  Mask  Device`
+pub fn parse_proc_net_arp(table: &str) -> HashMap<IpAddr, String> {
+    const ATF_COM: u32 = 0x2; // entry is complete
+
+    table
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let ip = fields.first()?.parse().ok()?;
+            let flags = u32::from_str_radix(fields.get(2)?.trim_start_matches("0x"), 16).ok()?;
+            if flags & ATF_COM == 0 {
+                return None;
+            }
+            Some((ip, normalize_mac(fields.get(3)?)?))
+        })
+        .collect()
+}
+
+/// Parse `arp -an` (macOS/BSD: `? (10.0.0.1) at 2:1a:… on en0 …`) or
+/// `arp -a` (Windows: `  10.0.0.1   02-1a-…   dynamic`) output.
+pub fn parse_arp_a(table: &str) -> HashMap<IpAddr, String> {
+    table
+        .lines()
+        .filter_map(|line| {
+            let mut words = line.split_whitespace();
+            let ip = words.find_map(|word| word.trim_matches(|c| c == '(' || c == ')').parse::<IpAddr>().ok())?;
+            let mac = words.find_map(normalize_mac)?;
+            Some((ip, mac))
+        })
+        .collect()
+}
+
+/// Normalise `2:1a:2b:3c:4d:5e` or `02-1A-2B-3C-4D-5E` to `02:1A:2B:3C:4D:5E`.
+/// Returns `None` for anything else, including all-zero, broadcast and
+/// multicast addresses.
+pub fn normalize_mac(word: &str) -> Option<String> {
+    let parts: Vec<&str> = word.split([':', '-']).collect();
+    if parts.len() != 6
+        || !parts.iter().all(|p| (1..=2).contains(&p.len()) && p.chars().all(|c| c.is_ascii_hexdigit()))
+    {
+        return None;
+    }
+
+    let octets: Vec<u8> = parts.iter().map(|p| u8::from_str_radix(p, 16).unwrap()).collect();
+    if octets.iter().all(|&b| b == 0) || octets[0] & 0x01 != 0 {
+        return None;
+    }
+
+    Some(
+        octets
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<_>>()
+            .join(":"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_proc_net_arp_fixture() {
+        let entries = parse_proc_net_arp(include_str!("../../tests/fixtures/arp/proc_net_arp"));
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[&ip("10.1.2.21")], "02:1A:2B:3C:4D:5E");
+        assert_eq!(entries[&ip("10.1.2.23")], "C4:82:E1:0A:0B:0C");
+        // Incomplete entry (flags 0x0, zero MAC) is skipped
+        assert!(!entries.contains_key(&ip("10.1.2.22")));
+    }
+
+    #[test]
+    fn test_parse_macos_fixture() {
+        let entries = parse_arp_a(include_str!("../../tests/fixtures/arp/macos_arp_an.txt"));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[&ip("10.1.2.21")], "02:1A:2B:3C:4D:5E");
+        assert_eq!(entries[&ip("10.1.2.23")], "C4:82:E1:0A:0B:0C");
+    }
+
+    #[test]
+    fn test_parse_windows_fixture() {
+        let entries = parse_arp_a(include_str!("../../tests/fixtures/arp/windows_arp_a.txt"));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[&ip("10.1.2.21")], "02:1A:2B:3C:4D:5E");
+        assert_eq!(entries[&ip("10.1.2.23")], "C4:82:E1:0A:0B:0C");
+        // The interface header line names our own address, not a neighbour
+        assert!(!entries.contains_key(&ip("10.1.2.5")));
+    }
+
+    /// Serves a fixed table and counts reads
+    struct FixtureReader {
+        table: &'static str,
+        reads: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl ArpReader for FixtureReader {
+        async fn read_table(&self) -> Result<String> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            Ok(self.table.to_string())
+        }
+
+        fn parse(&self, table: &str) -> HashMap<IpAddr, String> {
+            parse_proc_net_arp(table)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_reads_table_once() {
+        let reads = Arc::new(AtomicUsize::new(0));
+        let cache = ArpCache::new(Box::new(FixtureReader {
+            table: include_str!("../../tests/fixtures/arp/proc_net_arp"),
+            reads: reads.clone(),
+        }));
+
+        assert_eq!(cache.lookup("10.1.2.21", 14110).await.as_deref(), Some("02:1A:2B:3C:4D:5E"));
+        assert_eq!(cache.lookup("10.1.2.23", 14110).await.as_deref(), Some("C4:82:E1:0A:0B:0C"));
+        assert_eq!(reads.load(Ordering::SeqCst), 1);
+
+        // A miss primes the address and forces exactly one more read
+        assert_eq!(cache.lookup("127.0.0.1", 14110).await, None);
+        assert_eq!(reads.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,258 @@
+//! Which credentials to log in to a miner with.
+//!
+//! Besides the per-vendor credentials there can be scoped credential sets
+//! (by range, tag or single miner) for sites that kept their own passwords.
+//! A web API call tries them in priority order until one is accepted, and
+//! the one that worked is remembered per miner, so the next call — from the
+//! monitor or a command — goes straight to it.  Miners that refuse every
+//! candidate are flagged; after that only the first candidate is tried until
+//! `AUTH_RETRY_AFTER` has passed or the credential sets change, so a wrong
+//! password doesn't cost a round of failed logins on every poll.
+
+use crate::core::config::{AppSettings, CredentialSet};
+use crate::core::{vault, MinerCredentials, MinerError, MinerId, MinerVendor, Result};
+use dashmap::DashMap;
+use std::future::Future;
+use std::time::{Duration, SystemTime};
+
+/// How long a miner that refused every credential is only tried once per call
+pub const AUTH_RETRY_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// One credential to try, with the set it came from (`None`: the
+/// per-vendor credentials)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub set_name: Option<String>,
+    pub credentials: MinerCredentials,
+}
+
+/// A miner that accepted none of the candidates
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthFailure {
+    pub ip: String,
+    pub miner_id: Option<MinerId>,
+    /// How many distinct credentials were refused
+    pub tried: u32,
+    /// Unix timestamp of the last failed round
+    pub failed_at: u64,
+}
+
+lazy_static::lazy_static! {
+    /// Miner (id, or IP while unknown) -> set that worked last
+    static ref REMEMBERED: DashMap<String, Option<String>> = DashMap::new();
+    /// IP -> last failed round
+    static ref FAILURES: DashMap<String, AuthFailure> = DashMap::new();
+}
+
+/// Whether `set` applies to the miner at `ip`
+pub fn matches(set: &CredentialSet, ip: &str, miner_id: Option<&MinerId>, tags: &[String], vendor: MinerVendor) -> bool {
+    if set.vendor.is_some_and(|only| only != vendor) {
+        return false;
+    }
+    set.scope.contains(ip, miner_id, tags)
+}
+
+/// Credentials to try on the miner at `ip`, in order: matching sets by
+/// priority (narrower scope first on ties), then `default`.  A credential
+/// already listed is not tried twice.
+pub fn candidates(
+    ip: &str,
+    miner_id: Option<&MinerId>,
+    tags: &[String],
+    vendor: MinerVendor,
+    sets: &[CredentialSet],
+    default: MinerCredentials,
+) -> Vec<Candidate> {
+    let mut matching: Vec<&CredentialSet> = sets
+        .iter()
+        .filter(|set| matches(set, ip, miner_id, tags, vendor))
+        .collect();
+    matching.sort_by_key(|set| (set.priority, std::cmp::Reverse(set.scope.specificity())));
+
+    let mut list: Vec<Candidate> = Vec::new();
+    let all = matching
+        .into_iter()
+        .map(|set| Candidate { set_name: Some(set.name.clone()), credentials: set.credentials.clone() })
+        .chain(std::iter::once(Candidate { set_name: None, credentials: default }));
+    for candidate in all {
+        if !list.iter().any(|c| c.credentials == candidate.credentials) {
+            list.push(candidate);
+        }
+    }
+    list
+}
+
+/// Run `op` against the miner at `ip` with the saved credentials, falling
+/// back through the candidates while it fails with
+/// `MinerError::AuthenticationError`.  Any other error ends the attempt.
+pub async fn with_credentials<T, F, Fut>(ip: &str, vendor: MinerVendor, op: F) -> Result<T>
+where
+    F: Fn(MinerCredentials) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let default = vault::credentials(vendor)?;
+    let sets = vault::credential_sets()?;
+    let miner_id = crate::identity::ADDRESSES.id_at(ip);
+    let tags = miner_id.as_ref().map(|id| AppSettings::current().tags_of(id).to_vec()).unwrap_or_default();
+
+    let list = candidates(ip, miner_id.as_ref(), &tags, vendor, &sets, default);
+    try_candidates(ip, miner_id, list, op).await
+}
+
+/// `with_credentials`, unless the caller gave credentials of its own: then
+/// only those are tried, and nothing is remembered or flagged
+pub async fn with_given<T, F, Fut>(ip: &str, vendor: MinerVendor, given: Option<&MinerCredentials>, op: F) -> Result<T>
+where
+    F: Fn(MinerCredentials) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    match given {
+        Some(credentials) => op(credentials.clone()).await,
+        None => with_credentials(ip, vendor, op).await,
+    }
+}
+
+/// The fallback loop of `with_credentials` over an explicit candidate list
+pub async fn try_candidates<T, F, Fut>(ip: &str, miner_id: Option<MinerId>, mut list: Vec<Candidate>, op: F) -> Result<T>
+where
+    F: Fn(MinerCredentials) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let key = miner_id.as_ref().map(|id| id.0.clone()).unwrap_or_else(|| ip.to_string());
+
+    // What worked last time goes first
+    if let Some(remembered) = REMEMBERED.get(&key).map(|entry| entry.value().clone()) {
+        if let Some(index) = list.iter().position(|c| c.set_name == remembered) {
+            let candidate = list.remove(index);
+            list.insert(0, candidate);
+        }
+    }
+    if is_flagged(ip) {
+        list.truncate(1);
+    }
+
+    let mut tried = 0;
+    for candidate in list {
+        tried += 1;
+        match op(candidate.credentials).await {
+            Err(MinerError::AuthenticationError) => continue,
+            Ok(value) => {
+                if tried > 1 || FAILURES.contains_key(ip) {
+                    println!(
+                        "[credentials] {} accepted {}",
+                        ip,
+                        candidate.set_name.as_deref().map_or("the default credentials".to_string(), |name| format!("set '{}'", name))
+                    );
+                }
+                REMEMBERED.insert(key, candidate.set_name);
+                FAILURES.remove(ip);
+                return Ok(value);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    if !is_flagged(ip) {
+        eprintln!("[credentials] {} refused all {} credential(s)", ip, tried);
+        FAILURES.insert(
+            ip.to_string(),
+            AuthFailure { ip: ip.to_string(), miner_id, tried, failed_at: now() },
+        );
+    }
+    Err(MinerError::AuthenticationError)
+}
+
+/// Whether `ip` refused every credential less than `AUTH_RETRY_AFTER` ago
+pub fn is_flagged(ip: &str) -> bool {
+    FAILURES
+        .get(ip)
+        .is_some_and(|failure| now().saturating_sub(failure.failed_at) < AUTH_RETRY_AFTER.as_secs())
+}
+
+/// Miners that refused every credential, most recent first
+pub fn auth_failures() -> Vec<AuthFailure> {
+    let mut failures: Vec<AuthFailure> = FAILURES.iter().map(|entry| entry.value().clone()).collect();
+    failures.sort_by_key(|failure| std::cmp::Reverse(failure.failed_at));
+    failures
+}
+
+/// Set that last worked for the miner with `key` (its id, or IP while unknown).
+/// `Some(None)` means the per-vendor credentials.
+pub fn remembered(key: &str) -> Option<Option<String>> {
+    REMEMBERED.get(key).map(|entry| entry.value().clone())
+}
+
+/// Forget what worked for one miner and its flag, e.g. after its password
+/// was changed
+pub fn forget(ip: &str, miner_id: Option<&MinerId>) {
+    REMEMBERED.remove(miner_id.map_or(ip, |id| id.0.as_str()));
+    FAILURES.remove(ip);
+}
+
+/// Forget flags and remembered sets, e.g. after the sets were edited
+pub fn reset() {
+    FAILURES.clear();
+    REMEMBERED.clear();
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::CredentialScope;
+
+    fn set(name: &str, scope: CredentialScope, priority: i32, password: &str) -> CredentialSet {
+        CredentialSet {
+            name: name.to_string(),
+            scope,
+            vendor: None,
+            priority,
+            credentials: MinerCredentials::new("root".into(), password.into()),
+        }
+    }
+
+    #[test]
+    fn test_candidate_order() {
+        let id = MinerId::from_serial("SN1").unwrap();
+        let sets = vec![
+            set("everyone", CredentialScope::All, 0, "fleet"),
+            set("site-b", CredentialScope::Range("10.2.0.0/16".into()), 0, "siteb"),
+            set("legacy", CredentialScope::Tag("Acquired".into()), 0, "legacy"),
+            set("this-one", CredentialScope::Miner(id.clone()), 5, "special"),
+            set("elsewhere", CredentialScope::Range("10.3.0.0/16".into()), 0, "nope"),
+            CredentialSet { vendor: Some(MinerVendor::Whatsminer), ..set("wm", CredentialScope::All, 0, "wm") },
+        ];
+        let tags = vec!["acquired".to_string()];
+        let default = MinerCredentials::new("root".into(), "root".into());
+
+        let list = candidates("10.2.3.4", Some(&id), &tags, MinerVendor::Antminer, &sets, default.clone());
+        let names: Vec<Option<&str>> = list.iter().map(|c| c.set_name.as_deref()).collect();
+        assert_eq!(names, vec![Some("legacy"), Some("site-b"), Some("everyone"), Some("this-one"), None]);
+
+        // A set repeating the default credentials makes the default redundant
+        let sets = vec![set("factory", CredentialScope::All, 0, "root")];
+        let list = candidates("10.2.3.4", None, &[], MinerVendor::Antminer, &sets, default);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].set_name.as_deref(), Some("factory"));
+    }
+
+    #[test]
+    fn test_matches_range_and_vendor() {
+        let range = set("r", CredentialScope::Range("10.1.2.*, !10.1.2.1".into()), 0, "x");
+        assert!(matches(&range, "10.1.2.9", None, &[], MinerVendor::Antminer));
+        assert!(!matches(&range, "10.1.2.1", None, &[], MinerVendor::Antminer));
+        assert!(!matches(&range, "not-an-ip", None, &[], MinerVendor::Antminer));
+
+        let broken = set("b", CredentialScope::Range("10.1.2.300".into()), 0, "x");
+        assert!(!matches(&broken, "10.1.2.9", None, &[], MinerVendor::Antminer));
+
+        let whatsminer_only = CredentialSet { vendor: Some(MinerVendor::Whatsminer), ..set("w", CredentialScope::All, 0, "x") };
+        assert!(!matches(&whatsminer_only, "10.1.2.9", None, &[], MinerVendor::Antminer));
+    }
+}
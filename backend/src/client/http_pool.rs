@@ -0,0 +1,87 @@
+//! Shared HTTP clients for the miner web APIs.
+//!
+//! `reqwest::Client` keeps its own keep-alive connection pool, so building one
+//! per request throws away every open connection.  Clients here are built once
+//! per (API, host) and cloned out cheaply (a `Client` is an `Arc` internally).
+//! Keying by host also gives each Whatsminer its own cookie store, so LuCI
+//! session cookies never leak between miners.
+
+use crate::core::Result;
+use dashmap::DashMap;
+use reqwest::Client;
+use std::time::Duration;
+
+/// Which web API a client talks to; each has different TLS/redirect needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebApi {
+    /// Antminer CGI over plain HTTP with Digest Auth
+    Antminer,
+    /// Whatsminer LuCI over HTTPS with session cookies
+    WhatsminerLuci,
+}
+
+lazy_static::lazy_static! {
+    static ref CLIENTS: DashMap<(WebApi, String), Client> = DashMap::new();
+}
+
+/// Get the shared client for `host`, building it on first use.
+pub fn client_for(api: WebApi, host: &str) -> Result<Client> {
+    let key = (api, host.to_string());
+    if let Some(client) = CLIENTS.get(&key) {
+        return Ok(client.clone());
+    }
+
+    let client = build_client(api, host)?;
+    Ok(CLIENTS.entry(key).or_insert(client).clone())
+}
+
+/// Drop the client for `host` (and with it its connections and cookies).
+pub fn evict(api: WebApi, host: &str) {
+    CLIENTS.remove(&(api, host.to_string()));
+}
+
+/// Drop every client, e.g. after the source address bindings changed.
+pub fn clear() {
+    CLIENTS.clear();
+}
+
+fn build_client(api: WebApi, host: &str) -> Result<Client> {
+    let builder = match api {
+        WebApi::Antminer => Client::builder()
+            .timeout(Duration::from_secs(8))
+            .pool_idle_timeout(Duration::from_secs(30)),
+        // Redirects are not followed: LuCI answers login and daemon restarts
+        // with a 302 that we need to see, and the session cookie is set on it.
+        WebApi::WhatsminerLuci => Client::builder()
+            .timeout(Duration::from_secs(10))
+            .pool_idle_timeout(Duration::from_secs(30))
+            .danger_accept_invalid_certs(true) // Whatsminer uses self-signed certs
+            .cookie_store(true)                // Essential: keep the LuCI session cookie
+            .redirect(reqwest::redirect::Policy::none()),
+    };
+
+    // Go out through the interface configured for the miner's range
+    builder
+        .local_address(super::source::source_for_host(host))
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_reused_per_host() {
+        client_for(WebApi::Antminer, "10.0.0.1").unwrap();
+        client_for(WebApi::Antminer, "10.0.0.1").unwrap();
+        client_for(WebApi::WhatsminerLuci, "10.0.0.1").unwrap();
+        client_for(WebApi::Antminer, "10.0.0.2").unwrap();
+
+        assert!(CLIENTS.contains_key(&(WebApi::Antminer, "10.0.0.1".to_string())));
+        assert!(CLIENTS.contains_key(&(WebApi::WhatsminerLuci, "10.0.0.1".to_string())));
+
+        evict(WebApi::Antminer, "10.0.0.2");
+        assert!(!CLIENTS.contains_key(&(WebApi::Antminer, "10.0.0.2".to_string())));
+    }
+}
@@ -0,0 +1,242 @@
+//! Device inventory read from the miner itself.
+//!
+//! MAC, serial number, hostname and network configuration come from the
+//! device's own API — Antminer `/cgi-bin/get_system_info.cgi`, Whatsminer
+//! `get_miner_info` — which also works for miners behind a router, where the
+//! local ARP table has nothing.  ARP is only the fallback for a device that
+//! doesn't report its MAC.  Inventory rarely changes, so it is cached per IP
+//! and the monitor doesn't re-read it on every poll.
+
+use super::antminer_web::{AntminerSystemInfo, AntminerWebClient};
+use super::{arp, credentials, lookup_mac_address, send_command};
+use crate::core::{MinerError, MinerStats, MinerVendor, NetworkMode, Result};
+use dashmap::DashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// How long a successful inventory read is reused
+pub const INVENTORY_TTL: Duration = Duration::from_secs(600);
+
+/// How long to wait before retrying a device whose inventory read failed
+const RETRY_AFTER_FAILURE: Duration = Duration::from_secs(60);
+
+/// Lower bound for the web request: Digest Auth may need two round trips
+const MIN_WEB_TIMEOUT_MS: u64 = 3000;
+
+/// What a miner reports about itself
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeviceInventory {
+    pub mac_address: Option<String>,
+    pub serial_number: Option<String>,
+    pub hostname: Option<String>,
+    pub network_mode: Option<NetworkMode>,
+    pub ip_address: Option<String>,
+    pub netmask: Option<String>,
+    pub gateway: Option<String>,
+    pub dns_servers: Vec<String>,
+    /// Antminer only: "Release" for stock builds
+    pub firmware_type: Option<String>,
+}
+
+impl DeviceInventory {
+    pub fn from_antminer(info: &AntminerSystemInfo) -> Self {
+        Self {
+            mac_address: info.macaddr.as_deref().and_then(arp::normalize_mac),
+            serial_number: non_empty(info.serinum.as_deref()),
+            hostname: non_empty(info.hostname.as_deref()),
+            network_mode: info.nettype.as_deref().and_then(NetworkMode::parse),
+            ip_address: non_empty(info.ipaddress.as_deref()),
+            netmask: non_empty(info.netmask.as_deref()),
+            gateway: non_empty(info.gateway.as_deref()),
+            dns_servers: split_servers(info.dnsservers.as_deref()),
+            firmware_type: non_empty(info.firmware_type.as_deref()),
+        }
+    }
+
+    /// Parse a btminer `get_miner_info` response:
+    /// `{"STATUS":"S","Code":131,"Msg":{"ip":…,"proto":"dhcp","mac":…,"minersn":…}}`
+    pub fn parse_whatsminer(response: &str) -> Result<Self> {
+        let json = crate::utils::extract_clean_json(response).ok_or(MinerError::InvalidResponse)?;
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        let msg = value
+            .get("Msg")
+            .filter(|msg| msg.is_object())
+            .ok_or_else(|| MinerError::from(format!("get_miner_info rejected: {}", value["Msg"])))?;
+        let field = |key: &str| non_empty(msg.get(key).and_then(|v| v.as_str()));
+
+        Ok(Self {
+            mac_address: field("mac").as_deref().and_then(arp::normalize_mac),
+            serial_number: field("minersn"),
+            hostname: field("hostname"),
+            network_mode: field("proto").as_deref().and_then(NetworkMode::parse),
+            ip_address: field("ip"),
+            netmask: field("netmask"),
+            gateway: field("gateway"),
+            dns_servers: split_servers(field("dns").as_deref()),
+            firmware_type: None,
+        })
+    }
+
+    /// Copy what the device reported into `stats`, leaving fields it didn't
+    /// report untouched.
+    pub fn apply_to(&self, stats: &mut MinerStats) {
+        if self.mac_address.is_some() {
+            stats.mac_address = self.mac_address.clone();
+        }
+        if self.serial_number.is_some() {
+            stats.serial_number = self.serial_number.clone();
+        }
+        if self.hostname.is_some() {
+            stats.hostname = self.hostname.clone();
+        }
+        if self.network_mode.is_some() {
+            stats.network_mode = self.network_mode;
+        }
+        if self.netmask.is_some() {
+            stats.netmask = self.netmask.clone();
+        }
+        if self.gateway.is_some() {
+            stats.gateway = self.gateway.clone();
+        }
+        if !self.dns_servers.is_empty() {
+            stats.dns_servers = self.dns_servers.clone();
+        }
+    }
+}
+
+/// Cached read; `inventory` is `None` for a failed one
+#[derive(Debug, Clone)]
+struct CachedInventory {
+    fetched_at: Instant,
+    inventory: Option<DeviceInventory>,
+}
+
+lazy_static::lazy_static! {
+    static ref INVENTORIES: DashMap<String, CachedInventory> = DashMap::new();
+}
+
+/// Read the inventory of the miner at `ip`, bypassing the cache.
+/// Antminers are asked over the web API (port 80), Whatsminers over the
+/// CGMiner API on `port`.
+pub async fn fetch_inventory(ip: &str, port: u16, vendor: MinerVendor, timeout_ms: u64) -> Result<DeviceInventory> {
+    match vendor {
+        MinerVendor::Antminer => {
+            let request = credentials::with_credentials(ip, MinerVendor::Antminer, |creds| async move {
+                AntminerWebClient::get_system_info(ip, &creds.username, &creds.password).await
+            });
+            let info = tokio::time::timeout(Duration::from_millis(timeout_ms.max(MIN_WEB_TIMEOUT_MS)), request)
+                .await
+                .map_err(|_| MinerError::Timeout(format!("{} system info", ip)))??;
+            Ok(DeviceInventory::from_antminer(&info))
+        }
+        MinerVendor::Whatsminer => {
+            let response = send_command(ip, port, "get_miner_info", timeout_ms).await?;
+            DeviceInventory::parse_whatsminer(&response)
+        }
+    }
+}
+
+/// Inventory of the miner at `ip:port`, read at most every `INVENTORY_TTL`.
+/// A failed read is remembered for a minute so an unreachable web API
+/// doesn't slow down every poll.
+pub async fn cached_inventory(ip: &str, port: u16, vendor: MinerVendor, timeout_ms: u64) -> Option<DeviceInventory> {
+    let key = format!("{}:{}", ip, port);
+    if let Some(cached) = INVENTORIES.get(&key) {
+        let ttl = if cached.inventory.is_some() { INVENTORY_TTL } else { RETRY_AFTER_FAILURE };
+        if cached.fetched_at.elapsed() < ttl {
+            return cached.inventory.clone();
+        }
+    }
+
+    let inventory = match fetch_inventory(ip, port, vendor, timeout_ms).await {
+        Ok(inventory) => Some(inventory),
+        Err(e) => {
+            println!("[inventory] {} did not report its inventory: {}", ip, e);
+            None
+        }
+    };
+    INVENTORIES.insert(key, CachedInventory { fetched_at: Instant::now(), inventory: inventory.clone() });
+    inventory
+}
+
+/// Forget the cached inventory of `ip:port`, e.g. after changing its network config.
+pub fn invalidate(ip: &str, port: u16) {
+    INVENTORIES.remove(&format!("{}:{}", ip, port));
+}
+
+/// Fill MAC, serial, hostname and network config of `stats` from the device,
+/// falling back to the ARP table for the MAC.
+pub async fn fill_inventory(ip: &str, port: u16, vendor: MinerVendor, timeout_ms: u64, stats: &mut MinerStats) {
+    if let Some(inventory) = cached_inventory(ip, port, vendor, timeout_ms).await {
+        inventory.apply_to(stats);
+    }
+    if stats.mac_address.is_none() {
+        stats.mac_address = lookup_mac_address(ip).await;
+    }
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value.map(str::trim).filter(|v| !v.is_empty()).map(str::to_string)
+}
+
+/// DNS servers are space- or comma-separated depending on firmware
+fn split_servers(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_antminer_system_info() {
+        let raw = r#"{"minertype":"Antminer S19j Pro","nettype":"DHCP","netdevice":"eth0","macaddr":"02:1a:2b:3c:4d:5e","hostname":"Antminer","ipaddress":"10.1.2.21","netmask":"255.255.255.0","gateway":"10.1.2.1","dnsservers":"10.1.2.1 8.8.8.8 ","system_mode":"GNU/Linux","firmware_type":"Release","serinum":"JYZZB2ABCDEF1234"}"#;
+        let info: AntminerSystemInfo = serde_json::from_str(raw).unwrap();
+        let inventory = DeviceInventory::from_antminer(&info);
+
+        assert_eq!(inventory.mac_address.as_deref(), Some("02:1A:2B:3C:4D:5E"));
+        assert_eq!(inventory.serial_number.as_deref(), Some("JYZZB2ABCDEF1234"));
+        assert_eq!(inventory.network_mode, Some(NetworkMode::Dhcp));
+        assert_eq!(inventory.gateway.as_deref(), Some("10.1.2.1"));
+        assert_eq!(inventory.dns_servers, vec!["10.1.2.1", "8.8.8.8"]);
+        assert_eq!(inventory.firmware_type.as_deref(), Some("Release"));
+
+        // Older firmware: `serial_no`, static address
+        let info: AntminerSystemInfo = serde_json::from_str(r#"{"nettype":"Static","serial_no":"SN1","hostname":""}"#).unwrap();
+        let inventory = DeviceInventory::from_antminer(&info);
+        assert_eq!(inventory.serial_number.as_deref(), Some("SN1"));
+        assert_eq!(inventory.network_mode, Some(NetworkMode::Static));
+        assert_eq!(inventory.hostname, None);
+    }
+
+    #[test]
+    fn test_whatsminer_miner_info() {
+        let raw = r#"{"STATUS":"S","When":1738800000,"Code":131,"Msg":{"ip":"10.1.2.30","proto":"static","netmask":"255.255.255.0","gateway":"10.1.2.1","dns":"114.114.114.114","hostname":"WhatsMiner","mac":"C4:82:E1:0A:0B:0C","ledstat":"auto","minersn":"HTM3X10ABCDEF","powersn":"P221B"},"Description":""}"#;
+        let inventory = DeviceInventory::parse_whatsminer(raw).unwrap();
+
+        assert_eq!(inventory.mac_address.as_deref(), Some("C4:82:E1:0A:0B:0C"));
+        assert_eq!(inventory.serial_number.as_deref(), Some("HTM3X10ABCDEF"));
+        assert_eq!(inventory.hostname.as_deref(), Some("WhatsMiner"));
+        assert_eq!(inventory.network_mode, Some(NetworkMode::Static));
+        assert_eq!(inventory.dns_servers, vec!["114.114.114.114"]);
+
+        // Firmware without the command
+        let rejected = r#"{"STATUS":"E","When":1738800000,"Code":14,"Msg":"invalid cmd","Description":""}"#;
+        assert!(DeviceInventory::parse_whatsminer(rejected).is_err());
+    }
+
+    #[test]
+    fn test_apply_keeps_unreported_fields() {
+        let mut stats = MinerStats { mac_address: Some("02:00:00:00:00:01".into()), ..Default::default() };
+        let inventory = DeviceInventory { serial_number: Some("SN1".into()), ..Default::default() };
+        inventory.apply_to(&mut stats);
+
+        assert_eq!(stats.mac_address.as_deref(), Some("02:00:00:00:00:01"));
+        assert_eq!(stats.serial_number.as_deref(), Some("SN1"));
+    }
+}
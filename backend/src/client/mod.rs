@@ -1,13 +1,24 @@
-use crate::core::{MinerError, MinerStats, Result};
+use crate::core::{FirmwareFamily, MinerError, MinerStats, MinerVendor, Result};
+use dashmap::DashSet;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Duration;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
-use tokio::net::TcpStream;
-use tokio::time::{timeout, timeout_at};
+use tokio::io::AsyncWriteExt;
+use tokio::time::timeout;
 
 pub mod parsers;
+pub mod reader;
+pub mod http_pool;
 pub mod whatsminer_web;
 pub mod antminer_web;
+pub mod arp;
+pub mod source;
+pub mod inventory;
+pub mod credentials;
+pub mod rotation;
+pub mod retry;
+pub mod worker_name;
+pub mod pools;
 use parsers::{MinerParser, AntminerParser, WhatsminerParser};
 
 /// Default CGMiner API port
@@ -16,6 +27,51 @@ pub const DEFAULT_PORT: u16 = 4028;
 /// Default connection timeout
 pub const DEFAULT_TIMEOUT_MS: u64 = 1500;
 
+/// Timeouts and size limit for a single CGMiner request
+#[derive(Debug, Clone, Copy)]
+pub struct RequestOptions {
+    /// Time allowed for the TCP handshake
+    pub connect_timeout_ms: u64,
+    /// Time allowed for sending the request and reading the whole response
+    pub read_timeout_ms: u64,
+    /// Responses larger than this fail with `MinerError::ResponseTooLarge`
+    pub max_response_bytes: usize,
+}
+
+impl RequestOptions {
+    /// Use the same timeout for connecting and reading.
+    pub fn with_timeout(timeout_ms: u64) -> Self {
+        Self {
+            connect_timeout_ms: timeout_ms,
+            read_timeout_ms: timeout_ms,
+            max_response_bytes: reader::DEFAULT_MAX_RESPONSE_BYTES,
+        }
+    }
+}
+
+impl Default for RequestOptions {
+    fn default() -> Self {
+        Self::with_timeout(DEFAULT_TIMEOUT_MS)
+    }
+}
+
+/// Commands fetched for a full refresh of a miner.
+/// `summary` must stay first — it drives miner type detection.
+const REFRESH_COMMANDS: &[&str] = &["summary", "stats", "pools", "version"];
+
+/// Commands sent while identifying a miner during a scan
+const IDENTIFY_COMMANDS: &[&str] = &["summary", "version"];
+
+/// Raw JSON responses of a multi-command request, keyed by command name.
+/// Each value has the same shape as the response to the single command.
+pub type CommandSections = HashMap<String, String>;
+
+lazy_static::lazy_static! {
+    /// `ip:port` endpoints whose firmware rejected a joined command.
+    /// They go straight to sequential commands on later calls.
+    static ref JOINED_UNSUPPORTED: DashSet<String> = DashSet::new();
+}
+
 /// CGMiner JSON-RPC request format
 #[derive(Debug, Serialize)]
 struct CgMinerRequest {
@@ -35,7 +91,7 @@ struct CgMinerResponse {
 #[derive(Debug, Deserialize)]
 struct StatusInfo {
     #[serde(rename = "STATUS")]
-    status: String,
+    _status: String,
     #[serde(rename = "Msg")]
     _msg: String,
     #[serde(rename = "Description")]
@@ -132,9 +188,19 @@ pub async fn send_command(
     command: &str,
     timeout_ms: u64,
 ) -> Result<String> {
-    let address = format!("{}:{}", ip, port);
-    let timeout_duration = Duration::from_millis(timeout_ms);
-    
+    send_command_with_options(ip, port, command, &RequestOptions::with_timeout(timeout_ms)).await
+}
+
+/// Send a command with separate connect/read timeouts and a response size limit.
+///
+/// Reading stops at the terminating NUL or once the JSON object is complete,
+/// so firmware that keeps the socket open doesn't cost a full timeout.
+pub async fn send_command_with_options(
+    ip: &str,
+    port: u16,
+    command: &str,
+    options: &RequestOptions,
+) -> Result<String> {
     // Create the request
     let request = CgMinerRequest {
         command: command.to_string(),
@@ -144,23 +210,22 @@ pub async fn send_command(
     let request_json = serde_json::to_string(&request)?;
     
     // Connect with timeout
-    let stream = timeout(timeout_duration, TcpStream::connect(&address))
-        .await
-        .map_err(|_| MinerError::Timeout(ip.to_string()))?
-        .map_err(|e| MinerError::NetworkError(e))?;
+    let mut stream = timeout(
+        Duration::from_millis(options.connect_timeout_ms),
+        source::connect(ip, port),
+    )
+    .await
+    .map_err(|_| MinerError::Timeout(ip.to_string()))?
+    .map_err(MinerError::NetworkError)?;
     
-    let mut stream = stream;
-    
-    // Send the request
-    stream.write_all(request_json.as_bytes()).await?;
-    
-    // Read the response with timeout
-    let mut buffer = Vec::new();
-    let read_result = timeout(timeout_duration, stream.read_to_end(&mut buffer))
+    // Send the request and read one framed response
+    let exchange = async {
+        stream.write_all(request_json.as_bytes()).await?;
+        reader::read_response(&mut stream, options.max_response_bytes).await
+    };
+    let buffer = timeout(Duration::from_millis(options.read_timeout_ms), exchange)
         .await
-        .map_err(|_| MinerError::Timeout(ip.to_string()))?;
-    
-    read_result?;
+        .map_err(|_| MinerError::Timeout(ip.to_string()))??;
     
     // Convert to string, handling potential trailing null bytes or other garbage
     let response = String::from_utf8_lossy(&buffer).to_string();
@@ -168,34 +233,227 @@ pub async fn send_command(
     Ok(response)
 }
 
+/// Send several commands to a miner in a single TCP round trip.
+///
+/// bmminer/cgminer accept joined commands such as `summary+stats+pools` and
+/// answer with one object keyed by command name.  The combined response is
+/// split back into one section per command, so each value can be handed to
+/// the same parsers as a single-command response.
+///
+/// Firmware that rejects joined commands falls back to one `send_command`
+/// per entry.  In that mode a failing command is left out of the result;
+/// an error is only returned if every command failed.
+pub async fn send_commands(
+    ip: &str,
+    port: u16,
+    commands: &[&str],
+    timeout_ms: u64,
+) -> Result<CommandSections> {
+    if let Some(sections) = try_send_joined(ip, port, commands, timeout_ms).await? {
+        return Ok(sections);
+    }
+
+    let mut sections = CommandSections::new();
+    let mut last_error = None;
+    for &command in commands {
+        match send_command(ip, port, command, timeout_ms).await {
+            Ok(response) => {
+                sections.insert(command.to_string(), response);
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    match last_error {
+        Some(e) if sections.is_empty() => Err(e),
+        _ => Ok(sections),
+    }
+}
+
+/// Send `commands` joined with `+`.
+/// Returns `Ok(None)` if the firmware does not support joined commands
+/// (the endpoint is remembered so later calls skip the attempt).
+/// Network errors are returned as-is: a sequential retry would fail too.
+async fn try_send_joined(
+    ip: &str,
+    port: u16,
+    commands: &[&str],
+    timeout_ms: u64,
+) -> Result<Option<CommandSections>> {
+    let endpoint = format!("{}:{}", ip, port);
+    if commands.len() < 2 || JOINED_UNSUPPORTED.contains(&endpoint) {
+        return Ok(None);
+    }
+
+    let response = send_command(ip, port, &commands.join("+"), timeout_ms).await?;
+    match split_multi_response(&response, commands) {
+        Some(sections) => Ok(Some(sections)),
+        None => {
+            JOINED_UNSUPPORTED.insert(endpoint);
+            Ok(None)
+        }
+    }
+}
+
+/// Split a joined-command response into per-command JSON sections.
+///
+/// Combined format: `{"summary":[{"STATUS":[..],"SUMMARY":[..]}],"pools":[{..}],"id":1}`.
+/// Returns `None` if none of the requested commands are present, which is
+/// how firmware that rejects the join answers (a top-level `STATUS` error).
+fn split_multi_response(response: &str, commands: &[&str]) -> Option<CommandSections> {
+    let json_str = crate::utils::extract_clean_json(response)?;
+    let value: serde_json::Value = serde_json::from_str(&json_str).ok()?;
+    let obj = value.as_object()?;
+
+    let sections: CommandSections = commands
+        .iter()
+        .filter_map(|&command| {
+            let section = match obj.get(command)? {
+                serde_json::Value::Array(items) => items.first()?.clone(),
+                other => other.clone(),
+            };
+            Some((command.to_string(), section.to_string()))
+        })
+        .collect();
+
+    if sections.is_empty() {
+        None
+    } else {
+        Some(sections)
+    }
+}
+
+/// Whether `ip:port` is known to reject joined commands.
+/// Endpoints that were never asked are assumed to support them.
+pub fn supports_joined_commands(ip: &str, port: u16) -> bool {
+    !JOINED_UNSUPPORTED.contains(&format!("{}:{}", ip, port))
+}
+
+/// Return the response to `command`, using the prefetched section if present
+/// and sending the command on its own otherwise.
+pub(crate) async fn section_or_fetch(
+    sections: &CommandSections,
+    ip: &str,
+    port: u16,
+    command: &str,
+    timeout_ms: u64,
+) -> Result<String> {
+    match sections.get(command) {
+        Some(section) => Ok(section.clone()),
+        None => send_command(ip, port, command, timeout_ms).await,
+    }
+}
+
+/// Stats of a miner together with what the summary revealed about its type
+#[derive(Debug, Clone)]
+pub struct MinerReport {
+    pub stats: MinerStats,
+    pub vendor: MinerVendor,
+    pub firmware_family: FirmwareFamily,
+}
+
 /// Get summary statistics from a miner
 pub async fn get_summary(
     ip: &str,
     port: u16,
     timeout_ms: u64,
 ) -> Result<MinerStats> {
-    // 1. Get Summary (Main health check & Type detection)
+    Ok(get_report(ip, port, timeout_ms).await?.stats)
+}
+
+/// Identify a miner's vendor with a single `summary` command,
+/// without fetching any details.
+pub async fn detect_vendor(
+    ip: &str,
+    port: u16,
+    timeout_ms: u64,
+) -> Result<(MinerVendor, FirmwareFamily)> {
     let response_str = send_command(ip, port, "summary", timeout_ms).await?;
+    let (response, _) = parse_summary_response(&response_str)?;
+    let summary = response.summary.as_ref().and_then(|s| s.first()).ok_or(MinerError::InvalidResponse)?;
+    Ok(classify_summary(&response, summary))
+}
+
+/// Identify a miner and read its headline numbers (hashrate, uptime, model)
+/// together with its full identity (MAC, serial, hostname) from the web API.
+/// For commands that need to know exactly which device answers at `ip`.
+pub async fn identify(
+    ip: &str,
+    port: u16,
+    timeout_ms: u64,
+) -> Result<MinerReport> {
+    let mut report = identify_as(ip, port, timeout_ms, None).await?;
+    // MAC and serial are what `MinerId` is derived from
+    inventory::fill_inventory(ip, port, report.vendor, timeout_ms, &mut report.stats).await;
+    Ok(report)
+}
+
+/// Identify a miner with the driver already chosen (e.g. from the MAC OUI)
+/// instead of guessed from the summary, without fetching temps, fans, pools
+/// or anything from the web API.  The MAC only comes from the ARP table.
+/// This is what a scan needs; the monitor fills in the rest with `get_report`.
+pub async fn identify_as(
+    ip: &str,
+    port: u16,
+    timeout_ms: u64,
+    vendor: Option<MinerVendor>,
+) -> Result<MinerReport> {
+    let sections = fetch_sections(ip, port, IDENTIFY_COMMANDS, timeout_ms).await?;
+    let (parser, mut report) = base_report(ip, port, timeout_ms, &sections, vendor).await?;
+    parser.fetch_identity(ip, port, timeout_ms, &sections, &mut report.stats).await?;
+    // The MAC is what `MinerId` is derived from
+    if report.stats.mac_address.is_none() {
+        report.stats.mac_address = lookup_mac_address(ip).await;
+    }
+    Ok(report)
+}
+
+/// Get summary statistics plus vendor/firmware classification from a miner
+pub async fn get_report(
+    ip: &str,
+    port: u16,
+    timeout_ms: u64,
+) -> Result<MinerReport> {
+    let sections = fetch_sections(ip, port, REFRESH_COMMANDS, timeout_ms).await?;
+    let (parser, mut report) = base_report(ip, port, timeout_ms, &sections, None).await?;
+
+    // 4. Fetch Details (Model, Temps, Fans, Pools, etc.)
+    parser.fetch_details(ip, port, timeout_ms, &sections, &mut report.stats).await?;
     
-    // Clean response
-    let json_str = crate::utils::extract_clean_json(&response_str)
-        .unwrap_or_else(|| response_str.trim_matches(|c: char| c.is_whitespace() || c == '\0').to_string());
-    
-    // Parse the response
-    let response: CgMinerResponse = serde_json::from_str(&json_str)?;
-    
-    // Check status
-    let status_desc_raw = response.status.first().and_then(|s| s.description.clone());
-    let status_desc = status_desc_raw.as_deref().unwrap_or_default().to_lowercase();
-    
-    // Extract summary data
-    let summary = response
-        .summary
-        .and_then(|s| s.into_iter().next())
-        .ok_or(MinerError::InvalidResponse)?;
+    Ok(report)
+}
+
+/// Try to fetch `commands` in one round trip.  Firmware that rejects joined
+/// commands gets an empty map, and `summary` and the parser fetch what they
+/// need one command at a time.
+async fn fetch_sections(ip: &str, port: u16, commands: &[&str], timeout_ms: u64) -> Result<CommandSections> {
+    Ok(match try_send_joined(ip, port, commands, timeout_ms).await? {
+        Some(sections) if sections.contains_key("summary") => sections,
+        _ => CommandSections::new(),
+    })
+}
+
+/// Summary-based part of a report: vendor detection plus base stats.
+/// A known `vendor` overrides detection from the summary.
+async fn base_report(
+    ip: &str,
+    port: u16,
+    timeout_ms: u64,
+    sections: &CommandSections,
+    vendor: Option<MinerVendor>,
+) -> Result<(MinerParser, MinerReport)> {
+    // 1. Get Summary (Main health check & Type detection).
+    let response_str = section_or_fetch(sections, ip, port, "summary", timeout_ms).await?;
+    let (response, status_desc_raw) = parse_summary_response(&response_str)?;
 
     // 2. Detect Miner Type
-    let is_whatsminer = summary.firmware_version.is_some() || status_desc.contains("whatsminer");
+    let summary = response.summary.as_ref().and_then(|s| s.first()).ok_or(MinerError::InvalidResponse)?;
+    let (vendor, firmware_family) = match (vendor, classify_summary(&response, summary)) {
+        (Some(MinerVendor::Whatsminer), (_, FirmwareFamily::Unknown)) => (MinerVendor::Whatsminer, FirmwareFamily::Btminer),
+        (Some(vendor), (_, family)) => (vendor, family),
+        (None, detected) => detected,
+    };
+    let is_whatsminer = vendor == MinerVendor::Whatsminer;
     
     let parser = if is_whatsminer {
         MinerParser::Whatsminer(WhatsminerParser)
@@ -204,7 +462,7 @@ pub async fn get_summary(
     };
 
     // 3. Parse Base Stats
-    let mut stats = parser.parse_summary(&summary)?;
+    let mut stats = parser.parse_summary(summary)?;
 
     // If Whatsminer, update software field from Status Description if available
     if is_whatsminer {
@@ -215,107 +473,39 @@ pub async fn get_summary(
         }
     }
 
-    // 4. Fetch Details (Model, Temps, Fans, Pools, etc.)
-    parser.fetch_details(ip, port, timeout_ms, &mut stats).await?;
-    
-    Ok(stats)
+    Ok((parser, MinerReport { stats, vendor, firmware_family }))
 }
 
-/// Look up a device's MAC address from the OS ARP table.
-pub(crate) async fn lookup_mac_address(ip: &str) -> Option<String> {
-    let output = if cfg!(target_os = "macos") {
-        match tokio::process::Command::new("arp")
-            .arg("-n")
-            .arg(ip)
-            .output()
-            .await {
-            Ok(o) => o,
-            Err(e) => {
-                eprintln!("[MAC] Failed to execute 'arp' command for {}: {}", ip, e);
-                return None;
-            }
-        }
-    } else if cfg!(target_os = "windows") {
-        // Windows: `arp -a <ip>` ships with every Windows installation
-        // CREATE_NO_WINDOW (0x08000000) prevents a console window from flashing
-        // for each subprocess spawned during concurrent scanning.
-        #[allow(unused_mut)]
-        let mut cmd = tokio::process::Command::new("arp");
-        cmd.arg("-a").arg(ip);
-        #[cfg(target_os = "windows")]
-        {
-            use std::os::windows::process::CommandExt;
-            cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
-        }
-        match cmd.output().await {
-            Ok(o) => o,
-            Err(e) => {
-                eprintln!("[MAC] Failed to execute 'arp -a' command for {}: {}", ip, e);
-                return None;
-            }
-        }
-    } else {
-        // Linux
-        match tokio::process::Command::new("ip")
-            .args(["neigh", "show", ip])
-            .output()
-            .await {
-            Ok(o) => o,
-            Err(e) => {
-                eprintln!("[MAC] Failed to execute 'ip neigh' command for {}: {}", ip, e);
-                return None;
-            }
-        }
-    };
-
-    if !output.status.success() {
-        eprintln!("[MAC] ARP command failed for {} with status: {}", ip, output.status);
-        return None;
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
+/// Parse a raw `summary` response; also returns the STATUS description.
+fn parse_summary_response(response_str: &str) -> Result<(CgMinerResponse, Option<String>)> {
+    // Clean response
+    let json_str = crate::utils::extract_clean_json(response_str)
+        .unwrap_or_else(|| response_str.trim_matches(|c: char| c.is_whitespace() || c == '\0').to_string());
     
-    // If output is empty, it just means no ARP entry (common), so no need to log
-    if stdout.trim().is_empty() {
-        return None;
-    }
+    // Parse the response
+    let response: CgMinerResponse = serde_json::from_str(&json_str)?;
+    let status_desc = response.status.first().and_then(|s| s.description.clone());
+    Ok((response, status_desc))
+}
+
+/// Decide vendor and firmware family from a parsed summary.
+/// Whatsminer reports `Firmware Version` in the summary or says so in STATUS.
+fn classify_summary(response: &CgMinerResponse, summary: &SummaryData) -> (MinerVendor, FirmwareFamily) {
+    let status_desc = response.status.first().and_then(|s| s.description.as_deref()).unwrap_or_default();
+    let is_whatsminer = summary.firmware_version.is_some() || status_desc.to_lowercase().contains("whatsminer");
 
-    parse_mac_from_arp_output(&stdout)
+    let vendor = if is_whatsminer { MinerVendor::Whatsminer } else { MinerVendor::Antminer };
+    let family = match FirmwareFamily::from_description(status_desc) {
+        FirmwareFamily::Unknown if is_whatsminer => FirmwareFamily::Btminer,
+        family => family,
+    };
+    (vendor, family)
 }
 
-fn parse_mac_from_arp_output(output: &str) -> Option<String> {
-    // Look for a MAC address pattern: xx:xx:xx:xx:xx:xx (with possible single-digit segments)
-    for word in output.split_whitespace() {
-        let parts: Vec<&str> = word.split(':').collect();
-        
-        // Must have exactly 6 segments
-        if parts.len() != 6 {
-            continue;
-        }
-        
-        // Each segment must be 1-2 hex digits
-        if !parts.iter().all(|p| {
-            (p.len() == 1 || p.len() == 2) && p.chars().all(|c| c.is_ascii_hexdigit())
-        }) {
-            continue;
-        }
-        
-        // Normalize: pad single-digit segments with leading zero and uppercase
-        let normalized = parts
-            .iter()
-            .map(|p| {
-                if p.len() == 1 {
-                    format!("0{}", p.to_uppercase())
-                } else {
-                    p.to_uppercase()
-                }
-            })
-            .collect::<Vec<String>>()
-            .join(":");
-        
-        return Some(normalized);
-    }
-    None
+/// Look up a device's MAC address from the OS ARP table.
+/// Only a fallback: prefer what the device reports (see `inventory`).
+pub(crate) async fn lookup_mac_address(ip: &str) -> Option<String> {
+    arp::ARP_CACHE.lookup(ip, DEFAULT_PORT).await
 }
 
 /// Parse hashrate strings like "13.5T" or "13500G" to TH/s
@@ -341,7 +531,15 @@ pub(crate) fn parse_hashrate_string(s: &str) -> Result<f64> {
     }
 }
 
-pub(crate) fn parse_stats_data(json: &str) -> (Vec<Option<f64>>, Vec<Option<f64>>, Vec<Option<f64>>, Vec<Option<f64>>, Vec<Option<u32>>) {
+/// Per-board temperatures and per-fan speeds parsed from a `stats` response:
+/// (outlet min, outlet max, inlet min, inlet max, fans)
+pub(crate) type StatsData = (Vec<Option<f64>>, Vec<Option<f64>>, Vec<Option<f64>>, Vec<Option<f64>>, Vec<Option<u32>>);
+
+/// Pool URL/worker pairs for the first three pools by priority:
+/// (pool1, worker1, pool2, worker2, pool3, worker3)
+pub(crate) type PoolsData = (Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>);
+
+pub(crate) fn parse_stats_data(json: &str) -> StatsData {
     let mut temp_outlet_min = vec![None, None, None]; // Chip temps min (outlet)
     let mut temp_outlet_max = vec![None, None, None]; // Chip temps max (outlet)
     let mut temp_inlet_min = vec![None, None, None];  // PCB temps min (inlet)
@@ -447,14 +645,14 @@ fn parse_temp_string(s: &str) -> (Option<f64>, Option<f64>) {
     (min, max)
 }
 
-pub(crate) fn parse_pools_data(json: &str) -> (Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>) {
+pub(crate) fn parse_pools_data(json: &str) -> PoolsData {
     if let Ok(resp) = serde_json::from_str::<CgMinerPoolsResponse>(json) {
         if let Some(pools) = resp.pools {
              let mut sorted_pools: Vec<&PoolData> = pools.iter().collect();
              sorted_pools.sort_by_key(|p| p.priority);
              
-             let p1 = sorted_pools.get(0).map(|p| p.url.clone());
-             let w1 = sorted_pools.get(0).map(|p| p.user.clone());
+             let p1 = sorted_pools.first().map(|p| p.url.clone());
+             let w1 = sorted_pools.first().map(|p| p.user.clone());
              
              let p2 = sorted_pools.get(1).map(|p| p.url.clone());
              let w2 = sorted_pools.get(1).map(|p| p.user.clone());
@@ -476,7 +674,7 @@ pub(crate) fn parse_version_data(json: &str) -> (Option<String>, Option<String>,
                 let hardware = v.miner.clone();
                 
                 // Firmware: "CompileTime" formatted as YYYYMMDD
-                let firmware = v.compile_time.as_deref().map(parse_compile_time).flatten();
+                let firmware = v.compile_time.as_deref().and_then(parse_compile_time);
                 
                 // Software: "Bmminer " + "BMMiner" field
                 let software = v.bm_miner.as_ref().map(|s| format!("Bmminer {}", s));
@@ -535,6 +733,22 @@ mod tests {
         assert_eq!(parse_hashrate_string("1000G").unwrap(), 1.0);
     }
     
+    #[test]
+    fn test_split_multi_response() {
+        let response = r#"{"summary":[{"STATUS":[{"STATUS":"S","Msg":"Summary"}],"SUMMARY":[{"Elapsed":10}],"id":1}],"pools":[{"STATUS":[{"STATUS":"S","Msg":"1 Pool(s)"}],"POOLS":[],"id":1}],"id":1}"#;
+        let sections = split_multi_response(response, &["summary", "pools", "version"]).unwrap();
+        assert_eq!(sections.len(), 2);
+        assert!(sections["summary"].contains("\"Elapsed\":10"));
+        assert!(sections["pools"].starts_with("{\"POOLS\""));
+        assert!(!sections.contains_key("version"));
+    }
+
+    #[test]
+    fn test_split_multi_response_rejected() {
+        let response = "{\"STATUS\":[{\"STATUS\":\"E\",\"Msg\":\"Invalid command\"}],\"id\":1}\0";
+        assert!(split_multi_response(response, &["summary", "pools"]).is_none());
+    }
+
     #[test]
     fn test_parse_temp_string() {
         assert_eq!(parse_temp_string("40-40-60-60"), (Some(40.0), Some(60.0)));
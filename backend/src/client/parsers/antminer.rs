@@ -1,6 +1,7 @@
 use super::MinerResponseParser;
-use crate::client::{SummaryData, send_command, parse_hashrate_string, parse_stats_data, parse_pools_data, parse_version_data, lookup_mac_address, DEFAULT_TIMEOUT_MS};
-use crate::core::{MinerStats, Result, MinerError};
+use crate::client::{SummaryData, CommandSections, section_or_fetch, parse_stats_data, parse_pools_data, parse_version_data};
+use crate::client::inventory::fill_inventory;
+use crate::core::{MinerStats, MinerVendor, Result};
 use async_trait::async_trait;
 
 pub struct AntminerParser;
@@ -43,13 +44,35 @@ impl MinerResponseParser for AntminerParser {
             software: None,
             hardware: None,
             mac_address: None,
+            serial_number: None,
+            hostname: None,
+            network_mode: None,
+            netmask: None,
+            gateway: None,
+            dns_servers: Vec::new(),
             power_mode: None,
+            fan_mode: None,
+            freq_level: None,
         })
     }
 
-    async fn fetch_details(&self, ip: &str, port: u16, timeout_ms: u64, stats: &mut MinerStats) -> Result<()> {
+    async fn fetch_identity(&self, ip: &str, port: u16, timeout_ms: u64, sections: &CommandSections, stats: &mut MinerStats) -> Result<()> {
+        // Version carries Hardware/Firmware/Model
+        if let Ok(version_json) = section_or_fetch(sections, ip, port, "version", timeout_ms).await {
+            if let Some(clean_json) = crate::utils::extract_clean_json(&version_json) {
+                let (hw, fw, sw, model) = parse_version_data(&clean_json);
+                stats.hardware = hw;
+                stats.firmware = fw;
+                stats.software = sw;
+                stats.model = model;
+            }
+        }
+        Ok(())
+    }
+
+    async fn fetch_details(&self, ip: &str, port: u16, timeout_ms: u64, sections: &CommandSections, stats: &mut MinerStats) -> Result<()> {
         // 1. Get Detailed Stats (Temps, Fans)
-        if let Ok(stats_json) = send_command(ip, port, "stats", timeout_ms).await {
+        if let Ok(stats_json) = section_or_fetch(sections, ip, port, "stats", timeout_ms).await {
             if let Some(clean_json) = crate::utils::extract_clean_json(&stats_json) {
                 let (outlet_min, outlet_max, inlet_min, inlet_max, fans) = parse_stats_data(&clean_json);
                 stats.temp_outlet_min = outlet_min;
@@ -61,7 +84,7 @@ impl MinerResponseParser for AntminerParser {
         }
 
         // 2. Get Pools (Active Pool/Worker)
-        if let Ok(pools_json) = send_command(ip, port, "pools", timeout_ms).await {
+        if let Ok(pools_json) = section_or_fetch(sections, ip, port, "pools", timeout_ms).await {
             if let Some(clean_json) = crate::utils::extract_clean_json(&pools_json) {
                 let (p1, w1, p2, w2, p3, w3) = parse_pools_data(&clean_json);
                 stats.pool1 = p1;
@@ -74,35 +97,30 @@ impl MinerResponseParser for AntminerParser {
         }
 
         // 3. Get Version (Hardware/Firmware/Model)
-        if let Ok(version_json) = send_command(ip, port, "version", timeout_ms).await {
-            if let Some(clean_json) = crate::utils::extract_clean_json(&version_json) {
-                let (hw, fw, sw, model) = parse_version_data(&clean_json);
-                stats.hardware = hw;
-                stats.firmware = fw;
-                stats.software = sw;
-                stats.model = model;
-            }
-        }
+        self.fetch_identity(ip, port, timeout_ms, sections, stats).await?;
 
-        // 4. Get MAC Address
-        stats.mac_address = lookup_mac_address(ip).await;
+        // 4. Inventory (MAC, serial, hostname, network config); ARP fallback for the MAC
+        fill_inventory(ip, port, MinerVendor::Antminer, timeout_ms, stats).await;
 
-        // 5. Read power mode from miner conf (Antminer only).
-        //    Uses its own AppSettings load — mirrors set_miner_power_mode path.
-        //    On failure (wrong creds, timeout, sleep-mode quirk) we keep whatever
-        //    value `stats.power_mode` already has so a transient error doesn't
-        //    erase the last known good value.
+        // 5. Read power mode, fan control and frequency level from the miner
+        //    conf (Antminer only).  Uses the credential fallback — mirrors
+        //    set_miner_power_mode path.  On failure (wrong creds, timeout,
+        //    sleep-mode quirk) we keep whatever values `stats` already has so
+        //    a transient error doesn't erase the last known good value.
         {
             use crate::client::antminer_web::AntminerWebClient;
-            let settings = crate::core::config::AppSettings::load();
-            let creds = settings.antminer_credentials;
-            match AntminerWebClient::read_power_mode(ip, &creds.username, &creds.password).await {
-                Ok(mode) => {
-                    stats.power_mode = Some(mode);
+            let read = crate::client::credentials::with_credentials(ip, MinerVendor::Antminer, |creds| async move {
+                AntminerWebClient::read_conf_settings(ip, &creds.username, &creds.password).await
+            });
+            match read.await {
+                Ok(conf) => {
+                    stats.power_mode = Some(conf.power_mode);
+                    stats.fan_mode = Some(conf.fan_mode);
+                    stats.freq_level = conf.freq_level;
                 }
                 Err(e) => {
-                    println!("[antminer] read_power_mode failed for {}: {} (keeping previous value {:?})", ip, e, stats.power_mode);
-                    // keep stats.power_mode as-is (it was pre-set by caller for monitor path)
+                    println!("[antminer] read_conf_settings failed for {}: {} (keeping previous power mode {:?})", ip, e, stats.power_mode);
+                    // keep the values as-is (they were pre-set by caller for monitor path)
                 }
             }
         }
@@ -1,5 +1,5 @@
 use crate::core::{MinerStats, Result};
-use crate::client::SummaryData;
+use crate::client::{CommandSections, SummaryData};
 use async_trait::async_trait;
 
 pub mod antminer;
@@ -13,8 +13,13 @@ pub trait MinerResponseParser: Send + Sync {
     /// Parse the summary data into a base MinerStats object
     fn parse_summary(&self, summary: &SummaryData) -> Result<MinerStats>;
 
-    /// Fetch additional details (temps, fans, model, etc.) specific to the miner type
-    async fn fetch_details(&self, ip: &str, port: u16, timeout_ms: u64, stats: &mut MinerStats) -> Result<()>;
+    /// Fetch just enough to name the miner (model, firmware) during a scan.
+    async fn fetch_identity(&self, ip: &str, port: u16, timeout_ms: u64, sections: &CommandSections, stats: &mut MinerStats) -> Result<()>;
+
+    /// Fetch additional details (temps, fans, model, etc.) specific to the miner type.
+    /// `sections` holds responses already fetched in a joined request; only
+    /// commands missing from it are sent to the miner.
+    async fn fetch_details(&self, ip: &str, port: u16, timeout_ms: u64, sections: &CommandSections, stats: &mut MinerStats) -> Result<()>;
 }
 
 pub enum MinerParser {
@@ -30,10 +35,17 @@ impl MinerParser {
         }
     }
 
-    pub async fn fetch_details(&self, ip: &str, port: u16, timeout_ms: u64, stats: &mut MinerStats) -> Result<()> {
+    pub async fn fetch_identity(&self, ip: &str, port: u16, timeout_ms: u64, sections: &CommandSections, stats: &mut MinerStats) -> Result<()> {
+        match self {
+            MinerParser::Antminer(p) => p.fetch_identity(ip, port, timeout_ms, sections, stats).await,
+            MinerParser::Whatsminer(p) => p.fetch_identity(ip, port, timeout_ms, sections, stats).await,
+        }
+    }
+
+    pub async fn fetch_details(&self, ip: &str, port: u16, timeout_ms: u64, sections: &CommandSections, stats: &mut MinerStats) -> Result<()> {
         match self {
-            MinerParser::Antminer(p) => p.fetch_details(ip, port, timeout_ms, stats).await,
-            MinerParser::Whatsminer(p) => p.fetch_details(ip, port, timeout_ms, stats).await,
+            MinerParser::Antminer(p) => p.fetch_details(ip, port, timeout_ms, sections, stats).await,
+            MinerParser::Whatsminer(p) => p.fetch_details(ip, port, timeout_ms, sections, stats).await,
         }
     }
 }
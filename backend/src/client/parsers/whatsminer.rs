@@ -1,6 +1,7 @@
 use super::MinerResponseParser;
-use crate::client::{SummaryData, send_command, parse_hashrate_string, parse_pools_data, lookup_mac_address};
-use crate::core::{MinerStats, Result, MinerError};
+use crate::client::{SummaryData, CommandSections, send_command, section_or_fetch, parse_hashrate_string, parse_pools_data};
+use crate::client::inventory::fill_inventory;
+use crate::core::{MinerStats, MinerVendor, Result};
 use async_trait::async_trait;
 use serde::Deserialize;
 
@@ -90,12 +91,20 @@ impl MinerResponseParser for WhatsminerParser {
             software: None, // Could be "Description" from status?
             hardware: None,
             mac_address: None, // Will be fetched
+            serial_number: None,
+            hostname: None,
+            network_mode: None,
+            netmask: None,
+            gateway: None,
+            dns_servers: Vec::new(),
             power_mode: None,  // Whatsminer doesn't expose miner-mode via CGMiner API
+            fan_mode: None,
+            freq_level: None,
         })
     }
 
-    async fn fetch_details(&self, ip: &str, port: u16, timeout_ms: u64, stats: &mut MinerStats) -> Result<()> {
-        // 1. Fetch Model via devdetails
+    async fn fetch_identity(&self, ip: &str, port: u16, timeout_ms: u64, _sections: &CommandSections, stats: &mut MinerStats) -> Result<()> {
+        // btminer's `version` has no model; devdetails does
         if let Ok(response) = send_command(ip, port, "devdetails", timeout_ms).await {
             if let Some(clean) = crate::utils::extract_clean_json(&response) {
                  if let Ok(details) = serde_json::from_str::<DevDetailsResponse>(&clean) {
@@ -107,9 +116,15 @@ impl MinerResponseParser for WhatsminerParser {
                  }
             }
         }
+        Ok(())
+    }
+
+    async fn fetch_details(&self, ip: &str, port: u16, timeout_ms: u64, sections: &CommandSections, stats: &mut MinerStats) -> Result<()> {
+        // 1. Fetch Model via devdetails
+        self.fetch_identity(ip, port, timeout_ms, sections, stats).await?;
 
         // 2. Fetch Pools
-        if let Ok(pools_json) = send_command(ip, port, "pools", timeout_ms).await {
+        if let Ok(pools_json) = section_or_fetch(sections, ip, port, "pools", timeout_ms).await {
             if let Some(clean_json) = crate::utils::extract_clean_json(&pools_json) {
                 let (p1, w1, p2, w2, p3, w3) = parse_pools_data(&clean_json);
                 stats.pool1 = p1;
@@ -121,8 +136,8 @@ impl MinerResponseParser for WhatsminerParser {
             }
         }
 
-        // 3. MAC Address
-        stats.mac_address = lookup_mac_address(ip).await;
+        // 3. Inventory (MAC, serial, hostname, network config); ARP fallback for the MAC
+        fill_inventory(ip, port, MinerVendor::Whatsminer, timeout_ms, stats).await;
 
         Ok(())
     }
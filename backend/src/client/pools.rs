@@ -0,0 +1,342 @@
+//! Checking pool settings before they are sent, and comparing them with
+//! what a miner has.
+//!
+//! A bad stratum URL is only noticed after the miner has saved it and
+//! (Antminer) rebooted, so pools are validated strictly up front:
+//!
+//! - URL: `stratum+tcp://`, `stratum+ssl://`, `stratum+tls://` or
+//!   `stratum2+tcp://`, a hostname or IPv4 address, and a port; nothing after
+//!   the port
+//! - worker: 1–128 of `A-Z a-z 0-9 . _ -`, no leading, trailing or double dot.
+//!   Worker templates (see `worker_name`) are checked with placeholders
+//!   filled in, and again after expansion
+//! - password: up to 64 characters, no whitespace
+
+use super::antminer_web::AntminerWebClient;
+use super::whatsminer_web::WhatsminerWebClient;
+use super::{credentials, send_command, worker_name, DEFAULT_PORT, DEFAULT_TIMEOUT_MS};
+use crate::api::models::PoolConfig;
+use crate::core::{MinerError, MinerVendor, Result};
+use serde::Deserialize;
+use std::net::Ipv4Addr;
+
+/// Miners take at most this many pools
+pub const MAX_POOLS: usize = 3;
+
+const SCHEMES: &[&str] = &["stratum+tcp", "stratum+ssl", "stratum+tls", "stratum2+tcp"];
+const MAX_WORKER_LEN: usize = 128;
+const MAX_PASSWORD_LEN: usize = 64;
+
+/// One pool slot whose setting would change (slots are 1-based)
+#[derive(Debug, Clone)]
+pub struct PoolSlotChange {
+    pub slot: u32,
+    /// `None` if the slot is empty now
+    pub current: Option<PoolConfig>,
+    /// `None` if the slot would be cleared
+    pub intended: Option<PoolConfig>,
+}
+
+/// Pools configured on a miner
+#[derive(Debug, Clone, Default)]
+pub struct CurrentPools {
+    pub pools: Vec<PoolConfig>,
+    /// False when read from the CGMiner API, which doesn't report passwords
+    pub passwords_known: bool,
+}
+
+/// Check a stratum URL
+pub fn validate_url(url: &str) -> Result<()> {
+    let fail = |reason: &str| Err(invalid(format!("URL '{}': {}", url, reason)));
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return fail("missing scheme, e.g. stratum+tcp://");
+    };
+    if !SCHEMES.contains(&scheme.to_ascii_lowercase().as_str()) {
+        return fail(&format!("scheme must be one of {}", SCHEMES.join(", ")));
+    }
+    let Some((host, port)) = rest.rsplit_once(':') else {
+        return fail("missing port");
+    };
+    if !valid_host(host) {
+        return fail(&format!("invalid host '{}'", host));
+    }
+    match port.parse::<u16>() {
+        Ok(n) if n > 0 && port.chars().all(|c| c.is_ascii_digit()) => Ok(()),
+        _ => fail(&format!("invalid port '{}'", port)),
+    }
+}
+
+fn valid_host(host: &str) -> bool {
+    if host.parse::<Ipv4Addr>().is_ok() {
+        return true;
+    }
+    // All-numeric dotted names are malformed IPs, not hostnames
+    if host.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return false;
+    }
+    !host.is_empty()
+        && host.len() <= 253
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+/// Check an expanded worker name
+pub fn validate_worker(worker: &str) -> Result<()> {
+    let fail = |reason: &str| Err(invalid(format!("worker '{}': {}", worker, reason)));
+    if worker.is_empty() {
+        return fail("empty");
+    }
+    if worker.len() > MAX_WORKER_LEN {
+        return fail(&format!("longer than {} characters", MAX_WORKER_LEN));
+    }
+    if let Some(c) = worker.chars().find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '.' | '_' | '-')) {
+        return fail(&format!("'{}' not allowed", c));
+    }
+    if worker.starts_with('.') || worker.ends_with('.') || worker.contains("..") {
+        return fail("empty part between dots");
+    }
+    Ok(())
+}
+
+pub fn validate_password(password: &str) -> Result<()> {
+    if password.chars().count() > MAX_PASSWORD_LEN {
+        return Err(invalid(format!("pool password longer than {} characters", MAX_PASSWORD_LEN)));
+    }
+    if password.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(invalid("pool password contains whitespace".to_string()));
+    }
+    Ok(())
+}
+
+/// Check pools as they will be sent: 1–3 entries, workers expanded
+pub fn validate_pools(pools: &[PoolConfig]) -> Result<()> {
+    check_count(pools)?;
+    for pool in pools {
+        validate_url(&pool.url)?;
+        validate_worker(&pool.worker)?;
+        validate_password(&pool.password)?;
+    }
+    Ok(())
+}
+
+/// Check pools whose workers may still be templates
+pub fn validate_template_pools(pools: &[PoolConfig]) -> Result<()> {
+    check_count(pools)?;
+    for pool in pools {
+        validate_url(&pool.url)?;
+        let mut sample = pool.worker.clone();
+        for name in worker_name::placeholders(&pool.worker)? {
+            sample = sample.replacen(&format!("{{{}}}", name), "x", 1);
+        }
+        validate_worker(&sample).map_err(|_| invalid(format!("worker '{}': invalid characters or dots", pool.worker)))?;
+        validate_password(&pool.password)?;
+    }
+    Ok(())
+}
+
+fn check_count(pools: &[PoolConfig]) -> Result<()> {
+    if pools.is_empty() || pools.len() > MAX_POOLS {
+        return Err(invalid(format!("{} pools given, 1–{} allowed", pools.len(), MAX_POOLS)));
+    }
+    Ok(())
+}
+
+/// Validate `pools`, expand their worker templates for the miner at `ip`
+/// and validate the result.  This is what `SetPools` sends.
+pub async fn prepare(ip: &str, pools: &[PoolConfig]) -> Result<Vec<PoolConfig>> {
+    validate_template_pools(pools)?;
+    let pools = worker_name::resolve_pools(ip, pools).await?;
+    validate_pools(&pools)?;
+    Ok(pools)
+}
+
+/// Read the pools configured on the miner at `ip` from its web UI.  For a
+/// Whatsminer whose web UI can't be read, the CGMiner API is asked instead
+/// (without passwords).
+pub async fn read_pools(ip: &str, vendor: MinerVendor) -> Result<CurrentPools> {
+    match vendor {
+        MinerVendor::Antminer => {
+            let pools = credentials::with_credentials(ip, vendor, |c| async move {
+                AntminerWebClient::get_pools(ip, &c.username, &c.password).await
+            })
+            .await?;
+            Ok(CurrentPools {
+                pools: pools
+                    .into_iter()
+                    .filter(|p| !p.url.trim().is_empty())
+                    .map(|p| PoolConfig { url: p.url, worker: p.user, password: p.pass })
+                    .collect(),
+                passwords_known: true,
+            })
+        }
+        MinerVendor::Whatsminer => {
+            let web = credentials::with_credentials(ip, vendor, |c| async move {
+                WhatsminerWebClient::get_pools(ip, &c.username, &c.password).await
+            });
+            match web.await {
+                Ok(pools) => Ok(CurrentPools {
+                    pools: pools
+                        .into_iter()
+                        .map(|p| PoolConfig { url: p.url, worker: p.worker, password: p.password })
+                        .collect(),
+                    passwords_known: true,
+                }),
+                Err(e) => {
+                    println!("[pools] web UI of {} unreadable ({}), asking the CGMiner API", ip, e);
+                    let response = send_command(ip, DEFAULT_PORT, "pools", DEFAULT_TIMEOUT_MS).await?;
+                    Ok(CurrentPools { pools: parse_cgminer_pools(&response)?, passwords_known: false })
+                }
+            }
+        }
+    }
+}
+
+/// Pools from a CGMiner `pools` response, by priority; passwords are blank
+fn parse_cgminer_pools(response: &str) -> Result<Vec<PoolConfig>> {
+    #[derive(Deserialize)]
+    struct Response {
+        #[serde(rename = "POOLS", default)]
+        pools: Vec<Pool>,
+    }
+    #[derive(Deserialize)]
+    struct Pool {
+        #[serde(rename = "URL")]
+        url: String,
+        #[serde(rename = "User", default)]
+        user: String,
+        #[serde(rename = "Priority", default)]
+        priority: u64,
+    }
+
+    let json = crate::utils::extract_clean_json(response).ok_or(MinerError::InvalidResponse)?;
+    let mut pools = serde_json::from_str::<Response>(&json)?.pools;
+    pools.sort_by_key(|p| p.priority);
+    Ok(pools
+        .into_iter()
+        .filter(|p| !p.url.trim().is_empty())
+        .map(|p| PoolConfig { url: p.url, worker: p.user, password: String::new() })
+        .collect())
+}
+
+/// Slots where `current` differs from `intended`; empty if the miner
+/// already complies
+pub fn diff(current: &CurrentPools, intended: &[PoolConfig]) -> Vec<PoolSlotChange> {
+    (0..current.pools.len().max(intended.len()))
+        .filter_map(|i| {
+            let (now, want) = (current.pools.get(i), intended.get(i));
+            let same = match (now, want) {
+                (Some(a), Some(b)) => same_pool(a, b, current.passwords_known),
+                (None, None) => true,
+                _ => false,
+            };
+            (!same).then(|| PoolSlotChange { slot: i as u32 + 1, current: now.cloned(), intended: want.cloned() })
+        })
+        .collect()
+}
+
+fn same_pool(a: &PoolConfig, b: &PoolConfig, compare_password: bool) -> bool {
+    normalize_url(&a.url) == normalize_url(&b.url)
+        && a.worker.trim() == b.worker.trim()
+        && (!compare_password || a.password == b.password)
+}
+
+/// Scheme and host are case-insensitive
+fn normalize_url(url: &str) -> String {
+    url.trim().trim_end_matches('/').to_ascii_lowercase()
+}
+
+fn invalid(message: String) -> MinerError {
+    MinerError::InvalidPoolConfig(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(url: &str, worker: &str) -> PoolConfig {
+        PoolConfig { url: url.into(), worker: worker.into(), password: "x".into() }
+    }
+
+    #[test]
+    fn test_validate_url() {
+        assert!(validate_url("stratum+tcp://btc.pool.example:3333").is_ok());
+        assert!(validate_url("stratum+ssl://10.0.0.5:443").is_ok());
+        assert!(validate_url("STRATUM+TCP://Pool.Example:25").is_ok());
+
+        for bad in [
+            "btc.pool.example:3333",
+            "http://btc.pool.example:3333",
+            "stratum+tcp://btc.pool.example",
+            "stratum+tcp://btc.pool.example:0",
+            "stratum+tcp://btc.pool.example:70000",
+            "stratum+tcp://btc.pool.example:3333/path",
+            "stratum+tcp://-pool.example:3333",
+            "stratum+tcp://pool..example:3333",
+            "stratum+tcp://10.0.0.256:3333",
+            "stratum+tcp:// pool.example:3333",
+        ] {
+            assert!(matches!(validate_url(bad), Err(MinerError::InvalidPoolConfig(_))), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_validate_workers() {
+        assert!(validate_worker("acct.B12x3").is_ok());
+        assert!(validate_worker("acct.10.2.3.4").is_ok());
+        assert!(validate_worker("").is_err());
+        assert!(validate_worker("acct worker").is_err());
+        assert!(validate_worker("acct..rig").is_err());
+        assert!(validate_worker("acct.").is_err());
+        assert!(validate_password("x").is_ok());
+        assert!(validate_password("a b").is_err());
+
+        let url = "stratum+tcp://pool.example:3333";
+        assert!(validate_template_pools(&[pool(url, "acct.{rack}x{position}")]).is_ok());
+        assert!(validate_template_pools(&[pool(url, "acct.{slot}")]).is_err());
+        assert!(validate_template_pools(&[pool(url, "acct {ip4}")]).is_err());
+        assert!(validate_pools(&[]).is_err());
+        assert!(validate_pools(&vec![pool(url, "a.b"); 4]).is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        let url = "stratum+tcp://pool.example:3333";
+        let backup = "stratum+tcp://backup.example:3333";
+        let current = CurrentPools {
+            pools: vec![pool("stratum+tcp://Pool.Example:3333/", "acct.1"), pool(backup, "acct.1")],
+            passwords_known: false,
+        };
+
+        // Case, trailing slash and an unknown password don't count
+        let mut same = vec![pool(url, "acct.1"), pool(backup, "acct.1")];
+        same[0].password = "other".into();
+        assert!(diff(&current, &same).is_empty());
+
+        let changes = diff(&current, &[pool(url, "acct.2")]);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].slot, 1);
+        assert_eq!(changes[0].intended.as_ref().unwrap().worker, "acct.2");
+        assert_eq!(changes[1].slot, 2);
+        assert!(changes[1].intended.is_none());
+
+        let known = CurrentPools { passwords_known: true, ..current };
+        assert_eq!(diff(&known, &same).len(), 1);
+    }
+
+    #[test]
+    fn test_parse_cgminer_pools() {
+        let response = r#"{"STATUS":[{"STATUS":"S"}],"POOLS":[
+            {"POOL":1,"URL":"stratum+tcp://b.example:3333","User":"acct.2","Status":"Alive","Priority":1},
+            {"POOL":0,"URL":"stratum+tcp://a.example:3333","User":"acct.1","Status":"Alive","Priority":0},
+            {"POOL":2,"URL":"","User":"","Status":"Dead","Priority":2}],"id":1}"#;
+        let pools = parse_cgminer_pools(response).unwrap();
+        assert_eq!(pools.len(), 2);
+        assert_eq!(pools[0].url, "stratum+tcp://a.example:3333");
+        assert_eq!(pools[1].worker, "acct.2");
+    }
+}
@@ -0,0 +1,140 @@
+//! Framed reader for CGMiner API responses.
+//!
+//! The API has no length prefix.  cgminer/bmminer terminate a response with a
+//! NUL byte and close the socket, but some firmware keeps the connection open,
+//! so waiting for EOF turns every call into a full-timeout wait.  Instead we
+//! stop reading at the first NUL, or as soon as the top-level JSON object is
+//! balanced — whichever comes first.
+
+use crate::core::{MinerError, Result};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Upper bound on a single response (a joined `summary+stats+pools+version`
+/// from a large hash board is well under 64 KiB).
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 1024 * 1024;
+
+const READ_CHUNK_BYTES: usize = 4096;
+
+/// Read one framed response from `reader`.
+///
+/// Returns the response without the trailing NUL.  Fails with
+/// `MinerError::ResponseTooLarge` once more than `max_bytes` arrive without a
+/// frame end, and with `MinerError::TruncatedResponse` if the peer closes the
+/// connection before the JSON object is complete.
+pub async fn read_response<R: AsyncRead + Unpin>(reader: &mut R, max_bytes: usize) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    let mut scanner = JsonFrameScanner::default();
+    let mut chunk = [0u8; READ_CHUNK_BYTES];
+
+    loop {
+        let n = reader.read(&mut chunk).await?;
+        if n == 0 {
+            // EOF: accept only a complete object (firmware that closes without NUL)
+            return if scanner.is_complete() {
+                Ok(buffer)
+            } else {
+                Err(MinerError::TruncatedResponse { received: buffer.len() })
+            };
+        }
+
+        for &byte in &chunk[..n] {
+            if byte == 0 {
+                return Ok(buffer);
+            }
+            if buffer.len() >= max_bytes {
+                return Err(MinerError::ResponseTooLarge { limit: max_bytes });
+            }
+            buffer.push(byte);
+            if scanner.feed(byte) {
+                return Ok(buffer);
+            }
+        }
+    }
+}
+
+/// Incremental brace matcher that tracks string literals, so `{`/`}` inside
+/// pool URLs or worker names don't end the frame early.
+#[derive(Debug, Default)]
+struct JsonFrameScanner {
+    depth: usize,
+    started: bool,
+    in_string: bool,
+    escaped: bool,
+}
+
+impl JsonFrameScanner {
+    /// Feed one byte; returns `true` when the top-level object just closed.
+    fn feed(&mut self, byte: u8) -> bool {
+        if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+            }
+            return false;
+        }
+
+        match byte {
+            b'"' => self.in_string = true,
+            b'{' => {
+                self.started = true;
+                self.depth += 1;
+            }
+            b'}' if self.depth > 0 => {
+                self.depth -= 1;
+                return self.depth == 0;
+            }
+            _ => {}
+        }
+        false
+    }
+
+    fn is_complete(&self) -> bool {
+        self.started && self.depth == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn read(bytes: &[u8], max_bytes: usize) -> Result<Vec<u8>> {
+        let mut reader = bytes;
+        read_response(&mut reader, max_bytes).await
+    }
+
+    #[tokio::test]
+    async fn test_stops_at_nul() {
+        let response = read(b"{\"STATUS\":[]}\0trailing", 1024).await.unwrap();
+        assert_eq!(response, b"{\"STATUS\":[]}");
+    }
+
+    #[tokio::test]
+    async fn test_stops_at_balanced_object() {
+        let response = read(b"{\"a\":{\"b\":1}}garbage", 1024).await.unwrap();
+        assert_eq!(response, b"{\"a\":{\"b\":1}}");
+    }
+
+    #[tokio::test]
+    async fn test_braces_inside_strings() {
+        let response = read(br#"{"URL":"stratum+tcp://{pool}:3333","User":"a\"}"}"#, 1024).await.unwrap();
+        assert_eq!(response, br#"{"URL":"stratum+tcp://{pool}:3333","User":"a\"}"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_truncated() {
+        let result = read(b"{\"STATUS\":[{\"STATUS\":\"S\"", 1024).await;
+        assert!(matches!(result, Err(MinerError::TruncatedResponse { received: 24 })));
+
+        let result = read(b"", 1024).await;
+        assert!(matches!(result, Err(MinerError::TruncatedResponse { received: 0 })));
+    }
+
+    #[tokio::test]
+    async fn test_too_large() {
+        let result = read(b"{\"STATUS\":\"0123456789\"}", 8).await;
+        assert!(matches!(result, Err(MinerError::ResponseTooLarge { limit: 8 })));
+    }
+}
@@ -0,0 +1,261 @@
+//! Retrying commands that failed for a transient reason.
+//!
+//! A batch command that times out on one miner used to be reported as
+//! failed, and the operator re-ran the whole batch.  Now each command has a
+//! retry policy: how often to try, how long to wait between attempts and
+//! which error kinds are worth another try.
+//!
+//! Commands that are not idempotent (a reboot) get a pre-check before every
+//! attempt, so a retry can't repeat something the failed attempt actually
+//! did — e.g. a reboot whose response was lost because the miner went down.
+//! Before the first attempt the pre-check can also skip miners that booted
+//! only minutes ago, so re-running a batch doesn't reboot them twice.
+
+use crate::core::{ErrorKind, MinerError, Result};
+use std::future::Future;
+use std::time::{Duration, Instant, SystemTime};
+
+/// `min_uptime_secs` of the default reboot policy
+pub const RECENT_BOOT_SECS: u64 = 5 * 60;
+
+/// How a command is retried
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first; 1 disables retries
+    pub max_attempts: u32,
+    /// Wait before the first retry; doubled for each further one
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    /// Error kinds that are retried; `RejectedByMiner` matches any code
+    pub retry_on: Vec<ErrorKind>,
+    /// Non-idempotent commands only: a miner up for less than this is
+    /// taken to have done the command already and is skipped; 0 disables
+    pub min_uptime_secs: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff_ms: 2000,
+            max_backoff_ms: 10_000,
+            retry_on: vec![ErrorKind::Timeout, ErrorKind::Unreachable],
+            min_uptime_secs: 0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A single attempt
+    pub fn none() -> Self {
+        Self { max_attempts: 1, ..Self::default() }
+    }
+
+    /// Wait before retry number `retry` (1 = the second attempt)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u64.checked_shl(retry.saturating_sub(1)).unwrap_or(u64::MAX);
+        Duration::from_millis(self.initial_backoff_ms.saturating_mul(factor).min(self.max_backoff_ms))
+    }
+
+    pub fn retries(&self, kind: &ErrorKind) -> bool {
+        self.retry_on.iter().any(|k| std::mem::discriminant(k) == std::mem::discriminant(kind))
+    }
+
+    /// Pre-check of the first attempt from the miner's reported uptime
+    /// (0: not reported, which never skips)
+    pub fn check_uptime(&self, uptime: u64) -> PreCheck {
+        if uptime > 0 && uptime < self.min_uptime_secs {
+            PreCheck::Satisfied(format!("miner up for only {}s, restarted recently", uptime))
+        } else {
+            PreCheck::Proceed
+        }
+    }
+}
+
+/// One try of a command
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandAttempt {
+    /// 1-based
+    pub attempt: u32,
+    /// Unix timestamp
+    pub started_at: u64,
+    pub duration_ms: u64,
+    pub error: Option<String>,
+    pub error_kind: Option<ErrorKind>,
+}
+
+/// Verdict of a pre-check before an attempt
+#[derive(Debug, Clone, PartialEq)]
+pub enum PreCheck {
+    Proceed,
+    /// The command's effect is already there (e.g. the miner just
+    /// rebooted); stop and count it as done
+    Satisfied(String),
+    /// Can't tell whether the last attempt went through; stop and keep its
+    /// error rather than risk doing it twice
+    Hold(String),
+}
+
+/// Result of a command with its attempts
+#[derive(Debug)]
+pub struct RetryOutcome {
+    pub result: Result<()>,
+    pub attempts: Vec<CommandAttempt>,
+    /// Why the command (or its retry) wasn't sent, from the pre-check
+    pub skipped: Option<String>,
+}
+
+/// Run `op` under `policy`, asking `precheck` (with the attempt number)
+/// before every attempt
+pub async fn run<F, Fut, P, PFut>(policy: &RetryPolicy, precheck: P, op: F) -> RetryOutcome
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<()>>,
+    P: Fn(u32) -> PFut,
+    PFut: Future<Output = PreCheck>,
+{
+    let mut attempts = Vec::new();
+    let mut last_error: Option<MinerError> = None;
+
+    for attempt in 1..=policy.max_attempts.max(1) {
+        if attempt > 1 {
+            tokio::time::sleep(policy.backoff(attempt - 1)).await;
+        }
+        match precheck(attempt).await {
+            PreCheck::Proceed => {}
+            PreCheck::Satisfied(reason) => {
+                return RetryOutcome { result: Ok(()), attempts, skipped: Some(reason) };
+            }
+            PreCheck::Hold(reason) => {
+                let result = Err(last_error.unwrap_or_else(|| MinerError::GenericError(reason.clone())));
+                return RetryOutcome { result, attempts, skipped: Some(reason) };
+            }
+        }
+
+        let started_at = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs();
+        let start = Instant::now();
+        let result = op().await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        match result {
+            Ok(()) => {
+                attempts.push(CommandAttempt { attempt, started_at, duration_ms, error: None, error_kind: None });
+                return RetryOutcome { result: Ok(()), attempts, skipped: None };
+            }
+            Err(e) => {
+                let kind = e.kind();
+                attempts.push(CommandAttempt {
+                    attempt,
+                    started_at,
+                    duration_ms,
+                    error: Some(e.to_string()),
+                    error_kind: Some(kind.clone()),
+                });
+                let retry = policy.retries(&kind) && attempt < policy.max_attempts;
+                last_error = Some(e);
+                if !retry {
+                    break;
+                }
+            }
+        }
+    }
+
+    RetryOutcome { result: Err(last_error.unwrap_or(MinerError::InvalidResponse)), attempts, skipped: None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn fast(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy { max_attempts, initial_backoff_ms: 1, max_backoff_ms: 2, ..RetryPolicy::default() }
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_millis(2000));
+        assert_eq!(policy.backoff(2), Duration::from_millis(4000));
+        assert_eq!(policy.backoff(5), Duration::from_millis(10_000));
+        assert_eq!(policy.backoff(200), Duration::from_millis(10_000));
+
+        let rejected = RetryPolicy { retry_on: vec![ErrorKind::RejectedByMiner { code: String::new() }], ..policy };
+        assert!(rejected.retries(&ErrorKind::RejectedByMiner { code: "HTTP 500".into() }));
+        assert!(!rejected.retries(&ErrorKind::Timeout));
+    }
+
+    #[test]
+    fn test_check_uptime() {
+        let policy = RetryPolicy { min_uptime_secs: RECENT_BOOT_SECS, ..RetryPolicy::default() };
+        assert!(matches!(policy.check_uptime(90), PreCheck::Satisfied(_)));
+        assert_eq!(policy.check_uptime(RECENT_BOOT_SECS), PreCheck::Proceed);
+        assert_eq!(policy.check_uptime(0), PreCheck::Proceed);
+        assert_eq!(RetryPolicy::default().check_uptime(90), PreCheck::Proceed);
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_errors_only() {
+        let calls = AtomicU32::new(0);
+        let outcome = run(&fast(3), |_| async { PreCheck::Proceed }, || async {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(MinerError::Timeout("10.0.0.1".into())),
+                _ => Ok(()),
+            }
+        })
+        .await;
+        assert!(outcome.result.is_ok());
+        assert_eq!(outcome.attempts.len(), 2);
+        assert_eq!(outcome.attempts[0].error_kind, Some(ErrorKind::Timeout));
+        assert_eq!(outcome.attempts[1].error, None);
+
+        let calls = AtomicU32::new(0);
+        let outcome = run(&fast(3), |_| async { PreCheck::Proceed }, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(MinerError::AuthenticationError)
+        })
+        .await;
+        assert!(matches!(outcome.result, Err(MinerError::AuthenticationError)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_precheck_stops_repeat() {
+        // First attempt lost its response; the pre-check sees it went through
+        let outcome = run(
+            &fast(3),
+            |attempt| async move {
+                if attempt > 1 { PreCheck::Satisfied("up for 12s".into()) } else { PreCheck::Proceed }
+            },
+            || async { Err(MinerError::Unreachable("connection reset".into())) },
+        )
+        .await;
+        assert!(outcome.result.is_ok());
+        assert_eq!(outcome.attempts.len(), 1);
+        assert_eq!(outcome.skipped.as_deref(), Some("up for 12s"));
+
+        // Can't tell: keep the error instead of trying again
+        let outcome = run(
+            &fast(3),
+            |attempt| async move {
+                if attempt > 1 { PreCheck::Hold("not answering".into()) } else { PreCheck::Proceed }
+            },
+            || async { Err(MinerError::Timeout("10.0.0.1".into())) },
+        )
+        .await;
+        assert!(matches!(outcome.result, Err(MinerError::Timeout(_))));
+        assert_eq!(outcome.attempts.len(), 1);
+
+        // Booted a minute ago: the first attempt isn't sent at all
+        let policy = RetryPolicy { min_uptime_secs: RECENT_BOOT_SECS, ..fast(3) };
+        let outcome = run(
+            &policy,
+            |_| async { policy.check_uptime(60) },
+            || async { panic!("reboot sent to a freshly booted miner") },
+        )
+        .await;
+        assert!(outcome.result.is_ok());
+        assert!(outcome.attempts.is_empty());
+        assert!(outcome.skipped.is_some());
+    }
+}
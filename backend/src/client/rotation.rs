@@ -0,0 +1,379 @@
+//! Fleet-wide rotation of the miners' web passwords.
+//!
+//! Each miner is logged in to with whatever credential works today (see
+//! `client::credentials`), given a new password, and logged in to again with
+//! the new one before it counts as rotated.  The vault always holds a
+//! password the miner accepts:
+//!
+//! 1. the new password is staged in the vault as a `rotation-pending:` set,
+//!    tried after everything else
+//! 2. the miner's password is changed
+//! 3. a fresh login with the new password is verified
+//! 4. the staged set becomes the miner's `rotated:` set, tried first
+//!
+//! If verification fails the old password is restored (when the miner has
+//! not kept it anyway) and the staged set dropped.  A set staged by an
+//! interrupted rotation is left alone until a later rotation of that miner
+//! succeeds, since it may hold the only password the miner accepts.  If neither password can
+//! be verified both stay in the vault and the miner is reported for a look.
+//! Rotation needs an unlocked vault: new secrets never go to the plaintext
+//! settings file.
+
+use crate::client::antminer_web::AntminerWebClient;
+use crate::client::credentials;
+use crate::client::whatsminer_web::WhatsminerWebClient;
+use crate::core::config::{CredentialScope, CredentialSet};
+use crate::core::vault::{self, VaultError};
+use crate::core::{ErrorKind, MinerCredentials, MinerError, MinerId, MinerVendor, Result};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::OsRng;
+
+/// Shortest generated password
+pub const MIN_GENERATED_LENGTH: u32 = 12;
+/// Longest generated password; some firmware truncates beyond this
+pub const MAX_GENERATED_LENGTH: u32 = 64;
+/// Shortest password accepted for `PasswordPolicy::Fixed`
+pub const MIN_FIXED_LENGTH: usize = 8;
+
+/// Letters and digits without look-alikes (0/O, 1/l/I).  No symbols: the
+/// miners' web forms don't escape them consistently.
+const ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz23456789";
+
+/// Factory passwords a rotation must not set
+const FACTORY_PASSWORDS: [&str; 2] = ["root", "admin"];
+
+/// Name prefix of the set holding a miner's rotated password
+pub const ROTATED_PREFIX: &str = "rotated:";
+/// Name prefix of a new password staged while its rotation is in flight
+pub const PENDING_PREFIX: &str = "rotation-pending:";
+
+/// Which password each miner gets
+#[derive(Debug, Clone, PartialEq)]
+pub enum PasswordPolicy {
+    /// A different random password per miner
+    Generate { length: u32 },
+    /// The same password on every miner, e.g. a site standard
+    Fixed { password: String },
+}
+
+impl PasswordPolicy {
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        match self {
+            PasswordPolicy::Generate { length } => {
+                if !(MIN_GENERATED_LENGTH..=MAX_GENERATED_LENGTH).contains(length) {
+                    return Err(format!(
+                        "Generated passwords must be {} to {} characters",
+                        MIN_GENERATED_LENGTH, MAX_GENERATED_LENGTH
+                    ));
+                }
+            }
+            PasswordPolicy::Fixed { password } => {
+                if password.chars().count() < MIN_FIXED_LENGTH {
+                    return Err(format!("Password must be at least {} characters", MIN_FIXED_LENGTH));
+                }
+                if password.chars().any(char::is_whitespace) {
+                    return Err("Password must not contain whitespace".to_string());
+                }
+                if FACTORY_PASSWORDS.iter().any(|factory| password.eq_ignore_ascii_case(factory)) {
+                    return Err("Password must not be a factory default".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn password(&self) -> String {
+        match self {
+            PasswordPolicy::Generate { length } => generate_password(*length as usize),
+            PasswordPolicy::Fixed { password } => password.clone(),
+        }
+    }
+}
+
+/// Random password of `length` characters from `ALPHABET`, with at least
+/// one upper-case letter, one lower-case letter and one digit
+pub fn generate_password(length: usize) -> String {
+    loop {
+        let mut password = String::with_capacity(length);
+        while password.len() < length {
+            let mut byte = [0u8; 1];
+            OsRng.fill_bytes(&mut byte);
+            // Reject the top of the range so every character is equally likely
+            let limit = 256 - 256 % ALPHABET.len();
+            if (byte[0] as usize) < limit {
+                password.push(ALPHABET[byte[0] as usize % ALPHABET.len()] as char);
+            }
+        }
+        let has = |class: fn(&char) -> bool| password.chars().any(|c| class(&c));
+        if length < 3 || (has(char::is_ascii_uppercase) && has(char::is_ascii_lowercase) && has(char::is_ascii_digit)) {
+            return password;
+        }
+    }
+}
+
+/// How the rotation of one miner ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotationOutcome {
+    /// The miner has the new password and the vault holds it
+    Rotated,
+    /// The miner already had the policy's password
+    AlreadyCurrent,
+    /// Nothing was changed: unreachable, refused login or refused the change
+    Failed,
+    /// The new password didn't verify; the miner is back on the old one
+    RolledBack,
+    /// Neither password verified after the change; both are kept in the vault
+    NeedsAttention,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotationResult {
+    pub ip: String,
+    pub miner_id: Option<MinerId>,
+    pub outcome: RotationOutcome,
+    pub message: Option<String>,
+    /// Why a `Failed` rotation failed
+    pub error_kind: Option<ErrorKind>,
+}
+
+impl RotationResult {
+    fn new(ip: &str, outcome: RotationOutcome, message: Option<String>) -> Self {
+        let miner_id = crate::identity::ADDRESSES.id_at(ip);
+        Self { ip: ip.to_string(), miner_id, outcome, message, error_kind: None }
+    }
+
+    fn failed(ip: &str, error: MinerError) -> Self {
+        Self { error_kind: Some(error.kind()), ..Self::new(ip, RotationOutcome::Failed, Some(error.to_string())) }
+    }
+}
+
+/// Rotate the web password of every miner in `ips` under `policy`
+pub async fn rotate_passwords(ips: Vec<String>, policy: PasswordPolicy) -> Result<Vec<RotationResult>> {
+    policy.validate()?;
+    let status = vault::status();
+    if !status.exists {
+        return Err(VaultError::NotFound.into());
+    }
+    if !status.unlocked {
+        return Err(VaultError::Locked.into());
+    }
+
+    let tasks: Vec<_> = ips
+        .into_iter()
+        .map(|ip| {
+            let policy = policy.clone();
+            tokio::spawn(async move { rotate_miner(ip, policy).await })
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for task in tasks {
+        if let Ok(result) = task.await {
+            results.push(result);
+        }
+    }
+
+    let rotated = results.iter().filter(|r| r.outcome == RotationOutcome::Rotated).count();
+    println!("[rotation] Rotated {} of {} miner(s)", rotated, results.len());
+    Ok(results)
+}
+
+async fn rotate_miner(ip: String, policy: PasswordPolicy) -> RotationResult {
+    let vendor = match crate::identity::resolve(&ip).await {
+        Ok(identity) => identity.vendor,
+        Err(e) => return RotationResult::failed(&ip, e),
+    };
+    let miner_id = crate::identity::ADDRESSES.id_at(&ip);
+    let new_password = policy.password();
+
+    let host = ip.as_str();
+    let result = credentials::with_credentials(host, vendor, |current| {
+        let (miner_id, new_password) = (miner_id.clone(), new_password.clone());
+        async move { rotate_with(host, vendor, miner_id.as_ref(), current, new_password).await }
+    });
+    match result.await {
+        Ok(result) => result,
+        Err(e) => RotationResult::failed(host, e),
+    }
+}
+
+/// Rotate one miner that accepts `current`.  An `Err` means nothing was
+/// changed; `AuthenticationError` lets the caller try the next credential.
+async fn rotate_with(
+    ip: &str,
+    vendor: MinerVendor,
+    miner_id: Option<&MinerId>,
+    current: MinerCredentials,
+    new_password: String,
+) -> Result<RotationResult> {
+    // Nothing is staged for a candidate the miner doesn't take.  A refused
+    // login moves on to the next candidate; any other error ends the rotation
+    verify(ip, vendor, &current).await?;
+    if current.password == new_password {
+        return Ok(RotationResult::new(ip, RotationOutcome::AlreadyCurrent, None));
+    }
+    let key = miner_id.map_or_else(|| ip.to_string(), |id| id.0.clone());
+    let scope = miner_id.map_or_else(|| CredentialScope::Range(ip.to_string()), |id| CredentialScope::Miner(id.clone()));
+    let new = MinerCredentials::new(current.username.clone(), new_password);
+
+    let pending = format!("{}{}@{}", PENDING_PREFIX, key, now_millis());
+    vault::update_credential_sets(|sets| stage(sets, &pending, scope, vendor, new.clone()))?;
+    let outcome = change_and_verify(ip, vendor, &current, &new).await;
+    match &outcome {
+        Err(_) | Ok((RotationOutcome::RolledBack, _)) => {
+            if let Err(e) = vault::update_credential_sets(|sets| discard(sets, &pending)) {
+                eprintln!("[rotation] Could not drop the staged password of {}: {}", ip, e);
+            }
+        }
+        Ok((RotationOutcome::Rotated, _)) => {
+            credentials::forget(ip, miner_id);
+            if let Err(e) = vault::update_credential_sets(|sets| promote(sets, &key, &pending)) {
+                // The staged set still holds the new password, so logins keep working
+                let message = format!("the new password is staged in the vault but could not be promoted: {}", e);
+                return Ok(RotationResult::new(ip, RotationOutcome::Rotated, Some(message)));
+            }
+        }
+        Ok(_) => {}
+    }
+    let (outcome, message) = outcome?;
+    Ok(RotationResult::new(ip, outcome, message))
+}
+
+/// Change the password of the miner at `ip` from `current` to `new`, verify
+/// it with a fresh login and restore `current` if that fails.  Touches the
+/// miner only, not the vault.  An `Err` means the change was refused.
+pub async fn change_and_verify(
+    ip: &str,
+    vendor: MinerVendor,
+    current: &MinerCredentials,
+    new: &MinerCredentials,
+) -> Result<(RotationOutcome, Option<String>)> {
+    change_password(ip, vendor, &current.username, &current.password, &new.password).await?;
+
+    let error = match verify(ip, vendor, new).await {
+        Ok(()) => {
+            println!("[rotation] {} rotated", ip);
+            return Ok((RotationOutcome::Rotated, None));
+        }
+        Err(e) => e,
+    };
+    eprintln!("[rotation] New password of {} didn't verify ({}), rolling back", ip, error);
+
+    if verify(ip, vendor, current).await.is_ok() {
+        let message = format!("new password didn't verify ({}); the miner kept the old one", error);
+        return Ok((RotationOutcome::RolledBack, Some(message)));
+    }
+    let restored = change_password(ip, vendor, &new.username, &new.password, &current.password).await;
+    if restored.is_ok() && verify(ip, vendor, current).await.is_ok() {
+        let message = format!("new password didn't verify ({}); the old one was restored", error);
+        return Ok((RotationOutcome::RolledBack, Some(message)));
+    }
+
+    eprintln!("[rotation] {} accepts neither the old nor the new password", ip);
+    let message = format!("neither password verified after the change ({}); both are kept in the vault", error);
+    Ok((RotationOutcome::NeedsAttention, Some(message)))
+}
+
+async fn change_password(ip: &str, vendor: MinerVendor, username: &str, current: &str, new: &str) -> Result<()> {
+    match vendor {
+        MinerVendor::Antminer => AntminerWebClient::change_password(ip, username, current, new).await,
+        MinerVendor::Whatsminer => WhatsminerWebClient::change_password(ip, username, current, new).await,
+    }
+}
+
+/// Log in with `creds` from scratch
+async fn verify(ip: &str, vendor: MinerVendor, creds: &MinerCredentials) -> Result<()> {
+    match vendor {
+        MinerVendor::Antminer => AntminerWebClient::get_system_info(ip, &creds.username, &creds.password).await.map(|_| ()),
+        MinerVendor::Whatsminer => WhatsminerWebClient::verify_login(ip, &creds.username, &creds.password).await,
+    }
+}
+
+/// Add the pending set `name`, tried after everything else
+fn stage(sets: &mut Vec<CredentialSet>, name: &str, scope: CredentialScope, vendor: MinerVendor, credentials: MinerCredentials) {
+    sets.push(CredentialSet { name: name.to_string(), scope, vendor: Some(vendor), priority: i32::MAX, credentials });
+}
+
+/// Turn the pending set `pending` into the rotated set of `key`, tried
+/// first.  Older pending sets of `key` are dropped: the miner now verifiably
+/// has the new password.
+fn promote(sets: &mut Vec<CredentialSet>, key: &str, pending: &str) {
+    let Some(index) = sets.iter().position(|set| set.name == pending) else {
+        return;
+    };
+    let mut set = sets.remove(index);
+    let (stale, rotated) = (format!("{}{}@", PENDING_PREFIX, key), format!("{}{}", ROTATED_PREFIX, key));
+    sets.retain(|set| set.name != rotated && !set.name.starts_with(&stale));
+    set.name = rotated;
+    set.priority = i32::MIN;
+    sets.push(set);
+}
+
+fn discard(sets: &mut Vec<CredentialSet>, pending: &str) {
+    sets.retain(|set| set.name != pending);
+}
+
+fn now_millis() -> u128 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_password() {
+        for _ in 0..50 {
+            let password = generate_password(16);
+            assert_eq!(password.len(), 16);
+            assert!(password.bytes().all(|b| ALPHABET.contains(&b)));
+            assert!(password.chars().any(|c| c.is_ascii_digit()));
+        }
+        assert_ne!(generate_password(16), generate_password(16));
+    }
+
+    #[test]
+    fn test_policy_validation() {
+        assert!(PasswordPolicy::Generate { length: 16 }.validate().is_ok());
+        assert!(PasswordPolicy::Generate { length: 6 }.validate().is_err());
+        assert!(PasswordPolicy::Fixed { password: "Site-B-2026!".into() }.validate().is_ok());
+        assert!(PasswordPolicy::Fixed { password: "short".into() }.validate().is_err());
+        assert!(PasswordPolicy::Fixed { password: "has space 123".into() }.validate().is_err());
+    }
+
+    #[test]
+    fn test_stage_promote_discard() {
+        let id = MinerId::from_serial("SN1").unwrap();
+        let creds = |pw: &str| MinerCredentials::new("root".into(), pw.into());
+        let mut sets = vec![CredentialSet {
+            name: format!("{}{}", ROTATED_PREFIX, id.0),
+            scope: CredentialScope::Miner(id.clone()),
+            vendor: Some(MinerVendor::Antminer),
+            priority: i32::MIN,
+            credentials: creds("first"),
+        }];
+
+        let scope = CredentialScope::Miner(id.clone());
+
+        // While pending, the old rotated set stays first in line; a set left
+        // by an interrupted rotation survives a failed one
+        stage(&mut sets, "rotation-pending:sn:SN1@1", scope.clone(), MinerVendor::Antminer, creds("crashed"));
+        stage(&mut sets, "rotation-pending:sn:SN1@2", scope.clone(), MinerVendor::Antminer, creds("second"));
+        assert_eq!(sets.len(), 3);
+        assert_eq!(sets[2].priority, i32::MAX);
+        discard(&mut sets, "rotation-pending:sn:SN1@2");
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[1].credentials.password, "crashed");
+
+        // A successful one clears it
+        stage(&mut sets, "rotation-pending:sn:SN1@3", scope, MinerVendor::Antminer, creds("third"));
+        promote(&mut sets, &id.0, "rotation-pending:sn:SN1@3");
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets[0].name, "rotated:sn:SN1");
+        assert_eq!(sets[0].priority, i32::MIN);
+        assert_eq!(sets[0].credentials.password, "third");
+    }
+}
@@ -0,0 +1,160 @@
+//! Source address selection for outgoing miner connections.
+//!
+//! A management machine often sits on several networks at once (office Wi-Fi
+//! plus a wired miner VLAN), and the OS default route may not be the one that
+//! reaches the miners.  Named ranges in `AppSettings` can name a source
+//! interface or IP; every CGMiner, web API and scanner connection to an
+//! address in that range is bound to it.
+
+use crate::core::config::AppSettings;
+use crate::core::Result;
+use crate::scanner::ScanSpec;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::RwLock;
+use tokio::net::{TcpSocket, TcpStream};
+
+use super::http_pool;
+
+/// Connections to addresses in `spec` go out from `source`
+#[derive(Debug, Clone)]
+pub struct SourceBinding {
+    pub spec: ScanSpec,
+    pub source: IpAddr,
+}
+
+lazy_static::lazy_static! {
+    static ref BINDINGS: RwLock<Vec<SourceBinding>> =
+        RwLock::new(bindings_from_settings(&AppSettings::load()));
+}
+
+/// Replace the active bindings.  Pooled HTTP clients are dropped so the next
+/// request builds one with the new local address.
+pub fn set_bindings(bindings: Vec<SourceBinding>) {
+    *BINDINGS.write().unwrap() = bindings;
+    http_pool::clear();
+}
+
+/// Rebuild the bindings from the named ranges in `settings`.
+pub fn apply_settings(settings: &AppSettings) {
+    set_bindings(bindings_from_settings(settings));
+}
+
+/// Source address to bind to when connecting to `ip`, if any range claims it.
+/// The first matching named range wins.
+pub fn source_for(ip: &IpAddr) -> Option<IpAddr> {
+    BINDINGS
+        .read()
+        .unwrap()
+        .iter()
+        .find(|binding| binding.source.is_ipv4() == ip.is_ipv4() && binding.spec.contains(ip))
+        .map(|binding| binding.source)
+}
+
+/// Like `source_for`, for a host string that may carry a `:port` suffix.
+pub fn source_for_host(host: &str) -> Option<IpAddr> {
+    let ip = host
+        .parse::<IpAddr>()
+        .ok()
+        .or_else(|| host.parse::<SocketAddr>().ok().map(|addr| addr.ip()))?;
+    source_for(&ip)
+}
+
+/// Open a TCP connection to `ip:port`, bound to the configured source address.
+pub async fn connect(ip: &str, port: u16) -> std::io::Result<TcpStream> {
+    let target = match ip.parse::<IpAddr>() {
+        Ok(target) => SocketAddr::new(target, port),
+        // Host names: let the OS resolve and route
+        Err(_) => return TcpStream::connect((ip, port)).await,
+    };
+
+    match source_for(&target.ip()) {
+        Some(source) => connect_from(source, target).await,
+        None => TcpStream::connect(target).await,
+    }
+}
+
+/// Open a TCP connection to `target` from `source` (any local port).
+pub async fn connect_from(source: IpAddr, target: SocketAddr) -> std::io::Result<TcpStream> {
+    let socket = if target.is_ipv4() { TcpSocket::new_v4()? } else { TcpSocket::new_v6()? };
+    socket.bind(SocketAddr::new(source, 0))?;
+    socket.connect(target).await
+}
+
+/// Resolve a source given as an IP or an interface name to an address.
+/// Interfaces resolve to their first IPv4 address.
+pub fn resolve_source(source: &str) -> Result<IpAddr> {
+    use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
+
+    let source = source.trim();
+    if let Ok(ip) = source.parse::<IpAddr>() {
+        return Ok(ip);
+    }
+
+    let interfaces = NetworkInterface::show()
+        .map_err(|e| format!("Failed to list network interfaces: {}", e))?;
+    interfaces
+        .iter()
+        .filter(|iface| iface.name == source)
+        .flat_map(|iface| iface.addr.iter())
+        .find_map(|addr| match addr {
+            Addr::V4(v4) => Some(IpAddr::V4(v4.ip)),
+            Addr::V6(_) => None,
+        })
+        .ok_or_else(|| format!("No IPv4 address on interface '{}'", source).into())
+}
+
+fn bindings_from_settings(settings: &AppSettings) -> Vec<SourceBinding> {
+    let mut bindings = Vec::new();
+    for range in &settings.named_ranges {
+        let Some(source) = range.source.as_deref().filter(|s| !s.trim().is_empty()) else {
+            continue;
+        };
+        let spec = match ScanSpec::parse(&range.spec) {
+            Ok(spec) => spec,
+            Err(e) => {
+                eprintln!("[source] Ignoring range '{}': {}", range.name, e);
+                continue;
+            }
+        };
+        match resolve_source(source) {
+            Ok(source) => bindings.push(SourceBinding { spec, source }),
+            Err(e) => eprintln!("[source] Ignoring source for range '{}': {}", range.name, e),
+        }
+    }
+    bindings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::NamedRange;
+
+    #[test]
+    fn test_bindings_from_settings() {
+        let settings = AppSettings {
+            named_ranges: vec![
+                NamedRange { name: "Miners".into(), spec: "10.9.*.*, !10.9.0.1".into(), source: Some("10.9.0.50".into()) },
+                NamedRange { name: "Office".into(), spec: "192.168.1.0/24".into(), source: None },
+                NamedRange { name: "Broken".into(), spec: "10.9.0.300".into(), source: Some("10.9.0.51".into()) },
+            ],
+            ..Default::default()
+        };
+
+        let bindings = bindings_from_settings(&settings);
+        assert_eq!(bindings.len(), 1);
+        assert_eq!(bindings[0].source, "10.9.0.50".parse::<IpAddr>().unwrap());
+        assert!(bindings[0].spec.contains(&"10.9.3.4".parse().unwrap()));
+        assert!(!bindings[0].spec.contains(&"10.9.0.1".parse().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_connect_from_source() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let target = listener.local_addr().unwrap();
+
+        let source: IpAddr = "127.0.0.2".parse().unwrap();
+        let _stream = connect_from(source, target).await.unwrap();
+        let (_, peer) = listener.accept().await.unwrap();
+        assert_eq!(peer.ip(), source);
+    }
+}
@@ -40,16 +40,64 @@
 //! GET  /cgi-bin/luci/admin/status/btminerstatus/restart   (restarts mining daemon)
 //! ← 302 (redirect = success)
 //! ```
+//! The current pools are read from the same GET page: the `value`s of the
+//! `cbid.pools.default.poolNurl/user/pw` inputs.  Config backups keep every
+//! value of this page and of `/admin/network/<program>/power`, and restore
+//! them with the same POST (all fields), then restart the daemon.
+//!
+//! ### 4. Web Password
+//! ```text
+//! GET  /cgi-bin/luci/admin/system/admin
+//! ← 200 HTML containing: name="token" value="<csrf_token>"
+//!
+//! POST /cgi-bin/luci/admin/system/admin
+//! Body: token=<t>&cbi.submit=1&cbid.system._pass.pw1=<new>&cbid.system._pass.pw2=<new>
+//! ← 200 OK ("Password successfully changed!")
+//! ```
+//! LuCI doesn't ask for the current password; the session is the proof.
 
-use crate::core::Result;
+use crate::client::http_pool::{self, WebApi};
+use crate::core::{MinerError, Result};
+use dashmap::DashMap;
 use regex::Regex;
 use reqwest::{Client, header};
-use std::time::Duration;
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, RandomState};
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+/// How long a LuCI login is reused before logging in again.
+/// LuCI's default `sessiontime` is one hour; stay well inside it.
+const LUCI_SESSION_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// A cached LuCI login; the cookie itself lives in the pooled client.
+struct LuciSession {
+    /// `credentials_key` of the login, so other credentials never ride on it
+    credentials: u64,
+    expires_at: Instant,
+}
+
+lazy_static::lazy_static! {
+    static ref LUCI_SESSIONS: DashMap<String, LuciSession> = DashMap::new();
+    /// Keyed per process, so the hashes are no use outside it
+    static ref CREDENTIALS_HASHER: RandomState = RandomState::new();
+    /// BTCTools: `token:'([^']+)'` or `token:"([^"]+)"` (reboot page)
+    static ref REBOOT_TOKEN_RE: Regex = Regex::new(r#"token:\s*['"]([^'"]+)['"]"#).unwrap();
+    /// BTCTools: `name="token"%s*value="%s*([^"]-)*%s*"` (CBI form pages)
+    static ref FORM_TOKEN_RE: Regex = Regex::new(r#"name="token"\s+value="([^"]+)""#).unwrap();
+    /// Currently selected coin type on the pool config page
+    static ref COIN_TYPE_RE: Regex = Regex::new(r#"id="cbid\.pools\.default\.coin_type[^"]*"\s+value="([^"]*)"[^>]*selected="selected""#).unwrap();
+    static ref INPUT_TAG_RE: Regex = Regex::new(r#"(?is)<input\b[^>]*>"#).unwrap();
+    static ref SELECT_RE: Regex = Regex::new(r#"(?is)<select\b([^>]*)>(.*?)</select>"#).unwrap();
+    static ref OPTION_TAG_RE: Regex = Regex::new(r#"(?is)<option\b[^>]*>"#).unwrap();
+    static ref ATTR_RE: Regex = Regex::new(r#"(?s)([A-Za-z_:.-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap();
+}
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Pool entry
 // ─────────────────────────────────────────────────────────────────────────────
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct WhatsminerPool {
     pub url: String,
     pub worker: String,
@@ -63,40 +111,81 @@ pub struct WhatsminerPool {
 pub struct WhatsminerWebClient;
 
 impl WhatsminerWebClient {
-    // ── Shared HTTP client ────────────────────────────────────────────────────
+    // ── Session cache ─────────────────────────────────────────────────────────
+
+    /// Return a client holding a valid LuCI session for `ip`, logging in only
+    /// if there is no cached session for these credentials or it has expired.
+    /// A session of other credentials is dropped first: its cookie must not
+    /// make a wrong password look like it worked.
+    async fn session(ip: &str, username: &str, password: &str) -> Result<Client> {
+        let credentials = Self::credentials_key(username, password);
+        let cached = LUCI_SESSIONS
+            .get(ip)
+            .map(|s| (s.credentials == credentials, s.expires_at > Instant::now()));
+        match cached {
+            Some((true, true)) => return http_pool::client_for(WebApi::WhatsminerLuci, ip),
+            Some((false, _)) => Self::invalidate_session(ip),
+            _ => {}
+        }
 
-    fn build_client() -> Result<Client> {
-        Client::builder()
-            .timeout(Duration::from_secs(10))
-            .danger_accept_invalid_certs(true) // Whatsminer uses self-signed certs
-            .cookie_store(true)                // Essential: keep the LuCI session cookie
-            .build()
-            .map_err(|e| format!("Failed to build HTTP client: {}", e).into())
+        let client = Self::login(ip, username, password).await?;
+        LUCI_SESSIONS.insert(ip.to_string(), LuciSession {
+            credentials,
+            expires_at: Instant::now() + LUCI_SESSION_TTL,
+        });
+        Ok(client)
+    }
+
+    fn credentials_key(username: &str, password: &str) -> u64 {
+        CREDENTIALS_HASHER.hash_one((username, password))
+    }
+
+    /// Forget the session for `ip`, dropping its cookie jar with the client.
+    fn invalidate_session(ip: &str) {
+        LUCI_SESSIONS.remove(ip);
+        http_pool::evict(WebApi::WhatsminerLuci, ip);
+    }
+
+    /// Run `op` with a session client.  If the cached session turns out to be
+    /// rejected (`MinerError::AuthenticationError`), log in again and retry once.
+    async fn with_session<T, F, Fut>(ip: &str, username: &str, password: &str, op: F) -> Result<T>
+    where
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let client = Self::session(ip, username, password).await?;
+        match op(client).await {
+            Err(MinerError::AuthenticationError) => {
+                eprintln!("[whatsminer] session for {} rejected — logging in again", ip);
+                Self::invalidate_session(ip);
+                let client = Self::session(ip, username, password).await?;
+                op(client).await
+            }
+            other => other,
+        }
     }
 
-    /// Build a client that does NOT follow redirects — needed for the login POST
-    /// so we can capture the session cookie from the 302 Set-Cookie header.
-    fn build_client_no_redirect() -> Result<Client> {
-        Client::builder()
-            .timeout(Duration::from_secs(10))
-            .danger_accept_invalid_certs(true)
-            .cookie_store(true)
-            .redirect(reqwest::redirect::Policy::none())
-            .build()
-            .map_err(|e| format!("Failed to build no-redirect HTTP client: {}", e).into())
+    /// Map an expired/missing session to `MinerError::AuthenticationError`.
+    /// LuCI answers 403, or serves the login form with a 200.
+    fn check_session(status: u16, body: Option<&str>) -> Result<()> {
+        let login_form = body.map(|b| b.contains("luci_password")).unwrap_or(false);
+        if status == 401 || status == 403 || login_form {
+            return Err(MinerError::AuthenticationError);
+        }
+        Ok(())
     }
 
-    // ── Login → returns (client_with_session_cookie, session_ok) ─────────────
+    // ── Login → returns client_with_session_cookie ────────────────────────────
 
     /// POST to `/cgi-bin/luci` to obtain the LuCI session cookie.
     /// Whatsminer returns 302 on success. The cookie in the 302 `Set-Cookie` is
-    /// the session token. We capture it with a no-redirect client, then switch
-    /// to a normal following-client for subsequent requests.
+    /// the session token; the pooled client does not follow redirects, so it
+    /// lands in that client's cookie store and is sent on later requests.
     async fn login(ip: &str, username: &str, password: &str) -> Result<Client> {
         let login_url = format!("https://{}/cgi-bin/luci", ip);
+        let client = http_pool::client_for(WebApi::WhatsminerLuci, ip)?;
 
         for pw in &[password, ""] {
-            let client = Self::build_client_no_redirect()?;
             let params = [
                 ("luci_username", username),
                 ("luci_password", pw),
@@ -108,7 +197,7 @@ impl WhatsminerWebClient {
                 .form(&params)
                 .send()
                 .await
-                .map_err(|e| format!("Login POST to {} failed: {}", login_url, e))?;
+                .map_err(|e| MinerError::from_reqwest(format!("Login POST to {}", login_url), e))?;
 
             let code = resp.status().as_u16();
             eprintln!("[whatsminer] login({}) pw={:?} → HTTP {}", ip, if pw.is_empty() { "(empty)" } else { "(set)" }, code);
@@ -116,16 +205,12 @@ impl WhatsminerWebClient {
             // LuCI returns 302 on success. Accept 200 too (some firmware versions).
             if code == 302 || code == 200 {
                 // The cookie_store on `client` now has the sysauth session cookie.
-                // Return this same client for subsequent requests.
                 return Ok(client);
             }
         }
 
-        Err(format!(
-            "LuCI login failed for {} — miner returned non-302/200 for both password and no-password attempts. \
-            Check that HTTPS is enabled on port 443 and credentials are correct.",
-            ip
-        ).into())
+        eprintln!("[whatsminer] LuCI login refused for {} with and without the password", ip);
+        Err(MinerError::AuthenticationError)
     }
 
     // ── Extract CSRF token from Reboot page HTML ───────────────────────────────
@@ -138,23 +223,23 @@ impl WhatsminerWebClient {
             .get(&url)
             .send()
             .await
-            .map_err(|e| format!("GET reboot page failed: {}", e))?;
+            .map_err(|e| MinerError::from_reqwest("GET reboot page", e))?;
 
+        Self::check_session(resp.status().as_u16(), None)?;
         if !resp.status().is_success() {
-            return Err(format!("Reboot page returned HTTP {}", resp.status()).into());
+            return Err(MinerError::from_status("Reboot page", resp.status().as_u16()));
         }
 
-        let body = resp.text().await.map_err(|e| format!("Read body: {}", e))?;
+        let body = resp.text().await.map_err(|e| MinerError::from_reqwest("Read body", e))?;
+        Self::check_session(200, Some(&body))?;
 
-        // BTCTools uses: `token:'([^']+)'` or `token:"([^"]+)"`
-        let re = Regex::new(r#"token:\s*['"]([^'"]+)['"]"#).unwrap();
-        if let Some(m) = re.captures(&body) {
+        if let Some(m) = REBOOT_TOKEN_RE.captures(&body) {
             let token = m[1].trim().to_string();
             eprintln!("[whatsminer] reboot CSRF token: {}…", &token[..token.len().min(8)]);
             return Ok(token);
         }
 
-        Err(format!("Could not find CSRF token in reboot page body for {}", ip).into())
+        Err(MinerError::MalformedResponse(format!("Could not find CSRF token in reboot page body for {}", ip)))
     }
 
     // ── Extract CSRF token from Network/Pool config page ──────────────────────
@@ -163,6 +248,24 @@ impl WhatsminerWebClient {
     /// `name="token" value="<csrf_token>"`
     /// Returns (token, program_name, current_coin_type)
     async fn get_config_token(client: &Client, ip: &str) -> Result<(String, String, String)> {
+        let (body, program) = Self::get_config_page(client, ip).await?;
+
+        let token = FORM_TOKEN_RE.captures(&body)
+            .map(|c| c[1].trim().to_string())
+            .ok_or_else(|| MinerError::MalformedResponse(format!("No token in config page for program={}", program)))?;
+
+        // Also grab the current coin type (preserve it in the POST)
+        let coin_type = COIN_TYPE_RE.captures(&body)
+            .map(|c| c[1].to_string())
+            .unwrap_or_default();
+
+        eprintln!("[whatsminer] config token found for program={} coin_type={:?}", program, coin_type);
+        Ok((token, program, coin_type))
+    }
+
+    /// Fetch the pool config page; returns its HTML and the program name
+    /// (`cgminer` or `btminer`) it was found under.
+    async fn get_config_page(client: &Client, ip: &str) -> Result<(String, String)> {
         // Try cgminer first (confirmed on M31SV10), then btminer (newer firmware)
         for program in &["cgminer", "btminer"] {
             let url = format!("https://{}/cgi-bin/luci/admin/network/{}", ip, program);
@@ -170,37 +273,25 @@ impl WhatsminerWebClient {
                 .get(&url)
                 .send()
                 .await
-                .map_err(|e| format!("GET config page failed: {}", e))?;
+                .map_err(|e| MinerError::from_reqwest("GET config page", e))?;
 
             let status = resp.status().as_u16();
             eprintln!("[whatsminer] GET /admin/network/{} → HTTP {}", program, status);
 
+            Self::check_session(status, None)?;
             if status == 404 {
                 continue; // try next program
             }
             if status != 200 {
-                return Err(format!("Config page returned HTTP {} for program {}", status, program).into());
+                return Err(MinerError::from_status(format!("Config page for program {}", program), status));
             }
 
-            let body = resp.text().await.map_err(|e| format!("Read body: {}", e))?;
-
-            // BTCTools: `name="token"%s*value="%s*([^"]-)*%s*"`
-            let token_re = Regex::new(r#"name="token"\s+value="([^"]+)""#).unwrap();
-            let token = token_re.captures(&body)
-                .map(|c| c[1].trim().to_string())
-                .ok_or_else(|| format!("No token in config page for program={}", program))?;
-
-            // Also grab the current coin type (preserve it in the POST)
-            let coin_re = Regex::new(r#"id="cbid\.pools\.default\.coin_type[^"]*"\s+value="([^"]*)"[^>]*selected="selected""#).unwrap();
-            let coin_type = coin_re.captures(&body)
-                .map(|c| c[1].to_string())
-                .unwrap_or_default();
-
-            eprintln!("[whatsminer] config token found for program={} coin_type={:?}", program, coin_type);
-            return Ok((token, program.to_string(), coin_type));
+            let body = resp.text().await.map_err(|e| MinerError::from_reqwest("Read body", e))?;
+            Self::check_session(status, Some(&body))?;
+            return Ok((body, program.to_string()));
         }
 
-        Err(format!("Could not find network config page for {} (tried btminer/cgminer)", ip).into())
+        Err(MinerError::UnsupportedCommand(format!("Could not find network config page for {} (tried btminer/cgminer)", ip)))
     }
 
     // ── Restart mining daemon after pool/config change ────────────────────────
@@ -214,7 +305,7 @@ impl WhatsminerWebClient {
             .get(&url)
             .send()
             .await
-            .map_err(|e| format!("Restart daemon request failed: {}", e))?;
+            .map_err(|e| MinerError::from_reqwest("Restart daemon request", e))?;
 
         let code = resp.status().as_u16();
         eprintln!("[whatsminer] restart /admin/status/{}/restart → HTTP {}", daemon, code);
@@ -227,7 +318,7 @@ impl WhatsminerWebClient {
         // Fallback: try the other daemon name
         let daemon2 = if program == "cgminer" { "btminerstatus" } else { "cgminerstatus" };
         let url2 = format!("https://{}/cgi-bin/luci/admin/status/{}/restart", ip, daemon2);
-        let resp2 = client.get(&url2).send().await.map_err(|e| format!("Restart fallback failed: {}", e))?;
+        let resp2 = client.get(&url2).send().await.map_err(|e| MinerError::from_reqwest("Restart fallback", e))?;
         let code2 = resp2.status().as_u16();
         eprintln!("[whatsminer] restart fallback /admin/status/{}/restart → HTTP {}", daemon2, code2);
 
@@ -235,14 +326,17 @@ impl WhatsminerWebClient {
             return Ok(());
         }
 
-        Err(format!("restart_miner_daemon failed (code={}/{})", code, code2).into())
+        Err(MinerError::from_status(format!("restart_miner_daemon (code={})", code), code2))
     }
 
     // ── Public API ────────────────────────────────────────────────────────────
 
     /// Reboot the control board via LuCI.
     pub async fn reboot(ip: &str, username: &str, password: &str) -> Result<()> {
-        let client = Self::login(ip, username, password).await?;
+        Self::with_session(ip, username, password, |client| Self::reboot_in_session(client, ip)).await
+    }
+
+    async fn reboot_in_session(client: Client, ip: &str) -> Result<()> {
         let token = Self::get_reboot_token(&client, ip).await?;
 
         let url = format!("https://{}/cgi-bin/luci/admin/system/reboot/call", ip);
@@ -252,7 +346,7 @@ impl WhatsminerWebClient {
             .body(format!("token={}", token))
             .send()
             .await
-            .map_err(|e| format!("Reboot POST failed: {}", e))?;
+            .map_err(|e| MinerError::from_reqwest("Reboot POST", e))?;
 
         let code = resp.status().as_u16();
         eprintln!("[whatsminer] reboot/call → HTTP {}", code);
@@ -260,7 +354,7 @@ impl WhatsminerWebClient {
         if code == 200 || code == 302 {
             Ok(())
         } else {
-            Err(format!("Reboot call returned HTTP {}", code).into())
+            Err(MinerError::from_status("Reboot call", code))
         }
     }
 
@@ -271,7 +365,10 @@ impl WhatsminerWebClient {
             pools.push(WhatsminerPool { url: String::new(), worker: String::new(), password: String::new() });
         }
 
-        let client = Self::login(ip, username, password).await?;
+        Self::with_session(ip, username, password, |client| Self::set_pools_in_session(client, ip, &pools)).await
+    }
+
+    async fn set_pools_in_session(client: Client, ip: &str, pools: &[WhatsminerPool]) -> Result<()> {
         let (token, program, coin_type) = Self::get_config_token(&client, ip).await?;
 
         let url = format!("https://{}/cgi-bin/luci/admin/network/{}", ip, program);
@@ -294,24 +391,147 @@ impl WhatsminerWebClient {
             .body(body)
             .send()
             .await
-            .map_err(|e| format!("Set pools POST failed: {}", e))?;
+            .map_err(|e| MinerError::from_reqwest("Set pools POST", e))?;
 
         let code = resp.status().as_u16();
         eprintln!("[whatsminer] set_pools → HTTP {}", code);
         if code != 200 && code != 302 {
-            return Err(format!("set_pools returned HTTP {}", code).into());
+            return Err(MinerError::from_status("set_pools", code));
         }
 
         // Restart mining daemon to apply changes (BTCTools `restartCGMiner` step)
-        Self::restart_miner_daemon(&client, ip, &program).await?;
+        Self::restart_miner_daemon(&client, ip, &program)
+            .await
+            .map_err(|e| MinerError::PartialSuccess(format!("pools saved but the mining daemon didn't restart: {}", e)))?;
         Ok(())
     }
 
+    /// Read the configured pools from the pool config page (the values of
+    /// the `cbid.pools.default.poolNurl/user/pw` inputs).  Empty slots are
+    /// left out.
+    pub async fn get_pools(ip: &str, username: &str, password: &str) -> Result<Vec<WhatsminerPool>> {
+        Self::with_session(ip, username, password, |client| async move {
+            let (body, _) = Self::get_config_page(&client, ip).await?;
+            Ok(parse_pools_form(&parse_form_values(&body)))
+        })
+        .await
+    }
+
+    /// Values of the config forms, keyed by page under `/cgi-bin/luci/admin/`:
+    /// the pool page (`network/<program>`) and, where the firmware has one,
+    /// the power page (`network/<program>/power`).  Form tokens are left out.
+    pub async fn get_config_forms(ip: &str, username: &str, password: &str) -> Result<BTreeMap<String, BTreeMap<String, String>>> {
+        Self::with_session(ip, username, password, |client| Self::get_config_forms_in_session(client, ip)).await
+    }
+
+    async fn get_config_forms_in_session(client: Client, ip: &str) -> Result<BTreeMap<String, BTreeMap<String, String>>> {
+        let (body, program) = Self::get_config_page(&client, ip).await?;
+        let mut forms = BTreeMap::new();
+        forms.insert(format!("network/{}", program), form_data(&body));
+
+        let power_page = format!("network/{}/power", program);
+        match Self::get_form_page(&client, ip, &power_page).await? {
+            Some(body) => {
+                forms.insert(power_page, form_data(&body));
+            }
+            None => eprintln!("[whatsminer] {} has no power page", ip),
+        }
+        Ok(forms)
+    }
+
+    /// Submit form values as read by `get_config_forms`, page by page, then
+    /// restart the mining daemon once.
+    pub async fn set_config_forms(ip: &str, username: &str, password: &str, forms: &BTreeMap<String, BTreeMap<String, String>>) -> Result<()> {
+        Self::with_session(ip, username, password, |client| Self::set_config_forms_in_session(client, ip, forms)).await
+    }
+
+    async fn set_config_forms_in_session(client: Client, ip: &str, forms: &BTreeMap<String, BTreeMap<String, String>>) -> Result<()> {
+        let mut saved: Vec<&str> = Vec::new();
+        let mut program = None;
+
+        for (page, values) in forms {
+            let result = async {
+                let page_program = config_form_program(page)?;
+                let body = Self::get_form_page(&client, ip, page)
+                    .await?
+                    .ok_or_else(|| MinerError::UnsupportedCommand(format!("{} has no config page {}", ip, page)))?;
+                let token = FORM_TOKEN_RE.captures(&body)
+                    .map(|c| c[1].trim().to_string())
+                    .ok_or_else(|| MinerError::MalformedResponse(format!("No CSRF token in config page {}", page)))?;
+
+                let mut post_body = format!("token={}&cbi.submit=1&cbi.apply=1", urlencoding::encode(&token));
+                for (name, value) in values {
+                    post_body.push_str(&format!("&{}={}", urlencoding::encode(name), urlencoding::encode(value)));
+                }
+
+                let url = format!("https://{}/cgi-bin/luci/admin/{}", ip, page);
+                let resp = client
+                    .post(&url)
+                    .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                    .body(post_body)
+                    .send()
+                    .await
+                    .map_err(|e| MinerError::from_reqwest("POST config form", e))?;
+                let code = resp.status().as_u16();
+                eprintln!("[whatsminer] POST /admin/{} ({} fields) → HTTP {}", page, values.len(), code);
+                if code != 200 && code != 302 {
+                    return Err(MinerError::from_status(format!("Config form {}", page), code));
+                }
+                Ok(page_program)
+            }
+            .await;
+
+            match result {
+                Ok(page_program) => {
+                    saved.push(page);
+                    program = Some(page_program);
+                }
+                Err(e) if saved.is_empty() => return Err(e),
+                Err(e) => return Err(MinerError::PartialSuccess(format!("{} saved, {} not: {}", saved.join(", "), page, e))),
+            }
+        }
+
+        if let Some(program) = program {
+            Self::restart_miner_daemon(&client, ip, program)
+                .await
+                .map_err(|e| MinerError::PartialSuccess(format!("config saved but the mining daemon didn't restart: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    /// GET a form page under `/cgi-bin/luci/admin/`; `None` if the firmware
+    /// doesn't have it
+    async fn get_form_page(client: &Client, ip: &str, page: &str) -> Result<Option<String>> {
+        let url = format!("https://{}/cgi-bin/luci/admin/{}", ip, page);
+        let resp = client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| MinerError::from_reqwest("GET config form", e))?;
+
+        let status = resp.status().as_u16();
+        eprintln!("[whatsminer] GET /admin/{} → HTTP {}", page, status);
+        Self::check_session(status, None)?;
+        if status == 404 {
+            return Ok(None);
+        }
+        if status != 200 {
+            return Err(MinerError::from_status(format!("Config form {}", page), status));
+        }
+
+        let body = resp.text().await.map_err(|e| MinerError::from_reqwest("Read body", e))?;
+        Self::check_session(status, Some(&body))?;
+        Ok(Some(body))
+    }
+
     /// Control the locate LED blink.
     /// `blink = true`  → start blinking
     /// `blink = false` → stop blinking
     pub async fn blink_led(ip: &str, username: &str, password: &str, blink: bool) -> Result<()> {
-        let client = Self::login(ip, username, password).await?;
+        Self::with_session(ip, username, password, |client| Self::blink_led_in_session(client, ip, blink)).await
+    }
+
+    async fn blink_led_in_session(client: Client, ip: &str, blink: bool) -> Result<()> {
 
         // Candidate LED control endpoints (Whatsminer LuCI doesn't have a standardized one)
         let candidates = if blink {
@@ -335,6 +555,7 @@ impl WhatsminerWebClient {
             if let Ok(r) = resp {
                 let code = r.status().as_u16();
                 eprintln!("[whatsminer] blink_led → {} HTTP {}", url, code);
+                Self::check_session(code, None)?;
                 if code == 200 || code == 302 {
                     return Ok(());
                 }
@@ -346,6 +567,86 @@ impl WhatsminerWebClient {
         Ok(()) // best-effort, don't fail the whole operation
     }
 
+    /// Log in with exactly `password` — no empty-password fallback, no cached
+    /// session — and check that the session opens a protected page.
+    pub async fn verify_login(ip: &str, username: &str, password: &str) -> Result<()> {
+        Self::invalidate_session(ip);
+        let client = http_pool::client_for(WebApi::WhatsminerLuci, ip)?;
+        let resp = client
+            .post(format!("https://{}/cgi-bin/luci", ip))
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .form(&[("luci_username", username), ("luci_password", password)])
+            .send()
+            .await
+            .map_err(|e| MinerError::from_reqwest(format!("Login POST to {}", ip), e))?;
+        let code = resp.status().as_u16();
+        if code != 302 && code != 200 {
+            Self::invalidate_session(ip);
+            return Err(MinerError::AuthenticationError);
+        }
+
+        let resp = client
+            .get(format!("https://{}/cgi-bin/luci/admin/system/admin", ip))
+            .send()
+            .await
+            .map_err(|e| MinerError::from_reqwest("GET admin page", e))?;
+        let status = resp.status().as_u16();
+        let body = resp.text().await.unwrap_or_default();
+        let checked = Self::check_session(status, Some(&body));
+        // Don't leave a session behind that `session()` doesn't know about
+        Self::invalidate_session(ip);
+        checked
+    }
+
+    /// Change the LuCI password of the web user.  The cached session is
+    /// dropped afterwards so the next call logs in with the new password.
+    pub async fn change_password(ip: &str, username: &str, current: &str, new: &str) -> Result<()> {
+        let result = Self::with_session(ip, username, current, |client| Self::change_password_in_session(client, ip, new)).await;
+        Self::invalidate_session(ip);
+        result
+    }
+
+    async fn change_password_in_session(client: Client, ip: &str, new: &str) -> Result<()> {
+        let url = format!("https://{}/cgi-bin/luci/admin/system/admin", ip);
+        let resp = client.get(&url).send().await
+            .map_err(|e| MinerError::from_reqwest("GET admin page", e))?;
+        let status = resp.status().as_u16();
+        Self::check_session(status, None)?;
+        if status != 200 {
+            return Err(MinerError::from_status("Admin page", status));
+        }
+        let body = resp.text().await.map_err(|e| MinerError::from_reqwest("Read admin page", e))?;
+        Self::check_session(status, Some(&body))?;
+
+        let token = FORM_TOKEN_RE.captures(&body)
+            .map(|c| c[1].trim().to_string())
+            .ok_or_else(|| MinerError::MalformedResponse(format!("No CSRF token in admin page for {}", ip)))?;
+
+        let post_body = format!(
+            "token={}&cbi.submit=1&cbid.system._pass.pw1={}&cbid.system._pass.pw2={}",
+            urlencoding::encode(&token),
+            urlencoding::encode(new),
+            urlencoding::encode(new)
+        );
+        let resp = client.post(&url)
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(post_body)
+            .send().await
+            .map_err(|e| MinerError::from_reqwest("POST admin password", e))?;
+
+        let code = resp.status().as_u16();
+        eprintln!("[whatsminer] POST /admin/system/admin (password) → HTTP {}", code);
+        if code != 200 && code != 302 {
+            return Err(MinerError::from_status("change_password", code));
+        }
+        let body = resp.text().await.unwrap_or_default();
+        if body.contains("password not changed") {
+            let message = format!("LuCI on {} refused the password change", ip);
+            return Err(MinerError::Rejected { code: "password not changed".to_string(), message });
+        }
+        Ok(())
+    }
+
     /// Set power mode via LuCI CBI form (BTCTools `setPowerMode` flow).
     ///
     /// `mode` is the LuCI `miner_type` field value. Common values on Whatsminer:
@@ -353,7 +654,10 @@ impl WhatsminerWebClient {
     ///
     /// The mining daemon restarts after applying; no full board reboot required.
     pub async fn set_power_mode(ip: &str, username: &str, password: &str, mode: &str) -> Result<()> {
-        let client = Self::login(ip, username, password).await?;
+        Self::with_session(ip, username, password, |client| Self::set_power_mode_in_session(client, ip, mode)).await
+    }
+
+    async fn set_power_mode_in_session(client: Client, ip: &str, mode: &str) -> Result<()> {
 
         // Try cgminer first (M31SV10), then btminer (newer firmware)
         for program in &["cgminer", "btminer"] {
@@ -361,22 +665,23 @@ impl WhatsminerWebClient {
 
             // Step 1: GET the power page to retrieve the CSRF token
             let resp = client.get(&url).send().await
-                .map_err(|e| format!("GET power page failed: {}", e))?;
+                .map_err(|e| MinerError::from_reqwest("GET power page", e))?;
             let status = resp.status().as_u16();
             eprintln!("[whatsminer] GET /network/{}/power → HTTP {}", program, status);
 
+            Self::check_session(status, None)?;
             if status == 404 { continue; }
             if status != 200 {
-                return Err(format!("Power page returned HTTP {} for {}", status, program).into());
+                return Err(MinerError::from_status(format!("Power page for {}", program), status));
             }
 
-            let body = resp.text().await.map_err(|e| format!("Read power page: {}", e))?;
+            let body = resp.text().await.map_err(|e| MinerError::from_reqwest("Read power page", e))?;
+            Self::check_session(status, Some(&body))?;
 
             // Extract CSRF token
-            let token_re = Regex::new(r#"name="token"\s+value="([^"]+)""#).unwrap();
-            let token = token_re.captures(&body)
+            let token = FORM_TOKEN_RE.captures(&body)
                 .map(|c| c[1].trim().to_string())
-                .ok_or_else(|| format!("No CSRF token in power page for {}", program))?;
+                .ok_or_else(|| MinerError::MalformedResponse(format!("No CSRF token in power page for {}", program)))?;
 
             // Step 2: POST the new power mode
             // BTCTools field: cbid.<program>.default.miner_type = <mode_value>
@@ -391,20 +696,195 @@ impl WhatsminerWebClient {
                 .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
                 .body(post_body)
                 .send().await
-                .map_err(|e| format!("POST power mode failed: {}", e))?;
+                .map_err(|e| MinerError::from_reqwest("POST power mode", e))?;
 
             let code = resp2.status().as_u16();
             eprintln!("[whatsminer] POST /network/{}/power mode={} → HTTP {}", program, mode, code);
 
             if code == 200 || code == 302 {
                 // Restart mining daemon to apply
-                Self::restart_miner_daemon(&client, ip, program).await?;
+                Self::restart_miner_daemon(&client, ip, program)
+                    .await
+                    .map_err(|e| MinerError::PartialSuccess(format!("power mode saved but the mining daemon didn't restart: {}", e)))?;
                 return Ok(());
             }
 
-            return Err(format!("set_power_mode returned HTTP {}", code).into());
+            return Err(MinerError::from_status("set_power_mode", code));
         }
 
-        Err(format!("No power mode endpoint found for {} (tried cgminer/btminer)", ip).into())
+        Err(MinerError::UnsupportedCommand(format!("No power mode endpoint found for {} (tried cgminer/btminer)", ip)))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// LuCI form parsing
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Current values of a LuCI (CBI) form page, keyed by field name: text,
+/// password and hidden inputs, checked checkboxes and radios, and the
+/// selected option of each `<select>`.
+pub fn parse_form_values(html: &str) -> BTreeMap<String, String> {
+    let mut values = BTreeMap::new();
+
+    for tag in INPUT_TAG_RE.find_iter(html) {
+        let attrs = tag_attributes(tag.as_str());
+        let Some(name) = attrs.get("name") else { continue };
+        let kind = attrs.get("type").map(|t| t.to_ascii_lowercase()).unwrap_or_else(|| "text".to_string());
+        match kind.as_str() {
+            "submit" | "button" | "reset" | "image" | "file" => continue,
+            "checkbox" | "radio" if !attrs.contains_key("checked") => continue,
+            _ => {}
+        }
+        values.insert(name.clone(), attrs.get("value").cloned().unwrap_or_default());
+    }
+
+    for select in SELECT_RE.captures_iter(html) {
+        let Some(name) = tag_attributes(&select[1]).remove("name") else { continue };
+        let selected = OPTION_TAG_RE
+            .find_iter(&select[2])
+            .map(|option| tag_attributes(option.as_str()))
+            .find(|attrs| attrs.contains_key("selected"));
+        if let Some(value) = selected.and_then(|mut attrs| attrs.remove("value")) {
+            values.insert(name, value);
+        }
+    }
+
+    values
+}
+
+/// Form values worth keeping: everything but the CSRF token and the submit
+/// markers, which are sent fresh with every POST
+fn form_data(html: &str) -> BTreeMap<String, String> {
+    let mut values = parse_form_values(html);
+    for control in ["token", "cbi.submit", "cbi.apply"] {
+        values.remove(control);
+    }
+    values
+}
+
+/// Mining program of a config form page.  Only the pool and power pages
+/// are written back, so a saved form can't point anywhere else.
+fn config_form_program(page: &str) -> Result<&'static str> {
+    let program = page.strip_prefix("network/").map(|rest| rest.strip_suffix("/power").unwrap_or(rest));
+    match program {
+        Some("cgminer") => Ok("cgminer"),
+        Some("btminer") => Ok("btminer"),
+        _ => Err(MinerError::UnsupportedCommand(format!("writing config page {}", page))),
+    }
+}
+
+/// Pools 1–3 from the values of the pool config form
+fn parse_pools_form(values: &BTreeMap<String, String>) -> Vec<WhatsminerPool> {
+    let field = |n: usize, suffix: &str| {
+        values.get(&format!("cbid.pools.default.pool{}{}", n, suffix)).map(|v| v.trim().to_string()).unwrap_or_default()
+    };
+    (1..=3)
+        .map(|n| WhatsminerPool { url: field(n, "url"), worker: field(n, "user"), password: field(n, "pw") })
+        .filter(|pool| !pool.url.is_empty())
+        .collect()
+}
+
+/// Attributes of one HTML tag, names lowercased and values unescaped.
+/// Bare attributes (`checked`, `selected`) map to an empty value.
+fn tag_attributes(tag: &str) -> BTreeMap<String, String> {
+    let mut attrs: BTreeMap<String, String> = ATTR_RE
+        .captures_iter(tag)
+        .map(|c| {
+            let value = c.get(2).or_else(|| c.get(3)).map(|m| m.as_str()).unwrap_or_default();
+            (c[1].to_ascii_lowercase(), html_unescape(value))
+        })
+        .collect();
+    let without_values = ATTR_RE.replace_all(tag, " ");
+    for bare in ["checked", "selected"] {
+        if without_values.split(|c: char| c.is_whitespace() || c == '/' || c == '>').any(|word| word.eq_ignore_ascii_case(bare)) {
+            attrs.entry(bare.to_string()).or_default();
+        }
+    }
+    attrs
+}
+
+fn html_unescape(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&#34;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POOL_PAGE: &str = r#"
+        <form method="post" action="/cgi-bin/luci/admin/network/cgminer">
+        <input type="hidden" name="token" value="abc123" />
+        <select class="cbi-input-select" id="cbid.pools.default.coin_type" name="cbid.pools.default.coin_type" size="1">
+          <option id="cbid.pools.default.coin_type-BTC" value="BTC" selected="selected">BTC</option>
+          <option id="cbid.pools.default.coin_type-BCH" value="BCH">BCH</option>
+        </select>
+        <input type="text" class="cbi-input-text" onchange="cbi_d_update(this.id)" name="cbid.pools.default.pool1url" id="cbid.pools.default.pool1url" value="stratum+tcp://btc.pool.example:3333" />
+        <input type="text" class="cbi-input-text" name="cbid.pools.default.pool1user" id="cbid.pools.default.pool1user" value="acct.B12x3" />
+        <input type="password" class="cbi-input-password" name="cbid.pools.default.pool1pw" id="cbid.pools.default.pool1pw" value="x&amp;y" />
+        <input id="cbid.pools.default.pool2url" name="cbid.pools.default.pool2url" type="text" value="stratum+tcp://backup.example:443">
+        <input name="cbid.pools.default.pool2user" value="acct.B12x3" type="text">
+        <input name="cbid.pools.default.pool2pw" value="x" type="password">
+        <input type="text" name="cbid.pools.default.pool3url" value="" />
+        <input type="text" name="cbid.pools.default.pool3user" value="" />
+        <input type="checkbox" name="cbid.pools.default.enabled" value="1" checked="checked" />
+        <input type="checkbox" name="cbid.pools.default.verbose" value="1" />
+        <input type="submit" name="cbi.submit" value="Save &amp; Apply" />
+        </form>"#;
+
+    #[test]
+    fn test_session_key_covers_password() {
+        let key = WhatsminerWebClient::credentials_key("admin", "admin");
+        assert_eq!(key, WhatsminerWebClient::credentials_key("admin", "admin"));
+        assert_ne!(key, WhatsminerWebClient::credentials_key("admin", "s3cret"));
+        assert_ne!(key, WhatsminerWebClient::credentials_key("root", "admin"));
+    }
+
+    #[test]
+    fn test_parse_form_values() {
+        let values = parse_form_values(POOL_PAGE);
+        assert_eq!(values["token"], "abc123");
+        assert_eq!(values["cbid.pools.default.coin_type"], "BTC");
+        assert_eq!(values["cbid.pools.default.pool1pw"], "x&y");
+        assert_eq!(values["cbid.pools.default.enabled"], "1");
+        assert!(!values.contains_key("cbid.pools.default.verbose"));
+        assert!(!values.contains_key("cbi.submit"));
+    }
+
+    #[test]
+    fn test_config_forms() {
+        let values = form_data(POOL_PAGE);
+        assert!(!values.contains_key("token"));
+        assert_eq!(values["cbid.pools.default.pool2url"], "stratum+tcp://backup.example:443");
+
+        assert_eq!(config_form_program("network/cgminer").unwrap(), "cgminer");
+        assert_eq!(config_form_program("network/btminer/power").unwrap(), "btminer");
+        assert!(config_form_program("system/admin").is_err());
+        assert!(config_form_program("network/cgminer/../../system/admin").is_err());
+    }
+
+    #[test]
+    fn test_parse_pools_form() {
+        let pools = parse_pools_form(&parse_form_values(POOL_PAGE));
+        assert_eq!(
+            pools,
+            vec![
+                WhatsminerPool {
+                    url: "stratum+tcp://btc.pool.example:3333".into(),
+                    worker: "acct.B12x3".into(),
+                    password: "x&y".into(),
+                },
+                WhatsminerPool {
+                    url: "stratum+tcp://backup.example:443".into(),
+                    worker: "acct.B12x3".into(),
+                    password: "x".into(),
+                },
+            ]
+        );
     }
 }
@@ -0,0 +1,200 @@
+//! Worker names built per miner from a template.
+//!
+//! A pool's worker may contain placeholders, e.g. `acct.{rack}x{position}`
+//! or `acct.{ip3}x{ip4}`, expanded for each miner before its pools are set:
+//!
+//! | Placeholder   | Value                                            |
+//! |---------------|--------------------------------------------------|
+//! | `{ip}`        | IP address, e.g. `10.2.3.4`                      |
+//! | `{ip1}`–`{ip4}` | one octet of the IP                            |
+//! | `{mac}`       | MAC as 12 lowercase hex digits, no separators    |
+//! | `{serial}`    | serial number                                    |
+//! | `{hostname}`  | hostname                                         |
+//! | `{model}`     | model with spaces removed, e.g. `AntminerS19`    |
+//! | `{rack}`, `{position}` | the miner's location from the settings  |
+//!
+//! A placeholder the miner has no value for is an error rather than an
+//! empty string, so two miners never end up with the same worker by
+//! accident.  Worker names without `{` are used as they are.
+
+use super::{identify, DEFAULT_PORT, DEFAULT_TIMEOUT_MS};
+use crate::api::models::PoolConfig;
+use crate::core::config::{AppSettings, MinerLocation};
+use crate::core::{MinerError, MinerId, MinerStats, Result};
+
+/// What a worker template can refer to
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorkerFacts {
+    pub ip: String,
+    pub mac: Option<String>,
+    pub serial: Option<String>,
+    pub hostname: Option<String>,
+    pub model: Option<String>,
+    pub rack: Option<String>,
+    pub position: Option<String>,
+}
+
+impl WorkerFacts {
+    /// Facts from what a miner reported and where it sits
+    pub fn from_stats(ip: &str, stats: &MinerStats, location: Option<&MinerLocation>) -> Self {
+        Self {
+            ip: ip.to_string(),
+            mac: stats.mac_address.clone(),
+            serial: stats.serial_number.clone(),
+            hostname: stats.hostname.clone(),
+            model: stats.model.clone(),
+            rack: location.map(|l| l.rack.clone()),
+            position: location.map(|l| l.position.clone()),
+        }
+    }
+}
+
+const IP_PLACEHOLDERS: &[&str] = &["ip", "ip1", "ip2", "ip3", "ip4"];
+const DEVICE_PLACEHOLDERS: &[&str] = &["mac", "serial", "hostname", "model", "rack", "position"];
+
+pub fn is_template(worker: &str) -> bool {
+    worker.contains('{')
+}
+
+/// Placeholder names in `template`, in order.  Fails on an unknown name or
+/// an unclosed brace.
+pub fn placeholders(template: &str) -> Result<Vec<&str>> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let close = rest[open..]
+            .find('}')
+            .ok_or_else(|| invalid(template, "unclosed '{'"))?;
+        let name = &rest[open + 1..open + close];
+        if !IP_PLACEHOLDERS.contains(&name) && !DEVICE_PLACEHOLDERS.contains(&name) {
+            return Err(invalid(template, &format!("unknown placeholder {{{}}}", name)));
+        }
+        names.push(name);
+        rest = &rest[open + close + 1..];
+    }
+    if rest.contains('}') {
+        return Err(invalid(template, "unmatched '}'"));
+    }
+    Ok(names)
+}
+
+/// Whether expanding `pools` needs more than the miner's IP
+pub fn needs_device_facts(pools: &[PoolConfig]) -> bool {
+    pools.iter().any(|pool| {
+        placeholders(&pool.worker)
+            .map(|names| names.iter().any(|name| DEVICE_PLACEHOLDERS.contains(name)))
+            .unwrap_or(false)
+    })
+}
+
+/// Expand `template` for the miner described by `facts`
+pub fn expand(template: &str, facts: &WorkerFacts) -> Result<String> {
+    let mut out = template.to_string();
+    for name in placeholders(template)? {
+        let value = value_of(name, facts)
+            .ok_or_else(|| invalid(template, &format!("{} has no {}", facts.ip, name)))?;
+        out = out.replacen(&format!("{{{}}}", name), &value, 1);
+    }
+    Ok(out)
+}
+
+fn value_of(name: &str, facts: &WorkerFacts) -> Option<String> {
+    let octet = |i: usize| facts.ip.split('.').nth(i).map(str::to_string);
+    let value = match name {
+        "ip" => Some(facts.ip.clone()),
+        "ip1" => octet(0),
+        "ip2" => octet(1),
+        "ip3" => octet(2),
+        "ip4" => octet(3),
+        "mac" => facts.mac.as_ref().map(|mac| mac.chars().filter(char::is_ascii_hexdigit).collect::<String>().to_lowercase()),
+        "serial" => facts.serial.clone(),
+        "hostname" => facts.hostname.clone(),
+        "model" => facts.model.as_ref().map(|model| model.split_whitespace().collect()),
+        "rack" => facts.rack.clone(),
+        "position" => facts.position.clone(),
+        _ => None,
+    };
+    value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+}
+
+/// `pools` with every worker template expanded for `facts`
+pub fn expand_pools(pools: &[PoolConfig], facts: &WorkerFacts) -> Result<Vec<PoolConfig>> {
+    pools
+        .iter()
+        .map(|pool| {
+            Ok(PoolConfig { worker: expand(&pool.worker, facts)?, ..pool.clone() })
+        })
+        .collect()
+}
+
+/// Facts of the miner at `ip`, read from the miner itself (MAC, serial,
+/// hostname, model) and the settings (rack, position)
+pub async fn gather_facts(ip: &str) -> Result<WorkerFacts> {
+    let stats = identify(ip, DEFAULT_PORT, DEFAULT_TIMEOUT_MS).await?.stats;
+    let settings = AppSettings::load();
+    let location = MinerId::derive(&stats).and_then(|id| settings.location_of(&id));
+    Ok(WorkerFacts::from_stats(ip, &stats, location))
+}
+
+/// Expand the worker templates in `pools` for the miner at `ip`.  The miner
+/// is only asked for its details if a template needs them.
+pub async fn resolve_pools(ip: &str, pools: &[PoolConfig]) -> Result<Vec<PoolConfig>> {
+    if !pools.iter().any(|pool| is_template(&pool.worker)) {
+        return Ok(pools.to_vec());
+    }
+    let facts = if needs_device_facts(pools) {
+        gather_facts(ip).await?
+    } else {
+        WorkerFacts { ip: ip.to_string(), ..WorkerFacts::default() }
+    };
+    expand_pools(pools, &facts)
+}
+
+fn invalid(template: &str, reason: &str) -> MinerError {
+    MinerError::InvalidPoolConfig(format!("worker '{}': {}", template, reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts() -> WorkerFacts {
+        WorkerFacts {
+            ip: "10.2.3.4".into(),
+            mac: Some("AA:BB:CC:00:11:22".into()),
+            serial: Some("JYZZ1234".into()),
+            hostname: None,
+            model: Some("Antminer S19 Pro".into()),
+            rack: Some("B12".into()),
+            position: Some("3".into()),
+        }
+    }
+
+    #[test]
+    fn test_expand() {
+        let f = facts();
+        assert_eq!(expand("acct.{ip3}.{ip4}", &f).unwrap(), "acct.3.4");
+        assert_eq!(expand("acct.{rack}x{position}", &f).unwrap(), "acct.B12x3");
+        assert_eq!(expand("acct.{mac}", &f).unwrap(), "acct.aabbcc001122");
+        assert_eq!(expand("{model}-{serial}", &f).unwrap(), "AntminerS19Pro-JYZZ1234");
+        assert_eq!(expand("acct.rig1", &f).unwrap(), "acct.rig1");
+        // Same placeholder twice
+        assert_eq!(expand("{ip4}_{ip4}", &f).unwrap(), "4_4");
+    }
+
+    #[test]
+    fn test_expand_errors() {
+        let f = facts();
+        assert!(matches!(expand("acct.{hostname}", &f), Err(MinerError::InvalidPoolConfig(_))));
+        assert!(expand("acct.{slot}", &f).is_err());
+        assert!(expand("acct.{ip4", &f).is_err());
+        assert!(expand("acct.ip4}", &f).is_err());
+    }
+
+    #[test]
+    fn test_needs_device_facts() {
+        let pool = |worker: &str| PoolConfig { url: "stratum+tcp://pool:3333".into(), worker: worker.into(), password: "x".into() };
+        assert!(!needs_device_facts(&[pool("acct.{ip4}"), pool("acct.rig")]));
+        assert!(needs_device_facts(&[pool("acct.{ip4}"), pool("acct.{serial}")]));
+    }
+}
@@ -2,9 +2,15 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 use directories::ProjectDirs;
+use tokio::sync::watch;
+
+use super::models::{FanMode, MinerId, MinerVendor, PduOutlet};
+use crate::api::models::{PoolConfig, PowerMode};
+use crate::scanner::ScanSpec;
+use std::net::IpAddr;
 
 /// Configuration for miner authentication and connection settings
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MinerCredentials {
     pub username: String,
     pub password: String,
@@ -25,26 +31,296 @@ impl MinerCredentials {
     }
 }
 
+/// Which miners a credential set applies to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CredentialScope {
+    All,
+    /// Miners inside a scan spec, e.g. "10.2.0.0/16, !10.2.0.1"
+    Range(String),
+    /// Miners carrying this tag (case-insensitive)
+    Tag(String),
+    Miner(MinerId),
+}
+
+impl CredentialScope {
+    /// Whether the miner at `ip` (with `miner_id` and `tags`) is in scope
+    pub fn contains(&self, ip: &str, miner_id: Option<&MinerId>, tags: &[String]) -> bool {
+        match self {
+            CredentialScope::All => true,
+            CredentialScope::Range(spec) => {
+                let (Ok(spec), Ok(ip)) = (ScanSpec::parse(spec), ip.parse::<IpAddr>()) else {
+                    return false;
+                };
+                spec.contains(&ip)
+            }
+            CredentialScope::Tag(tag) => tags.iter().any(|t| t.eq_ignore_ascii_case(tag.trim())),
+            CredentialScope::Miner(id) => miner_id == Some(id),
+        }
+    }
+
+    /// Among sets of equal priority, narrower scopes are tried first
+    pub fn specificity(&self) -> u8 {
+        match self {
+            CredentialScope::Miner(_) => 3,
+            CredentialScope::Tag(_) => 2,
+            CredentialScope::Range(_) => 1,
+            CredentialScope::All => 0,
+        }
+    }
+}
+
+/// Credentials to try on the miners in `scope`, e.g. the old password of an
+/// acquired site.  The per-vendor credentials are the last fallback.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CredentialSet {
+    pub name: String,
+    pub scope: CredentialScope,
+    /// Only tried on this vendor's miners; any vendor if unset
+    #[serde(default)]
+    pub vendor: Option<MinerVendor>,
+    /// Lower is tried first
+    #[serde(default)]
+    pub priority: i32,
+    pub credentials: MinerCredentials,
+}
+
+/// Free-form tags of a miner, e.g. "site-b", "rack-12"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MinerTags {
+    pub miner_id: MinerId,
+    pub tags: Vec<String>,
+}
+
+/// Where a miner sits, e.g. rack "B12", position "3".  Used in worker names.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MinerLocation {
+    pub miner_id: MinerId,
+    pub rack: String,
+    pub position: String,
+}
+
+/// Named pool setup, e.g. "Main + backup", sent with `SetPools`.  Workers
+/// may be templates (see `client::worker_name`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PoolTemplate {
+    pub name: String,
+    pub pools: Vec<PoolConfig>,
+}
+
+/// Desired state of a group of miners, compared with what they report by
+/// the monitor (see `monitor::drift`).  Unset fields aren't checked.  A
+/// miner in several groups follows the most specific one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DesiredState {
+    pub name: String,
+    /// Which miners the policy covers, as for credential sets
+    pub scope: CredentialScope,
+    /// Workers may be templates (see `client::worker_name`)
+    #[serde(default)]
+    pub pools: Option<Vec<PoolConfig>>,
+    #[serde(default)]
+    pub power_mode: Option<PowerMode>,
+    #[serde(default)]
+    pub fan_mode: Option<FanMode>,
+    /// `bitmain-freq-level`, percent
+    #[serde(default)]
+    pub freq_level: Option<u32>,
+}
+
+/// A saved scan spec, e.g. "Container A" -> "10.1.2.*, !10.1.2.1"
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NamedRange {
+    pub name: String,
+    pub spec: String,
+    /// Interface name or local IP that connections to this range go out from
+    /// (OS default route if unset)
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+/// Which PDU outlet feeds a miner
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutletLink {
+    pub miner_id: MinerId,
+    pub outlet: PduOutlet,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettings {
+    /// Only kept in this file until a credential vault is created; see `vault`
+    #[serde(default)]
     pub antminer_credentials: MinerCredentials,
+    #[serde(default = "default_whatsminer_credentials")]
     pub whatsminer_credentials: MinerCredentials,
     pub scan_thread_count: u32,
+    /// Seconds between monitor polls
     pub monitor_interval: u64,
+    #[serde(default)]
+    pub named_ranges: Vec<NamedRange>,
+    /// Per-command timeout while identifying miners during a scan
+    #[serde(default = "default_scan_timeout_ms")]
+    pub scan_timeout_ms: u64,
+    /// CGMiner API ports probed during a scan
+    #[serde(default = "default_scan_ports")]
+    pub scan_ports: Vec<u16>,
+    /// Also probe addresses without an open miner port over SNMP, to find
+    /// PDUs and switches
+    #[serde(default)]
+    pub scan_devices: bool,
+    /// SNMP v2c community used for device probes
+    #[serde(default = "default_snmp_community")]
+    pub snmp_community: String,
+    #[serde(default)]
+    pub outlet_links: Vec<OutletLink>,
+    /// Addresses miners fall back to when reset or unconfigured.  A miner
+    /// found on one of them is reported as `ScanEvent::FactoryDefaultIp`;
+    /// include them in a scan range to catch miners stuck there.
+    #[serde(default = "default_factory_ips")]
+    pub factory_default_ips: Vec<String>,
+    /// Read each miner's inventory during scans to catch two devices
+    /// sharing an address.  Off by default: it adds a web API read per miner.
+    #[serde(default)]
+    pub detect_ip_conflicts: bool,
+    /// Scoped credentials tried before the per-vendor ones.  Kept in the
+    /// vault like them once one exists.
+    #[serde(default)]
+    pub credential_sets: Vec<CredentialSet>,
+    #[serde(default)]
+    pub miner_tags: Vec<MinerTags>,
+    #[serde(default)]
+    pub miner_locations: Vec<MinerLocation>,
+    #[serde(default)]
+    pub pool_templates: Vec<PoolTemplate>,
+    #[serde(default)]
+    pub desired_states: Vec<DesiredState>,
+}
+
+fn default_whatsminer_credentials() -> MinerCredentials {
+    MinerCredentials::new("admin".to_string(), "admin".to_string())
+}
+
+fn default_scan_timeout_ms() -> u64 {
+    500
+}
+
+fn default_scan_ports() -> Vec<u16> {
+    vec![4028]
+}
+
+fn default_snmp_community() -> String {
+    "public".to_string()
+}
+
+fn default_factory_ips() -> Vec<String> {
+    vec!["192.168.1.99".to_string()]
+}
+
+lazy_static::lazy_static! {
+    /// Latest saved settings; subscribers are woken on every save
+    static ref SETTINGS_CHANGED: watch::Sender<AppSettings> = watch::channel(AppSettings::load()).0;
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
             antminer_credentials: MinerCredentials::default(), // root/root
-            whatsminer_credentials: MinerCredentials::new("admin".to_string(), "admin".to_string()),
+            whatsminer_credentials: default_whatsminer_credentials(),
             scan_thread_count: 32,
             monitor_interval: 30,
+            named_ranges: Vec::new(),
+            scan_timeout_ms: default_scan_timeout_ms(),
+            scan_ports: default_scan_ports(),
+            scan_devices: false,
+            snmp_community: default_snmp_community(),
+            outlet_links: Vec::new(),
+            factory_default_ips: default_factory_ips(),
+            detect_ip_conflicts: false,
+            credential_sets: Vec::new(),
+            miner_tags: Vec::new(),
+            miner_locations: Vec::new(),
+            pool_templates: Vec::new(),
+            desired_states: Vec::new(),
         }
     }
 }
 
 impl AppSettings {
+    /// Look up a saved range by name (case-insensitive)
+    pub fn named_range(&self, name: &str) -> Option<&NamedRange> {
+        self.named_ranges
+            .iter()
+            .find(|range| range.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// PDU outlet linked to `miner_id`, if any
+    pub fn outlet_for(&self, miner_id: &MinerId) -> Option<&PduOutlet> {
+        self.outlet_links
+            .iter()
+            .find(|link| &link.miner_id == miner_id)
+            .map(|link| &link.outlet)
+    }
+
+    /// Link `miner_id` to `outlet`, replacing any previous link of the miner.
+    /// An outlet feeds one miner, so a link from another miner to the same
+    /// outlet is dropped as well.
+    pub fn link_outlet(&mut self, miner_id: MinerId, outlet: PduOutlet) {
+        self.outlet_links
+            .retain(|link| link.miner_id != miner_id && link.outlet != outlet);
+        self.outlet_links.push(OutletLink { miner_id, outlet });
+    }
+
+    /// Remove the outlet link of `miner_id`.  Returns false if there was none.
+    pub fn unlink_outlet(&mut self, miner_id: &MinerId) -> bool {
+        let before = self.outlet_links.len();
+        self.outlet_links.retain(|link| &link.miner_id != miner_id);
+        self.outlet_links.len() != before
+    }
+
+    /// Tags of `miner_id`; empty if it has none
+    pub fn tags_of(&self, miner_id: &MinerId) -> &[String] {
+        self.miner_tags
+            .iter()
+            .find(|entry| &entry.miner_id == miner_id)
+            .map(|entry| entry.tags.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Replace the tags of `miner_id`.  Tags are trimmed and deduplicated
+    /// (case-insensitively); an empty list removes the entry.
+    pub fn set_tags(&mut self, miner_id: MinerId, tags: Vec<String>) {
+        let mut cleaned: Vec<String> = Vec::new();
+        for tag in tags {
+            let tag = tag.trim();
+            if !tag.is_empty() && !cleaned.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                cleaned.push(tag.to_string());
+            }
+        }
+        self.miner_tags.retain(|entry| entry.miner_id != miner_id);
+        if !cleaned.is_empty() {
+            self.miner_tags.push(MinerTags { miner_id, tags: cleaned });
+        }
+    }
+
+    /// Look up a pool template by name (case-insensitive)
+    pub fn pool_template(&self, name: &str) -> Option<&PoolTemplate> {
+        self.pool_templates
+            .iter()
+            .find(|template| template.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    pub fn location_of(&self, miner_id: &MinerId) -> Option<&MinerLocation> {
+        self.miner_locations.iter().find(|entry| &entry.miner_id == miner_id)
+    }
+
+    /// Set where `miner_id` sits; blank rack and position remove the entry
+    pub fn set_location(&mut self, miner_id: MinerId, rack: &str, position: &str) {
+        self.miner_locations.retain(|entry| entry.miner_id != miner_id);
+        let (rack, position) = (rack.trim(), position.trim());
+        if !rack.is_empty() || !position.is_empty() {
+            self.miner_locations.push(MinerLocation { miner_id, rack: rack.to_string(), position: position.to_string() });
+        }
+    }
+
     pub fn load() -> Self {
         if let Some(config_path) = Self::get_config_path() {
             if config_path.exists() {
@@ -63,16 +339,66 @@ impl AppSettings {
             if let Some(parent) = config_path.parent() {
                 fs::create_dir_all(parent).map_err(|e| e.to_string())?;
             }
-            let content = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+            let content = serde_json::to_string_pretty(&self.to_disk_json(super::vault::exists()))
+                .map_err(|e| e.to_string())?;
             fs::write(config_path, content).map_err(|e| e.to_string())?;
+            Self::notify_changed(self.clone());
             Ok(())
         } else {
             Err("Could not determine config path".to_string())
         }
     }
 
-    fn get_config_path() -> Option<PathBuf> {
+    /// The settings as written to disk.  With `strip_credentials` (a vault
+    /// exists) the credentials are left out, since the vault holds them.
+    pub fn to_disk_json(&self, strip_credentials: bool) -> serde_json::Value {
+        let mut json = serde_json::to_value(self).unwrap_or_default();
+        if strip_credentials {
+            if let Some(object) = json.as_object_mut() {
+                object.remove("antminer_credentials");
+                object.remove("whatsminer_credentials");
+                object.remove("credential_sets");
+            }
+        }
+        json
+    }
+
+    /// Whether the settings file on disk still holds credentials in the clear
+    pub fn has_plaintext_credentials() -> bool {
+        Self::get_config_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .is_some_and(|json| {
+                json.get("antminer_credentials").is_some()
+                    || json.get("whatsminer_credentials").is_some()
+                    || json["credential_sets"].as_array().is_some_and(|sets| !sets.is_empty())
+            })
+    }
+
+    /// Watch for saved settings.  The receiver starts with the current
+    /// settings marked as seen; `changed()` resolves after the next save.
+    pub fn subscribe() -> watch::Receiver<AppSettings> {
+        SETTINGS_CHANGED.subscribe()
+    }
+
+    /// Latest saved settings without reading the file.  Don't hold on to
+    /// the guard across an `.await`: saves wait for it.
+    pub fn current() -> watch::Ref<'static, AppSettings> {
+        SETTINGS_CHANGED.borrow()
+    }
+
+    /// Push new settings to every subscriber without writing them to disk
+    pub fn notify_changed(settings: AppSettings) {
+        SETTINGS_CHANGED.send_replace(settings);
+    }
+
+    /// Directory holding the settings file and the credential vault
+    pub fn config_dir() -> Option<PathBuf> {
         ProjectDirs::from("com", "example", "miner-manager")
-            .map(|proj_dirs| proj_dirs.config_dir().join("app_settings.json"))
+            .map(|proj_dirs| proj_dirs.config_dir().to_path_buf())
+    }
+
+    fn get_config_path() -> Option<PathBuf> {
+        Self::config_dir().map(|dir| dir.join("app_settings.json"))
     }
 }
@@ -5,22 +5,64 @@ use thiserror::Error;
 pub enum MinerError {
     #[error("Connection timeout: {0}")]
     Timeout(String),
-    
+
     #[error("Invalid JSON response: {0}")]
     ParseError(#[from] serde_json::Error),
-    
+
     #[error("Network error: {0}")]
     NetworkError(#[from] std::io::Error),
-    
+
+    #[error("Miner unreachable: {0}")]
+    Unreachable(String),
+
     #[error("Unsupported miner model: {0}")]
     UnsupportedModel(String),
-    
+
+    #[error("Not supported by this miner: {0}")]
+    UnsupportedCommand(String),
+
+    #[error("Could not identify miner at {ip}: {source}")]
+    DetectionFailed { ip: String, source: Box<MinerError> },
+
     #[error("Authentication failed")]
     AuthenticationError,
-    
+
+    /// The miner understood the request and said no, e.g. `passwd.cgi`
+    /// answering a code other than `P000`, or an HTTP error status
+    #[error("Miner rejected the request ({code}): {message}")]
+    Rejected { code: String, message: String },
+
     #[error("Invalid response format")]
     InvalidResponse,
 
+    #[error("Unexpected response: {0}")]
+    MalformedResponse(String),
+
+    #[error("Response truncated after {received} bytes")]
+    TruncatedResponse { received: usize },
+
+    #[error("Response exceeded {limit} bytes")]
+    ResponseTooLarge { limit: usize },
+
+    /// The change was stored but not fully applied, e.g. pools saved but the
+    /// mining daemon didn't restart
+    #[error("Partially applied: {0}")]
+    PartialSuccess(String),
+
+    /// Pools or worker names that can't be sent as given
+    #[error("Invalid pool settings: {0}")]
+    InvalidPoolConfig(String),
+
+    /// A config snapshot that is missing, unreadable or doesn't fit the miner
+    #[error("Config backup: {0}")]
+    Backup(String),
+
+    #[error("Invalid scan range: {0}")]
+    InvalidScanSpec(#[from] crate::scanner::ScanSpecError),
+
+    #[error("Credentials unavailable: {0}")]
+    Vault(#[from] crate::core::vault::VaultError),
+
     #[error("Error: {0}")]
     GenericError(String),
 }
@@ -37,4 +79,141 @@ impl From<&str> for MinerError {
     }
 }
 
+/// What went wrong, coarse enough for the UI to pick a message and for a
+/// caller to decide whether to try again
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Wrong or missing credentials (including a locked vault)
+    AuthFailed,
+    /// Connection refused, no route, connection dropped
+    Unreachable,
+    Timeout,
+    /// The model or firmware lacks the command
+    Unsupported,
+    /// The miner refused the request with `code`
+    RejectedByMiner { code: String },
+    /// The miner answered something we couldn't read
+    ParseFailed,
+    /// Part of the change was applied
+    PartialSuccess,
+    Other,
+}
+
+impl ErrorKind {
+    /// Whether the same request may succeed if simply sent again
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ErrorKind::Unreachable | ErrorKind::Timeout)
+    }
+}
+
+impl MinerError {
+    /// Failed HTTP request to a miner's web UI
+    pub fn from_reqwest(context: impl std::fmt::Display, e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            MinerError::Timeout(format!("{}: {}", context, e))
+        } else {
+            MinerError::Unreachable(format!("{}: {}", context, e))
+        }
+    }
+
+    /// Non-success HTTP status from a miner's web UI
+    pub fn from_status(context: impl std::fmt::Display, status: u16) -> Self {
+        match status {
+            401 | 403 => MinerError::AuthenticationError,
+            404 | 405 | 501 => MinerError::UnsupportedCommand(format!("{} returned HTTP {}", context, status)),
+            _ => MinerError::Rejected {
+                code: format!("HTTP {}", status),
+                message: format!("{} returned HTTP {}", context, status),
+            },
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        use crate::core::vault::VaultError;
+        match self {
+            MinerError::Timeout(_) => ErrorKind::Timeout,
+            MinerError::NetworkError(e) if e.kind() == std::io::ErrorKind::TimedOut => ErrorKind::Timeout,
+            MinerError::NetworkError(_) | MinerError::Unreachable(_) => ErrorKind::Unreachable,
+            MinerError::UnsupportedModel(_) | MinerError::UnsupportedCommand(_) => ErrorKind::Unsupported,
+            // Something answered, but not like a miner
+            MinerError::DetectionFailed { source, .. } => match source.kind() {
+                ErrorKind::ParseFailed | ErrorKind::Other => ErrorKind::Unsupported,
+                kind => kind,
+            },
+            MinerError::AuthenticationError => ErrorKind::AuthFailed,
+            MinerError::Rejected { code, .. } => ErrorKind::RejectedByMiner { code: code.clone() },
+            MinerError::ParseError(_)
+            | MinerError::InvalidResponse
+            | MinerError::MalformedResponse(_)
+            | MinerError::TruncatedResponse { .. }
+            | MinerError::ResponseTooLarge { .. } => ErrorKind::ParseFailed,
+            MinerError::PartialSuccess(_) => ErrorKind::PartialSuccess,
+            MinerError::Vault(VaultError::Io(_) | VaultError::Corrupt(_) | VaultError::NoConfigDir) => ErrorKind::Other,
+            MinerError::Vault(_) => ErrorKind::AuthFailed,
+            MinerError::InvalidScanSpec(_)
+            | MinerError::InvalidPoolConfig(_)
+            | MinerError::Backup(_)
+            | MinerError::GenericError(_) => ErrorKind::Other,
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.kind().is_retryable()
+    }
+
+    /// What the user can do about it, one sentence
+    pub fn suggested_action(&self) -> String {
+        use crate::core::vault::VaultError;
+        match (self, self.kind()) {
+            (MinerError::Vault(VaultError::Locked), _) => "Unlock the credential vault and retry".to_string(),
+            (MinerError::Vault(VaultError::NotFound), _) => "Create the credential vault first".to_string(),
+            (MinerError::InvalidPoolConfig(_), _) => "Fix the pool settings and try again".to_string(),
+            (_, ErrorKind::AuthFailed) => "Check the web credentials for this miner in Settings".to_string(),
+            (_, ErrorKind::Unreachable) => "Check that the miner is powered and on the network, then retry".to_string(),
+            (_, ErrorKind::Timeout) => "Retry; the miner may be busy or rebooting".to_string(),
+            (_, ErrorKind::Unsupported) => "This model or firmware doesn't support the command".to_string(),
+            (_, ErrorKind::RejectedByMiner { code }) => format!("The miner refused the request (code {}); check the values sent", code),
+            (_, ErrorKind::ParseFailed) => "The miner's answer wasn't understood; check its firmware version".to_string(),
+            (_, ErrorKind::PartialSuccess) => "Check the miner: part of the change was applied".to_string(),
+            (_, ErrorKind::Other) => "See the error message".to_string(),
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, MinerError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_kinds() {
+        assert_eq!(MinerError::AuthenticationError.kind(), ErrorKind::AuthFailed);
+        assert!(MinerError::Timeout("10.0.0.1".into()).is_retryable());
+
+        let refused = std::io::Error::from(std::io::ErrorKind::ConnectionRefused);
+        assert_eq!(MinerError::NetworkError(refused).kind(), ErrorKind::Unreachable);
+
+        assert_eq!(
+            MinerError::from_status("GET /cgi-bin/x.cgi", 500).kind(),
+            ErrorKind::RejectedByMiner { code: "HTTP 500".to_string() }
+        );
+        assert_eq!(MinerError::from_status("GET /cgi-bin/x.cgi", 404).kind(), ErrorKind::Unsupported);
+        assert!(!MinerError::from_status("GET /cgi-bin/x.cgi", 500).is_retryable());
+
+        // Detection keeps the cause: a dead address is unreachable, a
+        // non-miner is unsupported
+        let unreachable = MinerError::DetectionFailed {
+            ip: "10.0.0.1".into(),
+            source: Box::new(MinerError::NetworkError(std::io::Error::from(std::io::ErrorKind::ConnectionRefused))),
+        };
+        assert_eq!(unreachable.kind(), ErrorKind::Unreachable);
+        let not_a_miner = MinerError::DetectionFailed { ip: "10.0.0.1".into(), source: Box::new(MinerError::InvalidResponse) };
+        assert_eq!(not_a_miner.kind(), ErrorKind::Unsupported);
+
+        assert_eq!(
+            MinerError::Vault(crate::core::vault::VaultError::Locked).suggested_action(),
+            "Unlock the credential vault and retry"
+        );
+    }
+}
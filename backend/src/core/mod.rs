@@ -1,7 +1,8 @@
 pub mod models;
 pub mod error;
 pub mod config;
+pub mod vault;
 
-pub use models::{Miner, MinerStats, MinerStatus};
-pub use error::{MinerError, Result};
-pub use config::MinerCredentials;
+pub use models::{Miner, MinerId, MinerStats, MinerStatus, MinerVendor, FirmwareFamily, NetworkMode, Device, DeviceKind, PduOutlet, FanMode};
+pub use error::{ErrorKind, MinerError, Result};
+pub use config::{CredentialScope, CredentialSet, MinerCredentials, MinerTags, NamedRange, OutletLink};
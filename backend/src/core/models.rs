@@ -1,13 +1,61 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents a discovered miner on the network
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Miner {
+    /// Stable identity; `None` until a MAC, serial or hostname is known
+    #[serde(default)]
+    pub id: Option<MinerId>,
     pub ip: String,
     pub model: Option<String>,
     pub status: MinerStatus,
     pub stats: MinerStats,
     pub last_updated: u64,
+    /// Vendor according to the MAC OUI table, e.g. "Bitmain"
+    #[serde(default)]
+    pub vendor_hint: Option<String>,
+    /// PDU outlet feeding this miner, if linked in the settings
+    #[serde(default)]
+    pub outlet: Option<PduOutlet>,
+}
+
+/// A PDU outlet, identified by the PDU's address and the outlet number
+/// printed on it (1-based)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PduOutlet {
+    pub pdu_ip: String,
+    pub outlet: u32,
+}
+
+/// Something on the network that isn't a miner: PDUs, switches, cameras
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Device {
+    pub ip: String,
+    pub kind: DeviceKind,
+    pub mac_address: Option<String>,
+    /// Vendor according to the MAC OUI table
+    pub vendor_hint: Option<String>,
+    /// SNMP `sysName.0`
+    pub sys_name: Option<String>,
+    /// SNMP `sysDescr.0`
+    pub sys_descr: Option<String>,
+    /// `Server` header and page title of its web interface
+    pub http_banner: Option<String>,
+    /// Miner port it accepted a connection on, if any
+    pub open_port: Option<u16>,
+    pub last_updated: u64,
+}
+
+/// What kind of non-miner device this is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeviceKind {
+    Pdu,
+    /// Switches, routers, access points
+    Network,
+    Camera,
+    Computer,
+    Unknown,
 }
 
 /// Performance metrics for a miner
@@ -37,11 +85,89 @@ pub struct MinerStats {
     pub software: Option<String>,    // "Bmminer X.X.X"
     pub hardware: Option<String>,    // "uart_trans.X.X"
     pub mac_address: Option<String>, // Keep this if we find it later
+    #[serde(default)]
+    pub serial_number: Option<String>,
+    #[serde(default)]
+    pub hostname: Option<String>,
+    /// How the miner gets its address, as configured on the device
+    #[serde(default)]
+    pub network_mode: Option<NetworkMode>,
+    #[serde(default)]
+    pub netmask: Option<String>,
+    #[serde(default)]
+    pub gateway: Option<String>,
+    #[serde(default)]
+    pub dns_servers: Vec<String>,
 
     /// Current power mode as raw Antminer `miner-mode` value:
     ///   0 = Normal, 1 = Sleep, 2 = LPM
     /// `None` if not yet read or unsupported.
     pub power_mode: Option<u8>,
+    /// Fan control from the miner conf (Antminer only); `None` if not read
+    #[serde(default)]
+    pub fan_mode: Option<FanMode>,
+    /// `bitmain-freq-level` from the miner conf, percent (Antminer only)
+    #[serde(default)]
+    pub freq_level: Option<u32>,
+}
+
+/// Fan control of a miner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FanMode {
+    /// Firmware regulates fan speed by temperature
+    Auto,
+    /// Fans held at `percent` PWM
+    Fixed { percent: u32 },
+}
+
+/// Miner vendor — decides which CGMiner parser and web client to use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MinerVendor {
+    Antminer,
+    Whatsminer,
+}
+
+/// Mining daemon behind the CGMiner API (from the `STATUS` description)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FirmwareFamily {
+    Bmminer,  // Bitmain stock firmware
+    Cgminer,  // Generic/older cgminer builds
+    Btminer,  // MicroBT Whatsminer
+    Unknown,
+}
+
+impl FirmwareFamily {
+    /// Classify a `STATUS[0].Description` such as "bmminer 1.0.0" or "btminer".
+    pub fn from_description(description: &str) -> Self {
+        let desc = description.to_lowercase();
+        if desc.contains("bmminer") {
+            FirmwareFamily::Bmminer
+        } else if desc.contains("btminer") || desc.contains("whatsminer") {
+            FirmwareFamily::Btminer
+        } else if desc.contains("cgminer") {
+            FirmwareFamily::Cgminer
+        } else {
+            FirmwareFamily::Unknown
+        }
+    }
+}
+
+/// Address configuration of a miner's network interface
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NetworkMode {
+    Dhcp,
+    Static,
+}
+
+impl NetworkMode {
+    /// Parse the device's wording: `DHCP`/`dhcp` or `Static`/`static`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "dhcp" => Some(NetworkMode::Dhcp),
+            "static" => Some(NetworkMode::Static),
+            _ => None,
+        }
+    }
 }
 
 /// Status of a miner
@@ -75,7 +201,102 @@ impl Default for MinerStats {
             software: None,
             hardware: None,
             mac_address: None,
+            serial_number: None,
+            hostname: None,
+            network_mode: None,
+            netmask: None,
+            gateway: None,
+            dns_servers: Vec::new(),
             power_mode: None,
+            fan_mode: None,
+            freq_level: None,
+        }
+    }
+}
+
+/// Stable miner identity that survives DHCP handing out new addresses.
+///
+/// Derived from the MAC address, falling back to the serial number and then
+/// the hostname.  The source is kept as a prefix (`mac:`, `sn:`, `host:`) so
+/// ids from different sources never collide.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MinerId(pub String);
+
+impl MinerId {
+    /// Id from a MAC address in any common notation (`aa-bb-…`, `AA:BB:…`).
+    /// Returns `None` for malformed, all-zero and broadcast addresses.
+    pub fn from_mac(mac: &str) -> Option<Self> {
+        let hex: String = mac.chars().filter(|c| c.is_ascii_hexdigit()).collect();
+        if hex.len() != 12 || mac.chars().any(|c| !c.is_ascii_hexdigit() && !matches!(c, ':' | '-' | '.')) {
+            return None;
         }
+        let hex = hex.to_uppercase();
+        if hex == "000000000000" || hex == "FFFFFFFFFFFF" {
+            return None;
+        }
+        let octets: Vec<&str> = (0..6).map(|i| &hex[i * 2..i * 2 + 2]).collect();
+        Some(Self(format!("mac:{}", octets.join(":"))))
+    }
+
+    pub fn from_serial(serial: &str) -> Option<Self> {
+        let serial = serial.trim();
+        (!serial.is_empty()).then(|| Self(format!("sn:{}", serial)))
+    }
+
+    pub fn from_hostname(hostname: &str) -> Option<Self> {
+        let hostname = hostname.trim().to_lowercase();
+        (!hostname.is_empty()).then(|| Self(format!("host:{}", hostname)))
+    }
+
+    /// Best available id for a miner: MAC, then serial, then hostname.
+    pub fn derive(stats: &MinerStats) -> Option<Self> {
+        stats.mac_address.as_deref().and_then(Self::from_mac)
+            .or_else(|| stats.serial_number.as_deref().and_then(Self::from_serial))
+            .or_else(|| stats.hostname.as_deref().and_then(Self::from_hostname))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for MinerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_miner_id_from_mac() {
+        let id = MinerId::from_mac("aa-bb-cc-00-11-2f").unwrap();
+        assert_eq!(id.as_str(), "mac:AA:BB:CC:00:11:2F");
+        assert_eq!(MinerId::from_mac("AA:BB:CC:00:11:2F"), Some(id));
+
+        assert_eq!(MinerId::from_mac("00:00:00:00:00:00"), None);
+        assert_eq!(MinerId::from_mac("ff:ff:ff:ff:ff:ff"), None);
+        assert_eq!(MinerId::from_mac("<incomplete>"), None);
+        assert_eq!(MinerId::from_mac("AA:BB:CC:00:11"), None);
+    }
+
+    #[test]
+    fn test_miner_id_fallbacks() {
+        let mut stats = MinerStats {
+            serial_number: Some("JYZZ1234".to_string()),
+            hostname: Some("Antminer-R12".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(MinerId::derive(&stats).unwrap().as_str(), "sn:JYZZ1234");
+
+        stats.serial_number = None;
+        assert_eq!(MinerId::derive(&stats).unwrap().as_str(), "host:antminer-r12");
+
+        stats.mac_address = Some("02:11:22:33:44:55".to_string());
+        assert_eq!(MinerId::derive(&stats).unwrap().as_str(), "mac:02:11:22:33:44:55");
+
+        assert_eq!(MinerId::derive(&MinerStats::default()), None);
     }
 }
@@ -0,0 +1,548 @@
+//! Encrypted credential store.
+//!
+//! Miner passwords used to sit in `app_settings.json` in the clear.  Once a
+//! vault is created they live in `credentials.vault` next to it instead,
+//! encrypted with XChaCha20-Poly1305 under a key derived from a master
+//! passphrase with Argon2id, and the settings file no longer carries them.
+//!
+//! The vault is unlocked once per session; the derived key and the decrypted
+//! contents stay in memory until `lock`.  Without a vault, credentials keep
+//! coming from the settings file as before.
+//!
+//! File layout (JSON): format version, Argon2 parameters, salt, nonce and
+//! ciphertext, the binary fields base64-encoded.  The header is bound to the
+//! ciphertext as associated data, so tampering with the KDF parameters fails
+//! the unlock like a wrong passphrase does.
+
+use super::config::{AppSettings, CredentialSet, MinerCredentials};
+use super::models::MinerVendor;
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use thiserror::Error;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Current file format
+const FORMAT_VERSION: u32 = 1;
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+#[derive(Debug, Error)]
+pub enum VaultError {
+    #[error("no credential vault has been created")]
+    NotFound,
+
+    #[error("a credential vault already exists")]
+    AlreadyExists,
+
+    #[error("the credential vault is locked")]
+    Locked,
+
+    #[error("wrong passphrase or corrupted vault")]
+    WrongPassphrase,
+
+    #[error("passphrase must not be empty")]
+    EmptyPassphrase,
+
+    #[error("unreadable vault file: {0}")]
+    Corrupt(String),
+
+    #[error("could not determine the config directory")]
+    NoConfigDir,
+
+    #[error("vault I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Argon2id cost parameters, stored with the vault so they can be raised later
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    /// Memory in KiB
+    pub m_cost: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+}
+
+impl Default for KdfParams {
+    /// The Argon2id defaults: 19 MiB, 2 passes, 1 lane
+    fn default() -> Self {
+        Self {
+            m_cost: Params::DEFAULT_M_COST,
+            t_cost: Params::DEFAULT_T_COST,
+            p_cost: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+/// What the vault holds
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VaultContents {
+    pub antminer_credentials: MinerCredentials,
+    pub whatsminer_credentials: MinerCredentials,
+    #[serde(default)]
+    pub credential_sets: Vec<CredentialSet>,
+}
+
+impl VaultContents {
+    pub fn credentials_for(&self, vendor: MinerVendor) -> &MinerCredentials {
+        match vendor {
+            MinerVendor::Antminer => &self.antminer_credentials,
+            MinerVendor::Whatsminer => &self.whatsminer_credentials,
+        }
+    }
+}
+
+impl Drop for VaultContents {
+    fn drop(&mut self) {
+        self.antminer_credentials.password.zeroize();
+        self.whatsminer_credentials.password.zeroize();
+        for set in &mut self.credential_sets {
+            set.credentials.password.zeroize();
+        }
+    }
+}
+
+/// Whether a vault exists and is unlocked in this session
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VaultStatus {
+    pub exists: bool,
+    pub unlocked: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct VaultFile {
+    version: u32,
+    kdf: KdfParams,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl VaultFile {
+    /// Associated data: everything that isn't the ciphertext or nonce
+    fn header(&self) -> Vec<u8> {
+        format!(
+            "miner-vault:{}:{}:{}:{}:{}",
+            self.version, self.kdf.m_cost, self.kdf.t_cost, self.kdf.p_cost, self.salt
+        )
+        .into_bytes()
+    }
+}
+
+/// A vault opened with its passphrase.  Changes to `contents` are written
+/// back with `save`.
+pub struct UnlockedVault {
+    path: PathBuf,
+    kdf: KdfParams,
+    salt: [u8; SALT_LEN],
+    key: Zeroizing<[u8; KEY_LEN]>,
+    pub contents: VaultContents,
+}
+
+impl std::fmt::Debug for UnlockedVault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnlockedVault").field("path", &self.path).finish_non_exhaustive()
+    }
+}
+
+impl UnlockedVault {
+    /// Create a new vault file at `path` holding `contents`.
+    /// Fails if a file already exists there.
+    pub fn create(path: &Path, passphrase: &str, contents: VaultContents, kdf: KdfParams) -> Result<Self, VaultError> {
+        if passphrase.is_empty() {
+            return Err(VaultError::EmptyPassphrase);
+        }
+        if path.exists() {
+            return Err(VaultError::AlreadyExists);
+        }
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let key = derive_key(passphrase, &salt, kdf)?;
+        let vault = Self { path: path.to_path_buf(), kdf, salt, key, contents };
+        vault.save()?;
+        Ok(vault)
+    }
+
+    /// Open the vault at `path`
+    pub fn open(path: &Path, passphrase: &str) -> Result<Self, VaultError> {
+        let raw = match fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err(VaultError::NotFound),
+            Err(e) => return Err(e.into()),
+        };
+        let file: VaultFile = serde_json::from_str(&raw).map_err(|e| VaultError::Corrupt(e.to_string()))?;
+        if file.version != FORMAT_VERSION {
+            return Err(VaultError::Corrupt(format!("unsupported format version {}", file.version)));
+        }
+
+        let salt: [u8; SALT_LEN] = decode(&file.salt)?
+            .try_into()
+            .map_err(|_| VaultError::Corrupt("bad salt length".into()))?;
+        let nonce = decode(&file.nonce)?;
+        if nonce.len() != 24 {
+            return Err(VaultError::Corrupt("bad nonce length".into()));
+        }
+        let ciphertext = decode(&file.ciphertext)?;
+
+        let key = derive_key(passphrase, &salt, file.kdf)?;
+        let cipher = XChaCha20Poly1305::new(key.as_ref().into());
+        let header = file.header();
+        let plaintext = Zeroizing::new(
+            cipher
+                .decrypt(XNonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: &header })
+                .map_err(|_| VaultError::WrongPassphrase)?,
+        );
+        let contents = serde_json::from_slice(&plaintext).map_err(|e| VaultError::Corrupt(e.to_string()))?;
+
+        Ok(Self { path: path.to_path_buf(), kdf: file.kdf, salt, key, contents })
+    }
+
+    /// Encrypt `contents` under a fresh nonce and replace the file atomically
+    pub fn save(&self) -> Result<(), VaultError> {
+        let plaintext = Zeroizing::new(serde_json::to_vec(&self.contents).map_err(|e| VaultError::Corrupt(e.to_string()))?);
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let mut file = VaultFile {
+            version: FORMAT_VERSION,
+            kdf: self.kdf,
+            salt: BASE64.encode(self.salt),
+            nonce: BASE64.encode(nonce),
+            ciphertext: String::new(),
+        };
+        let header = file.header();
+        let cipher = XChaCha20Poly1305::new(self.key.as_ref().into());
+        let ciphertext = cipher
+            .encrypt(&nonce, Payload { msg: &plaintext, aad: &header })
+            .map_err(|_| VaultError::Corrupt("encryption failed".into()))?;
+        file.ciphertext = BASE64.encode(ciphertext);
+
+        let content = serde_json::to_string_pretty(&file).map_err(|e| VaultError::Corrupt(e.to_string()))?;
+        write_private(&self.path, content.as_bytes())
+    }
+
+    /// Re-key the vault under `passphrase` (new salt) and save it
+    pub fn change_passphrase(&mut self, passphrase: &str) -> Result<(), VaultError> {
+        if passphrase.is_empty() {
+            return Err(VaultError::EmptyPassphrase);
+        }
+        OsRng.fill_bytes(&mut self.salt);
+        self.key = derive_key(passphrase, &self.salt, self.kdf)?;
+        self.save()
+    }
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], kdf: KdfParams) -> Result<Zeroizing<[u8; KEY_LEN]>, VaultError> {
+    let params = Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(KEY_LEN))
+        .map_err(|e| VaultError::Corrupt(format!("bad KDF parameters: {}", e)))?;
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut())
+        .map_err(|e| VaultError::Corrupt(format!("key derivation failed: {}", e)))?;
+    Ok(key)
+}
+
+fn decode(field: &str) -> Result<Vec<u8>, VaultError> {
+    BASE64.decode(field).map_err(|e| VaultError::Corrupt(e.to_string()))
+}
+
+/// Write through a temporary file and rename, so a crash never leaves a
+/// half-written vault.  The file is readable by the owner only.
+fn write_private(path: &Path, content: &[u8]) -> Result<(), VaultError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("vault.tmp");
+    {
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        use std::io::Write;
+        let mut file = options.open(&tmp)?;
+        file.write_all(content)?;
+        file.sync_all()?;
+    }
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Session vault
+// ---------------------------------------------------------------------------
+
+lazy_static::lazy_static! {
+    static ref SESSION: RwLock<Option<UnlockedVault>> = RwLock::new(None);
+}
+
+/// Where the session vault lives: next to `app_settings.json`
+pub fn vault_path() -> Option<PathBuf> {
+    AppSettings::config_dir().map(|dir| dir.join("credentials.vault"))
+}
+
+/// Whether a vault file exists
+pub fn exists() -> bool {
+    vault_path().is_some_and(|path| path.exists())
+}
+
+pub fn status() -> VaultStatus {
+    VaultStatus { exists: exists(), unlocked: SESSION.read().unwrap().is_some() }
+}
+
+/// Create the vault, moving the credentials out of the settings file into
+/// it.  The vault is left unlocked.
+pub fn create(passphrase: &str) -> Result<(), VaultError> {
+    let path = vault_path().ok_or(VaultError::NoConfigDir)?;
+    let settings = AppSettings::load();
+    let contents = VaultContents {
+        antminer_credentials: settings.antminer_credentials.clone(),
+        whatsminer_credentials: settings.whatsminer_credentials.clone(),
+        credential_sets: settings.credential_sets.clone(),
+    };
+    let vault = UnlockedVault::create(&path, passphrase, contents, KdfParams::default())?;
+    *SESSION.write().unwrap() = Some(vault);
+
+    // Now that the vault exists, saving drops the plaintext copies
+    settings.save().map_err(|e| VaultError::Io(std::io::Error::other(e)))?;
+    println!("[vault] Created {:?}; plaintext credentials removed from the settings file", path);
+    Ok(())
+}
+
+/// Unlock the vault for this session.  Plaintext credentials still found in
+/// the settings file (e.g. written by an older version) are scrubbed.
+pub fn unlock(passphrase: &str) -> Result<(), VaultError> {
+    let path = vault_path().ok_or(VaultError::NoConfigDir)?;
+    let vault = UnlockedVault::open(&path, passphrase)?;
+    *SESSION.write().unwrap() = Some(vault);
+
+    if AppSettings::has_plaintext_credentials() {
+        AppSettings::load().save().map_err(|e| VaultError::Io(std::io::Error::other(e)))?;
+        println!("[vault] Scrubbed plaintext credentials from the settings file");
+    }
+    Ok(())
+}
+
+/// Forget the key and the decrypted credentials
+pub fn lock() {
+    SESSION.write().unwrap().take();
+}
+
+/// Change the master passphrase.  `current` must match even though the vault
+/// is unlocked, so an unattended session can't be used to take it over.
+/// The session stays write-locked from reading the file until the re-keyed
+/// vault replaces it, so concurrent edits are neither lost nor overwritten.
+/// A locked vault is re-keyed on disk and stays locked.
+pub fn change_passphrase(current: &str, new: &str) -> Result<(), VaultError> {
+    let path = vault_path().ok_or(VaultError::NoConfigDir)?;
+    rekey(&mut SESSION.write().unwrap(), &path, current, new)
+}
+
+/// `change_passphrase` for the vault at `path`, with `session` its session
+fn rekey(session: &mut Option<UnlockedVault>, path: &Path, current: &str, new: &str) -> Result<(), VaultError> {
+    let mut vault = UnlockedVault::open(path, current)?;
+    vault.change_passphrase(new)?;
+    if session.is_some() {
+        *session = Some(vault);
+    }
+    Ok(())
+}
+
+/// Credentials to use for `vendor`: from the vault if there is one (it must
+/// be unlocked), otherwise from the settings file.
+pub fn credentials(vendor: MinerVendor) -> Result<MinerCredentials, VaultError> {
+    if let Some(vault) = SESSION.read().unwrap().as_ref() {
+        return Ok(vault.contents.credentials_for(vendor).clone());
+    }
+    if exists() {
+        return Err(VaultError::Locked);
+    }
+    let settings = AppSettings::current();
+    Ok(match vendor {
+        MinerVendor::Antminer => settings.antminer_credentials.clone(),
+        MinerVendor::Whatsminer => settings.whatsminer_credentials.clone(),
+    })
+}
+
+/// Scoped credential sets: from the vault if there is one (it must be
+/// unlocked), otherwise from the settings file.
+pub fn credential_sets() -> Result<Vec<CredentialSet>, VaultError> {
+    if let Some(vault) = SESSION.read().unwrap().as_ref() {
+        return Ok(vault.contents.credential_sets.clone());
+    }
+    if exists() {
+        return Err(VaultError::Locked);
+    }
+    Ok(AppSettings::current().credential_sets.clone())
+}
+
+/// Replace the scoped credential sets, in the vault if there is one
+pub fn store_credential_sets(sets: Vec<CredentialSet>) -> Result<(), VaultError> {
+    if exists() {
+        let mut session = SESSION.write().unwrap();
+        let vault = session.as_mut().ok_or(VaultError::Locked)?;
+        vault.contents.credential_sets = sets;
+        return vault.save();
+    }
+    let mut settings = AppSettings::load();
+    settings.credential_sets = sets;
+    settings.save().map_err(|e| VaultError::Io(std::io::Error::other(e)))
+}
+
+/// Edit the credential sets of the unlocked vault in place and save it.  The
+/// session stays write-locked until the file is written, so concurrent edits
+/// (e.g. a batch password rotation) don't overwrite each other.
+pub fn update_credential_sets<R>(edit: impl FnOnce(&mut Vec<CredentialSet>) -> R) -> Result<R, VaultError> {
+    let mut session = SESSION.write().unwrap();
+    let Some(vault) = session.as_mut() else {
+        return Err(if exists() { VaultError::Locked } else { VaultError::NotFound });
+    };
+    let before = vault.contents.credential_sets.clone();
+    let result = edit(&mut vault.contents.credential_sets);
+    if let Err(e) = vault.save() {
+        vault.contents.credential_sets = before;
+        return Err(e);
+    }
+    Ok(result)
+}
+
+/// Put the vault's credentials into `settings` for display.  With a locked
+/// vault they are blanked rather than showing the defaults.
+pub fn fill_credentials(settings: &mut AppSettings) {
+    if let Some(vault) = SESSION.read().unwrap().as_ref() {
+        settings.antminer_credentials = vault.contents.antminer_credentials.clone();
+        settings.whatsminer_credentials = vault.contents.whatsminer_credentials.clone();
+        settings.credential_sets = vault.contents.credential_sets.clone();
+    } else if exists() {
+        settings.antminer_credentials = MinerCredentials::new(String::new(), String::new());
+        settings.whatsminer_credentials = MinerCredentials::new(String::new(), String::new());
+        settings.credential_sets = Vec::new();
+    }
+}
+
+/// Store the credentials of `settings` in the vault, if there is one.
+/// A locked vault is left alone: the settings carry blanked credentials then.
+pub fn store_credentials(settings: &AppSettings) -> Result<(), VaultError> {
+    let mut session = SESSION.write().unwrap();
+    let Some(vault) = session.as_mut() else {
+        return Ok(());
+    };
+    vault.contents.antminer_credentials = settings.antminer_credentials.clone();
+    vault.contents.whatsminer_credentials = settings.whatsminer_credentials.clone();
+    vault.contents.credential_sets = settings.credential_sets.clone();
+    vault.save()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cheap parameters so the tests don't spend seconds in Argon2
+    const TEST_KDF: KdfParams = KdfParams { m_cost: 64, t_cost: 1, p_cost: 1 };
+
+    fn temp_vault(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("vault-test-{}-{}.vault", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn contents() -> VaultContents {
+        VaultContents {
+            antminer_credentials: MinerCredentials::new("root".into(), "s3cret-A".into()),
+            whatsminer_credentials: MinerCredentials::new("admin".into(), "s3cret-W".into()),
+            credential_sets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path = temp_vault("round-trip");
+        UnlockedVault::create(&path, "correct horse", contents(), TEST_KDF).unwrap();
+
+        let raw = fs::read_to_string(&path).unwrap();
+        assert!(!raw.contains("s3cret"), "plaintext leaked into {}", raw);
+
+        let vault = UnlockedVault::open(&path, "correct horse").unwrap();
+        assert_eq!(vault.contents.credentials_for(MinerVendor::Whatsminer).password, "s3cret-W");
+        assert!(matches!(UnlockedVault::open(&path, "wrong"), Err(VaultError::WrongPassphrase)));
+        assert!(matches!(
+            UnlockedVault::create(&path, "again", contents(), TEST_KDF),
+            Err(VaultError::AlreadyExists)
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_save_and_rekey() {
+        let path = temp_vault("rekey");
+        let mut vault = UnlockedVault::create(&path, "first", contents(), TEST_KDF).unwrap();
+        vault.contents.antminer_credentials.password = "rotated".into();
+        vault.save().unwrap();
+        vault.change_passphrase("second").unwrap();
+
+        assert!(matches!(UnlockedVault::open(&path, "first"), Err(VaultError::WrongPassphrase)));
+        let vault = UnlockedVault::open(&path, "second").unwrap();
+        assert_eq!(vault.contents.antminer_credentials.password, "rotated");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_rekey_keeps_session_state() {
+        let path = temp_vault("rekey-session");
+        UnlockedVault::create(&path, "first", contents(), TEST_KDF).unwrap();
+
+        let mut session = None;
+        rekey(&mut session, &path, "first", "second").unwrap();
+        assert!(session.is_none(), "re-keying a locked vault unlocked it");
+
+        let mut session = Some(UnlockedVault::open(&path, "second").unwrap());
+        assert!(matches!(rekey(&mut session, &path, "first", "third"), Err(VaultError::WrongPassphrase)));
+        rekey(&mut session, &path, "second", "third").unwrap();
+        // The session holds the re-keyed vault, so its next save keeps the new passphrase
+        session.as_ref().unwrap().save().unwrap();
+        assert!(UnlockedVault::open(&path, "third").is_ok());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_tampered_header_rejected() {
+        let path = temp_vault("tamper");
+        UnlockedVault::create(&path, "pass", contents(), TEST_KDF).unwrap();
+
+        // Lowering the cost parameters must not yield a usable vault
+        let raw = fs::read_to_string(&path).unwrap();
+        let mut file: serde_json::Value = serde_json::from_str(&raw).unwrap();
+        file["kdf"]["t_cost"] = 2.into();
+        fs::write(&path, file.to_string()).unwrap();
+        assert!(matches!(UnlockedVault::open(&path, "pass"), Err(VaultError::WrongPassphrase)));
+
+        fs::write(&path, "not a vault").unwrap();
+        assert!(matches!(UnlockedVault::open(&path, "pass"), Err(VaultError::Corrupt(_))));
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(UnlockedVault::open(&path, "pass"), Err(VaultError::NotFound)));
+    }
+
+    #[test]
+    fn test_settings_file_drops_credentials() {
+        let settings = AppSettings::default();
+        let plain = settings.to_disk_json(false);
+        assert_eq!(plain["whatsminer_credentials"]["username"], "admin");
+
+        let stripped = settings.to_disk_json(true);
+        assert!(stripped.get("antminer_credentials").is_none());
+        assert!(stripped.get("whatsminer_credentials").is_none());
+
+        // A stripped file still loads, with the default credentials
+        let loaded: AppSettings = serde_json::from_value(stripped).unwrap();
+        assert_eq!(loaded.antminer_credentials.username, "root");
+        assert_eq!(loaded.scan_thread_count, settings.scan_thread_count);
+    }
+}
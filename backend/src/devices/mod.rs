@@ -0,0 +1,197 @@
+//! Non-miner devices on the miner subnets: PDUs, switches, cameras.
+//!
+//! Containers put managed PDUs and switches next to the miners, and a dead
+//! miner is often just a tripped outlet.  Devices are identified with an
+//! SNMP v2c `sysDescr`/`sysName` GET and the banner of their web interface;
+//! the MAC OUI, when known, settles the kind before any text matching.
+
+use crate::core::{Device, DeviceKind, Result};
+use crate::identity::oui::{DeviceClass, OuiVendor};
+use std::time::{Duration, SystemTime};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+pub mod snmp;
+
+/// Largest part of an HTTP response read for the banner
+const MAX_BANNER_BYTES: usize = 8 * 1024;
+
+/// How non-miner devices are probed
+#[derive(Debug, Clone)]
+pub struct DeviceProbe {
+    pub community: String,
+    pub snmp_port: u16,
+    pub http_port: u16,
+    pub timeout_ms: u64,
+}
+
+impl Default for DeviceProbe {
+    fn default() -> Self {
+        Self {
+            community: "public".to_string(),
+            snmp_port: snmp::SNMP_PORT,
+            http_port: 80,
+            timeout_ms: 1000,
+        }
+    }
+}
+
+/// `sysDescr.0` and `sysName.0` of the SNMP agent at `ip`
+pub async fn snmp_system(ip: &str, probe: &DeviceProbe) -> Result<(Option<String>, Option<String>)> {
+    let values = snmp::get(ip, probe.snmp_port, &probe.community, &[snmp::SYS_DESCR, snmp::SYS_NAME], probe.timeout_ms).await?;
+    let text = |oid: &str| {
+        values
+            .iter()
+            .find(|(name, _)| name == oid)
+            .and_then(|(_, value)| value.as_text())
+            .filter(|text| !text.is_empty())
+    };
+    Ok((text(snmp::SYS_DESCR), text(snmp::SYS_NAME)))
+}
+
+/// `Server` header and `<title>` of the web interface at `ip:port`, e.g.
+/// `"lighttpd (Rack PDU)"`.  `None` if nothing answers HTTP there.
+pub async fn http_banner(ip: &str, port: u16, timeout_ms: u64) -> Option<String> {
+    let exchange = async {
+        let mut stream = crate::client::source::connect(ip, port).await.ok()?;
+        let request = format!("GET / HTTP/1.0\r\nHost: {}\r\nConnection: close\r\n\r\n", ip);
+        stream.write_all(request.as_bytes()).await.ok()?;
+
+        let mut response = Vec::new();
+        let mut chunk = [0u8; 2048];
+        while response.len() < MAX_BANNER_BYTES {
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => break,
+                Ok(n) => response.extend_from_slice(&chunk[..n]),
+            }
+        }
+        parse_banner(&String::from_utf8_lossy(&response))
+    };
+    tokio::time::timeout(Duration::from_millis(timeout_ms), exchange).await.ok().flatten()
+}
+
+/// Banner from a raw HTTP response
+pub fn parse_banner(response: &str) -> Option<String> {
+    if !response.starts_with("HTTP/") {
+        return None;
+    }
+    let (head, body) = response.split_once("\r\n\r\n").unwrap_or((response, ""));
+
+    let server = head
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.trim().eq_ignore_ascii_case("server").then(|| value.trim().to_string())
+        })
+        .filter(|server| !server.is_empty());
+
+    // ASCII-only, so offsets into `lower` are valid in `body`
+    let lower = body.to_ascii_lowercase();
+    let title = lower
+        .find("<title")
+        .and_then(|start| Some(start + lower[start..].find('>')? + 1))
+        .and_then(|start| Some(body[start..start + lower[start..].find("</title")?].trim().to_string()))
+        .filter(|title| !title.is_empty());
+
+    match (server, title) {
+        (Some(server), Some(title)) => Some(format!("{} ({})", server, title)),
+        (server, title) => server.or(title),
+    }
+}
+
+/// Decide what kind of device this is: the OUI vendor class if known, then
+/// keywords in the SNMP description and web banner.
+pub fn classify(vendor: Option<&OuiVendor>, sys_descr: Option<&str>, http_banner: Option<&str>) -> DeviceKind {
+    match vendor.map(|vendor| vendor.class) {
+        Some(DeviceClass::Power) => return DeviceKind::Pdu,
+        Some(DeviceClass::Network) => return DeviceKind::Network,
+        Some(DeviceClass::Camera) => return DeviceKind::Camera,
+        Some(DeviceClass::Computer) => return DeviceKind::Computer,
+        Some(DeviceClass::Miner) | None => {}
+    }
+
+    const PDU: &[&str] = &["pdu", "powernet", "apc web/snmp", "raritan", "dominion px", "servertech", "sentry", "power distribution"];
+    const NETWORK: &[&str] = &["switch", "router", "routeros", "cisco ios", "edgeos", "unifi", "procurve", "junos", "access point"];
+    const CAMERA: &[&str] = &["camera", "ipcam", "hikvision", "dahua", "nvr", "dvr"];
+    const COMPUTER: &[&str] = &["linux", "windows", "raspberry", "freebsd"];
+
+    let text = format!("{} {}", sys_descr.unwrap_or_default(), http_banner.unwrap_or_default()).to_lowercase();
+    let mentions = |words: &[&str]| words.iter().any(|word| text.contains(word));
+    if mentions(PDU) {
+        DeviceKind::Pdu
+    } else if mentions(NETWORK) {
+        DeviceKind::Network
+    } else if mentions(CAMERA) {
+        DeviceKind::Camera
+    } else if mentions(COMPUTER) {
+        DeviceKind::Computer
+    } else {
+        DeviceKind::Unknown
+    }
+}
+
+/// Identify the device at `ip`.  `sys` is an SNMP answer already in hand
+/// (e.g. from a scan's probe); otherwise SNMP is asked here.
+pub async fn describe(
+    ip: &str,
+    open_port: Option<u16>,
+    mac_address: Option<String>,
+    sys: Option<(Option<String>, Option<String>)>,
+    probe: &DeviceProbe,
+) -> Device {
+    let snmp_query = async {
+        match sys {
+            Some(sys) => sys,
+            None => snmp_system(ip, probe).await.unwrap_or_default(),
+        }
+    };
+    let ((sys_descr, sys_name), http_banner) = tokio::join!(snmp_query, http_banner(ip, probe.http_port, probe.timeout_ms));
+
+    let vendor = mac_address.as_deref().and_then(crate::identity::oui::lookup);
+    Device {
+        ip: ip.to_string(),
+        kind: classify(vendor.as_ref(), sys_descr.as_deref(), http_banner.as_deref()),
+        mac_address,
+        vendor_hint: vendor.map(|vendor| vendor.name.to_string()),
+        sys_name,
+        sys_descr,
+        http_banner,
+        open_port,
+        last_updated: SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_banner() {
+        let response = "HTTP/1.1 200 OK\r\nServer: lighttpd/1.4.35\r\nContent-Type: text/html\r\n\r\n<html><head><TITLE> Rack PDU </TITLE></head></html>";
+        assert_eq!(parse_banner(response).as_deref(), Some("lighttpd/1.4.35 (Rack PDU)"));
+
+        let response = "HTTP/1.0 401 Unauthorized\r\nWWW-Authenticate: Basic\r\n\r\n";
+        assert_eq!(parse_banner(response), None);
+
+        assert_eq!(parse_banner("{\"STATUS\":\"E\"}"), None);
+
+        // Characters whose lowercase form has a different byte length
+        let response = "HTTP/1.1 200 OK\r\n\r\n<p>İİİ</p><title>KVM İ</title>";
+        assert_eq!(parse_banner(response).as_deref(), Some("KVM İ"));
+    }
+
+    #[test]
+    fn test_classify() {
+        let apc = "APC Web/SNMP Management Card (MB:v4.1.0 PF:v6.4.6 PN:apc_hw05_aos_646.bin)";
+        assert_eq!(classify(None, Some(apc), None), DeviceKind::Pdu);
+        assert_eq!(classify(None, Some("RouterOS CRS326-24G-2S+"), None), DeviceKind::Network);
+        assert_eq!(classify(None, None, Some("Boa/0.94 (Hikvision)")), DeviceKind::Camera);
+        assert_eq!(classify(None, None, None), DeviceKind::Unknown);
+
+        // The OUI wins over a generic Linux description
+        let vendor = crate::identity::oui::lookup("00:0D:5D:00:00:01").unwrap();
+        assert_eq!(classify(Some(&vendor), Some("Linux 4.9"), None), DeviceKind::Pdu);
+    }
+}
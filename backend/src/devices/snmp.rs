@@ -0,0 +1,396 @@
+//! Minimal SNMP v2c: BER encoding of GET requests and responses, and a
+//! UDP `get` that is enough to read `sysDescr`/`sysName` from PDUs and
+//! switches.  No walks, no SNMP v3.
+
+use crate::core::{MinerError, Result};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+pub const SNMP_PORT: u16 = 161;
+
+/// SNMPv2-MIB::sysDescr.0
+pub const SYS_DESCR: &str = "1.3.6.1.2.1.1.1.0";
+/// SNMPv2-MIB::sysObjectID.0
+pub const SYS_OBJECT_ID: &str = "1.3.6.1.2.1.1.2.0";
+/// SNMPv2-MIB::sysName.0
+pub const SYS_NAME: &str = "1.3.6.1.2.1.1.5.0";
+
+const VERSION_2C: i64 = 1;
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_NULL: u8 = 0x05;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_IP_ADDRESS: u8 = 0x40;
+const TAG_COUNTER32: u8 = 0x41;
+const TAG_GAUGE32: u8 = 0x42;
+const TAG_TIMETICKS: u8 = 0x43;
+const TAG_NO_SUCH_OBJECT: u8 = 0x80;
+const TAG_NO_SUCH_INSTANCE: u8 = 0x81;
+const TAG_END_OF_MIB_VIEW: u8 = 0x82;
+
+/// PDU types we speak
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PduType {
+    GetRequest = 0xA0,
+    GetResponse = 0xA2,
+}
+
+/// A variable binding value
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnmpValue {
+    Integer(i64),
+    OctetString(Vec<u8>),
+    Null,
+    Oid(String),
+    IpAddress([u8; 4]),
+    /// Counter32, Gauge32 and TimeTicks
+    Unsigned(u32),
+    /// noSuchObject / noSuchInstance / endOfMibView
+    Missing,
+}
+
+impl SnmpValue {
+    /// Text of an OCTET STRING, lossily decoded
+    pub fn as_text(&self) -> Option<String> {
+        match self {
+            SnmpValue::OctetString(bytes) => Some(String::from_utf8_lossy(bytes).trim().to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// A whole SNMP v2c message
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub community: String,
+    pub pdu_type: PduType,
+    pub request_id: i32,
+    pub error_status: i64,
+    pub error_index: i64,
+    pub varbinds: Vec<(String, SnmpValue)>,
+}
+
+impl Message {
+    /// GET request for `oids`
+    pub fn get_request(community: &str, request_id: i32, oids: &[&str]) -> Self {
+        Self {
+            community: community.to_string(),
+            pdu_type: PduType::GetRequest,
+            request_id,
+            error_status: 0,
+            error_index: 0,
+            varbinds: oids.iter().map(|oid| (oid.to_string(), SnmpValue::Null)).collect(),
+        }
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut varbinds = Vec::new();
+        for (oid, value) in &self.varbinds {
+            let mut varbind = encode_oid(oid)?;
+            varbind.extend(encode_value(value)?);
+            varbinds.extend(tlv(TAG_SEQUENCE, &varbind));
+        }
+
+        let mut pdu = encode_integer(self.request_id as i64);
+        pdu.extend(encode_integer(self.error_status));
+        pdu.extend(encode_integer(self.error_index));
+        pdu.extend(tlv(TAG_SEQUENCE, &varbinds));
+
+        let mut message = encode_integer(VERSION_2C);
+        message.extend(tlv(TAG_OCTET_STRING, self.community.as_bytes()));
+        message.extend(tlv(self.pdu_type as u8, &pdu));
+        Ok(tlv(TAG_SEQUENCE, &message))
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut outer = Reader::new(bytes);
+        let mut message = Reader::new(outer.expect(TAG_SEQUENCE)?);
+
+        let version = message.integer()?;
+        if version != VERSION_2C {
+            return Err(MinerError::MalformedResponse(format!("Unsupported SNMP version {}", version)));
+        }
+        let community = String::from_utf8_lossy(message.expect(TAG_OCTET_STRING)?).into_owned();
+
+        let (tag, body) = message.next()?;
+        let pdu_type = match tag {
+            0xA0 => PduType::GetRequest,
+            0xA2 => PduType::GetResponse,
+            other => return Err(MinerError::MalformedResponse(format!("Unsupported SNMP PDU type 0x{:02X}", other))),
+        };
+
+        let mut pdu = Reader::new(body);
+        let request_id = pdu.integer()? as i32;
+        let error_status = pdu.integer()?;
+        let error_index = pdu.integer()?;
+
+        let mut list = Reader::new(pdu.expect(TAG_SEQUENCE)?);
+        let mut varbinds = Vec::new();
+        while !list.is_empty() {
+            let mut varbind = Reader::new(list.expect(TAG_SEQUENCE)?);
+            let oid = decode_oid(varbind.expect(TAG_OID)?)?;
+            let (tag, body) = varbind.next()?;
+            varbinds.push((oid, decode_value(tag, body)?));
+        }
+
+        Ok(Self { community, pdu_type, request_id, error_status, error_index, varbinds })
+    }
+}
+
+static NEXT_REQUEST_ID: AtomicI32 = AtomicI32::new(1);
+
+/// GET `oids` from the agent at `ip:port`.  Values come back in request
+/// order; objects the agent doesn't have are `SnmpValue::Missing`.
+pub async fn get(ip: &str, port: u16, community: &str, oids: &[&str], timeout_ms: u64) -> Result<Vec<(String, SnmpValue)>> {
+    let target = SocketAddr::new(
+        ip.parse::<IpAddr>().map_err(|e| format!("Invalid address {}: {}", ip, e))?,
+        port,
+    );
+    let local = match crate::client::source::source_for(&target.ip()) {
+        Some(source) => SocketAddr::new(source, 0),
+        None if target.is_ipv4() => "0.0.0.0:0".parse().unwrap(),
+        None => "[::]:0".parse().unwrap(),
+    };
+
+    let socket = UdpSocket::bind(local).await?;
+    socket.connect(target).await?;
+
+    let request_id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed) & 0x7FFF_FFFF;
+    socket.send(&Message::get_request(community, request_id, oids).encode()?).await?;
+
+    let exchange = async {
+        let mut buffer = vec![0u8; 65_535];
+        loop {
+            let n = socket.recv(&mut buffer).await?;
+            // Ignore stray datagrams (late answers to an earlier request)
+            match Message::decode(&buffer[..n]) {
+                Ok(response) if response.pdu_type == PduType::GetResponse && response.request_id == request_id => {
+                    return Ok::<_, MinerError>(response);
+                }
+                _ => continue,
+            }
+        }
+    };
+    let response = tokio::time::timeout(Duration::from_millis(timeout_ms), exchange)
+        .await
+        .map_err(|_| MinerError::Timeout(format!("{} SNMP", ip)))??;
+
+    if response.error_status != 0 {
+        let message = format!("SNMP error status {} (index {})", response.error_status, response.error_index);
+        return Err(MinerError::Rejected { code: format!("SNMP {}", response.error_status), message });
+    }
+    Ok(response.varbinds)
+}
+
+// ---------------------------------------------------------------------------
+// BER
+// ---------------------------------------------------------------------------
+
+fn tlv(tag: u8, body: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    let len = body.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes: Vec<u8> = len.to_be_bytes().iter().copied().skip_while(|&b| b == 0).collect();
+        out.push(0x80 | bytes.len() as u8);
+        out.extend(bytes);
+    }
+    out.extend_from_slice(body);
+    out
+}
+
+fn encode_integer(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    // Drop redundant leading bytes, keeping the sign bit intact
+    let mut start = 0;
+    while start < 7
+        && ((bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0) || (bytes[start] == 0xFF && bytes[start + 1] & 0x80 != 0))
+    {
+        start += 1;
+    }
+    tlv(TAG_INTEGER, &bytes[start..])
+}
+
+fn encode_unsigned(tag: u8, value: u32) -> Vec<u8> {
+    let mut body = encode_integer(value as i64);
+    body.drain(..2);
+    tlv(tag, &body)
+}
+
+fn encode_oid(oid: &str) -> Result<Vec<u8>> {
+    let arcs: Vec<u32> = oid
+        .trim_start_matches('.')
+        .split('.')
+        .map(|arc| arc.parse::<u32>())
+        .collect::<std::result::Result<_, _>>()
+        .map_err(|_| format!("Invalid OID '{}'", oid))?;
+    if arcs.len() < 2 || arcs[0] > 2 {
+        return Err(format!("Invalid OID '{}'", oid).into());
+    }
+
+    let mut body = Vec::new();
+    for arc in std::iter::once(arcs[0] * 40 + arcs[1]).chain(arcs[2..].iter().copied()) {
+        let mut chunk = vec![(arc & 0x7F) as u8];
+        let mut rest = arc >> 7;
+        while rest > 0 {
+            chunk.push(0x80 | (rest & 0x7F) as u8);
+            rest >>= 7;
+        }
+        body.extend(chunk.iter().rev());
+    }
+    Ok(tlv(TAG_OID, &body))
+}
+
+fn encode_value(value: &SnmpValue) -> Result<Vec<u8>> {
+    Ok(match value {
+        SnmpValue::Integer(v) => encode_integer(*v),
+        SnmpValue::OctetString(bytes) => tlv(TAG_OCTET_STRING, bytes),
+        SnmpValue::Null => tlv(TAG_NULL, &[]),
+        SnmpValue::Oid(oid) => encode_oid(oid)?,
+        SnmpValue::IpAddress(octets) => tlv(TAG_IP_ADDRESS, octets),
+        SnmpValue::Unsigned(v) => encode_unsigned(TAG_GAUGE32, *v),
+        SnmpValue::Missing => tlv(TAG_NO_SUCH_OBJECT, &[]),
+    })
+}
+
+fn decode_oid(body: &[u8]) -> Result<String> {
+    let mut arcs = Vec::new();
+    let mut value: u32 = 0;
+    for &byte in body {
+        value = value.checked_mul(128).ok_or("OID arc overflow")? | (byte & 0x7F) as u32;
+        if byte & 0x80 == 0 {
+            if arcs.is_empty() {
+                let first = (value / 40).min(2);
+                arcs.push(first);
+                arcs.push(value - first * 40);
+            } else {
+                arcs.push(value);
+            }
+            value = 0;
+        }
+    }
+    if arcs.is_empty() {
+        return Err(MinerError::MalformedResponse("Empty OID".to_string()));
+    }
+    Ok(arcs.iter().map(u32::to_string).collect::<Vec<_>>().join("."))
+}
+
+fn decode_integer(body: &[u8]) -> Result<i64> {
+    if body.is_empty() || body.len() > 8 {
+        return Err(MinerError::MalformedResponse("Invalid INTEGER length".to_string()));
+    }
+    let negative = body[0] & 0x80 != 0;
+    Ok(body.iter().fold(if negative { -1i64 } else { 0 }, |acc, &b| (acc << 8) | b as i64))
+}
+
+fn decode_value(tag: u8, body: &[u8]) -> Result<SnmpValue> {
+    Ok(match tag {
+        TAG_INTEGER => SnmpValue::Integer(decode_integer(body)?),
+        TAG_OCTET_STRING => SnmpValue::OctetString(body.to_vec()),
+        TAG_NULL => SnmpValue::Null,
+        TAG_OID => SnmpValue::Oid(decode_oid(body)?),
+        TAG_IP_ADDRESS if body.len() == 4 => SnmpValue::IpAddress([body[0], body[1], body[2], body[3]]),
+        TAG_COUNTER32 | TAG_GAUGE32 | TAG_TIMETICKS => SnmpValue::Unsigned(decode_integer(body)? as u32),
+        TAG_NO_SUCH_OBJECT | TAG_NO_SUCH_INSTANCE | TAG_END_OF_MIB_VIEW => SnmpValue::Missing,
+        other => return Err(MinerError::MalformedResponse(format!("Unsupported SNMP value type 0x{:02X}", other))),
+    })
+}
+
+/// Cursor over consecutive TLVs
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn next(&mut self) -> Result<(u8, &'a [u8])> {
+        let truncated = || MinerError::MalformedResponse("Truncated SNMP message".to_string());
+        let (&tag, rest) = self.bytes.split_first().ok_or_else(truncated)?;
+        let (&first, mut rest) = rest.split_first().ok_or_else(truncated)?;
+
+        let len = if first & 0x80 == 0 {
+            first as usize
+        } else {
+            let count = (first & 0x7F) as usize;
+            if count == 0 || count > 4 || rest.len() < count {
+                return Err(truncated());
+            }
+            let len = rest[..count].iter().fold(0usize, |acc, &b| (acc << 8) | b as usize);
+            rest = &rest[count..];
+            len
+        };
+
+        if rest.len() < len {
+            return Err(truncated());
+        }
+        let (body, rest) = rest.split_at(len);
+        self.bytes = rest;
+        Ok((tag, body))
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<&'a [u8]> {
+        let (tag, body) = self.next()?;
+        if tag != expected {
+            return Err(MinerError::MalformedResponse(format!("Expected SNMP tag 0x{:02X}, got 0x{:02X}", expected, tag)));
+        }
+        Ok(body)
+    }
+
+    fn integer(&mut self) -> Result<i64> {
+        decode_integer(self.expect(TAG_INTEGER)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_get_request() {
+        // snmpget -v2c -c public <host> 1.3.6.1.2.1.1.5.0, request id 1
+        let encoded = Message::get_request("public", 1, &[SYS_NAME]).encode().unwrap();
+        let expected = [
+            0x30, 0x26, 0x02, 0x01, 0x01, 0x04, 0x06, b'p', b'u', b'b', b'l', b'i', b'c',
+            0xA0, 0x19, 0x02, 0x01, 0x01, 0x02, 0x01, 0x00, 0x02, 0x01, 0x00,
+            0x30, 0x0E, 0x30, 0x0C, 0x06, 0x08, 0x2B, 0x06, 0x01, 0x02, 0x01, 0x01, 0x05, 0x00, 0x05, 0x00,
+        ];
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let message = Message {
+            community: "private".into(),
+            pdu_type: PduType::GetResponse,
+            request_id: 300_000,
+            error_status: 0,
+            error_index: 0,
+            varbinds: vec![
+                (SYS_DESCR.into(), SnmpValue::OctetString(vec![b'x'; 200])),
+                (SYS_OBJECT_ID.into(), SnmpValue::Oid("1.3.6.1.4.1.318.1.3.4.5".into())),
+                ("1.3.6.1.2.1.1.3.0".into(), SnmpValue::Unsigned(4_000_000_000)),
+                ("1.3.6.1.2.1.1.7.0".into(), SnmpValue::Integer(-72)),
+                ("1.3.6.1.2.1.1.9.0".into(), SnmpValue::Missing),
+            ],
+        };
+        assert_eq!(Message::decode(&message.encode().unwrap()).unwrap(), message);
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage() {
+        assert!(Message::decode(b"HTTP/1.1 400 Bad Request").is_err());
+        assert!(Message::decode(&[0x30, 0x10, 0x02]).is_err());
+    }
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 225659152;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 671313466;
 
 // Section: executor
 
@@ -45,40 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__scanner__detect_local_ranges_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "detect_local_ranges",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::scanner::detect_local_ranges())?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__commands__execute_batch_command_impl(
+fn wire__crate__api__backup__backup_miners_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -86,7 +53,7 @@ fn wire__crate__api__commands__execute_batch_command_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "execute_batch_command",
+            debug_name: "backup_miners",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -101,20 +68,13 @@ fn wire__crate__api__commands__execute_batch_command_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_target_ips = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_command = <crate::api::models::MinerCommand>::sse_decode(&mut deserializer);
-            let api_credentials =
-                <Option<crate::core::config::MinerCredentials>>::sse_decode(&mut deserializer);
+            let api_note = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::commands::execute_batch_command(
-                                api_target_ips,
-                                api_command,
-                                api_credentials,
-                            )
-                            .await,
+                            crate::api::backup::backup_miners(api_target_ips, api_note).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -124,16 +84,17 @@ fn wire__crate__api__commands__execute_batch_command_impl(
         },
     )
 }
-fn wire__crate__api__settings__get_app_settings_impl(
+fn wire__crate__api__vault__change_vault_passphrase_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_app_settings",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "change_vault_passphrase",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -145,23 +106,30 @@ fn wire__crate__api__settings__get_app_settings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_current = <String>::sse_decode(&mut deserializer);
+            let api_new_passphrase = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::settings::get_app_settings())?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::vault::change_vault_passphrase(
+                        api_current,
+                        api_new_passphrase,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__monitor__get_current_miners_impl(
+fn wire__crate__api__vault__create_vault_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_current_miners",
+            debug_name: "create_vault",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -175,29 +143,26 @@ fn wire__crate__api__monitor__get_current_miners_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::monitor::get_current_miners().await)?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::vault::create_vault(api_passphrase)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__commands__get_miner_pools_impl(
+fn wire__crate__api__backup__delete_config_snapshot_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_miner_pools",
+            debug_name: "delete_config_snapshot",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -211,32 +176,28 @@ fn wire__crate__api__commands__get_miner_pools_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ip = <String>::sse_decode(&mut deserializer);
+            let api_snapshot_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::commands::get_miner_pools(api_ip).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::backup::delete_config_snapshot(api_snapshot_id)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__scanner__detect_local_ranges_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "detect_local_ranges",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -248,16 +209,18 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::scanner::detect_local_ranges())?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__backup__diff_config_snapshots_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -265,7 +228,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "diff_config_snapshots",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -279,28 +242,30 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_before_id = <String>::sse_decode(&mut deserializer);
+            let api_after_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::backup::diff_config_snapshots(api_before_id, api_after_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__settings__save_app_settings_impl(
+fn wire__crate__api__backup__diff_config_with_miner_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_app_settings",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "diff_config_with_miner",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -312,16 +277,24 @@ fn wire__crate__api__settings__save_app_settings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_settings = <crate::core::config::AppSettings>::sse_decode(&mut deserializer);
+            let api_snapshot_id = <String>::sse_decode(&mut deserializer);
+            let api_ip = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::settings::save_app_settings(api_settings)?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::backup::diff_config_with_miner(api_snapshot_id, api_ip)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__commands__set_miner_pools_impl(
+fn wire__crate__api__pools__dry_run_pool_template_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -329,7 +302,7 @@ fn wire__crate__api__commands__set_miner_pools_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_miner_pools",
+            debug_name: "dry_run_pool_template",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -343,15 +316,17 @@ fn wire__crate__api__commands__set_miner_pools_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ip = <String>::sse_decode(&mut deserializer);
-            let api_pools = <Vec<crate::api::models::PoolConfig>>::sse_decode(&mut deserializer);
+            let api_target_ips = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_template_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::commands::set_miner_pools(api_ip, api_pools).await,
-                        )?;
+                        let output_ok = crate::api::pools::dry_run_pool_template(
+                            api_target_ips,
+                            api_template_name,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -360,7 +335,7 @@ fn wire__crate__api__commands__set_miner_pools_impl(
         },
     )
 }
-fn wire__crate__api__commands__set_miner_power_mode_impl(
+fn wire__crate__api__pools__dry_run_set_pools_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -368,7 +343,7 @@ fn wire__crate__api__commands__set_miner_power_mode_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_miner_power_mode",
+            debug_name: "dry_run_set_pools",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -382,14 +357,14 @@ fn wire__crate__api__commands__set_miner_power_mode_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ip = <String>::sse_decode(&mut deserializer);
-            let api_mode = <crate::api::models::PowerMode>::sse_decode(&mut deserializer);
+            let api_target_ips = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_pools = <Vec<crate::api::models::PoolConfig>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::commands::set_miner_power_mode(api_ip, api_mode).await,
+                            crate::api::pools::dry_run_set_pools(api_target_ips, api_pools).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -399,7 +374,7 @@ fn wire__crate__api__commands__set_miner_power_mode_impl(
         },
     )
 }
-fn wire__crate__api__monitor__start_monitoring_impl(
+fn wire__crate__api__commands__execute_batch_command_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -407,7 +382,7 @@ fn wire__crate__api__monitor__start_monitoring_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_monitoring",
+            debug_name: "execute_batch_command",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -421,12 +396,25 @@ fn wire__crate__api__monitor__start_monitoring_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_miners = <Vec<crate::core::models::Miner>>::sse_decode(&mut deserializer);
+            let api_target_ips = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_command = <crate::api::models::MinerCommand>::sse_decode(&mut deserializer);
+            let api_credentials =
+                <Option<crate::core::config::MinerCredentials>>::sse_decode(&mut deserializer);
+            let api_retry_policy =
+                <Option<crate::client::retry::RetryPolicy>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = crate::api::monitor::start_monitoring(api_miners).await?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::commands::execute_batch_command(
+                                api_target_ips,
+                                api_command,
+                                api_credentials,
+                                api_retry_policy,
+                            )
+                            .await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -435,17 +423,16 @@ fn wire__crate__api__monitor__start_monitoring_impl(
         },
     )
 }
-fn wire__crate__api__scanner__start_scan_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__settings__get_app_settings_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_scan",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_app_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -457,21 +444,15 @@ fn wire__crate__api__scanner__start_scan_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ip_range = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::scanner::start_scan(api_ip_range).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::settings::get_app_settings())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__monitor__stop_monitoring_impl(
+fn wire__crate__api__monitor__get_current_miners_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -479,7 +460,7 @@ fn wire__crate__api__monitor__stop_monitoring_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_monitoring",
+            debug_name: "get_current_miners",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -497,9 +478,8 @@ fn wire__crate__api__monitor__stop_monitoring_impl(
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::monitor::stop_monitoring().await;
-                        })?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::monitor::get_current_miners().await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -508,15 +488,15 @@ fn wire__crate__api__monitor__stop_monitoring_impl(
         },
     )
 }
-fn wire__crate__api__commands__test_connection_impl(
+fn wire__crate__api__monitor__get_drift_reports_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_connection",
+            debug_name: "get_drift_reports",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -530,27 +510,29 @@ fn wire__crate__api__commands__test_connection_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ip = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::commands::test_connection(api_ip))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::monitor::get_drift_reports().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__scanner__validate_ip_range_impl(
+fn wire__crate__api__commands__get_miner_pools_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "validate_ip_range",
+            debug_name: "get_miner_pools",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -564,524 +546,4652 @@ fn wire__crate__api__scanner__validate_ip_range_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_range = <String>::sse_decode(&mut deserializer);
+            let api_ip = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::commands::get_miner_pools(api_ip).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__credentials__get_miner_tags_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_miner_tags",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_miner_id = <crate::core::models::MinerId>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::credentials::get_miner_tags(api_miner_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__vault__get_vault_status_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_vault_status",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::vault::get_vault_status())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__devices__link_miner_outlet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "link_miner_outlet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_miner_id = <crate::core::models::MinerId>::sse_decode(&mut deserializer);
+            let api_pdu_ip = <String>::sse_decode(&mut deserializer);
+            let api_outlet = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::devices::link_miner_outlet(api_miner_id, api_pdu_ip, api_outlet)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__credentials__list_auth_failures_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_auth_failures",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::credentials::list_auth_failures())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__backup__list_config_snapshots_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_config_snapshots",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_miner_id =
+                <Option<crate::core::models::MinerId>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::scanner::validate_ip_range(api_range)?;
+                    let output_ok = crate::api::backup::list_config_snapshots(api_miner_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
+fn wire__crate__api__credentials__list_credential_sets_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_credential_sets",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::credentials::list_credential_sets()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for String {
+fn wire__crate__api__policy__list_desired_states_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_desired_states",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::policy::list_desired_states())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__scanner__list_local_interfaces_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_local_interfaces",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::scanner::list_local_interfaces())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__devices__list_miner_locations_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_miner_locations",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::devices::list_miner_locations())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__devices__list_outlet_links_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_outlet_links",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::devices::list_outlet_links())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__pools__list_pool_templates_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_pool_templates",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::pools::list_pool_templates())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__vault__lock_vault_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lock_vault",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::vault::lock_vault();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__pools__preview_worker_names_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_worker_names",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target_ips = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_pools = <Vec<crate::api::models::PoolConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::pools::preview_worker_names(api_target_ips, api_pools)
+                                .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__devices__probe_device_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "probe_device",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ip = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::devices::probe_device(api_ip).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__policy__reconcile_drift_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reconcile_drift",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target_ips = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::policy::reconcile_drift(api_target_ips).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__backup__restore_config_snapshot_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restore_config_snapshot",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_snapshot_id = <String>::sse_decode(&mut deserializer);
+            let api_target_ips = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::backup::restore_config_snapshot(
+                                api_snapshot_id,
+                                api_target_ips,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__credentials__rotate_web_passwords_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rotate_web_passwords",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target_ips = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_policy =
+                <crate::client::rotation::PasswordPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::credentials::rotate_web_passwords(
+                            api_target_ips,
+                            api_policy,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__save_app_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_app_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings = <crate::core::config::AppSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::settings::save_app_settings(api_settings)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__credentials__save_credential_sets_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_credential_sets",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sets = <Vec<crate::core::config::CredentialSet>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::credentials::save_credential_sets(api_sets)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__policy__save_desired_states_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_desired_states",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_states =
+                <Vec<crate::core::config::DesiredState>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::policy::save_desired_states(api_states)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__pools__save_pool_templates_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_pool_templates",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_templates =
+                <Vec<crate::core::config::PoolTemplate>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::pools::save_pool_templates(api_templates)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__devices__set_miner_location_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_miner_location",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_miner_id = <crate::core::models::MinerId>::sse_decode(&mut deserializer);
+            let api_rack = <String>::sse_decode(&mut deserializer);
+            let api_position = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::devices::set_miner_location(api_miner_id, api_rack, api_position)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__commands__set_miner_pools_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_miner_pools",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ip = <String>::sse_decode(&mut deserializer);
+            let api_pools = <Vec<crate::api::models::PoolConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::commands::set_miner_pools(api_ip, api_pools).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__commands__set_miner_power_mode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_miner_power_mode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ip = <String>::sse_decode(&mut deserializer);
+            let api_mode = <crate::api::models::PowerMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::commands::set_miner_power_mode(api_ip, api_mode).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__credentials__set_miner_tags_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_miner_tags",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_miner_id = <crate::core::models::MinerId>::sse_decode(&mut deserializer);
+            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::credentials::set_miner_tags(api_miner_id, api_tags)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__monitor__start_monitoring_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_monitoring",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_miners = <Vec<crate::core::models::Miner>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::monitor::start_monitoring(api_miners).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__start_scan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_scan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ip_range = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::scanner::start_scan(api_ip_range).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__start_scan_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_scan_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ip_range = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::scanner::ScanEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::scanner::start_scan_stream(api_ip_range, api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__monitor__stop_monitoring_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_monitoring",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::monitor::stop_monitoring().await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__stop_scan_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_scan",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::scanner::stop_scan())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__commands__test_connection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_connection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ip = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::commands::test_connection(api_ip))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__devices__unlink_miner_outlet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlink_miner_outlet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_miner_id = <crate::core::models::MinerId>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::devices::unlink_miner_outlet(api_miner_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__vault__unlock_vault_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock_vault",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::vault::unlock_vault(api_passphrase)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__scanner__validate_ip_range_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_ip_range",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_range = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::scanner::validate_ip_range(api_range)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::scanner::ScanEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::core::config::AppSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_antminerCredentials =
+            <crate::core::config::MinerCredentials>::sse_decode(deserializer);
+        let mut var_whatsminerCredentials =
+            <crate::core::config::MinerCredentials>::sse_decode(deserializer);
+        let mut var_scanThreadCount = <u32>::sse_decode(deserializer);
+        let mut var_monitorInterval = <u64>::sse_decode(deserializer);
+        let mut var_namedRanges = <Vec<crate::core::config::NamedRange>>::sse_decode(deserializer);
+        let mut var_scanTimeoutMs = <u64>::sse_decode(deserializer);
+        let mut var_scanPorts = <Vec<u16>>::sse_decode(deserializer);
+        let mut var_scanDevices = <bool>::sse_decode(deserializer);
+        let mut var_snmpCommunity = <String>::sse_decode(deserializer);
+        let mut var_outletLinks = <Vec<crate::core::config::OutletLink>>::sse_decode(deserializer);
+        let mut var_factoryDefaultIps = <Vec<String>>::sse_decode(deserializer);
+        let mut var_detectIpConflicts = <bool>::sse_decode(deserializer);
+        let mut var_credentialSets =
+            <Vec<crate::core::config::CredentialSet>>::sse_decode(deserializer);
+        let mut var_minerTags = <Vec<crate::core::config::MinerTags>>::sse_decode(deserializer);
+        let mut var_minerLocations =
+            <Vec<crate::core::config::MinerLocation>>::sse_decode(deserializer);
+        let mut var_poolTemplates =
+            <Vec<crate::core::config::PoolTemplate>>::sse_decode(deserializer);
+        let mut var_desiredStates =
+            <Vec<crate::core::config::DesiredState>>::sse_decode(deserializer);
+        return crate::core::config::AppSettings {
+            antminer_credentials: var_antminerCredentials,
+            whatsminer_credentials: var_whatsminerCredentials,
+            scan_thread_count: var_scanThreadCount,
+            monitor_interval: var_monitorInterval,
+            named_ranges: var_namedRanges,
+            scan_timeout_ms: var_scanTimeoutMs,
+            scan_ports: var_scanPorts,
+            scan_devices: var_scanDevices,
+            snmp_community: var_snmpCommunity,
+            outlet_links: var_outletLinks,
+            factory_default_ips: var_factoryDefaultIps,
+            detect_ip_conflicts: var_detectIpConflicts,
+            credential_sets: var_credentialSets,
+            miner_tags: var_minerTags,
+            miner_locations: var_minerLocations,
+            pool_templates: var_poolTemplates,
+            desired_states: var_desiredStates,
+        };
+    }
+}
+
+impl SseDecode for crate::client::credentials::AuthFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_minerId = <Option<crate::core::models::MinerId>>::sse_decode(deserializer);
+        let mut var_tried = <u32>::sse_decode(deserializer);
+        let mut var_failedAt = <u64>::sse_decode(deserializer);
+        return crate::client::credentials::AuthFailure {
+            ip: var_ip,
+            miner_id: var_minerId,
+            tried: var_tried,
+            failed_at: var_failedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::backup::BackupResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_minerId = <Option<crate::core::models::MinerId>>::sse_decode(deserializer);
+        let mut var_snapshot = <Option<crate::backup::SnapshotInfo>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::backup::BackupResult {
+            ip: var_ip,
+            miner_id: var_minerId,
+            snapshot: var_snapshot,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::client::retry::CommandAttempt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_attempt = <u32>::sse_decode(deserializer);
+        let mut var_startedAt = <u64>::sse_decode(deserializer);
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorKind = <Option<crate::core::error::ErrorKind>>::sse_decode(deserializer);
+        return crate::client::retry::CommandAttempt {
+            attempt: var_attempt,
+            started_at: var_startedAt,
+            duration_ms: var_durationMs,
+            error: var_error,
+            error_kind: var_errorKind,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::CommandResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_minerId = <Option<crate::core::models::MinerId>>::sse_decode(deserializer);
+        let mut var_success = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorKind = <Option<crate::core::error::ErrorKind>>::sse_decode(deserializer);
+        let mut var_retryable = <bool>::sse_decode(deserializer);
+        let mut var_suggestedAction = <Option<String>>::sse_decode(deserializer);
+        let mut var_attempts =
+            <Vec<crate::client::retry::CommandAttempt>>::sse_decode(deserializer);
+        let mut var_skipped = <Option<String>>::sse_decode(deserializer);
+        return crate::api::models::CommandResult {
+            ip: var_ip,
+            miner_id: var_minerId,
+            success: var_success,
+            error: var_error,
+            error_kind: var_errorKind,
+            retryable: var_retryable,
+            suggested_action: var_suggestedAction,
+            attempts: var_attempts,
+            skipped: var_skipped,
+        };
+    }
+}
+
+impl SseDecode for crate::backup::ConfigChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_before = <Option<String>>::sse_decode(deserializer);
+        let mut var_after = <Option<String>>::sse_decode(deserializer);
+        return crate::backup::ConfigChange {
+            key: var_key,
+            before: var_before,
+            after: var_after,
+        };
+    }
+}
+
+impl SseDecode for crate::core::config::CredentialScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::core::config::CredentialScope::All;
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::core::config::CredentialScope::Range(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::core::config::CredentialScope::Tag(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <crate::core::models::MinerId>::sse_decode(deserializer);
+                return crate::core::config::CredentialScope::Miner(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::core::config::CredentialSet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_scope = <crate::core::config::CredentialScope>::sse_decode(deserializer);
+        let mut var_vendor = <Option<crate::core::models::MinerVendor>>::sse_decode(deserializer);
+        let mut var_priority = <i32>::sse_decode(deserializer);
+        let mut var_credentials = <crate::core::config::MinerCredentials>::sse_decode(deserializer);
+        return crate::core::config::CredentialSet {
+            name: var_name,
+            scope: var_scope,
+            vendor: var_vendor,
+            priority: var_priority,
+            credentials: var_credentials,
+        };
+    }
+}
+
+impl SseDecode for crate::core::config::DesiredState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_scope = <crate::core::config::CredentialScope>::sse_decode(deserializer);
+        let mut var_pools = <Option<Vec<crate::api::models::PoolConfig>>>::sse_decode(deserializer);
+        let mut var_powerMode = <Option<crate::api::models::PowerMode>>::sse_decode(deserializer);
+        let mut var_fanMode = <Option<crate::core::models::FanMode>>::sse_decode(deserializer);
+        let mut var_freqLevel = <Option<u32>>::sse_decode(deserializer);
+        return crate::core::config::DesiredState {
+            name: var_name,
+            scope: var_scope,
+            pools: var_pools,
+            power_mode: var_powerMode,
+            fan_mode: var_fanMode,
+            freq_level: var_freqLevel,
+        };
+    }
+}
+
+impl SseDecode for crate::core::models::Device {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::core::models::DeviceKind>::sse_decode(deserializer);
+        let mut var_macAddress = <Option<String>>::sse_decode(deserializer);
+        let mut var_vendorHint = <Option<String>>::sse_decode(deserializer);
+        let mut var_sysName = <Option<String>>::sse_decode(deserializer);
+        let mut var_sysDescr = <Option<String>>::sse_decode(deserializer);
+        let mut var_httpBanner = <Option<String>>::sse_decode(deserializer);
+        let mut var_openPort = <Option<u16>>::sse_decode(deserializer);
+        let mut var_lastUpdated = <u64>::sse_decode(deserializer);
+        return crate::core::models::Device {
+            ip: var_ip,
+            kind: var_kind,
+            mac_address: var_macAddress,
+            vendor_hint: var_vendorHint,
+            sys_name: var_sysName,
+            sys_descr: var_sysDescr,
+            http_banner: var_httpBanner,
+            open_port: var_openPort,
+            last_updated: var_lastUpdated,
+        };
+    }
+}
+
+impl SseDecode for crate::core::models::DeviceKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::models::DeviceKind::Pdu,
+            1 => crate::core::models::DeviceKind::Network,
+            2 => crate::core::models::DeviceKind::Camera,
+            3 => crate::core::models::DeviceKind::Computer,
+            4 => crate::core::models::DeviceKind::Unknown,
+            _ => unreachable!("Invalid variant for DeviceKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::monitor::drift::Drift {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <crate::monitor::drift::DriftField>::sse_decode(deserializer);
+        let mut var_expected = <String>::sse_decode(deserializer);
+        let mut var_actual = <String>::sse_decode(deserializer);
+        return crate::monitor::drift::Drift {
+            field: var_field,
+            expected: var_expected,
+            actual: var_actual,
+        };
+    }
+}
+
+impl SseDecode for crate::monitor::drift::DriftField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::monitor::drift::DriftField::Pools,
+            1 => crate::monitor::drift::DriftField::PowerMode,
+            2 => crate::monitor::drift::DriftField::FanMode,
+            3 => crate::monitor::drift::DriftField::FreqLevel,
+            _ => unreachable!("Invalid variant for DriftField: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::monitor::drift::DriftReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_minerId = <Option<crate::core::models::MinerId>>::sse_decode(deserializer);
+        let mut var_policy = <String>::sse_decode(deserializer);
+        let mut var_drifts = <Vec<crate::monitor::drift::Drift>>::sse_decode(deserializer);
+        let mut var_detectedAt = <u64>::sse_decode(deserializer);
+        return crate::monitor::drift::DriftReport {
+            ip: var_ip,
+            miner_id: var_minerId,
+            policy: var_policy,
+            drifts: var_drifts,
+            detected_at: var_detectedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::core::error::ErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::core::error::ErrorKind::AuthFailed;
+            }
+            1 => {
+                return crate::core::error::ErrorKind::Unreachable;
+            }
+            2 => {
+                return crate::core::error::ErrorKind::Timeout;
+            }
+            3 => {
+                return crate::core::error::ErrorKind::Unsupported;
+            }
+            4 => {
+                let mut var_code = <String>::sse_decode(deserializer);
+                return crate::core::error::ErrorKind::RejectedByMiner { code: var_code };
+            }
+            5 => {
+                return crate::core::error::ErrorKind::ParseFailed;
+            }
+            6 => {
+                return crate::core::error::ErrorKind::PartialSuccess;
+            }
+            7 => {
+                return crate::core::error::ErrorKind::Other;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::core::models::FanMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::core::models::FanMode::Auto;
+            }
+            1 => {
+                let mut var_percent = <u32>::sse_decode(deserializer);
+                return crate::core::models::FanMode::Fixed {
+                    percent: var_percent,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::client::credentials::AuthFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::client::credentials::AuthFailure>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::backup::BackupResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::backup::BackupResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::client::retry::CommandAttempt> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::client::retry::CommandAttempt>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::CommandResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::CommandResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::backup::ConfigChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::backup::ConfigChange>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::config::CredentialSet> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::config::CredentialSet>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::config::DesiredState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::config::DesiredState>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::monitor::drift::Drift> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::monitor::drift::Drift>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::monitor::drift::DriftReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::monitor::drift::DriftReport>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::error::ErrorKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::error::ErrorKind>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scanner::LocalInterface> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scanner::LocalInterface>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::models::Miner> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::models::Miner>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::config::MinerLocation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::config::MinerLocation>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::config::MinerTags> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::config::MinerTags>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::config::NamedRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::config::NamedRange>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Option<f64>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Option<f64>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Option<u32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Option<u32>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::config::OutletLink> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::config::OutletLink>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::PoolConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::PoolConfig>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::pools::PoolDiff> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::pools::PoolDiff>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::client::pools::PoolSlotChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::client::pools::PoolSlotChange>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::config::PoolTemplate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::config::PoolTemplate>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u16>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::client::rotation::RotationResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::client::rotation::RotationResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::backup::SnapshotInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::backup::SnapshotInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::pools::WorkerPreview> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::pools::WorkerPreview>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::scanner::LocalInterface {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_range = <String>::sse_decode(deserializer);
+        return crate::api::scanner::LocalInterface {
+            name: var_name,
+            ip: var_ip,
+            range: var_range,
+        };
+    }
+}
+
+impl SseDecode for crate::core::models::Miner {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <Option<crate::core::models::MinerId>>::sse_decode(deserializer);
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_model = <Option<String>>::sse_decode(deserializer);
+        let mut var_status = <crate::core::models::MinerStatus>::sse_decode(deserializer);
+        let mut var_stats = <crate::core::models::MinerStats>::sse_decode(deserializer);
+        let mut var_lastUpdated = <u64>::sse_decode(deserializer);
+        let mut var_vendorHint = <Option<String>>::sse_decode(deserializer);
+        let mut var_outlet = <Option<crate::core::models::PduOutlet>>::sse_decode(deserializer);
+        return crate::core::models::Miner {
+            id: var_id,
+            ip: var_ip,
+            model: var_model,
+            status: var_status,
+            stats: var_stats,
+            last_updated: var_lastUpdated,
+            vendor_hint: var_vendorHint,
+            outlet: var_outlet,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::MinerCommand {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::models::MinerCommand::Reboot;
+            }
+            1 => {
+                return crate::api::models::MinerCommand::BlinkLed;
+            }
+            2 => {
+                return crate::api::models::MinerCommand::StopBlink;
+            }
+            3 => {
+                let mut var_pools = <Vec<crate::api::models::PoolConfig>>::sse_decode(deserializer);
+                return crate::api::models::MinerCommand::SetPools { pools: var_pools };
+            }
+            4 => {
+                let mut var_pools =
+                    <Option<Vec<crate::api::models::PoolConfig>>>::sse_decode(deserializer);
+                let mut var_powerMode =
+                    <Option<crate::api::models::PowerMode>>::sse_decode(deserializer);
+                let mut var_fanMode =
+                    <Option<crate::core::models::FanMode>>::sse_decode(deserializer);
+                let mut var_freqLevel = <Option<u32>>::sse_decode(deserializer);
+                return crate::api::models::MinerCommand::ApplyConfig {
+                    pools: var_pools,
+                    power_mode: var_powerMode,
+                    fan_mode: var_fanMode,
+                    freq_level: var_freqLevel,
+                };
+            }
+            5 => {
+                let mut var_snapshotId = <String>::sse_decode(deserializer);
+                return crate::api::models::MinerCommand::RestoreConfig {
+                    snapshot_id: var_snapshotId,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::core::config::MinerCredentials {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_username = <String>::sse_decode(deserializer);
+        let mut var_password = <String>::sse_decode(deserializer);
+        return crate::core::config::MinerCredentials {
+            username: var_username,
+            password: var_password,
+        };
+    }
+}
+
+impl SseDecode for crate::core::models::MinerId {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        return crate::core::models::MinerId(var_field0);
+    }
+}
+
+impl SseDecode for crate::core::config::MinerLocation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_minerId = <crate::core::models::MinerId>::sse_decode(deserializer);
+        let mut var_rack = <String>::sse_decode(deserializer);
+        let mut var_position = <String>::sse_decode(deserializer);
+        return crate::core::config::MinerLocation {
+            miner_id: var_minerId,
+            rack: var_rack,
+            position: var_position,
+        };
+    }
+}
+
+impl SseDecode for crate::core::models::MinerStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hashrateRt = <f64>::sse_decode(deserializer);
+        let mut var_hashrateAvg = <f64>::sse_decode(deserializer);
+        let mut var_tempOutletMin = <Vec<Option<f64>>>::sse_decode(deserializer);
+        let mut var_tempOutletMax = <Vec<Option<f64>>>::sse_decode(deserializer);
+        let mut var_tempInletMin = <Vec<Option<f64>>>::sse_decode(deserializer);
+        let mut var_tempInletMax = <Vec<Option<f64>>>::sse_decode(deserializer);
+        let mut var_fanSpeeds = <Vec<Option<u32>>>::sse_decode(deserializer);
+        let mut var_uptime = <u64>::sse_decode(deserializer);
+        let mut var_pool1 = <Option<String>>::sse_decode(deserializer);
+        let mut var_worker1 = <Option<String>>::sse_decode(deserializer);
+        let mut var_pool2 = <Option<String>>::sse_decode(deserializer);
+        let mut var_worker2 = <Option<String>>::sse_decode(deserializer);
+        let mut var_pool3 = <Option<String>>::sse_decode(deserializer);
+        let mut var_worker3 = <Option<String>>::sse_decode(deserializer);
+        let mut var_model = <Option<String>>::sse_decode(deserializer);
+        let mut var_firmware = <Option<String>>::sse_decode(deserializer);
+        let mut var_software = <Option<String>>::sse_decode(deserializer);
+        let mut var_hardware = <Option<String>>::sse_decode(deserializer);
+        let mut var_macAddress = <Option<String>>::sse_decode(deserializer);
+        let mut var_serialNumber = <Option<String>>::sse_decode(deserializer);
+        let mut var_hostname = <Option<String>>::sse_decode(deserializer);
+        let mut var_networkMode =
+            <Option<crate::core::models::NetworkMode>>::sse_decode(deserializer);
+        let mut var_netmask = <Option<String>>::sse_decode(deserializer);
+        let mut var_gateway = <Option<String>>::sse_decode(deserializer);
+        let mut var_dnsServers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_powerMode = <Option<u8>>::sse_decode(deserializer);
+        let mut var_fanMode = <Option<crate::core::models::FanMode>>::sse_decode(deserializer);
+        let mut var_freqLevel = <Option<u32>>::sse_decode(deserializer);
+        return crate::core::models::MinerStats {
+            hashrate_rt: var_hashrateRt,
+            hashrate_avg: var_hashrateAvg,
+            temp_outlet_min: var_tempOutletMin,
+            temp_outlet_max: var_tempOutletMax,
+            temp_inlet_min: var_tempInletMin,
+            temp_inlet_max: var_tempInletMax,
+            fan_speeds: var_fanSpeeds,
+            uptime: var_uptime,
+            pool1: var_pool1,
+            worker1: var_worker1,
+            pool2: var_pool2,
+            worker2: var_worker2,
+            pool3: var_pool3,
+            worker3: var_worker3,
+            model: var_model,
+            firmware: var_firmware,
+            software: var_software,
+            hardware: var_hardware,
+            mac_address: var_macAddress,
+            serial_number: var_serialNumber,
+            hostname: var_hostname,
+            network_mode: var_networkMode,
+            netmask: var_netmask,
+            gateway: var_gateway,
+            dns_servers: var_dnsServers,
+            power_mode: var_powerMode,
+            fan_mode: var_fanMode,
+            freq_level: var_freqLevel,
+        };
+    }
+}
+
+impl SseDecode for crate::core::models::MinerStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::models::MinerStatus::Active,
+            1 => crate::core::models::MinerStatus::Warning,
+            2 => crate::core::models::MinerStatus::Dead,
+            3 => crate::core::models::MinerStatus::Scanning,
+            _ => unreachable!("Invalid variant for MinerStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::core::config::MinerTags {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_minerId = <crate::core::models::MinerId>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        return crate::core::config::MinerTags {
+            miner_id: var_minerId,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::core::models::MinerVendor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::models::MinerVendor::Antminer,
+            1 => crate::core::models::MinerVendor::Whatsminer,
+            _ => unreachable!("Invalid variant for MinerVendor: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::core::config::NamedRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_spec = <String>::sse_decode(deserializer);
+        let mut var_source = <Option<String>>::sse_decode(deserializer);
+        return crate::core::config::NamedRange {
+            name: var_name,
+            spec: var_spec,
+            source: var_source,
+        };
+    }
+}
+
+impl SseDecode for crate::core::models::NetworkMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::core::models::NetworkMode::Dhcp,
+            1 => crate::core::models::NetworkMode::Static,
+            _ => unreachable!("Invalid variant for NetworkMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::error::ErrorKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::error::ErrorKind>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::models::FanMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::models::FanMode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::config::MinerCredentials> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::config::MinerCredentials>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::models::MinerId> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::models::MinerId>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::models::MinerVendor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::models::MinerVendor>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::models::NetworkMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::models::NetworkMode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::core::models::PduOutlet> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::core::models::PduOutlet>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::PoolConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::PoolConfig>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::PowerMode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::PowerMode>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::client::retry::RetryPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::client::retry::RetryPolicy>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::backup::SnapshotInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::backup::SnapshotInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u8>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::api::models::PoolConfig>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::api::models::PoolConfig>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::core::config::OutletLink {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_minerId = <crate::core::models::MinerId>::sse_decode(deserializer);
+        let mut var_outlet = <crate::core::models::PduOutlet>::sse_decode(deserializer);
+        return crate::core::config::OutletLink {
+            miner_id: var_minerId,
+            outlet: var_outlet,
+        };
+    }
+}
+
+impl SseDecode for crate::client::rotation::PasswordPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_length = <u32>::sse_decode(deserializer);
+                return crate::client::rotation::PasswordPolicy::Generate { length: var_length };
+            }
+            1 => {
+                let mut var_password = <String>::sse_decode(deserializer);
+                return crate::client::rotation::PasswordPolicy::Fixed {
+                    password: var_password,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::core::models::PduOutlet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pduIp = <String>::sse_decode(deserializer);
+        let mut var_outlet = <u32>::sse_decode(deserializer);
+        return crate::core::models::PduOutlet {
+            pdu_ip: var_pduIp,
+            outlet: var_outlet,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::PoolConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_worker = <String>::sse_decode(deserializer);
+        let mut var_password = <String>::sse_decode(deserializer);
+        return crate::api::models::PoolConfig {
+            url: var_url,
+            worker: var_worker,
+            password: var_password,
+        };
+    }
+}
+
+impl SseDecode for crate::api::pools::PoolDiff {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_minerId = <Option<crate::core::models::MinerId>>::sse_decode(deserializer);
+        let mut var_changes = <Vec<crate::client::pools::PoolSlotChange>>::sse_decode(deserializer);
+        let mut var_passwordsCompared = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::pools::PoolDiff {
+            ip: var_ip,
+            miner_id: var_minerId,
+            changes: var_changes,
+            passwords_compared: var_passwordsCompared,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::client::pools::PoolSlotChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_slot = <u32>::sse_decode(deserializer);
+        let mut var_current = <Option<crate::api::models::PoolConfig>>::sse_decode(deserializer);
+        let mut var_intended = <Option<crate::api::models::PoolConfig>>::sse_decode(deserializer);
+        return crate::client::pools::PoolSlotChange {
+            slot: var_slot,
+            current: var_current,
+            intended: var_intended,
+        };
+    }
+}
+
+impl SseDecode for crate::core::config::PoolTemplate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_pools = <Vec<crate::api::models::PoolConfig>>::sse_decode(deserializer);
+        return crate::core::config::PoolTemplate {
+            name: var_name,
+            pools: var_pools,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::PowerMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::models::PowerMode::Normal,
+            1 => crate::api::models::PowerMode::Lpm,
+            2 => crate::api::models::PowerMode::Sleep,
+            _ => unreachable!("Invalid variant for PowerMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::client::retry::RetryPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxAttempts = <u32>::sse_decode(deserializer);
+        let mut var_initialBackoffMs = <u64>::sse_decode(deserializer);
+        let mut var_maxBackoffMs = <u64>::sse_decode(deserializer);
+        let mut var_retryOn = <Vec<crate::core::error::ErrorKind>>::sse_decode(deserializer);
+        let mut var_minUptimeSecs = <u64>::sse_decode(deserializer);
+        return crate::client::retry::RetryPolicy {
+            max_attempts: var_maxAttempts,
+            initial_backoff_ms: var_initialBackoffMs,
+            max_backoff_ms: var_maxBackoffMs,
+            retry_on: var_retryOn,
+            min_uptime_secs: var_minUptimeSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::client::rotation::RotationOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::client::rotation::RotationOutcome::Rotated,
+            1 => crate::client::rotation::RotationOutcome::AlreadyCurrent,
+            2 => crate::client::rotation::RotationOutcome::Failed,
+            3 => crate::client::rotation::RotationOutcome::RolledBack,
+            4 => crate::client::rotation::RotationOutcome::NeedsAttention,
+            _ => unreachable!("Invalid variant for RotationOutcome: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::client::rotation::RotationResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_minerId = <Option<crate::core::models::MinerId>>::sse_decode(deserializer);
+        let mut var_outcome = <crate::client::rotation::RotationOutcome>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorKind = <Option<crate::core::error::ErrorKind>>::sse_decode(deserializer);
+        return crate::client::rotation::RotationResult {
+            ip: var_ip,
+            miner_id: var_minerId,
+            outcome: var_outcome,
+            message: var_message,
+            error_kind: var_errorKind,
+        };
+    }
+}
+
+impl SseDecode for crate::scanner::ScanEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_totalIps = <usize>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::Started {
+                    total_ips: var_totalIps,
+                };
+            }
+            1 => {
+                let mut var_field0 = <crate::core::models::Miner>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::Found(var_field0);
+            }
+            2 => {
+                let mut var_scanned = <usize>::sse_decode(deserializer);
+                let mut var_total = <usize>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::Progress {
+                    scanned: var_scanned,
+                    total: var_total,
+                };
+            }
+            3 => {
+                let mut var_found = <usize>::sse_decode(deserializer);
+                let mut var_failed = <usize>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::Complete {
+                    found: var_found,
+                    failed: var_failed,
+                };
+            }
+            4 => {
+                let mut var_scanned = <usize>::sse_decode(deserializer);
+                let mut var_found = <usize>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::Cancelled {
+                    scanned: var_scanned,
+                    found: var_found,
+                };
+            }
+            5 => {
+                let mut var_id = <crate::core::models::MinerId>::sse_decode(deserializer);
+                let mut var_fromIp = <String>::sse_decode(deserializer);
+                let mut var_toIp = <String>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::MinerMoved {
+                    id: var_id,
+                    from_ip: var_fromIp,
+                    to_ip: var_toIp,
+                };
+            }
+            6 => {
+                let mut var_ip = <String>::sse_decode(deserializer);
+                let mut var_previous = <crate::core::models::MinerId>::sse_decode(deserializer);
+                let mut var_current = <crate::core::models::MinerId>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::IpTakenOver {
+                    ip: var_ip,
+                    previous: var_previous,
+                    current: var_current,
+                };
+            }
+            7 => {
+                let mut var_field0 = <crate::core::models::Device>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::NonMiner(var_field0);
+            }
+            8 => {
+                let mut var_ip = <String>::sse_decode(deserializer);
+                let mut var_macAddresses = <Vec<String>>::sse_decode(deserializer);
+                let mut var_serialNumbers = <Vec<String>>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::DuplicateIp {
+                    ip: var_ip,
+                    mac_addresses: var_macAddresses,
+                    serial_numbers: var_serialNumbers,
+                };
+            }
+            9 => {
+                let mut var_ip = <String>::sse_decode(deserializer);
+                let mut var_id = <Option<crate::core::models::MinerId>>::sse_decode(deserializer);
+                let mut var_macAddress = <Option<String>>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::FactoryDefaultIp {
+                    ip: var_ip,
+                    id: var_id,
+                    mac_address: var_macAddress,
+                };
+            }
+            10 => {
+                let mut var_ip = <String>::sse_decode(deserializer);
+                let mut var_id = <Option<crate::core::models::MinerId>>::sse_decode(deserializer);
+                let mut var_netmask = <Option<String>>::sse_decode(deserializer);
+                let mut var_gateway = <Option<String>>::sse_decode(deserializer);
+                let mut var_expectedSubnet = <Option<String>>::sse_decode(deserializer);
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::scanner::ScanEvent::NetworkMismatch {
+                    ip: var_ip,
+                    id: var_id,
+                    netmask: var_netmask,
+                    gateway: var_gateway,
+                    expected_subnet: var_expectedSubnet,
+                    reason: var_reason,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::backup::SnapshotInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_minerId = <crate::core::models::MinerId>::sse_decode(deserializer);
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_vendor = <crate::core::models::MinerVendor>::sse_decode(deserializer);
+        let mut var_model = <Option<String>>::sse_decode(deserializer);
+        let mut var_takenAt = <u64>::sse_decode(deserializer);
+        let mut var_note = <Option<String>>::sse_decode(deserializer);
+        return crate::backup::SnapshotInfo {
+            id: var_id,
+            miner_id: var_minerId,
+            ip: var_ip,
+            vendor: var_vendor,
+            model: var_model,
+            taken_at: var_takenAt,
+            note: var_note,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::core::vault::VaultStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_exists = <bool>::sse_decode(deserializer);
+        let mut var_unlocked = <bool>::sse_decode(deserializer);
+        return crate::core::vault::VaultStatus {
+            exists: var_exists,
+            unlocked: var_unlocked,
+        };
+    }
+}
+
+impl SseDecode for crate::api::pools::WorkerPreview {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ip = <String>::sse_decode(deserializer);
+        let mut var_minerId = <Option<crate::core::models::MinerId>>::sse_decode(deserializer);
+        let mut var_workers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::pools::WorkerPreview {
+            ip: var_ip,
+            miner_id: var_minerId,
+            workers: var_workers,
+            error: var_error,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__backup__backup_miners_impl(port, ptr, rust_vec_len, data_len),
+        2 => {
+            wire__crate__api__vault__change_vault_passphrase_impl(port, ptr, rust_vec_len, data_len)
+        }
+        3 => wire__crate__api__vault__create_vault_impl(port, ptr, rust_vec_len, data_len),
+        4 => {
+            wire__crate__api__backup__delete_config_snapshot_impl(port, ptr, rust_vec_len, data_len)
+        }
+        5 => wire__crate__api__scanner__detect_local_ranges_impl(port, ptr, rust_vec_len, data_len),
+        6 => {
+            wire__crate__api__backup__diff_config_snapshots_impl(port, ptr, rust_vec_len, data_len)
+        }
+        7 => {
+            wire__crate__api__backup__diff_config_with_miner_impl(port, ptr, rust_vec_len, data_len)
+        }
+        8 => wire__crate__api__pools__dry_run_pool_template_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__pools__dry_run_set_pools_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__commands__execute_batch_command_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__monitor__get_current_miners_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__monitor__get_drift_reports_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__commands__get_miner_pools_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        21 => {
+            wire__crate__api__backup__list_config_snapshots_impl(port, ptr, rust_vec_len, data_len)
+        }
+        22 => wire__crate__api__credentials__list_credential_sets_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => {
+            wire__crate__api__scanner__list_local_interfaces_impl(port, ptr, rust_vec_len, data_len)
+        }
+        29 => wire__crate__api__pools__preview_worker_names_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__devices__probe_device_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__policy__reconcile_drift_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__backup__restore_config_snapshot_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__credentials__rotate_web_passwords_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__credentials__save_credential_sets_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__commands__set_miner_pools_impl(port, ptr, rust_vec_len, data_len),
+        40 => {
+            wire__crate__api__commands__set_miner_power_mode_impl(port, ptr, rust_vec_len, data_len)
+        }
+        42 => wire__crate__api__monitor__start_monitoring_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__scanner__start_scan_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__scanner__start_scan_stream_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__monitor__stop_monitoring_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__commands__test_connection_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__vault__unlock_vault_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__scanner__validate_ip_range_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        11 => wire__crate__api__settings__get_app_settings_impl(ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__credentials__get_miner_tags_impl(ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__vault__get_vault_status_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__devices__link_miner_outlet_impl(ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__credentials__list_auth_failures_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__policy__list_desired_states_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__devices__list_miner_locations_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__devices__list_outlet_links_impl(ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__pools__list_pool_templates_impl(ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__vault__lock_vault_impl(ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__settings__save_app_settings_impl(ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__policy__save_desired_states_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__pools__save_pool_templates_impl(ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__devices__set_miner_location_impl(ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__credentials__set_miner_tags_impl(ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__scanner__stop_scan_impl(ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__devices__unlink_miner_outlet_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::config::AppSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.antminer_credentials.into_into_dart().into_dart(),
+            self.whatsminer_credentials.into_into_dart().into_dart(),
+            self.scan_thread_count.into_into_dart().into_dart(),
+            self.monitor_interval.into_into_dart().into_dart(),
+            self.named_ranges.into_into_dart().into_dart(),
+            self.scan_timeout_ms.into_into_dart().into_dart(),
+            self.scan_ports.into_into_dart().into_dart(),
+            self.scan_devices.into_into_dart().into_dart(),
+            self.snmp_community.into_into_dart().into_dart(),
+            self.outlet_links.into_into_dart().into_dart(),
+            self.factory_default_ips.into_into_dart().into_dart(),
+            self.detect_ip_conflicts.into_into_dart().into_dart(),
+            self.credential_sets.into_into_dart().into_dart(),
+            self.miner_tags.into_into_dart().into_dart(),
+            self.miner_locations.into_into_dart().into_dart(),
+            self.pool_templates.into_into_dart().into_dart(),
+            self.desired_states.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::config::AppSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::config::AppSettings>
+    for crate::core::config::AppSettings
+{
+    fn into_into_dart(self) -> crate::core::config::AppSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::client::credentials::AuthFailure {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ip.into_into_dart().into_dart(),
+            self.miner_id.into_into_dart().into_dart(),
+            self.tried.into_into_dart().into_dart(),
+            self.failed_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::client::credentials::AuthFailure
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::client::credentials::AuthFailure>
+    for crate::client::credentials::AuthFailure
+{
+    fn into_into_dart(self) -> crate::client::credentials::AuthFailure {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::backup::BackupResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ip.into_into_dart().into_dart(),
+            self.miner_id.into_into_dart().into_dart(),
+            self.snapshot.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::backup::BackupResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::backup::BackupResult>
+    for crate::api::backup::BackupResult
+{
+    fn into_into_dart(self) -> crate::api::backup::BackupResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::client::retry::CommandAttempt {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.attempt.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.error_kind.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::client::retry::CommandAttempt
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::client::retry::CommandAttempt>
+    for crate::client::retry::CommandAttempt
+{
+    fn into_into_dart(self) -> crate::client::retry::CommandAttempt {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::CommandResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ip.into_into_dart().into_dart(),
+            self.miner_id.into_into_dart().into_dart(),
+            self.success.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+            self.error_kind.into_into_dart().into_dart(),
+            self.retryable.into_into_dart().into_dart(),
+            self.suggested_action.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::CommandResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::CommandResult>
+    for crate::api::models::CommandResult
+{
+    fn into_into_dart(self) -> crate::api::models::CommandResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::backup::ConfigChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.before.into_into_dart().into_dart(),
+            self.after.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::backup::ConfigChange {}
+impl flutter_rust_bridge::IntoIntoDart<crate::backup::ConfigChange>
+    for crate::backup::ConfigChange
+{
+    fn into_into_dart(self) -> crate::backup::ConfigChange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::config::CredentialScope {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::core::config::CredentialScope::All => [0.into_dart()].into_dart(),
+            crate::core::config::CredentialScope::Range(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::core::config::CredentialScope::Tag(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::core::config::CredentialScope::Miner(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::config::CredentialScope
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::config::CredentialScope>
+    for crate::core::config::CredentialScope
+{
+    fn into_into_dart(self) -> crate::core::config::CredentialScope {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::config::CredentialSet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.scope.into_into_dart().into_dart(),
+            self.vendor.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.credentials.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::config::CredentialSet
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::config::CredentialSet>
+    for crate::core::config::CredentialSet
+{
+    fn into_into_dart(self) -> crate::core::config::CredentialSet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::config::DesiredState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.scope.into_into_dart().into_dart(),
+            self.pools.into_into_dart().into_dart(),
+            self.power_mode.into_into_dart().into_dart(),
+            self.fan_mode.into_into_dart().into_dart(),
+            self.freq_level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::config::DesiredState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::config::DesiredState>
+    for crate::core::config::DesiredState
+{
+    fn into_into_dart(self) -> crate::core::config::DesiredState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::models::Device {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ip.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.mac_address.into_into_dart().into_dart(),
+            self.vendor_hint.into_into_dart().into_dart(),
+            self.sys_name.into_into_dart().into_dart(),
+            self.sys_descr.into_into_dart().into_dart(),
+            self.http_banner.into_into_dart().into_dart(),
+            self.open_port.into_into_dart().into_dart(),
+            self.last_updated.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::core::models::Device {}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::models::Device>
+    for crate::core::models::Device
+{
+    fn into_into_dart(self) -> crate::core::models::Device {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::models::DeviceKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Pdu => 0.into_dart(),
+            Self::Network => 1.into_dart(),
+            Self::Camera => 2.into_dart(),
+            Self::Computer => 3.into_dart(),
+            Self::Unknown => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::models::DeviceKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::models::DeviceKind>
+    for crate::core::models::DeviceKind
+{
+    fn into_into_dart(self) -> crate::core::models::DeviceKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::monitor::drift::Drift {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.expected.into_into_dart().into_dart(),
+            self.actual.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::monitor::drift::Drift {}
+impl flutter_rust_bridge::IntoIntoDart<crate::monitor::drift::Drift>
+    for crate::monitor::drift::Drift
+{
+    fn into_into_dart(self) -> crate::monitor::drift::Drift {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::monitor::drift::DriftField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Pools => 0.into_dart(),
+            Self::PowerMode => 1.into_dart(),
+            Self::FanMode => 2.into_dart(),
+            Self::FreqLevel => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::monitor::drift::DriftField
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::monitor::drift::DriftField>
+    for crate::monitor::drift::DriftField
+{
+    fn into_into_dart(self) -> crate::monitor::drift::DriftField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::monitor::drift::DriftReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ip.into_into_dart().into_dart(),
+            self.miner_id.into_into_dart().into_dart(),
+            self.policy.into_into_dart().into_dart(),
+            self.drifts.into_into_dart().into_dart(),
+            self.detected_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::monitor::drift::DriftReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::monitor::drift::DriftReport>
+    for crate::monitor::drift::DriftReport
+{
+    fn into_into_dart(self) -> crate::monitor::drift::DriftReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::error::ErrorKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::core::error::ErrorKind::AuthFailed => [0.into_dart()].into_dart(),
+            crate::core::error::ErrorKind::Unreachable => [1.into_dart()].into_dart(),
+            crate::core::error::ErrorKind::Timeout => [2.into_dart()].into_dart(),
+            crate::core::error::ErrorKind::Unsupported => [3.into_dart()].into_dart(),
+            crate::core::error::ErrorKind::RejectedByMiner { code } => {
+                [4.into_dart(), code.into_into_dart().into_dart()].into_dart()
+            }
+            crate::core::error::ErrorKind::ParseFailed => [5.into_dart()].into_dart(),
+            crate::core::error::ErrorKind::PartialSuccess => [6.into_dart()].into_dart(),
+            crate::core::error::ErrorKind::Other => [7.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::core::error::ErrorKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::error::ErrorKind>
+    for crate::core::error::ErrorKind
+{
+    fn into_into_dart(self) -> crate::core::error::ErrorKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::models::FanMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::core::models::FanMode::Auto => [0.into_dart()].into_dart(),
+            crate::core::models::FanMode::Fixed { percent } => {
+                [1.into_dart(), percent.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::core::models::FanMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::models::FanMode>
+    for crate::core::models::FanMode
+{
+    fn into_into_dart(self) -> crate::core::models::FanMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scanner::LocalInterface {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.ip.into_into_dart().into_dart(),
+            self.range.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scanner::LocalInterface
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scanner::LocalInterface>
+    for crate::api::scanner::LocalInterface
+{
+    fn into_into_dart(self) -> crate::api::scanner::LocalInterface {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::models::Miner {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.ip.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.stats.into_into_dart().into_dart(),
+            self.last_updated.into_into_dart().into_dart(),
+            self.vendor_hint.into_into_dart().into_dart(),
+            self.outlet.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::core::models::Miner {}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::models::Miner> for crate::core::models::Miner {
+    fn into_into_dart(self) -> crate::core::models::Miner {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::MinerCommand {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::models::MinerCommand::Reboot => [0.into_dart()].into_dart(),
+            crate::api::models::MinerCommand::BlinkLed => [1.into_dart()].into_dart(),
+            crate::api::models::MinerCommand::StopBlink => [2.into_dart()].into_dart(),
+            crate::api::models::MinerCommand::SetPools { pools } => {
+                [3.into_dart(), pools.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::models::MinerCommand::ApplyConfig {
+                pools,
+                power_mode,
+                fan_mode,
+                freq_level,
+            } => [
+                4.into_dart(),
+                pools.into_into_dart().into_dart(),
+                power_mode.into_into_dart().into_dart(),
+                fan_mode.into_into_dart().into_dart(),
+                freq_level.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::models::MinerCommand::RestoreConfig { snapshot_id } => {
+                [5.into_dart(), snapshot_id.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::MinerCommand
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::MinerCommand>
+    for crate::api::models::MinerCommand
+{
+    fn into_into_dart(self) -> crate::api::models::MinerCommand {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::config::MinerCredentials {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.username.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::config::MinerCredentials
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::config::MinerCredentials>
+    for crate::core::config::MinerCredentials
+{
+    fn into_into_dart(self) -> crate::core::config::MinerCredentials {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::models::MinerId {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.0.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::core::models::MinerId {}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::models::MinerId>
+    for crate::core::models::MinerId
+{
+    fn into_into_dart(self) -> crate::core::models::MinerId {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::config::MinerLocation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.miner_id.into_into_dart().into_dart(),
+            self.rack.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::config::MinerLocation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::config::MinerLocation>
+    for crate::core::config::MinerLocation
+{
+    fn into_into_dart(self) -> crate::core::config::MinerLocation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::models::MinerStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hashrate_rt.into_into_dart().into_dart(),
+            self.hashrate_avg.into_into_dart().into_dart(),
+            self.temp_outlet_min.into_into_dart().into_dart(),
+            self.temp_outlet_max.into_into_dart().into_dart(),
+            self.temp_inlet_min.into_into_dart().into_dart(),
+            self.temp_inlet_max.into_into_dart().into_dart(),
+            self.fan_speeds.into_into_dart().into_dart(),
+            self.uptime.into_into_dart().into_dart(),
+            self.pool1.into_into_dart().into_dart(),
+            self.worker1.into_into_dart().into_dart(),
+            self.pool2.into_into_dart().into_dart(),
+            self.worker2.into_into_dart().into_dart(),
+            self.pool3.into_into_dart().into_dart(),
+            self.worker3.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.firmware.into_into_dart().into_dart(),
+            self.software.into_into_dart().into_dart(),
+            self.hardware.into_into_dart().into_dart(),
+            self.mac_address.into_into_dart().into_dart(),
+            self.serial_number.into_into_dart().into_dart(),
+            self.hostname.into_into_dart().into_dart(),
+            self.network_mode.into_into_dart().into_dart(),
+            self.netmask.into_into_dart().into_dart(),
+            self.gateway.into_into_dart().into_dart(),
+            self.dns_servers.into_into_dart().into_dart(),
+            self.power_mode.into_into_dart().into_dart(),
+            self.fan_mode.into_into_dart().into_dart(),
+            self.freq_level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::models::MinerStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::models::MinerStats>
+    for crate::core::models::MinerStats
+{
+    fn into_into_dart(self) -> crate::core::models::MinerStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::models::MinerStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Active => 0.into_dart(),
+            Self::Warning => 1.into_dart(),
+            Self::Dead => 2.into_dart(),
+            Self::Scanning => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::models::MinerStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::models::MinerStatus>
+    for crate::core::models::MinerStatus
+{
+    fn into_into_dart(self) -> crate::core::models::MinerStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::config::MinerTags {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.miner_id.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::config::MinerTags
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::config::MinerTags>
+    for crate::core::config::MinerTags
+{
+    fn into_into_dart(self) -> crate::core::config::MinerTags {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::models::MinerVendor {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Antminer => 0.into_dart(),
+            Self::Whatsminer => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::models::MinerVendor
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::models::MinerVendor>
+    for crate::core::models::MinerVendor
+{
+    fn into_into_dart(self) -> crate::core::models::MinerVendor {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::config::NamedRange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.spec.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::config::NamedRange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::config::NamedRange>
+    for crate::core::config::NamedRange
+{
+    fn into_into_dart(self) -> crate::core::config::NamedRange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::models::NetworkMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Dhcp => 0.into_dart(),
+            Self::Static => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::models::NetworkMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::models::NetworkMode>
+    for crate::core::models::NetworkMode
+{
+    fn into_into_dart(self) -> crate::core::models::NetworkMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::config::OutletLink {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.miner_id.into_into_dart().into_dart(),
+            self.outlet.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::config::OutletLink
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::config::OutletLink>
+    for crate::core::config::OutletLink
+{
+    fn into_into_dart(self) -> crate::core::config::OutletLink {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::client::rotation::PasswordPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::client::rotation::PasswordPolicy::Generate { length } => {
+                [0.into_dart(), length.into_into_dart().into_dart()].into_dart()
+            }
+            crate::client::rotation::PasswordPolicy::Fixed { password } => {
+                [1.into_dart(), password.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::client::rotation::PasswordPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::client::rotation::PasswordPolicy>
+    for crate::client::rotation::PasswordPolicy
+{
+    fn into_into_dart(self) -> crate::client::rotation::PasswordPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::models::PduOutlet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pdu_ip.into_into_dart().into_dart(),
+            self.outlet.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::models::PduOutlet
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::models::PduOutlet>
+    for crate::core::models::PduOutlet
+{
+    fn into_into_dart(self) -> crate::core::models::PduOutlet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::PoolConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.worker.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::PoolConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::PoolConfig>
+    for crate::api::models::PoolConfig
+{
+    fn into_into_dart(self) -> crate::api::models::PoolConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pools::PoolDiff {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ip.into_into_dart().into_dart(),
+            self.miner_id.into_into_dart().into_dart(),
+            self.changes.into_into_dart().into_dart(),
+            self.passwords_compared.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::pools::PoolDiff {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pools::PoolDiff>
+    for crate::api::pools::PoolDiff
+{
+    fn into_into_dart(self) -> crate::api::pools::PoolDiff {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::client::pools::PoolSlotChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.slot.into_into_dart().into_dart(),
+            self.current.into_into_dart().into_dart(),
+            self.intended.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::client::pools::PoolSlotChange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::client::pools::PoolSlotChange>
+    for crate::client::pools::PoolSlotChange
+{
+    fn into_into_dart(self) -> crate::client::pools::PoolSlotChange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::config::PoolTemplate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.pools.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::config::PoolTemplate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::config::PoolTemplate>
+    for crate::core::config::PoolTemplate
+{
+    fn into_into_dart(self) -> crate::core::config::PoolTemplate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::PowerMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Normal => 0.into_dart(),
+            Self::Lpm => 1.into_dart(),
+            Self::Sleep => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::models::PowerMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::PowerMode>
+    for crate::api::models::PowerMode
+{
+    fn into_into_dart(self) -> crate::api::models::PowerMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::client::retry::RetryPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_attempts.into_into_dart().into_dart(),
+            self.initial_backoff_ms.into_into_dart().into_dart(),
+            self.max_backoff_ms.into_into_dart().into_dart(),
+            self.retry_on.into_into_dart().into_dart(),
+            self.min_uptime_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::client::retry::RetryPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::client::retry::RetryPolicy>
+    for crate::client::retry::RetryPolicy
+{
+    fn into_into_dart(self) -> crate::client::retry::RetryPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::client::rotation::RotationOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Rotated => 0.into_dart(),
+            Self::AlreadyCurrent => 1.into_dart(),
+            Self::Failed => 2.into_dart(),
+            Self::RolledBack => 3.into_dart(),
+            Self::NeedsAttention => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::client::rotation::RotationOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::client::rotation::RotationOutcome>
+    for crate::client::rotation::RotationOutcome
+{
+    fn into_into_dart(self) -> crate::client::rotation::RotationOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::client::rotation::RotationResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ip.into_into_dart().into_dart(),
+            self.miner_id.into_into_dart().into_dart(),
+            self.outcome.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.error_kind.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::client::rotation::RotationResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::client::rotation::RotationResult>
+    for crate::client::rotation::RotationResult
+{
+    fn into_into_dart(self) -> crate::client::rotation::RotationResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::scanner::ScanEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::scanner::ScanEvent::Started { total_ips } => {
+                [0.into_dart(), total_ips.into_into_dart().into_dart()].into_dart()
+            }
+            crate::scanner::ScanEvent::Found(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::scanner::ScanEvent::Progress { scanned, total } => [
+                2.into_dart(),
+                scanned.into_into_dart().into_dart(),
+                total.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::scanner::ScanEvent::Complete { found, failed } => [
+                3.into_dart(),
+                found.into_into_dart().into_dart(),
+                failed.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::scanner::ScanEvent::Cancelled { scanned, found } => [
+                4.into_dart(),
+                scanned.into_into_dart().into_dart(),
+                found.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::scanner::ScanEvent::MinerMoved { id, from_ip, to_ip } => [
+                5.into_dart(),
+                id.into_into_dart().into_dart(),
+                from_ip.into_into_dart().into_dart(),
+                to_ip.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::scanner::ScanEvent::IpTakenOver {
+                ip,
+                previous,
+                current,
+            } => [
+                6.into_dart(),
+                ip.into_into_dart().into_dart(),
+                previous.into_into_dart().into_dart(),
+                current.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::scanner::ScanEvent::NonMiner(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::scanner::ScanEvent::DuplicateIp {
+                ip,
+                mac_addresses,
+                serial_numbers,
+            } => [
+                8.into_dart(),
+                ip.into_into_dart().into_dart(),
+                mac_addresses.into_into_dart().into_dart(),
+                serial_numbers.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::scanner::ScanEvent::FactoryDefaultIp {
+                ip,
+                id,
+                mac_address,
+            } => [
+                9.into_dart(),
+                ip.into_into_dart().into_dart(),
+                id.into_into_dart().into_dart(),
+                mac_address.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::scanner::ScanEvent::NetworkMismatch {
+                ip,
+                id,
+                netmask,
+                gateway,
+                expected_subnet,
+                reason,
+            } => [
+                10.into_dart(),
+                ip.into_into_dart().into_dart(),
+                id.into_into_dart().into_dart(),
+                netmask.into_into_dart().into_dart(),
+                gateway.into_into_dart().into_dart(),
+                expected_subnet.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::scanner::ScanEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::scanner::ScanEvent> for crate::scanner::ScanEvent {
+    fn into_into_dart(self) -> crate::scanner::ScanEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::backup::SnapshotInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.miner_id.into_into_dart().into_dart(),
+            self.ip.into_into_dart().into_dart(),
+            self.vendor.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.taken_at.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::backup::SnapshotInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::backup::SnapshotInfo>
+    for crate::backup::SnapshotInfo
+{
+    fn into_into_dart(self) -> crate::backup::SnapshotInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::vault::VaultStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.exists.into_into_dart().into_dart(),
+            self.unlocked.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::vault::VaultStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::vault::VaultStatus>
+    for crate::core::vault::VaultStatus
+{
+    fn into_into_dart(self) -> crate::core::vault::VaultStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::pools::WorkerPreview {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ip.into_into_dart().into_dart(),
+            self.miner_id.into_into_dart().into_dart(),
+            self.workers.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::pools::WorkerPreview
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::pools::WorkerPreview>
+    for crate::api::pools::WorkerPreview
+{
+    fn into_into_dart(self) -> crate::api::pools::WorkerPreview {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::scanner::ScanEvent, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::core::config::AppSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::core::config::MinerCredentials>::sse_encode(self.antminer_credentials, serializer);
+        <crate::core::config::MinerCredentials>::sse_encode(
+            self.whatsminer_credentials,
+            serializer,
+        );
+        <u32>::sse_encode(self.scan_thread_count, serializer);
+        <u64>::sse_encode(self.monitor_interval, serializer);
+        <Vec<crate::core::config::NamedRange>>::sse_encode(self.named_ranges, serializer);
+        <u64>::sse_encode(self.scan_timeout_ms, serializer);
+        <Vec<u16>>::sse_encode(self.scan_ports, serializer);
+        <bool>::sse_encode(self.scan_devices, serializer);
+        <String>::sse_encode(self.snmp_community, serializer);
+        <Vec<crate::core::config::OutletLink>>::sse_encode(self.outlet_links, serializer);
+        <Vec<String>>::sse_encode(self.factory_default_ips, serializer);
+        <bool>::sse_encode(self.detect_ip_conflicts, serializer);
+        <Vec<crate::core::config::CredentialSet>>::sse_encode(self.credential_sets, serializer);
+        <Vec<crate::core::config::MinerTags>>::sse_encode(self.miner_tags, serializer);
+        <Vec<crate::core::config::MinerLocation>>::sse_encode(self.miner_locations, serializer);
+        <Vec<crate::core::config::PoolTemplate>>::sse_encode(self.pool_templates, serializer);
+        <Vec<crate::core::config::DesiredState>>::sse_encode(self.desired_states, serializer);
+    }
+}
+
+impl SseEncode for crate::client::credentials::AuthFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.ip, serializer);
+        <Option<crate::core::models::MinerId>>::sse_encode(self.miner_id, serializer);
+        <u32>::sse_encode(self.tried, serializer);
+        <u64>::sse_encode(self.failed_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::backup::BackupResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.ip, serializer);
+        <Option<crate::core::models::MinerId>>::sse_encode(self.miner_id, serializer);
+        <Option<crate::backup::SnapshotInfo>>::sse_encode(self.snapshot, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::client::retry::CommandAttempt {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.attempt, serializer);
+        <u64>::sse_encode(self.started_at, serializer);
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <Option<crate::core::error::ErrorKind>>::sse_encode(self.error_kind, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::CommandResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.ip, serializer);
+        <Option<crate::core::models::MinerId>>::sse_encode(self.miner_id, serializer);
+        <bool>::sse_encode(self.success, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <Option<crate::core::error::ErrorKind>>::sse_encode(self.error_kind, serializer);
+        <bool>::sse_encode(self.retryable, serializer);
+        <Option<String>>::sse_encode(self.suggested_action, serializer);
+        <Vec<crate::client::retry::CommandAttempt>>::sse_encode(self.attempts, serializer);
+        <Option<String>>::sse_encode(self.skipped, serializer);
+    }
+}
+
+impl SseEncode for crate::backup::ConfigChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <Option<String>>::sse_encode(self.before, serializer);
+        <Option<String>>::sse_encode(self.after, serializer);
+    }
+}
+
+impl SseEncode for crate::core::config::CredentialScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::core::config::CredentialScope::All => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::core::config::CredentialScope::Range(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::core::config::CredentialScope::Tag(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::core::config::CredentialScope::Miner(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <crate::core::models::MinerId>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::core::config::CredentialSet {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <crate::core::config::CredentialScope>::sse_encode(self.scope, serializer);
+        <Option<crate::core::models::MinerVendor>>::sse_encode(self.vendor, serializer);
+        <i32>::sse_encode(self.priority, serializer);
+        <crate::core::config::MinerCredentials>::sse_encode(self.credentials, serializer);
     }
 }
 
-impl SseDecode for crate::core::config::AppSettings {
+impl SseEncode for crate::core::config::DesiredState {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_antminerCredentials =
-            <crate::core::config::MinerCredentials>::sse_decode(deserializer);
-        let mut var_whatsminerCredentials =
-            <crate::core::config::MinerCredentials>::sse_decode(deserializer);
-        let mut var_scanThreadCount = <u32>::sse_decode(deserializer);
-        let mut var_monitorInterval = <u64>::sse_decode(deserializer);
-        return crate::core::config::AppSettings {
-            antminer_credentials: var_antminerCredentials,
-            whatsminer_credentials: var_whatsminerCredentials,
-            scan_thread_count: var_scanThreadCount,
-            monitor_interval: var_monitorInterval,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <crate::core::config::CredentialScope>::sse_encode(self.scope, serializer);
+        <Option<Vec<crate::api::models::PoolConfig>>>::sse_encode(self.pools, serializer);
+        <Option<crate::api::models::PowerMode>>::sse_encode(self.power_mode, serializer);
+        <Option<crate::core::models::FanMode>>::sse_encode(self.fan_mode, serializer);
+        <Option<u32>>::sse_encode(self.freq_level, serializer);
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for crate::core::models::Device {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.ip, serializer);
+        <crate::core::models::DeviceKind>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.mac_address, serializer);
+        <Option<String>>::sse_encode(self.vendor_hint, serializer);
+        <Option<String>>::sse_encode(self.sys_name, serializer);
+        <Option<String>>::sse_encode(self.sys_descr, serializer);
+        <Option<String>>::sse_encode(self.http_banner, serializer);
+        <Option<u16>>::sse_encode(self.open_port, serializer);
+        <u64>::sse_encode(self.last_updated, serializer);
     }
 }
 
-impl SseDecode for crate::api::models::CommandResult {
+impl SseEncode for crate::core::models::DeviceKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ip = <String>::sse_decode(deserializer);
-        let mut var_success = <bool>::sse_decode(deserializer);
-        let mut var_error = <Option<String>>::sse_decode(deserializer);
-        return crate::api::models::CommandResult {
-            ip: var_ip,
-            success: var_success,
-            error: var_error,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::core::models::DeviceKind::Pdu => 0,
+                crate::core::models::DeviceKind::Network => 1,
+                crate::core::models::DeviceKind::Camera => 2,
+                crate::core::models::DeviceKind::Computer => 3,
+                crate::core::models::DeviceKind::Unknown => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for f64 {
+impl SseEncode for crate::monitor::drift::Drift {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::monitor::drift::DriftField>::sse_encode(self.field, serializer);
+        <String>::sse_encode(self.expected, serializer);
+        <String>::sse_encode(self.actual, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for crate::monitor::drift::DriftField {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::monitor::drift::DriftField::Pools => 0,
+                crate::monitor::drift::DriftField::PowerMode => 1,
+                crate::monitor::drift::DriftField::FanMode => 2,
+                crate::monitor::drift::DriftField::FreqLevel => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for crate::monitor::drift::DriftReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.ip, serializer);
+        <Option<crate::core::models::MinerId>>::sse_encode(self.miner_id, serializer);
+        <String>::sse_encode(self.policy, serializer);
+        <Vec<crate::monitor::drift::Drift>>::sse_encode(self.drifts, serializer);
+        <u64>::sse_encode(self.detected_at, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::models::CommandResult> {
+impl SseEncode for crate::core::error::ErrorKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::models::CommandResult>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::core::error::ErrorKind::AuthFailed => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::core::error::ErrorKind::Unreachable => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::core::error::ErrorKind::Timeout => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::core::error::ErrorKind::Unsupported => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::core::error::ErrorKind::RejectedByMiner { code } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(code, serializer);
+            }
+            crate::core::error::ErrorKind::ParseFailed => {
+                <i32>::sse_encode(5, serializer);
+            }
+            crate::core::error::ErrorKind::PartialSuccess => {
+                <i32>::sse_encode(6, serializer);
+            }
+            crate::core::error::ErrorKind::Other => {
+                <i32>::sse_encode(7, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::core::models::Miner> {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::core::models::Miner>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for Vec<Option<f64>> {
+impl SseEncode for crate::core::models::FanMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<f64>>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::core::models::FanMode::Auto => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::core::models::FanMode::Fixed { percent } => {
+                <i32>::sse_encode(1, serializer);
+                <u32>::sse_encode(percent, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<Option<u32>> {
+impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<u32>>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for Vec<crate::api::models::PoolConfig> {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::models::PoolConfig>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::client::credentials::AuthFailure> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::client::credentials::AuthFailure>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for crate::core::models::Miner {
+impl SseEncode for Vec<crate::api::backup::BackupResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ip = <String>::sse_decode(deserializer);
-        let mut var_model = <Option<String>>::sse_decode(deserializer);
-        let mut var_status = <crate::core::models::MinerStatus>::sse_decode(deserializer);
-        let mut var_stats = <crate::core::models::MinerStats>::sse_decode(deserializer);
-        let mut var_lastUpdated = <u64>::sse_decode(deserializer);
-        return crate::core::models::Miner {
-            ip: var_ip,
-            model: var_model,
-            status: var_status,
-            stats: var_stats,
-            last_updated: var_lastUpdated,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::backup::BackupResult>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::models::MinerCommand {
+impl SseEncode for Vec<crate::client::retry::CommandAttempt> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                return crate::api::models::MinerCommand::Reboot;
-            }
-            1 => {
-                return crate::api::models::MinerCommand::BlinkLed;
-            }
-            2 => {
-                return crate::api::models::MinerCommand::StopBlink;
-            }
-            3 => {
-                let mut var_pools = <Vec<crate::api::models::PoolConfig>>::sse_decode(deserializer);
-                return crate::api::models::MinerCommand::SetPools { pools: var_pools };
-            }
-            _ => {
-                unimplemented!("");
-            }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::client::retry::CommandAttempt>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for crate::core::config::MinerCredentials {
+impl SseEncode for Vec<crate::api::models::CommandResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_username = <String>::sse_decode(deserializer);
-        let mut var_password = <String>::sse_decode(deserializer);
-        return crate::core::config::MinerCredentials {
-            username: var_username,
-            password: var_password,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::CommandResult>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::core::models::MinerStats {
+impl SseEncode for Vec<crate::backup::ConfigChange> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_hashrateRt = <f64>::sse_decode(deserializer);
-        let mut var_hashrateAvg = <f64>::sse_decode(deserializer);
-        let mut var_tempOutletMin = <Vec<Option<f64>>>::sse_decode(deserializer);
-        let mut var_tempOutletMax = <Vec<Option<f64>>>::sse_decode(deserializer);
-        let mut var_tempInletMin = <Vec<Option<f64>>>::sse_decode(deserializer);
-        let mut var_tempInletMax = <Vec<Option<f64>>>::sse_decode(deserializer);
-        let mut var_fanSpeeds = <Vec<Option<u32>>>::sse_decode(deserializer);
-        let mut var_uptime = <u64>::sse_decode(deserializer);
-        let mut var_pool1 = <Option<String>>::sse_decode(deserializer);
-        let mut var_worker1 = <Option<String>>::sse_decode(deserializer);
-        let mut var_pool2 = <Option<String>>::sse_decode(deserializer);
-        let mut var_worker2 = <Option<String>>::sse_decode(deserializer);
-        let mut var_pool3 = <Option<String>>::sse_decode(deserializer);
-        let mut var_worker3 = <Option<String>>::sse_decode(deserializer);
-        let mut var_model = <Option<String>>::sse_decode(deserializer);
-        let mut var_firmware = <Option<String>>::sse_decode(deserializer);
-        let mut var_software = <Option<String>>::sse_decode(deserializer);
-        let mut var_hardware = <Option<String>>::sse_decode(deserializer);
-        let mut var_macAddress = <Option<String>>::sse_decode(deserializer);
-        let mut var_powerMode = <Option<u8>>::sse_decode(deserializer);
-        return crate::core::models::MinerStats {
-            hashrate_rt: var_hashrateRt,
-            hashrate_avg: var_hashrateAvg,
-            temp_outlet_min: var_tempOutletMin,
-            temp_outlet_max: var_tempOutletMax,
-            temp_inlet_min: var_tempInletMin,
-            temp_inlet_max: var_tempInletMax,
-            fan_speeds: var_fanSpeeds,
-            uptime: var_uptime,
-            pool1: var_pool1,
-            worker1: var_worker1,
-            pool2: var_pool2,
-            worker2: var_worker2,
-            pool3: var_pool3,
-            worker3: var_worker3,
-            model: var_model,
-            firmware: var_firmware,
-            software: var_software,
-            hardware: var_hardware,
-            mac_address: var_macAddress,
-            power_mode: var_powerMode,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::backup::ConfigChange>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::core::models::MinerStatus {
+impl SseEncode for Vec<crate::core::config::CredentialSet> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::core::models::MinerStatus::Active,
-            1 => crate::core::models::MinerStatus::Warning,
-            2 => crate::core::models::MinerStatus::Dead,
-            3 => crate::core::models::MinerStatus::Scanning,
-            _ => unreachable!("Invalid variant for MinerStatus: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::config::CredentialSet>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<crate::core::config::DesiredState> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::config::DesiredState>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<f64> {
+impl SseEncode for Vec<crate::monitor::drift::Drift> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f64>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::monitor::drift::Drift>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::core::config::MinerCredentials> {
+impl SseEncode for Vec<crate::monitor::drift::DriftReport> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::core::config::MinerCredentials>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::monitor::drift::DriftReport>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<u32> {
+impl SseEncode for Vec<crate::core::error::ErrorKind> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::error::ErrorKind>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<u8> {
+impl SseEncode for Vec<crate::api::scanner::LocalInterface> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u8>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scanner::LocalInterface>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for crate::api::models::PoolConfig {
+impl SseEncode for Vec<crate::core::models::Miner> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_url = <String>::sse_decode(deserializer);
-        let mut var_worker = <String>::sse_decode(deserializer);
-        let mut var_password = <String>::sse_decode(deserializer);
-        return crate::api::models::PoolConfig {
-            url: var_url,
-            worker: var_worker,
-            password: var_password,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::models::Miner>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::models::PowerMode {
+impl SseEncode for Vec<crate::core::config::MinerLocation> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::models::PowerMode::Normal,
-            1 => crate::api::models::PowerMode::Lpm,
-            2 => crate::api::models::PowerMode::Sleep,
-            _ => unreachable!("Invalid variant for PowerMode: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::config::MinerLocation>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for Vec<crate::core::config::MinerTags> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::config::MinerTags>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for Vec<crate::core::config::NamedRange> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::config::NamedRange>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Vec<Option<f64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Option<f64>>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Vec<Option<u32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Option<u32>>::sse_encode(item, serializer);
+        }
+    }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__scanner__detect_local_ranges_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__commands__execute_batch_command_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__api__monitor__get_current_miners_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__commands__get_miner_pools_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__commands__set_miner_pools_impl(port, ptr, rust_vec_len, data_len),
-        10 => {
-            wire__crate__api__commands__set_miner_power_mode_impl(port, ptr, rust_vec_len, data_len)
+impl SseEncode for Vec<crate::core::config::OutletLink> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::config::OutletLink>::sse_encode(item, serializer);
         }
-        11 => wire__crate__api__monitor__start_monitoring_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__scanner__start_scan_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__monitor__stop_monitoring_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__commands__test_connection_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__scanner__validate_ip_range_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        3 => wire__crate__api__settings__get_app_settings_impl(ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__settings__save_app_settings_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::api::models::PoolConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::PoolConfig>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Section: rust2dart
+impl SseEncode for Vec<crate::api::pools::PoolDiff> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::pools::PoolDiff>::sse_encode(item, serializer);
+        }
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::config::AppSettings {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.antminer_credentials.into_into_dart().into_dart(),
-            self.whatsminer_credentials.into_into_dart().into_dart(),
-            self.scan_thread_count.into_into_dart().into_dart(),
-            self.monitor_interval.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Vec<crate::client::pools::PoolSlotChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::client::pools::PoolSlotChange>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::core::config::AppSettings
-{
+
+impl SseEncode for Vec<crate::core::config::PoolTemplate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::config::PoolTemplate>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::core::config::AppSettings>
-    for crate::core::config::AppSettings
-{
-    fn into_into_dart(self) -> crate::core::config::AppSettings {
-        self
+
+impl SseEncode for Vec<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u16>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::CommandResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ip.into_into_dart().into_dart(),
-            self.success.into_into_dart().into_dart(),
-            self.error.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::models::CommandResult
-{
+
+impl SseEncode for Vec<crate::client::rotation::RotationResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::client::rotation::RotationResult>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::CommandResult>
-    for crate::api::models::CommandResult
-{
-    fn into_into_dart(self) -> crate::api::models::CommandResult {
-        self
+
+impl SseEncode for Vec<crate::backup::SnapshotInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::backup::SnapshotInfo>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::models::Miner {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ip.into_into_dart().into_dart(),
-            self.model.into_into_dart().into_dart(),
-            self.status.into_into_dart().into_dart(),
-            self.stats.into_into_dart().into_dart(),
-            self.last_updated.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::pools::WorkerPreview> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::pools::WorkerPreview>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::core::models::Miner {}
-impl flutter_rust_bridge::IntoIntoDart<crate::core::models::Miner> for crate::core::models::Miner {
-    fn into_into_dart(self) -> crate::core::models::Miner {
-        self
+
+impl SseEncode for crate::api::scanner::LocalInterface {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.ip, serializer);
+        <String>::sse_encode(self.range, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::MinerCommand {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+
+impl SseEncode for crate::core::models::Miner {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::core::models::MinerId>>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.ip, serializer);
+        <Option<String>>::sse_encode(self.model, serializer);
+        <crate::core::models::MinerStatus>::sse_encode(self.status, serializer);
+        <crate::core::models::MinerStats>::sse_encode(self.stats, serializer);
+        <u64>::sse_encode(self.last_updated, serializer);
+        <Option<String>>::sse_encode(self.vendor_hint, serializer);
+        <Option<crate::core::models::PduOutlet>>::sse_encode(self.outlet, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::MinerCommand {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            crate::api::models::MinerCommand::Reboot => [0.into_dart()].into_dart(),
-            crate::api::models::MinerCommand::BlinkLed => [1.into_dart()].into_dart(),
-            crate::api::models::MinerCommand::StopBlink => [2.into_dart()].into_dart(),
+            crate::api::models::MinerCommand::Reboot => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::models::MinerCommand::BlinkLed => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::models::MinerCommand::StopBlink => {
+                <i32>::sse_encode(2, serializer);
+            }
             crate::api::models::MinerCommand::SetPools { pools } => {
-                [3.into_dart(), pools.into_into_dart().into_dart()].into_dart()
+                <i32>::sse_encode(3, serializer);
+                <Vec<crate::api::models::PoolConfig>>::sse_encode(pools, serializer);
+            }
+            crate::api::models::MinerCommand::ApplyConfig {
+                pools,
+                power_mode,
+                fan_mode,
+                freq_level,
+            } => {
+                <i32>::sse_encode(4, serializer);
+                <Option<Vec<crate::api::models::PoolConfig>>>::sse_encode(pools, serializer);
+                <Option<crate::api::models::PowerMode>>::sse_encode(power_mode, serializer);
+                <Option<crate::core::models::FanMode>>::sse_encode(fan_mode, serializer);
+                <Option<u32>>::sse_encode(freq_level, serializer);
+            }
+            crate::api::models::MinerCommand::RestoreConfig { snapshot_id } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(snapshot_id, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -1089,296 +5199,321 @@ impl flutter_rust_bridge::IntoDart for crate::api::models::MinerCommand {
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::models::MinerCommand
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::MinerCommand>
-    for crate::api::models::MinerCommand
-{
-    fn into_into_dart(self) -> crate::api::models::MinerCommand {
-        self
+
+impl SseEncode for crate::core::config::MinerCredentials {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.username, serializer);
+        <String>::sse_encode(self.password, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::config::MinerCredentials {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.username.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::core::models::MinerId {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.0, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::core::config::MinerCredentials
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::core::config::MinerCredentials>
-    for crate::core::config::MinerCredentials
-{
-    fn into_into_dart(self) -> crate::core::config::MinerCredentials {
-        self
+
+impl SseEncode for crate::core::config::MinerLocation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::core::models::MinerId>::sse_encode(self.miner_id, serializer);
+        <String>::sse_encode(self.rack, serializer);
+        <String>::sse_encode(self.position, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::models::MinerStats {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.hashrate_rt.into_into_dart().into_dart(),
-            self.hashrate_avg.into_into_dart().into_dart(),
-            self.temp_outlet_min.into_into_dart().into_dart(),
-            self.temp_outlet_max.into_into_dart().into_dart(),
-            self.temp_inlet_min.into_into_dart().into_dart(),
-            self.temp_inlet_max.into_into_dart().into_dart(),
-            self.fan_speeds.into_into_dart().into_dart(),
-            self.uptime.into_into_dart().into_dart(),
-            self.pool1.into_into_dart().into_dart(),
-            self.worker1.into_into_dart().into_dart(),
-            self.pool2.into_into_dart().into_dart(),
-            self.worker2.into_into_dart().into_dart(),
-            self.pool3.into_into_dart().into_dart(),
-            self.worker3.into_into_dart().into_dart(),
-            self.model.into_into_dart().into_dart(),
-            self.firmware.into_into_dart().into_dart(),
-            self.software.into_into_dart().into_dart(),
-            self.hardware.into_into_dart().into_dart(),
-            self.mac_address.into_into_dart().into_dart(),
-            self.power_mode.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::core::models::MinerStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.hashrate_rt, serializer);
+        <f64>::sse_encode(self.hashrate_avg, serializer);
+        <Vec<Option<f64>>>::sse_encode(self.temp_outlet_min, serializer);
+        <Vec<Option<f64>>>::sse_encode(self.temp_outlet_max, serializer);
+        <Vec<Option<f64>>>::sse_encode(self.temp_inlet_min, serializer);
+        <Vec<Option<f64>>>::sse_encode(self.temp_inlet_max, serializer);
+        <Vec<Option<u32>>>::sse_encode(self.fan_speeds, serializer);
+        <u64>::sse_encode(self.uptime, serializer);
+        <Option<String>>::sse_encode(self.pool1, serializer);
+        <Option<String>>::sse_encode(self.worker1, serializer);
+        <Option<String>>::sse_encode(self.pool2, serializer);
+        <Option<String>>::sse_encode(self.worker2, serializer);
+        <Option<String>>::sse_encode(self.pool3, serializer);
+        <Option<String>>::sse_encode(self.worker3, serializer);
+        <Option<String>>::sse_encode(self.model, serializer);
+        <Option<String>>::sse_encode(self.firmware, serializer);
+        <Option<String>>::sse_encode(self.software, serializer);
+        <Option<String>>::sse_encode(self.hardware, serializer);
+        <Option<String>>::sse_encode(self.mac_address, serializer);
+        <Option<String>>::sse_encode(self.serial_number, serializer);
+        <Option<String>>::sse_encode(self.hostname, serializer);
+        <Option<crate::core::models::NetworkMode>>::sse_encode(self.network_mode, serializer);
+        <Option<String>>::sse_encode(self.netmask, serializer);
+        <Option<String>>::sse_encode(self.gateway, serializer);
+        <Vec<String>>::sse_encode(self.dns_servers, serializer);
+        <Option<u8>>::sse_encode(self.power_mode, serializer);
+        <Option<crate::core::models::FanMode>>::sse_encode(self.fan_mode, serializer);
+        <Option<u32>>::sse_encode(self.freq_level, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::core::models::MinerStats
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::core::models::MinerStats>
-    for crate::core::models::MinerStats
-{
-    fn into_into_dart(self) -> crate::core::models::MinerStats {
-        self
+
+impl SseEncode for crate::core::models::MinerStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::core::models::MinerStatus::Active => 0,
+                crate::core::models::MinerStatus::Warning => 1,
+                crate::core::models::MinerStatus::Dead => 2,
+                crate::core::models::MinerStatus::Scanning => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::models::MinerStatus {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Active => 0.into_dart(),
-            Self::Warning => 1.into_dart(),
-            Self::Dead => 2.into_dart(),
-            Self::Scanning => 3.into_dart(),
-            _ => unreachable!(),
-        }
+
+impl SseEncode for crate::core::config::MinerTags {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::core::models::MinerId>::sse_encode(self.miner_id, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::core::models::MinerStatus
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::core::models::MinerStatus>
-    for crate::core::models::MinerStatus
-{
-    fn into_into_dart(self) -> crate::core::models::MinerStatus {
-        self
+
+impl SseEncode for crate::core::models::MinerVendor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::core::models::MinerVendor::Antminer => 0,
+                crate::core::models::MinerVendor::Whatsminer => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::PoolConfig {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.url.into_into_dart().into_dart(),
-            self.worker.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::core::config::NamedRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.spec, serializer);
+        <Option<String>>::sse_encode(self.source, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::models::PoolConfig
-{
+
+impl SseEncode for crate::core::models::NetworkMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::core::models::NetworkMode::Dhcp => 0,
+                crate::core::models::NetworkMode::Static => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::PoolConfig>
-    for crate::api::models::PoolConfig
-{
-    fn into_into_dart(self) -> crate::api::models::PoolConfig {
-        self
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::PowerMode {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Normal => 0.into_dart(),
-            Self::Lpm => 1.into_dart(),
-            Self::Sleep => 2.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Option<crate::core::error::ErrorKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::error::ErrorKind>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::models::PowerMode {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::PowerMode>
-    for crate::api::models::PowerMode
-{
-    fn into_into_dart(self) -> crate::api::models::PowerMode {
-        self
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for Option<crate::core::models::FanMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::models::FanMode>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Option<crate::core::config::MinerCredentials> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::config::MinerCredentials>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::core::config::AppSettings {
+impl SseEncode for Option<crate::core::models::MinerId> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::core::config::MinerCredentials>::sse_encode(self.antminer_credentials, serializer);
-        <crate::core::config::MinerCredentials>::sse_encode(
-            self.whatsminer_credentials,
-            serializer,
-        );
-        <u32>::sse_encode(self.scan_thread_count, serializer);
-        <u64>::sse_encode(self.monitor_interval, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::models::MinerId>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Option<crate::core::models::MinerVendor> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::models::MinerVendor>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::models::CommandResult {
+impl SseEncode for Option<crate::core::models::NetworkMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.ip, serializer);
-        <bool>::sse_encode(self.success, serializer);
-        <Option<String>>::sse_encode(self.error, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::models::NetworkMode>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for Option<crate::core::models::PduOutlet> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::core::models::PduOutlet>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Option<crate::api::models::PoolConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::PoolConfig>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Option<crate::api::models::PowerMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::PowerMode>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::models::CommandResult> {
+impl SseEncode for Option<crate::client::retry::RetryPolicy> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::models::CommandResult>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::client::retry::RetryPolicy>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::core::models::Miner> {
+impl SseEncode for Option<crate::backup::SnapshotInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::core::models::Miner>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::backup::SnapshotInfo>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<Option<f64>> {
+impl SseEncode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<f64>>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<Option<u32>> {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<u32>>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::models::PoolConfig> {
+impl SseEncode for Option<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::models::PoolConfig>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u8>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Option<Vec<crate::api::models::PoolConfig>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::api::models::PoolConfig>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for crate::core::models::Miner {
+impl SseEncode for crate::core::config::OutletLink {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.ip, serializer);
-        <Option<String>>::sse_encode(self.model, serializer);
-        <crate::core::models::MinerStatus>::sse_encode(self.status, serializer);
-        <crate::core::models::MinerStats>::sse_encode(self.stats, serializer);
-        <u64>::sse_encode(self.last_updated, serializer);
+        <crate::core::models::MinerId>::sse_encode(self.miner_id, serializer);
+        <crate::core::models::PduOutlet>::sse_encode(self.outlet, serializer);
     }
 }
 
-impl SseEncode for crate::api::models::MinerCommand {
+impl SseEncode for crate::client::rotation::PasswordPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            crate::api::models::MinerCommand::Reboot => {
+            crate::client::rotation::PasswordPolicy::Generate { length } => {
                 <i32>::sse_encode(0, serializer);
+                <u32>::sse_encode(length, serializer);
             }
-            crate::api::models::MinerCommand::BlinkLed => {
+            crate::client::rotation::PasswordPolicy::Fixed { password } => {
                 <i32>::sse_encode(1, serializer);
-            }
-            crate::api::models::MinerCommand::StopBlink => {
-                <i32>::sse_encode(2, serializer);
-            }
-            crate::api::models::MinerCommand::SetPools { pools } => {
-                <i32>::sse_encode(3, serializer);
-                <Vec<crate::api::models::PoolConfig>>::sse_encode(pools, serializer);
+                <String>::sse_encode(password, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -1387,49 +5522,59 @@ impl SseEncode for crate::api::models::MinerCommand {
     }
 }
 
-impl SseEncode for crate::core::config::MinerCredentials {
+impl SseEncode for crate::core::models::PduOutlet {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.username, serializer);
+        <String>::sse_encode(self.pdu_ip, serializer);
+        <u32>::sse_encode(self.outlet, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::PoolConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.worker, serializer);
         <String>::sse_encode(self.password, serializer);
     }
 }
 
-impl SseEncode for crate::core::models::MinerStats {
+impl SseEncode for crate::api::pools::PoolDiff {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <f64>::sse_encode(self.hashrate_rt, serializer);
-        <f64>::sse_encode(self.hashrate_avg, serializer);
-        <Vec<Option<f64>>>::sse_encode(self.temp_outlet_min, serializer);
-        <Vec<Option<f64>>>::sse_encode(self.temp_outlet_max, serializer);
-        <Vec<Option<f64>>>::sse_encode(self.temp_inlet_min, serializer);
-        <Vec<Option<f64>>>::sse_encode(self.temp_inlet_max, serializer);
-        <Vec<Option<u32>>>::sse_encode(self.fan_speeds, serializer);
-        <u64>::sse_encode(self.uptime, serializer);
-        <Option<String>>::sse_encode(self.pool1, serializer);
-        <Option<String>>::sse_encode(self.worker1, serializer);
-        <Option<String>>::sse_encode(self.pool2, serializer);
-        <Option<String>>::sse_encode(self.worker2, serializer);
-        <Option<String>>::sse_encode(self.pool3, serializer);
-        <Option<String>>::sse_encode(self.worker3, serializer);
-        <Option<String>>::sse_encode(self.model, serializer);
-        <Option<String>>::sse_encode(self.firmware, serializer);
-        <Option<String>>::sse_encode(self.software, serializer);
-        <Option<String>>::sse_encode(self.hardware, serializer);
-        <Option<String>>::sse_encode(self.mac_address, serializer);
-        <Option<u8>>::sse_encode(self.power_mode, serializer);
+        <String>::sse_encode(self.ip, serializer);
+        <Option<crate::core::models::MinerId>>::sse_encode(self.miner_id, serializer);
+        <Vec<crate::client::pools::PoolSlotChange>>::sse_encode(self.changes, serializer);
+        <bool>::sse_encode(self.passwords_compared, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode for crate::core::models::MinerStatus {
+impl SseEncode for crate::client::pools::PoolSlotChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.slot, serializer);
+        <Option<crate::api::models::PoolConfig>>::sse_encode(self.current, serializer);
+        <Option<crate::api::models::PoolConfig>>::sse_encode(self.intended, serializer);
+    }
+}
+
+impl SseEncode for crate::core::config::PoolTemplate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <Vec<crate::api::models::PoolConfig>>::sse_encode(self.pools, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::PowerMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::core::models::MinerStatus::Active => 0,
-                crate::core::models::MinerStatus::Warning => 1,
-                crate::core::models::MinerStatus::Dead => 2,
-                crate::core::models::MinerStatus::Scanning => 3,
+                crate::api::models::PowerMode::Normal => 0,
+                crate::api::models::PowerMode::Lpm => 1,
+                crate::api::models::PowerMode::Sleep => 2,
                 _ => {
                     unimplemented!("");
                 }
@@ -1439,79 +5584,154 @@ impl SseEncode for crate::core::models::MinerStatus {
     }
 }
 
-impl SseEncode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
-    }
-}
-
-impl SseEncode for Option<f64> {
+impl SseEncode for crate::client::retry::RetryPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <f64>::sse_encode(value, serializer);
-        }
+        <u32>::sse_encode(self.max_attempts, serializer);
+        <u64>::sse_encode(self.initial_backoff_ms, serializer);
+        <u64>::sse_encode(self.max_backoff_ms, serializer);
+        <Vec<crate::core::error::ErrorKind>>::sse_encode(self.retry_on, serializer);
+        <u64>::sse_encode(self.min_uptime_secs, serializer);
     }
 }
 
-impl SseEncode for Option<crate::core::config::MinerCredentials> {
+impl SseEncode for crate::client::rotation::RotationOutcome {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::core::config::MinerCredentials>::sse_encode(value, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::client::rotation::RotationOutcome::Rotated => 0,
+                crate::client::rotation::RotationOutcome::AlreadyCurrent => 1,
+                crate::client::rotation::RotationOutcome::Failed => 2,
+                crate::client::rotation::RotationOutcome::RolledBack => 3,
+                crate::client::rotation::RotationOutcome::NeedsAttention => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Option<u32> {
+impl SseEncode for crate::client::rotation::RotationResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.ip, serializer);
+        <Option<crate::core::models::MinerId>>::sse_encode(self.miner_id, serializer);
+        <crate::client::rotation::RotationOutcome>::sse_encode(self.outcome, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
+        <Option<crate::core::error::ErrorKind>>::sse_encode(self.error_kind, serializer);
     }
 }
 
-impl SseEncode for Option<u8> {
+impl SseEncode for crate::scanner::ScanEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u8>::sse_encode(value, serializer);
+        match self {
+            crate::scanner::ScanEvent::Started { total_ips } => {
+                <i32>::sse_encode(0, serializer);
+                <usize>::sse_encode(total_ips, serializer);
+            }
+            crate::scanner::ScanEvent::Found(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <crate::core::models::Miner>::sse_encode(field0, serializer);
+            }
+            crate::scanner::ScanEvent::Progress { scanned, total } => {
+                <i32>::sse_encode(2, serializer);
+                <usize>::sse_encode(scanned, serializer);
+                <usize>::sse_encode(total, serializer);
+            }
+            crate::scanner::ScanEvent::Complete { found, failed } => {
+                <i32>::sse_encode(3, serializer);
+                <usize>::sse_encode(found, serializer);
+                <usize>::sse_encode(failed, serializer);
+            }
+            crate::scanner::ScanEvent::Cancelled { scanned, found } => {
+                <i32>::sse_encode(4, serializer);
+                <usize>::sse_encode(scanned, serializer);
+                <usize>::sse_encode(found, serializer);
+            }
+            crate::scanner::ScanEvent::MinerMoved { id, from_ip, to_ip } => {
+                <i32>::sse_encode(5, serializer);
+                <crate::core::models::MinerId>::sse_encode(id, serializer);
+                <String>::sse_encode(from_ip, serializer);
+                <String>::sse_encode(to_ip, serializer);
+            }
+            crate::scanner::ScanEvent::IpTakenOver {
+                ip,
+                previous,
+                current,
+            } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(ip, serializer);
+                <crate::core::models::MinerId>::sse_encode(previous, serializer);
+                <crate::core::models::MinerId>::sse_encode(current, serializer);
+            }
+            crate::scanner::ScanEvent::NonMiner(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <crate::core::models::Device>::sse_encode(field0, serializer);
+            }
+            crate::scanner::ScanEvent::DuplicateIp {
+                ip,
+                mac_addresses,
+                serial_numbers,
+            } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(ip, serializer);
+                <Vec<String>>::sse_encode(mac_addresses, serializer);
+                <Vec<String>>::sse_encode(serial_numbers, serializer);
+            }
+            crate::scanner::ScanEvent::FactoryDefaultIp {
+                ip,
+                id,
+                mac_address,
+            } => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(ip, serializer);
+                <Option<crate::core::models::MinerId>>::sse_encode(id, serializer);
+                <Option<String>>::sse_encode(mac_address, serializer);
+            }
+            crate::scanner::ScanEvent::NetworkMismatch {
+                ip,
+                id,
+                netmask,
+                gateway,
+                expected_subnet,
+                reason,
+            } => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(ip, serializer);
+                <Option<crate::core::models::MinerId>>::sse_encode(id, serializer);
+                <Option<String>>::sse_encode(netmask, serializer);
+                <Option<String>>::sse_encode(gateway, serializer);
+                <Option<String>>::sse_encode(expected_subnet, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
-impl SseEncode for crate::api::models::PoolConfig {
+impl SseEncode for crate::backup::SnapshotInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.url, serializer);
-        <String>::sse_encode(self.worker, serializer);
-        <String>::sse_encode(self.password, serializer);
+        <String>::sse_encode(self.id, serializer);
+        <crate::core::models::MinerId>::sse_encode(self.miner_id, serializer);
+        <String>::sse_encode(self.ip, serializer);
+        <crate::core::models::MinerVendor>::sse_encode(self.vendor, serializer);
+        <Option<String>>::sse_encode(self.model, serializer);
+        <u64>::sse_encode(self.taken_at, serializer);
+        <Option<String>>::sse_encode(self.note, serializer);
     }
 }
 
-impl SseEncode for crate::api::models::PowerMode {
+impl SseEncode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::models::PowerMode::Normal => 0,
-                crate::api::models::PowerMode::Lpm => 1,
-                crate::api::models::PowerMode::Sleep => 2,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
     }
 }
 
@@ -1541,6 +5761,34 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_u64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
+impl SseEncode for crate::core::vault::VaultStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.exists, serializer);
+        <bool>::sse_encode(self.unlocked, serializer);
+    }
+}
+
+impl SseEncode for crate::api::pools::WorkerPreview {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.ip, serializer);
+        <Option<crate::core::models::MinerId>>::sse_encode(self.miner_id, serializer);
+        <Vec<String>>::sse_encode(self.workers, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -0,0 +1,375 @@
+//! Cached miner identities (vendor, firmware family, capabilities).
+//!
+//! Commands need to know which web API a miner speaks before they can do
+//! anything.  The scanner and monitor already learn that on every pass, so
+//! they record it here; command paths read it back and only fall back to a
+//! one-shot `summary` probe on a cache miss.  An entry is re-detected once
+//! it is older than `IDENTITY_TTL_SECS`, after `MAX_FAILURES` failed commands
+//! in a row, or when another device takes over its address.
+//!
+//! The module also keeps the IP <-> `MinerId` mapping, so a miner that DHCP
+//! moves to a new address is recognised as the same box.
+
+use crate::client::{self, MinerReport, DEFAULT_PORT, DEFAULT_TIMEOUT_MS};
+use crate::core::{FirmwareFamily, MinerError, MinerId, MinerVendor, Result};
+use dashmap::DashMap;
+use std::time::SystemTime;
+
+pub mod oui;
+
+/// Cached identities older than this are re-detected before use
+pub const IDENTITY_TTL_SECS: u64 = 15 * 60;
+
+/// Failed commands in a row after which a cached identity is dropped
+pub const MAX_FAILURES: u32 = 3;
+
+/// What a miner can do beyond the basic CGMiner API
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinerCapabilities {
+    /// Answers joined commands like `summary+stats` in one round trip
+    pub joined_commands: bool,
+    /// Current power mode can be read back (Antminer `bitmain-work-mode`)
+    pub power_mode_readback: bool,
+}
+
+impl MinerCapabilities {
+    fn for_miner(vendor: MinerVendor, joined_commands: bool) -> Self {
+        Self {
+            joined_commands,
+            power_mode_readback: vendor == MinerVendor::Antminer,
+        }
+    }
+}
+
+/// Everything we know about which kind of miner sits at an address
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinerIdentity {
+    pub ip: String,
+    pub mac_address: Option<String>,
+    pub vendor: MinerVendor,
+    pub firmware_family: FirmwareFamily,
+    pub capabilities: MinerCapabilities,
+    /// Unix timestamp of the detection this entry came from
+    pub detected_at: u64,
+}
+
+impl MinerIdentity {
+    /// Whether the detection is recent enough to act on at `now`
+    pub fn is_fresh(&self, now: u64) -> bool {
+        now.saturating_sub(self.detected_at) < IDENTITY_TTL_SECS
+    }
+}
+
+/// Identity cache indexed by IP and, once known, by MAC address.
+#[derive(Debug, Default)]
+pub struct IdentityCache {
+    by_ip: DashMap<String, MinerIdentity>,
+    by_mac: DashMap<String, MinerIdentity>,
+    /// Failed commands in a row per IP
+    failures: DashMap<String, u32>,
+}
+
+impl IdentityCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an identity, replacing any previous entry for its IP and MAC.
+    pub fn insert(&self, identity: MinerIdentity) {
+        if let Some(mac) = &identity.mac_address {
+            self.by_mac.insert(mac.clone(), identity.clone());
+        }
+        self.failures.remove(&identity.ip);
+        self.by_ip.insert(identity.ip.clone(), identity);
+    }
+
+    /// Look up by IP.
+    pub fn get(&self, ip: &str) -> Option<MinerIdentity> {
+        self.by_ip.get(ip).map(|entry| entry.clone())
+    }
+
+    /// Look up by MAC address (`AA:BB:CC:DD:EE:FF`).  The returned identity
+    /// carries the IP the device was last seen at.
+    pub fn get_by_mac(&self, mac: &str) -> Option<MinerIdentity> {
+        self.by_mac.get(mac).map(|entry| entry.clone())
+    }
+
+    /// Count a command on `ip` that worked or failed; the entry is dropped
+    /// after `MAX_FAILURES` failures in a row, in case the vendor is wrong.
+    /// Returns whether it was dropped.
+    pub fn record_outcome(&self, ip: &str, ok: bool) -> bool {
+        if ok {
+            self.failures.remove(ip);
+            return false;
+        }
+        let failures = {
+            let mut count = self.failures.entry(ip.to_string()).or_insert(0);
+            *count += 1;
+            *count
+        };
+        if failures >= MAX_FAILURES {
+            self.remove(ip);
+            return true;
+        }
+        false
+    }
+
+    /// Drop the entry for `ip` so the next lookup re-detects it.
+    pub fn remove(&self, ip: &str) {
+        self.failures.remove(ip);
+        if let Some((_, identity)) = self.by_ip.remove(ip) {
+            if let Some(mac) = identity.mac_address {
+                self.by_mac.remove_if(&mac, |_, entry| entry.ip == ip);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_ip.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_ip.is_empty()
+    }
+}
+
+/// What changed in the IP <-> id mapping after an observation
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdentityChange {
+    /// A known miner now answers at a different address
+    Moved { id: MinerId, from_ip: String, to_ip: String },
+    /// A different device now answers at an address that belonged to `previous`
+    TakenOver { ip: String, previous: MinerId, current: MinerId },
+}
+
+/// Two-way mapping between addresses and miner ids.
+#[derive(Debug, Default)]
+pub struct AddressBook {
+    ip_by_id: DashMap<MinerId, String>,
+    id_by_ip: DashMap<String, MinerId>,
+}
+
+impl AddressBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `id` answered at `ip` and report what that changed.
+    pub fn observe(&self, ip: &str, id: &MinerId) -> Vec<IdentityChange> {
+        let mut changes = Vec::new();
+
+        if let Some(previous) = self.id_at(ip) {
+            if &previous != id {
+                // The previous owner is no longer known to be anywhere
+                self.ip_by_id.remove_if(&previous, |_, owned_ip| owned_ip == ip);
+                changes.push(IdentityChange::TakenOver {
+                    ip: ip.to_string(),
+                    previous,
+                    current: id.clone(),
+                });
+            }
+        }
+
+        if let Some(from_ip) = self.ip_of(id) {
+            if from_ip != ip {
+                self.id_by_ip.remove_if(&from_ip, |_, owner| owner == id);
+                changes.push(IdentityChange::Moved {
+                    id: id.clone(),
+                    from_ip,
+                    to_ip: ip.to_string(),
+                });
+            }
+        }
+
+        self.ip_by_id.insert(id.clone(), ip.to_string());
+        self.id_by_ip.insert(ip.to_string(), id.clone());
+        changes
+    }
+
+    /// Last address `id` answered at.
+    pub fn ip_of(&self, id: &MinerId) -> Option<String> {
+        self.ip_by_id.get(id).map(|entry| entry.clone())
+    }
+
+    /// Id of the device last seen at `ip`.
+    pub fn id_at(&self, ip: &str) -> Option<MinerId> {
+        self.id_by_ip.get(ip).map(|entry| entry.clone())
+    }
+}
+
+lazy_static::lazy_static! {
+    /// Process-wide identity cache shared by scanner, monitor and commands.
+    pub static ref IDENTITIES: IdentityCache = IdentityCache::new();
+
+    /// Process-wide IP <-> id mapping, updated on every scan and poll.
+    pub static ref ADDRESSES: AddressBook = AddressBook::new();
+}
+
+/// Record in `ADDRESSES` that `id` answered at `ip`.  A takeover drops the
+/// cached identity of `ip`: it may still carry the previous device's MAC.
+pub fn observe(ip: &str, id: &MinerId) -> Vec<IdentityChange> {
+    let changes = ADDRESSES.observe(ip, id);
+    if changes.iter().any(|change| matches!(change, IdentityChange::TakenOver { .. })) {
+        println!("[identity] {} taken over by {}, re-detecting", ip, id);
+        IDENTITIES.remove(ip);
+    }
+    changes
+}
+
+/// Record what a full stats fetch revealed about the miner at `ip:port`.
+pub fn record_report(ip: &str, port: u16, report: &MinerReport) -> MinerIdentity {
+    let identity = MinerIdentity {
+        ip: ip.to_string(),
+        // Scans identify without a MAC lookup; keep one learned earlier
+        mac_address: report
+            .stats
+            .mac_address
+            .clone()
+            .or_else(|| IDENTITIES.get(ip).and_then(|previous| previous.mac_address)),
+        vendor: report.vendor,
+        firmware_family: report.firmware_family,
+        capabilities: MinerCapabilities::for_miner(report.vendor, client::supports_joined_commands(ip, port)),
+        detected_at: current_timestamp(),
+    };
+    IDENTITIES.insert(identity.clone());
+    identity
+}
+
+/// Cached identity for `ip`, detecting it with a `summary` probe on a miss
+/// or when the entry is older than `IDENTITY_TTL_SECS`.
+///
+/// Fails with `MinerError::DetectionFailed` if the miner can't be reached or
+/// doesn't answer like a miner — callers must not guess a vendor.
+pub async fn resolve(ip: &str) -> Result<MinerIdentity> {
+    if let Some(identity) = IDENTITIES.get(ip).filter(|identity| identity.is_fresh(current_timestamp())) {
+        return Ok(identity);
+    }
+    detect(ip, DEFAULT_PORT, DEFAULT_TIMEOUT_MS).await
+}
+
+/// Detect and cache the identity of the miner at `ip:port`, ignoring the cache.
+pub async fn detect(ip: &str, port: u16, timeout_ms: u64) -> Result<MinerIdentity> {
+    let (vendor, firmware_family) = client::detect_vendor(ip, port, timeout_ms)
+        .await
+        .map_err(|e| MinerError::DetectionFailed { ip: ip.to_string(), source: Box::new(e) })?;
+
+    // Keep a MAC learned by an earlier full fetch
+    let mac_address = IDENTITIES.get(ip).and_then(|previous| previous.mac_address);
+
+    let identity = MinerIdentity {
+        ip: ip.to_string(),
+        mac_address,
+        vendor,
+        firmware_family,
+        capabilities: MinerCapabilities::for_miner(vendor, client::supports_joined_commands(ip, port)),
+        detected_at: current_timestamp(),
+    };
+    IDENTITIES.insert(identity.clone());
+    Ok(identity)
+}
+
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity(ip: &str, mac: Option<&str>, vendor: MinerVendor) -> MinerIdentity {
+        MinerIdentity {
+            ip: ip.to_string(),
+            mac_address: mac.map(str::to_string),
+            vendor,
+            firmware_family: FirmwareFamily::Unknown,
+            capabilities: MinerCapabilities::for_miner(vendor, true),
+            detected_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_lookup_by_ip_and_mac() {
+        let cache = IdentityCache::new();
+        cache.insert(identity("10.0.0.5", Some("AA:BB:CC:00:00:01"), MinerVendor::Whatsminer));
+
+        assert_eq!(cache.get("10.0.0.5").unwrap().vendor, MinerVendor::Whatsminer);
+        assert_eq!(cache.get_by_mac("AA:BB:CC:00:00:01").unwrap().ip, "10.0.0.5");
+        assert!(cache.get("10.0.0.6").is_none());
+    }
+
+    #[test]
+    fn test_mac_follows_new_ip() {
+        let cache = IdentityCache::new();
+        cache.insert(identity("10.0.0.5", Some("AA:BB:CC:00:00:01"), MinerVendor::Antminer));
+        cache.insert(identity("10.0.0.9", Some("AA:BB:CC:00:00:01"), MinerVendor::Antminer));
+
+        assert_eq!(cache.get_by_mac("AA:BB:CC:00:00:01").unwrap().ip, "10.0.0.9");
+
+        // Removing the stale IP must not drop the MAC entry of the new one
+        cache.remove("10.0.0.5");
+        assert_eq!(cache.get_by_mac("AA:BB:CC:00:00:01").unwrap().ip, "10.0.0.9");
+
+        cache.remove("10.0.0.9");
+        assert!(cache.get_by_mac("AA:BB:CC:00:00:01").is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_freshness_and_failures() {
+        let cache = IdentityCache::new();
+        let mut fresh = identity("10.0.0.5", Some("AA:BB:CC:00:00:01"), MinerVendor::Antminer);
+        fresh.detected_at = 10_000;
+        assert!(fresh.is_fresh(10_000 + IDENTITY_TTL_SECS - 1));
+        assert!(!fresh.is_fresh(10_000 + IDENTITY_TTL_SECS));
+
+        cache.insert(fresh);
+        for _ in 1..MAX_FAILURES {
+            assert!(!cache.record_outcome("10.0.0.5", false));
+        }
+        // A success in between starts the count over
+        cache.record_outcome("10.0.0.5", true);
+        for _ in 1..MAX_FAILURES {
+            assert!(!cache.record_outcome("10.0.0.5", false));
+        }
+        assert!(cache.record_outcome("10.0.0.5", false));
+        assert!(cache.get("10.0.0.5").is_none());
+        assert!(cache.get_by_mac("AA:BB:CC:00:00:01").is_none());
+    }
+
+    #[test]
+    fn test_address_book_moves_and_takeovers() {
+        let book = AddressBook::new();
+        let a = MinerId::from_mac("AA:BB:CC:00:00:01").unwrap();
+        let b = MinerId::from_mac("AA:BB:CC:00:00:02").unwrap();
+
+        assert!(book.observe("10.0.0.5", &a).is_empty());
+        assert!(book.observe("10.0.0.5", &a).is_empty());
+
+        // DHCP hands `a` a new address
+        assert_eq!(
+            book.observe("10.0.0.9", &a),
+            vec![IdentityChange::Moved { id: a.clone(), from_ip: "10.0.0.5".into(), to_ip: "10.0.0.9".into() }]
+        );
+        assert_eq!(book.id_at("10.0.0.5"), None);
+
+        // ...and `b`, never seen before, takes over `a`'s address
+        assert_eq!(
+            book.observe("10.0.0.9", &b),
+            vec![IdentityChange::TakenOver { ip: "10.0.0.9".into(), previous: a.clone(), current: b.clone() }]
+        );
+        assert_eq!(book.ip_of(&a), None);
+        assert_eq!(book.ip_of(&b).as_deref(), Some("10.0.0.9"));
+
+        // `a` reappearing elsewhere is not a move from its lost address
+        assert!(book.observe("10.0.0.12", &a).is_empty());
+    }
+
+    #[test]
+    fn test_capabilities() {
+        assert!(MinerCapabilities::for_miner(MinerVendor::Antminer, true).power_mode_readback);
+        assert!(!MinerCapabilities::for_miner(MinerVendor::Whatsminer, false).power_mode_readback);
+    }
+}
@@ -0,0 +1,132 @@
+//! MAC OUI (first three octets) table for telling miners from the other
+//! devices on a mining network.
+//!
+//! Scans across mixed VLANs find switches, PDUs and cameras that happen to
+//! accept TCP on a miner port.  Looking the MAC up here lets the scanner pick
+//! the miner driver before sending anything and tell what the devices that
+//! don't answer as miners are.  Vendors register new blocks regularly;
+//! extend the table from the IEEE MA-L registry as they show up.
+
+use crate::core::MinerVendor;
+
+/// What kind of device a vendor's OUI usually belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceClass {
+    Miner,
+    /// Switches, routers, access points
+    Network,
+    /// PDUs and UPSes
+    Power,
+    Camera,
+    /// Single-board computers and other hosts
+    Computer,
+}
+
+/// A vendor block from the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OuiVendor {
+    /// Display name, used as the `vendor_hint` of scan results
+    pub name: &'static str,
+    pub class: DeviceClass,
+    /// Driver to use for this vendor's miners, if we have one
+    pub driver: Option<MinerVendor>,
+}
+
+const BITMAIN: OuiVendor = OuiVendor { name: "Bitmain", class: DeviceClass::Miner, driver: Some(MinerVendor::Antminer) };
+const MICROBT: OuiVendor = OuiVendor { name: "MicroBT", class: DeviceClass::Miner, driver: Some(MinerVendor::Whatsminer) };
+const CANAAN: OuiVendor = OuiVendor { name: "Canaan", class: DeviceClass::Miner, driver: None };
+const UBIQUITI: OuiVendor = OuiVendor { name: "Ubiquiti", class: DeviceClass::Network, driver: None };
+const MIKROTIK: OuiVendor = OuiVendor { name: "MikroTik", class: DeviceClass::Network, driver: None };
+const CISCO: OuiVendor = OuiVendor { name: "Cisco", class: DeviceClass::Network, driver: None };
+const TP_LINK: OuiVendor = OuiVendor { name: "TP-Link", class: DeviceClass::Network, driver: None };
+const APC: OuiVendor = OuiVendor { name: "APC", class: DeviceClass::Power, driver: None };
+const RARITAN: OuiVendor = OuiVendor { name: "Raritan", class: DeviceClass::Power, driver: None };
+const EATON: OuiVendor = OuiVendor { name: "Eaton", class: DeviceClass::Power, driver: None };
+const HIKVISION: OuiVendor = OuiVendor { name: "Hikvision", class: DeviceClass::Camera, driver: None };
+const DAHUA: OuiVendor = OuiVendor { name: "Dahua", class: DeviceClass::Camera, driver: None };
+const RASPBERRY_PI: OuiVendor = OuiVendor { name: "Raspberry Pi", class: DeviceClass::Computer, driver: None };
+
+/// OUI -> vendor, sorted by OUI for binary search
+static OUI_TABLE: &[([u8; 3], OuiVendor)] = &[
+    ([0x00, 0x00, 0x0C], CISCO),
+    ([0x00, 0x0D, 0x5D], RARITAN),
+    ([0x00, 0x20, 0x85], EATON),
+    ([0x00, 0xC0, 0xB7], APC),
+    ([0x14, 0xCC, 0x20], TP_LINK),
+    ([0x24, 0xA4, 0x3C], UBIQUITI),
+    ([0x28, 0x29, 0x86], APC),
+    ([0x28, 0xCD, 0xC1], RASPBERRY_PI),
+    ([0x2C, 0xC8, 0x1B], MIKROTIK),
+    ([0x3C, 0xEF, 0x8C], DAHUA),
+    ([0x44, 0x19, 0xB6], HIKVISION),
+    ([0x44, 0xD9, 0xE7], UBIQUITI),
+    ([0x48, 0x8F, 0x5A], MIKROTIK),
+    ([0x4C, 0x5E, 0x0C], MIKROTIK),
+    ([0x4C, 0xBD, 0x8F], HIKVISION),
+    ([0x50, 0xC7, 0xBF], TP_LINK),
+    ([0x60, 0xE3, 0x27], TP_LINK),
+    ([0x68, 0x72, 0x51], UBIQUITI),
+    ([0x6C, 0x3B, 0x6B], MIKROTIK),
+    ([0x74, 0x83, 0xC2], UBIQUITI),
+    ([0x78, 0x8A, 0x20], UBIQUITI),
+    ([0x80, 0x2A, 0xA8], UBIQUITI),
+    ([0x90, 0x02, 0xA9], DAHUA),
+    ([0x98, 0xDA, 0xC4], TP_LINK),
+    ([0xB4, 0x10, 0x7B], BITMAIN),
+    ([0xB4, 0xFB, 0xE4], UBIQUITI),
+    ([0xB8, 0x27, 0xEB], RASPBERRY_PI),
+    ([0xB8, 0x69, 0xF4], MIKROTIK),
+    ([0xBC, 0xAD, 0x28], HIKVISION),
+    ([0xC0, 0x56, 0xE3], HIKVISION),
+    ([0xC4, 0x08, 0x4A], MICROBT),
+    ([0xC8, 0x52, 0x61], CANAAN),
+    ([0xCC, 0x2D, 0xE0], MIKROTIK),
+    ([0xD4, 0xCA, 0x6D], MIKROTIK),
+    ([0xD8, 0x3A, 0xDD], RASPBERRY_PI),
+    ([0xDC, 0xA6, 0x32], RASPBERRY_PI),
+    ([0xE0, 0x50, 0x8B], DAHUA),
+    ([0xE0, 0x63, 0xDA], UBIQUITI),
+    ([0xE4, 0x5F, 0x01], RASPBERRY_PI),
+    ([0xE4, 0x8D, 0x8C], MIKROTIK),
+    ([0xF0, 0x9F, 0xC2], UBIQUITI),
+    ([0xFC, 0xEC, 0xDA], UBIQUITI),
+];
+
+/// Vendor of the device with MAC `mac` (`AA:BB:CC:DD:EE:FF` or `aa-bb-…`).
+/// Locally administered addresses carry no vendor and return `None`.
+pub fn lookup(mac: &str) -> Option<OuiVendor> {
+    let mut octets = mac.split([':', '-']).map(|part| u8::from_str_radix(part, 16));
+    let oui = [octets.next()?.ok()?, octets.next()?.ok()?, octets.next()?.ok()?];
+    if oui[0] & 0x02 != 0 {
+        return None;
+    }
+    OUI_TABLE
+        .binary_search_by(|(prefix, _)| prefix.cmp(&oui))
+        .ok()
+        .map(|index| OUI_TABLE[index].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_sorted() {
+        assert!(OUI_TABLE.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_lookup() {
+        let bitmain = lookup("B4:10:7B:01:02:03").unwrap();
+        assert_eq!(bitmain.driver, Some(MinerVendor::Antminer));
+
+        let whatsminer = lookup("c4-08-4a-01-02-03").unwrap();
+        assert_eq!(whatsminer.driver, Some(MinerVendor::Whatsminer));
+
+        assert_eq!(lookup("00:C0:B7:11:22:33").unwrap().class, DeviceClass::Power);
+        assert_eq!(lookup("00:11:22:33:44:55"), None);
+        // Locally administered: no vendor, even if the rest matches
+        assert_eq!(lookup("02:1A:2B:3C:4D:5E"), None);
+        assert_eq!(lookup("not a mac"), None);
+    }
+}
@@ -3,10 +3,13 @@ pub mod core;
 pub mod client;
 pub mod scanner;
 pub mod monitor;
+pub mod identity;
+pub mod devices;
+pub mod backup;
 pub mod api;
 pub mod utils;
 
-pub use core::{Miner, MinerStats, MinerStatus, MinerError, Result};
-pub use client::{send_command, get_summary, DEFAULT_PORT, DEFAULT_TIMEOUT_MS};
-pub use scanner::{scan_range, parse_ip_range, ScanEvent, ScanConfig};
-pub use monitor::{start_monitor, MonitorEvent, MonitorConfig};
+pub use core::{Miner, MinerId, MinerStats, MinerStatus, MinerError, Result};
+pub use client::{send_command, send_command_with_options, send_commands, get_summary, RequestOptions, DEFAULT_PORT, DEFAULT_TIMEOUT_MS};
+pub use scanner::{scan_range, scan_range_cancellable, scan_spec_cancellable, parse_ip_range, ScanEvent, ScanConfig, ScanHandle};
+pub use monitor::{start_monitor, start_monitor_with_settings, MonitorEvent, MonitorConfig};
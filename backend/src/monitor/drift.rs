@@ -0,0 +1,252 @@
+//! Comparing miners with the desired state of their group.
+//!
+//! Each poll, the monitor checks a miner against the most specific
+//! `DesiredState` covering it (see `AppSettings::desired_states`): pools and
+//! workers from the CGMiner API, and for Antminers power mode, fan control
+//! and frequency level from the miner conf.  A value the miner didn't report
+//! isn't counted as drift.  `MonitorEvent::DriftDetected` is sent when a
+//! miner's drift changes, `MonitorEvent::DriftResolved` when it is gone.
+//!
+//! `corrective_command` turns a drift into one `MinerCommand::ApplyConfig`
+//! for the batch executor.
+
+use crate::api::models::{MinerCommand, PoolConfig, PowerMode};
+use crate::client::pools::{self, CurrentPools};
+use crate::client::worker_name::{self, WorkerFacts};
+use crate::core::config::{AppSettings, DesiredState, MinerLocation};
+use crate::core::{FanMode, Miner, MinerId, MinerStats};
+use std::collections::HashMap;
+
+/// A setting a policy can pin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftField {
+    Pools,
+    PowerMode,
+    FanMode,
+    FreqLevel,
+}
+
+/// One setting that differs from the policy, both sides formatted for display
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drift {
+    pub field: DriftField,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Drift of one miner from its policy
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriftReport {
+    pub ip: String,
+    pub miner_id: Option<MinerId>,
+    /// Name of the `DesiredState` the miner was compared with
+    pub policy: String,
+    pub drifts: Vec<Drift>,
+    /// Unix timestamp of the poll that found it
+    pub detected_at: u64,
+}
+
+/// Policies with what is needed to pick one per miner and expand its
+/// worker templates
+#[derive(Debug, Clone, Default)]
+pub struct DesiredStates {
+    pub policies: Vec<DesiredState>,
+    tags: HashMap<MinerId, Vec<String>>,
+    locations: HashMap<MinerId, MinerLocation>,
+}
+
+impl DesiredStates {
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        Self {
+            policies: settings.desired_states.clone(),
+            tags: settings.miner_tags.iter().map(|entry| (entry.miner_id.clone(), entry.tags.clone())).collect(),
+            locations: settings
+                .miner_locations
+                .iter()
+                .map(|location| (location.miner_id.clone(), location.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty()
+    }
+
+    /// The policy for the miner at `ip`: the most specific matching one,
+    /// the first listed on ties
+    pub fn policy_for(&self, ip: &str, miner_id: Option<&MinerId>) -> Option<&DesiredState> {
+        let tags = miner_id.and_then(|id| self.tags.get(id)).map(Vec::as_slice).unwrap_or_default();
+        self.policies
+            .iter()
+            .filter(|policy| policy.scope.contains(ip, miner_id, tags))
+            .rev()
+            .max_by_key(|policy| policy.scope.specificity())
+    }
+
+    /// Compare `miner` with its policy; `None` if no policy covers it
+    pub fn evaluate(&self, miner: &Miner) -> Option<(&DesiredState, Vec<Drift>)> {
+        let policy = self.policy_for(&miner.ip, miner.id.as_ref())?;
+        let location = miner.id.as_ref().and_then(|id| self.locations.get(id));
+        let facts = WorkerFacts::from_stats(&miner.ip, &miner.stats, location);
+        Some((policy, evaluate(policy, &miner.stats, &facts)))
+    }
+}
+
+/// Settings of `stats` that differ from `policy`
+pub fn evaluate(policy: &DesiredState, stats: &MinerStats, facts: &WorkerFacts) -> Vec<Drift> {
+    let mut drifts = Vec::new();
+
+    // A template the miner lacks facts for can't be checked
+    let intended = policy.pools.as_ref().and_then(|pools| worker_name::expand_pools(pools, facts).ok());
+    if let (Some(intended), Some(current)) = (intended, reported_pools(stats)) {
+        if !pools::diff(&current, &intended).is_empty() {
+            drifts.push(Drift { field: DriftField::Pools, expected: describe_pools(&intended), actual: describe_pools(&current.pools) });
+        }
+    }
+
+    if let (Some(mode), Some(actual)) = (policy.power_mode, stats.power_mode) {
+        if mode.antminer_mode() != actual {
+            drifts.push(Drift { field: DriftField::PowerMode, expected: format!("{:?}", mode), actual: describe_power_mode(actual) });
+        }
+    }
+
+    if let (Some(mode), Some(actual)) = (policy.fan_mode, stats.fan_mode) {
+        if mode != actual {
+            drifts.push(Drift { field: DriftField::FanMode, expected: describe_fan_mode(mode), actual: describe_fan_mode(actual) });
+        }
+    }
+
+    if let (Some(level), Some(actual)) = (policy.freq_level, stats.freq_level) {
+        if level != actual {
+            drifts.push(Drift { field: DriftField::FreqLevel, expected: format!("{}%", level), actual: format!("{}%", actual) });
+        }
+    }
+
+    drifts
+}
+
+/// The command that puts the drifted settings back to `policy`
+pub fn corrective_command(policy: &DesiredState, drifts: &[Drift]) -> Option<MinerCommand> {
+    let drifted = |field: DriftField| drifts.iter().any(|drift| drift.field == field);
+    let command = MinerCommand::ApplyConfig {
+        pools: policy.pools.clone().filter(|_| drifted(DriftField::Pools)),
+        power_mode: policy.power_mode.filter(|_| drifted(DriftField::PowerMode)),
+        fan_mode: policy.fan_mode.filter(|_| drifted(DriftField::FanMode)),
+        freq_level: policy.freq_level.filter(|_| drifted(DriftField::FreqLevel)),
+    };
+    match &command {
+        MinerCommand::ApplyConfig { pools: None, power_mode: None, fan_mode: None, freq_level: None } => None,
+        _ => Some(command),
+    }
+}
+
+/// Pools as reported over the CGMiner API; `None` if none were read
+fn reported_pools(stats: &MinerStats) -> Option<CurrentPools> {
+    let pools: Vec<PoolConfig> = [(&stats.pool1, &stats.worker1), (&stats.pool2, &stats.worker2), (&stats.pool3, &stats.worker3)]
+        .into_iter()
+        .filter_map(|(url, worker)| {
+            let url = url.as_deref().map(str::trim).filter(|url| !url.is_empty())?;
+            Some(PoolConfig { url: url.to_string(), worker: worker.clone().unwrap_or_default(), password: String::new() })
+        })
+        .collect();
+    (!pools.is_empty()).then_some(CurrentPools { pools, passwords_known: false })
+}
+
+fn describe_pools(pools: &[PoolConfig]) -> String {
+    pools.iter().map(|pool| format!("{} ({})", pool.url, pool.worker)).collect::<Vec<_>>().join(", ")
+}
+
+fn describe_power_mode(mode: u8) -> String {
+    [PowerMode::Normal, PowerMode::Sleep, PowerMode::Lpm]
+        .into_iter()
+        .find(|known| known.antminer_mode() == mode)
+        .map(|known| format!("{:?}", known))
+        .unwrap_or_else(|| format!("mode {}", mode))
+}
+
+fn describe_fan_mode(mode: FanMode) -> String {
+    match mode {
+        FanMode::Auto => "auto".to_string(),
+        FanMode::Fixed { percent } => format!("fixed {}%", percent),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::CredentialScope;
+
+    fn policy(name: &str, scope: CredentialScope) -> DesiredState {
+        DesiredState {
+            name: name.into(),
+            scope,
+            pools: Some(vec![PoolConfig {
+                url: "stratum+tcp://pool.example:3333".into(),
+                worker: "acct.{ip4}".into(),
+                password: "x".into(),
+            }]),
+            power_mode: Some(PowerMode::Normal),
+            fan_mode: Some(FanMode::Auto),
+            freq_level: None,
+        }
+    }
+
+    fn stats() -> MinerStats {
+        MinerStats {
+            pool1: Some("stratum+tcp://pool.example:3333".into()),
+            worker1: Some("acct.4".into()),
+            power_mode: Some(0),
+            fan_mode: Some(FanMode::Auto),
+            freq_level: Some(100),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let policy = policy("site", CredentialScope::All);
+        let facts = WorkerFacts { ip: "10.2.3.4".into(), ..WorkerFacts::default() };
+        assert!(evaluate(&policy, &stats(), &facts).is_empty());
+
+        let drifted = MinerStats {
+            worker1: Some("acct.old".into()),
+            power_mode: Some(3),
+            fan_mode: Some(FanMode::Fixed { percent: 80 }),
+            ..stats()
+        };
+        let drifts = evaluate(&policy, &drifted, &facts);
+        let fields: Vec<DriftField> = drifts.iter().map(|d| d.field).collect();
+        assert_eq!(fields, vec![DriftField::Pools, DriftField::PowerMode, DriftField::FanMode]);
+        assert_eq!(drifts[1].actual, "Lpm");
+        assert_eq!(drifts[2].actual, "fixed 80%");
+
+        // Not reported is not drift
+        let unknown = MinerStats { pool1: None, power_mode: None, fan_mode: None, ..stats() };
+        assert!(evaluate(&policy, &unknown, &facts).is_empty());
+
+        match corrective_command(&policy, &drifts[1..2]) {
+            Some(MinerCommand::ApplyConfig { pools: None, power_mode: Some(PowerMode::Normal), fan_mode: None, freq_level: None }) => {}
+            other => panic!("unexpected command {:?}", other),
+        }
+        assert!(corrective_command(&policy, &[]).is_none());
+    }
+
+    #[test]
+    fn test_most_specific_policy() {
+        let id = MinerId::from_serial("SN1").unwrap();
+        let settings = AppSettings {
+            desired_states: vec![
+                policy("all", CredentialScope::All),
+                policy("rack", CredentialScope::Tag("rack-12".into())),
+                policy("other-rack", CredentialScope::Tag("RACK-12".into())),
+                policy("range", CredentialScope::Range("10.2.3.0/24".into())),
+            ],
+            miner_tags: vec![crate::core::MinerTags { miner_id: id.clone(), tags: vec!["rack-12".into()] }],
+            ..AppSettings::default()
+        };
+        let states = DesiredStates::from_settings(&settings);
+        assert_eq!(states.policy_for("10.2.3.4", Some(&id)).unwrap().name, "rack");
+        assert_eq!(states.policy_for("10.2.3.4", None).unwrap().name, "range");
+        assert_eq!(states.policy_for("10.9.9.9", None).unwrap().name, "all");
+    }
+}
@@ -1,9 +1,15 @@
-use crate::client::{get_summary, DEFAULT_TIMEOUT_MS};
-use crate::core::{Miner, MinerStatus};
+pub mod drift;
+
+use crate::client::{get_report, DEFAULT_TIMEOUT_MS};
+use crate::core::config::AppSettings;
+use crate::core::{Miner, MinerId, MinerStatus, PduOutlet};
+use crate::identity::IdentityChange;
+use drift::{DesiredStates, Drift, DriftReport};
 use dashmap::DashMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
 /// Events emitted by the monitor
 #[derive(Debug, Clone)]
@@ -16,6 +22,15 @@ pub enum MonitorEvent {
     MinerRemoved(String),
     /// Full snapshot of all miners
     FullSnapshot(Vec<Miner>),
+    /// A known miner now answers at a different address; the entry for
+    /// `from_ip` was dropped in favour of `to_ip`
+    MinerMoved { id: MinerId, from_ip: String, to_ip: String },
+    /// A different device now answers at an address that belonged to `previous`
+    IpTakenOver { ip: String, previous: MinerId, current: MinerId },
+    /// A miner differs from its group's desired state, or differs in a new way
+    DriftDetected(DriftReport),
+    /// A miner that had drifted matches its desired state again
+    DriftResolved { ip: String, miner_id: Option<MinerId> },
 }
 
 /// Configuration for the monitor
@@ -27,6 +42,30 @@ pub struct MonitorConfig {
     pub warning_hashrate_ratio: f64,
     pub timeout_ms: u64,
     pub port: u16,
+    /// PDU outlet feeding each miner; attached to every polled `Miner`
+    pub outlet_links: HashMap<MinerId, PduOutlet>,
+    /// Desired state per group, compared with every successful poll
+    pub desired: DesiredStates,
+}
+
+impl MonitorConfig {
+    /// Monitor configuration from the persisted app settings
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        let mut config = Self::default();
+        config.apply_settings(settings);
+        config
+    }
+
+    /// Take over the user-configurable parts of `settings`
+    pub fn apply_settings(&mut self, settings: &AppSettings) {
+        self.poll_interval_ms = settings.monitor_interval.max(1) * 1000;
+        self.outlet_links = settings
+            .outlet_links
+            .iter()
+            .map(|link| (link.miner_id.clone(), link.outlet.clone()))
+            .collect();
+        self.desired = DesiredStates::from_settings(settings);
+    }
 }
 
 impl Default for MonitorConfig {
@@ -38,6 +77,8 @@ impl Default for MonitorConfig {
             warning_hashrate_ratio: 0.90,
             timeout_ms: DEFAULT_TIMEOUT_MS,
             port: 4028,
+            outlet_links: HashMap::new(),
+            desired: DesiredStates::default(),
         }
     }
 }
@@ -47,6 +88,25 @@ impl Default for MonitorConfig {
 pub async fn start_monitor(
     miners: Vec<Miner>,
     config: MonitorConfig,
+) -> mpsc::Receiver<MonitorEvent> {
+    spawn_monitor(miners, config, None)
+}
+
+/// Like `start_monitor`, but re-applies settings whenever `settings` changes
+/// (see `AppSettings::subscribe`).  A new poll interval applies to the wait
+/// already in progress.
+pub async fn start_monitor_with_settings(
+    miners: Vec<Miner>,
+    config: MonitorConfig,
+    settings: watch::Receiver<AppSettings>,
+) -> mpsc::Receiver<MonitorEvent> {
+    spawn_monitor(miners, config, Some(settings))
+}
+
+fn spawn_monitor(
+    miners: Vec<Miner>,
+    config: MonitorConfig,
+    settings: Option<watch::Receiver<AppSettings>>,
 ) -> mpsc::Receiver<MonitorEvent> {
     let (tx, rx) = mpsc::channel(100);
     
@@ -60,7 +120,7 @@ pub async fn start_monitor(
     }
     
     // Spawn the polling loop
-    tokio::spawn(polling_loop(state, config, tx));
+    tokio::spawn(polling_loop(state, Arc::new(DashMap::new()), config, tx, settings));
     
     rx
 }
@@ -68,16 +128,43 @@ pub async fn start_monitor(
 /// Internal polling loop that continuously updates miner states
 async fn polling_loop(
     state: Arc<DashMap<String, Miner>>,
-    config: MonitorConfig,
+    drifts: Arc<DashMap<String, Vec<Drift>>>,
+    mut config: MonitorConfig,
     tx: mpsc::Sender<MonitorEvent>,
+    mut settings: Option<watch::Receiver<AppSettings>>,
 ) {
     // Send initial snapshot
     let snapshot: Vec<Miner> = state.iter().map(|entry| entry.value().clone()).collect();
     let _ = tx.send(MonitorEvent::FullSnapshot(snapshot)).await;
     
     loop {
-        // Wait for poll interval
-        tokio::time::sleep(Duration::from_millis(config.poll_interval_ms)).await;
+        // Wait for poll interval.  A settings change re-computes the deadline
+        // from the same start, so a shorter interval takes effect right away.
+        let wait_started = tokio::time::Instant::now();
+        loop {
+            let deadline = wait_started + Duration::from_millis(config.poll_interval_ms);
+            let changed = async {
+                match settings.as_mut() {
+                    Some(rx) => rx.changed().await.is_ok(),
+                    None => std::future::pending().await,
+                }
+            };
+            tokio::select! {
+                _ = tokio::time::sleep_until(deadline) => break,
+                still_open = changed => {
+                    match (still_open, settings.as_mut()) {
+                        (true, Some(rx)) => config.apply_settings(&rx.borrow_and_update()),
+                        // Settings sender gone: keep the current config
+                        _ => settings = None,
+                    }
+                }
+            }
+        }
+        
+        // Nobody is listening any more
+        if tx.is_closed() {
+            break;
+        }
         
         // Poll each miner concurrently
         let mut tasks = Vec::new();
@@ -85,11 +172,12 @@ async fn polling_loop(
         for entry in state.iter() {
             let ip = entry.key().clone();
             let state = state.clone();
+            let drifts = drifts.clone();
             let config = config.clone();
             let tx = tx.clone();
             
             let task = tokio::spawn(async move {
-                poll_single_miner(ip, state, config, tx).await;
+                poll_single_miner(ip, state, drifts, config, tx).await;
             });
             
             tasks.push(task);
@@ -110,6 +198,7 @@ async fn polling_loop(
 async fn poll_single_miner(
     ip: String,
     state: Arc<DashMap<String, Miner>>,
+    drifts: Arc<DashMap<String, Vec<Drift>>>,
     config: MonitorConfig,
     tx: mpsc::Sender<MonitorEvent>,
 ) {
@@ -117,9 +206,10 @@ async fn poll_single_miner(
     let mut stats_result = None;
     
     for attempt in 0..=config.retry_attempts {
-        match get_summary(&ip, config.port, config.timeout_ms).await {
-            Ok(stats) => {
-                stats_result = Some(stats);
+        match get_report(&ip, config.port, config.timeout_ms).await {
+            Ok(report) => {
+                crate::identity::record_report(&ip, config.port, &report);
+                stats_result = Some(report.stats);
                 break;
             }
             Err(_) if attempt < config.retry_attempts => {
@@ -134,19 +224,31 @@ async fn poll_single_miner(
     }
     
     // Update the miner state
+    let mut changes = Vec::new();
+    let mut drift_event = None;
     if let Some(mut entry) = state.get_mut(&ip) {
         let old_status = entry.status.clone();
         // Remember last known power_mode so a transient HTTP failure
         // doesn't erase it (fetch_details keeps the value if the call fails,
-        // but get_summary creates a fresh MinerStats default().  We restore it here.)
+        // but get_report creates a fresh MinerStats default().  We restore it here.)
         let prev_power_mode = entry.stats.power_mode;
+        let (prev_fan_mode, prev_freq_level) = (entry.stats.fan_mode, entry.stats.freq_level);
         
         match stats_result {
             Some(mut stats) => {
-                // Preserve last known power_mode if the new read returned None
+                // Preserve last known conf values if the new read returned None
                 if stats.power_mode.is_none() {
                     stats.power_mode = prev_power_mode;
+                    stats.fan_mode = stats.fan_mode.or(prev_fan_mode);
+                    stats.freq_level = stats.freq_level.or(prev_freq_level);
+                }
+                // Keep the IP <-> id mapping current; a poll that couldn't
+                // read the MAC keeps the id we already had
+                if let Some(id) = MinerId::derive(&stats).or_else(|| entry.id.clone()) {
+                    changes = crate::identity::observe(&ip, &id);
+                    entry.id = Some(id);
                 }
+                
                 // Update stats and model
                 entry.model = stats.model.clone();
                 entry.stats = stats;
@@ -162,11 +264,68 @@ async fn poll_single_miner(
             }
         }
         
+        // A dead miner's outlet is the first thing to check
+        entry.outlet = entry.id.as_ref().and_then(|id| config.outlet_links.get(id).cloned());
+        if entry.status == MinerStatus::Dead && old_status != MinerStatus::Dead {
+            if let Some(outlet) = &entry.outlet {
+                println!("[monitor] {} is dead; fed by PDU {} outlet {}", ip, outlet.pdu_ip, outlet.outlet);
+            }
+        }
+        
         // If status changed, send update event
         if entry.status != old_status {
             let _ = tx.send(MonitorEvent::MinerUpdated(entry.clone())).await;
         }
+        
+        // A dead miner reports nothing to compare; keep its last drift
+        if entry.status != MinerStatus::Dead {
+            drift_event = check_drift(&entry, &drifts, &config.desired);
+        }
     }
+    
+    if let Some(event) = drift_event {
+        let _ = tx.send(event).await;
+    }
+    
+    for change in changes {
+        match change {
+            IdentityChange::Moved { id, from_ip, to_ip } => {
+                // The old address no longer has this miner behind it
+                state.remove_if(&from_ip, |_, miner| miner.id.as_ref() == Some(&id));
+                let _ = tx.send(MonitorEvent::MinerMoved { id, from_ip, to_ip }).await;
+            }
+            IdentityChange::TakenOver { ip, previous, current } => {
+                let _ = tx.send(MonitorEvent::IpTakenOver { ip, previous, current }).await;
+            }
+        }
+    }
+}
+
+/// Compare `miner` with its desired state; an event if its drift changed
+/// since the last poll
+fn check_drift(miner: &Miner, drifts: &DashMap<String, Vec<Drift>>, desired: &DesiredStates) -> Option<MonitorEvent> {
+    let (policy, found) = match desired.evaluate(miner) {
+        Some((policy, found)) if !found.is_empty() => (policy, found),
+        // In line, or no longer covered by any policy
+        _ => {
+            return drifts.remove(&miner.ip).map(|_| MonitorEvent::DriftResolved {
+                ip: miner.ip.clone(),
+                miner_id: miner.id.clone(),
+            });
+        }
+    };
+    if drifts.get(&miner.ip).is_some_and(|known| *known == found) {
+        return None;
+    }
+    println!("[monitor] {} drifted from '{}': {:?}", miner.ip, policy.name, found.iter().map(|d| d.field).collect::<Vec<_>>());
+    drifts.insert(miner.ip.clone(), found.clone());
+    Some(MonitorEvent::DriftDetected(DriftReport {
+        ip: miner.ip.clone(),
+        miner_id: miner.id.clone(),
+        policy: policy.name.clone(),
+        drifts: found,
+        detected_at: current_timestamp(),
+    }))
 }
 
 /// Determine miner status based on stats and thresholds
@@ -240,6 +399,40 @@ mod tests {
         assert_eq!(status, MinerStatus::Warning);
     }
     
+    #[test]
+    fn test_drift_events() {
+        use crate::core::config::{CredentialScope, DesiredState};
+        let desired = DesiredStates::from_settings(&AppSettings {
+            desired_states: vec![DesiredState {
+                name: "eco".into(),
+                scope: CredentialScope::All,
+                pools: None,
+                power_mode: Some(crate::api::models::PowerMode::Lpm),
+                fan_mode: None,
+                freq_level: None,
+            }],
+            ..AppSettings::default()
+        });
+        let drifts = DashMap::new();
+        let mut miner = Miner {
+            id: None,
+            ip: "10.0.0.5".into(),
+            model: None,
+            status: MinerStatus::Active,
+            stats: MinerStats { power_mode: Some(0), ..Default::default() },
+            last_updated: 0,
+            vendor_hint: None,
+            outlet: None,
+        };
+        
+        assert!(matches!(check_drift(&miner, &drifts, &desired), Some(MonitorEvent::DriftDetected(r)) if r.policy == "eco"));
+        // Reported once until it changes
+        assert!(check_drift(&miner, &drifts, &desired).is_none());
+        miner.stats.power_mode = Some(3);
+        assert!(matches!(check_drift(&miner, &drifts, &desired), Some(MonitorEvent::DriftResolved { .. })));
+        assert!(check_drift(&miner, &drifts, &desired).is_none());
+    }
+    
     #[test]
     fn test_status_determination_warning_no_hashrate() {
         let stats = MinerStats {
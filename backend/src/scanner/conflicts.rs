@@ -0,0 +1,209 @@
+//! Address conflicts and misconfigured miners found while scanning.
+//!
+//! The scanner reports whatever answers at an address, which hides three
+//! common field problems:
+//!
+//! - two devices sharing a static IP: the address answers as different
+//!   devices (MAC or serial) from one probe to the next
+//! - a miner sitting on a factory-default address such as `192.168.1.99`
+//!   after a reset
+//! - a miner whose configured netmask or gateway doesn't fit the subnet it
+//!   was found in (the local subnet, or the scanned CIDR block for routed
+//!   ranges), so it hashes today but drops off after the next reboot or
+//!   route change
+//!
+//! The checks here are pure; the scanner collects the sightings.
+
+use ipnetwork::Ipv4Network;
+use std::net::{IpAddr, Ipv4Addr};
+
+/// What one probe saw at an address
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Sighting {
+    pub mac_address: Option<String>,
+    pub serial_number: Option<String>,
+}
+
+/// Distinct MACs and serials across `sightings`, if they disagree.
+/// A probe that saw no MAC (or no serial) doesn't count as disagreeing.
+pub fn conflicting(sightings: &[Sighting]) -> Option<(Vec<String>, Vec<String>)> {
+    fn distinct<'a>(values: impl Iterator<Item = &'a String>) -> Vec<String> {
+        let mut seen: Vec<String> = Vec::new();
+        for value in values {
+            if !seen.iter().any(|s| s.eq_ignore_ascii_case(value)) {
+                seen.push(value.clone());
+            }
+        }
+        seen
+    }
+
+    let macs = distinct(sightings.iter().filter_map(|s| s.mac_address.as_ref()));
+    let serials = distinct(sightings.iter().filter_map(|s| s.serial_number.as_ref()));
+    (macs.len() > 1 || serials.len() > 1).then_some((macs, serials))
+}
+
+/// Whether `ip` is one of the configured factory-default addresses
+pub fn is_factory_default(ip: &str, factory_ips: &[String]) -> bool {
+    factory_ips.iter().any(|factory| factory.trim() == ip)
+}
+
+/// IPv4 subnets of the local interfaces, used as the expected subnet of
+/// miners found on-link.  Loopback and link-local are left out.
+pub fn local_networks() -> Vec<Ipv4Network> {
+    use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
+
+    let Ok(interfaces) = NetworkInterface::show() else {
+        return Vec::new();
+    };
+    interfaces
+        .iter()
+        .flat_map(|iface| iface.addr.iter())
+        .filter_map(|addr| match addr {
+            Addr::V4(v4) if !v4.ip.is_loopback() && !v4.ip.is_link_local() => {
+                let prefix = v4.netmask.map(|mask| u32::from(mask).leading_ones()).unwrap_or(24);
+                let network = Ipv4Network::new(v4.ip, prefix as u8).ok()?;
+                Ipv4Network::new(network.network(), network.prefix()).ok()
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Why the network config of the miner at `ip` doesn't fit, with the subnet
+/// it should be on when that is known.
+///
+/// The miner's own netmask and gateway must agree with each other, and when
+/// `ip` is on a local subnet they must agree with that subnet too.  A routed
+/// miner is checked against the narrowest `scanned` block it was found in
+/// (see `ScanSpec::networks`): the scan may cover only part of a subnet, so
+/// its netmask only has to take in the whole block.  Missing values are not
+/// flagged: not every firmware reports them.
+pub fn network_mismatch(
+    ip: &str,
+    netmask: Option<&str>,
+    gateway: Option<&str>,
+    local: &[Ipv4Network],
+    scanned: &[Ipv4Network],
+) -> Option<(Option<String>, String)> {
+    let Ok(IpAddr::V4(ip)) = ip.parse::<IpAddr>() else {
+        return None;
+    };
+    let prefix = netmask.and_then(prefix_of);
+    let gateway = gateway.and_then(|gw| gw.trim().parse::<Ipv4Addr>().ok());
+    let on_link = local.iter().find(|net| net.contains(ip));
+    let scanned = match on_link {
+        Some(_) => None,
+        None => scanned.iter().filter(|net| net.contains(ip)).max_by_key(|net| net.prefix()),
+    };
+    let expected = on_link.or(scanned).map(|net| net.to_string());
+
+    if let (Some(prefix), Some(on_link)) = (prefix, on_link) {
+        if prefix != on_link.prefix() {
+            let reason = format!("netmask {} doesn't match subnet {}", netmask.unwrap_or_default().trim(), on_link);
+            return Some((expected, reason));
+        }
+    }
+    if let (Some(prefix), Some(scanned)) = (prefix, scanned) {
+        if prefix > scanned.prefix() {
+            let reason = format!("netmask {} is narrower than the scanned range {}", netmask.unwrap_or_default().trim(), scanned);
+            return Some((expected, reason));
+        }
+    }
+    if let Some(gateway) = gateway {
+        if let Some(on_link) = on_link.filter(|net| !net.contains(gateway)) {
+            return Some((expected, format!("gateway {} is outside subnet {}", gateway, on_link)));
+        }
+        let own = prefix.and_then(|prefix| Ipv4Network::new(ip, prefix).ok());
+        if let Some(own) = own.filter(|net| !net.contains(gateway)) {
+            let own = Ipv4Network::new(own.network(), own.prefix()).unwrap_or(own);
+            return Some((expected, format!("gateway {} is outside the miner's own subnet {}", gateway, own)));
+        }
+    }
+    None
+}
+
+/// Prefix length of a dotted netmask; `None` for garbage or non-contiguous masks
+fn prefix_of(netmask: &str) -> Option<u8> {
+    let mask = u32::from(netmask.trim().parse::<Ipv4Addr>().ok()?);
+    let prefix = mask.leading_ones();
+    (mask.checked_shl(prefix).unwrap_or(0) == 0).then_some(prefix as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sighting(mac: Option<&str>, serial: Option<&str>) -> Sighting {
+        Sighting { mac_address: mac.map(str::to_string), serial_number: serial.map(str::to_string) }
+    }
+
+    #[test]
+    fn test_conflicting_sightings() {
+        let same = [
+            sighting(Some("B4:10:7B:00:00:01"), None),
+            sighting(Some("b4:10:7b:00:00:01"), Some("SN1")),
+            sighting(None, Some("SN1")),
+        ];
+        assert_eq!(conflicting(&same), None);
+
+        let shared = [sighting(Some("B4:10:7B:00:00:01"), Some("SN1")), sighting(Some("B4:10:7B:00:00:02"), None)];
+        assert_eq!(
+            conflicting(&shared),
+            Some((vec!["B4:10:7B:00:00:01".to_string(), "B4:10:7B:00:00:02".to_string()], vec!["SN1".to_string()]))
+        );
+
+        let serials = [sighting(None, Some("SN1")), sighting(None, Some("SN2"))];
+        assert!(conflicting(&serials).is_some());
+    }
+
+    #[test]
+    fn test_factory_default() {
+        let factory = vec!["192.168.1.99".to_string()];
+        assert!(is_factory_default("192.168.1.99", &factory));
+        assert!(!is_factory_default("10.1.2.99", &factory));
+    }
+
+    #[test]
+    fn test_network_mismatch() {
+        let local: Vec<Ipv4Network> = vec!["10.1.0.0/22".parse().unwrap()];
+
+        assert_eq!(network_mismatch("10.1.2.21", Some("255.255.252.0"), Some("10.1.0.1"), &local, &[]), None);
+        // Nothing reported, nothing flagged
+        assert_eq!(network_mismatch("10.1.2.21", None, None, &local, &[]), None);
+
+        let (expected, reason) = network_mismatch("10.1.2.21", Some("255.255.255.0"), Some("10.1.0.1"), &local, &[]).unwrap();
+        assert_eq!(expected.as_deref(), Some("10.1.0.0/22"));
+        assert_eq!(reason, "netmask 255.255.255.0 doesn't match subnet 10.1.0.0/22");
+
+        let (_, reason) = network_mismatch("10.1.2.21", Some("255.255.252.0"), Some("192.168.1.1"), &local, &[]).unwrap();
+        assert_eq!(reason, "gateway 192.168.1.1 is outside subnet 10.1.0.0/22");
+
+        // Routed miner: only its own config is checked
+        let (expected, reason) = network_mismatch("10.9.5.7", Some("255.255.255.0"), Some("10.9.4.1"), &local, &[]).unwrap();
+        assert_eq!(expected, None);
+        assert_eq!(reason, "gateway 10.9.4.1 is outside the miner's own subnet 10.9.5.0/24");
+        assert_eq!(network_mismatch("10.9.5.7", Some("255.255.255.0"), Some("10.9.5.1"), &local, &[]), None);
+
+        // ...and against the CIDR block it was scanned in
+        let scanned: Vec<Ipv4Network> = vec!["10.9.0.0/16".parse().unwrap(), "10.9.4.0/22".parse().unwrap()];
+        let (expected, reason) = network_mismatch("10.9.5.7", Some("255.255.255.0"), Some("10.9.5.1"), &local, &scanned).unwrap();
+        assert_eq!(expected.as_deref(), Some("10.9.4.0/22"));
+        assert_eq!(reason, "netmask 255.255.255.0 is narrower than the scanned range 10.9.4.0/22");
+        assert_eq!(network_mismatch("10.9.5.7", Some("255.255.252.0"), Some("10.9.4.1"), &local, &scanned), None);
+        // A scan of part of the subnet is fine
+        let part: Vec<Ipv4Network> = vec!["10.9.5.0/26".parse().unwrap()];
+        assert_eq!(network_mismatch("10.9.5.7", Some("255.255.255.0"), Some("10.9.5.1"), &local, &part), None);
+        // On-link miners are checked against the local subnet only
+        let (expected, _) = network_mismatch("10.1.2.21", Some("255.255.255.0"), Some("10.1.0.1"), &local, &scanned).unwrap();
+        assert_eq!(expected.as_deref(), Some("10.1.0.0/22"));
+    }
+
+    #[test]
+    fn test_prefix_of() {
+        assert_eq!(prefix_of("255.255.255.0"), Some(24));
+        assert_eq!(prefix_of("255.255.252.0"), Some(22));
+        assert_eq!(prefix_of("0.0.0.0"), Some(0));
+        assert_eq!(prefix_of("255.0.255.0"), None);
+        assert_eq!(prefix_of("garbage"), None);
+    }
+}
@@ -1,13 +1,24 @@
-use crate::core::{Miner, MinerError, MinerStatus, Result};
-use crate::client::{get_summary, send_command};
-use ipnetwork::IpNetwork;
+use crate::core::config::AppSettings;
+use crate::core::{Device, Miner, MinerId, MinerStatus, MinerVendor, Result};
+use crate::client::{arp, get_report, identify_as, inventory, source};
+use crate::devices::{self, DeviceProbe};
+use crate::identity::oui;
+use crate::identity::IdentityChange;
+use ipnetwork::Ipv4Network;
 use std::net::IpAddr;
 use std::sync::Arc;
-use std::time::SystemTime;
-use tokio::sync::{mpsc, Semaphore};
+use std::time::{Duration, SystemTime};
+use tokio::sync::{mpsc, watch, Semaphore};
+use tokio::time::Instant;
+
+pub mod conflicts;
+pub mod spec;
+
+pub use spec::{parse_scan_spec, ScanSpec, ScanSpecError, SpecPosition};
 
 /// Events emitted during network scanning
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // `Found` dominates the stream; boxing it buys nothing
 pub enum ScanEvent {
     /// Scan has started
     Started { total_ips: usize },
@@ -17,14 +28,108 @@ pub enum ScanEvent {
     Progress { scanned: usize, total: usize },
     /// Scan completed
     Complete { found: usize, failed: usize },
+    /// Scan was stopped through its `ScanHandle` before finishing
+    Cancelled { scanned: usize, found: usize },
+    /// A known miner was found at a different address than last time
+    MinerMoved { id: MinerId, from_ip: String, to_ip: String },
+    /// A different device now answers at an address that belonged to `previous`
+    IpTakenOver { ip: String, previous: MinerId, current: MinerId },
+    /// Something answered that is not a miner: its MAC belongs to a
+    /// non-miner vendor, it didn't speak the CGMiner API on an open port, or
+    /// (with `scan_devices`) it answered the SNMP probe
+    NonMiner(Device),
+    /// `ip` answered as more than one device during the scan: two devices
+    /// share the address.  Lists every MAC and serial seen there.
+    DuplicateIp { ip: String, mac_addresses: Vec<String>, serial_numbers: Vec<String> },
+    /// A miner sits on one of the factory-default addresses in the settings
+    FactoryDefaultIp { ip: String, id: Option<MinerId>, mac_address: Option<String> },
+    /// The miner's configured netmask or gateway doesn't fit the subnet it
+    /// was found in.  `expected_subnet` is the local subnet it is on, or for
+    /// a routed miner the scanned CIDR block it was found in, if any.
+    NetworkMismatch {
+        ip: String,
+        id: Option<MinerId>,
+        netmask: Option<String>,
+        gateway: Option<String>,
+        expected_subnet: Option<String>,
+        reason: String,
+    },
+}
+
+impl From<IdentityChange> for ScanEvent {
+    fn from(change: IdentityChange) -> Self {
+        match change {
+            IdentityChange::Moved { id, from_ip, to_ip } => ScanEvent::MinerMoved { id, from_ip, to_ip },
+            IdentityChange::TakenOver { ip, previous, current } => ScanEvent::IpTakenOver { ip, previous, current },
+        }
+    }
+}
+
+/// Handle to stop a running scan.
+/// Clones share the same scan; dropping a handle does not cancel it.
+#[derive(Debug, Clone)]
+pub struct ScanHandle {
+    cancel_tx: Arc<watch::Sender<bool>>,
+}
+
+impl ScanHandle {
+    pub fn new() -> Self {
+        let (cancel_tx, _) = watch::channel(false);
+        Self { cancel_tx: Arc::new(cancel_tx) }
+    }
+
+    /// Stop the scan: queued IPs are skipped and in-flight probes abandoned.
+    /// The scan then ends with `ScanEvent::Cancelled`.
+    pub fn cancel(&self) {
+        self.cancel_tx.send_replace(true);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        *self.cancel_tx.borrow()
+    }
+
+    /// Resolves once `cancel` has been called.
+    async fn cancelled(&self) {
+        let mut rx = self.cancel_tx.subscribe();
+        let _ = rx.wait_for(|cancelled| *cancelled).await;
+    }
+
+    pub(crate) fn same_scan(&self, other: &ScanHandle) -> bool {
+        Arc::ptr_eq(&self.cancel_tx, &other.cancel_tx)
+    }
+}
+
+impl Default for ScanHandle {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Configuration for network scanning
+///
+/// A scan runs in two phases per address: a TCP connect sweep over `ports`
+/// (`sweep_concurrent` at a time, `connect_timeout_ms` each), then miner
+/// identification on the first open port (`max_concurrent` at a time,
+/// `timeout_ms` per command).  Dead addresses never reach the second phase.
 #[derive(Debug, Clone)]
 pub struct ScanConfig {
     pub timeout_ms: u64,
     pub max_concurrent: usize,
     pub ports: Vec<u16>,
+    pub connect_timeout_ms: u64,
+    pub sweep_concurrent: usize,
+    /// Fetch temps, fans, pools etc. for each miner found instead of leaving
+    /// them to the monitor.  Slows the scan down by several round trips per miner.
+    pub fetch_details: bool,
+    /// Send an SNMP probe to addresses with no open miner port, so PDUs and
+    /// switches show up as `NonMiner`
+    pub scan_devices: bool,
+    pub device_probe: DeviceProbe,
+    /// Read the inventory of each miner found (unless identification already
+    /// did) and compare it with what the probe and the ARP table saw, to
+    /// catch shared addresses.  Costs one web API read per miner.
+    pub detect_conflicts: bool,
+    pub factory_ips: Vec<String>,
 }
 
 impl Default for ScanConfig {
@@ -33,65 +138,48 @@ impl Default for ScanConfig {
             timeout_ms: 500,  
             max_concurrent: 100,
             ports: vec![4028], //4028, 4029, 4030 when need to scan more ports
+            connect_timeout_ms: 300,
+            // Stays below the common 1024 open-files limit
+            sweep_concurrent: 512,
+            fetch_details: false,
+            scan_devices: false,
+            device_probe: DeviceProbe::default(),
+            detect_conflicts: false,
+            factory_ips: AppSettings::default().factory_default_ips,
         }
     }
 }
 
-/// Parse an IP range string into a list of IP addresses
-/// Supports two formats:
-/// - CIDR notation: "192.168.1.0/24"
-/// - Range notation: "192.168.1.1-192.168.1.254"
-pub fn parse_ip_range(range: &str) -> Result<Vec<IpAddr>> {
-    // Try CIDR notation first
-    if range.contains('/') {
-        let network: IpNetwork = range
-            .parse()
-            .map_err(|_| MinerError::InvalidResponse)?;
-        
-        Ok(network.iter().collect())
-    }
-    // Try range notation (e.g., "192.168.1.1-192.168.1.254")
-    else if range.contains('-') {
-        let parts: Vec<&str> = range.split('-').collect();
-        if parts.len() != 2 {
-            return Err(MinerError::InvalidResponse);
-        }
-        
-        let start: IpAddr = parts[0]
-            .trim()
-            .parse()
-            .map_err(|_| MinerError::InvalidResponse)?;
-        let end: IpAddr = parts[1]
-            .trim()
-            .parse()
-            .map_err(|_| MinerError::InvalidResponse)?;
-        
-        // Generate range (only works for IPv4)
-        match (start, end) {
-            (IpAddr::V4(start_v4), IpAddr::V4(end_v4)) => {
-                let start_num = u32::from(start_v4);
-                let end_num = u32::from(end_v4);
-                
-                if start_num > end_num {
-                    return Err(MinerError::InvalidResponse);
-                }
-                
-                let ips: Vec<IpAddr> = (start_num..=end_num)
-                    .map(|n| IpAddr::V4(n.into()))
-                    .collect();
-                
-                Ok(ips)
-            }
-            _ => Err(MinerError::InvalidResponse),
+impl ScanConfig {
+    /// Scan configuration from the persisted app settings
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        let defaults = Self::default();
+        Self {
+            timeout_ms: settings.scan_timeout_ms,
+            max_concurrent: settings.scan_thread_count.max(1) as usize,
+            ports: if settings.scan_ports.is_empty() { defaults.ports.clone() } else { settings.scan_ports.clone() },
+            scan_devices: settings.scan_devices,
+            device_probe: DeviceProbe {
+                community: settings.snmp_community.clone(),
+                ..DeviceProbe::default()
+            },
+            detect_conflicts: settings.detect_ip_conflicts,
+            factory_ips: settings.factory_default_ips.clone(),
+            ..defaults
         }
     }
-    // Single IP
-    else {
-        let ip: IpAddr = range
-            .parse()
-            .map_err(|_| MinerError::InvalidResponse)?;
-        Ok(vec![ip])
-    }
+}
+
+/// Parse a scan spec into a list of IP addresses
+/// Supports comma/newline separated lists of:
+/// - CIDR notation: "192.168.1.0/24"
+/// - Range notation: "192.168.1.1-192.168.1.254" or "192.168.1.10-50"
+/// - Wildcards: "10.1.*.*"
+/// - Exclusions: "!192.168.1.1"
+///
+/// See `spec` for the full grammar.
+pub fn parse_ip_range(range: &str) -> Result<Vec<IpAddr>> {
+    Ok(spec::parse_scan_spec(range)?)
 }
 
 /// Scan a network range for miners
@@ -100,33 +188,57 @@ pub async fn scan_range(
     range: &str,
     config: ScanConfig,
 ) -> Result<mpsc::Receiver<ScanEvent>> {
-    let ips = parse_ip_range(range)?;
-    let _total_ips = ips.len();
+    let (rx, _handle) = scan_range_cancellable(range, config).await?;
+    Ok(rx)
+}
+
+/// Like `scan_range`, but also returns a handle that can stop the scan
+pub async fn scan_range_cancellable(
+    range: &str,
+    config: ScanConfig,
+) -> Result<(mpsc::Receiver<ScanEvent>, ScanHandle)> {
+    scan_spec_cancellable(&ScanSpec::parse(range)?, config)
+}
+
+/// Like `scan_range_cancellable`, for a spec that is already parsed
+pub fn scan_spec_cancellable(
+    spec: &ScanSpec,
+    config: ScanConfig,
+) -> Result<(mpsc::Receiver<ScanEvent>, ScanHandle)> {
+    let ips = spec.addresses()?;
+    let scanned_networks = spec.networks();
     
     let (tx, rx) = mpsc::channel(100);
+    let handle = ScanHandle::new();
     
     // Spawn the scanning task
+    let scan_handle = handle.clone();
     tokio::spawn(async move {
-        scan_ips(ips, config, tx).await;
+        scan_ips(ips, scanned_networks, config, tx, scan_handle).await;
     });
     
-    Ok(rx)
+    Ok((rx, handle))
 }
 
 /// Internal function to scan a list of IPs
 async fn scan_ips(
     ips: Vec<IpAddr>,
+    scanned_networks: Vec<Ipv4Network>,
     config: ScanConfig,
     tx: mpsc::Sender<ScanEvent>,
+    handle: ScanHandle,
 ) {
     let total_ips = ips.len();
     
     // Send started event
     let _ = tx.send(ScanEvent::Started { total_ips }).await;
     
-    // Create semaphore to limit concurrent connections
-    let semaphore = Arc::new(Semaphore::new(config.max_concurrent));
+    // Separate limits for the cheap connect sweep and for identification
+    let sweep_permits = Arc::new(Semaphore::new(config.sweep_concurrent.max(1)));
+    let identify_permits = Arc::new(Semaphore::new(config.max_concurrent.max(1)));
     let tx = Arc::new(tx);
+    let local_networks = Arc::new(conflicts::local_networks());
+    let scanned_networks = Arc::new(scanned_networks);
     
     // Counters
     let scanned = Arc::new(tokio::sync::Mutex::new(0usize));
@@ -136,21 +248,54 @@ async fn scan_ips(
     let mut tasks = Vec::new();
     
     for ip in ips {
-        let semaphore = semaphore.clone();
+        let sweep_permits = sweep_permits.clone();
+        let identify_permits = identify_permits.clone();
         let tx = tx.clone();
         let config = config.clone();
         let scanned = scanned.clone();
         let found = found.clone();
+        let handle = handle.clone();
+        let local_networks = local_networks.clone();
+        let scanned_networks = scanned_networks.clone();
         
         let task = tokio::spawn(async move {
-            // Acquire semaphore permit
-            let _permit = semaphore.acquire().await.unwrap();
+            // Sweep, then identify if a port is open, unless the scan is
+            // cancelled first
+            let probe = async {
+                let (open_port, swept_at) = {
+                    let _permit = sweep_permits.acquire().await.unwrap();
+                    let swept_at = Instant::now();
+                    (find_open_port(ip, &config).await, swept_at)
+                };
+                let Some(open_port) = open_port else {
+                    if !config.scan_devices {
+                        return None;
+                    }
+                    let _permit = sweep_permits.acquire().await.unwrap();
+                    return probe_silent_device(ip, swept_at, &config).await.map(Probe::NonMiner);
+                };
+                let _permit = identify_permits.acquire().await.unwrap();
+                Some(probe_device(ip, open_port, swept_at, &config, &local_networks, &scanned_networks).await)
+            };
+            let result = tokio::select! {
+                biased;
+                _ = handle.cancelled() => return,
+                result = probe => result,
+            };
             
-            // Try to connect to the miner
-            if let Some(miner) = scan_single_ip(ip, &config).await {
-                // Found a miner!
-                *found.lock().await += 1;
-                let _ = tx.send(ScanEvent::Found(miner)).await;
+            match result {
+                Some(Probe::Miner(miner, events)) => {
+                    // Found a miner!
+                    *found.lock().await += 1;
+                    let _ = tx.send(ScanEvent::Found(miner)).await;
+                    for event in events {
+                        let _ = tx.send(event).await;
+                    }
+                }
+                Some(Probe::NonMiner(device)) => {
+                    let _ = tx.send(ScanEvent::NonMiner(device)).await;
+                }
+                None => {}
             }
             
             // Update progress
@@ -178,6 +323,15 @@ async fn scan_ips(
     
     // Send completion event
     let found_count = *found.lock().await;
+    
+    if handle.is_cancelled() {
+        let _ = tx.send(ScanEvent::Cancelled {
+            scanned: *scanned.lock().await,
+            found: found_count,
+        }).await;
+        return;
+    }
+    
     let failed_count = total_ips - found_count;
     
     let _ = tx.send(ScanEvent::Complete {
@@ -186,45 +340,193 @@ async fn scan_ips(
     }).await;
 }
 
-/// Scan a single IP address for a miner
-/// Returns Some(Miner) if found, None otherwise
-async fn scan_single_ip(ip: IpAddr, config: &ScanConfig) -> Option<Miner> {
-    // Try each port
+/// Phase 1: TCP connect sweep.
+/// Returns the first configured port that accepts a connection.
+async fn find_open_port(ip: IpAddr, config: &ScanConfig) -> Option<u16> {
+    let connect_timeout = Duration::from_millis(config.connect_timeout_ms);
+    let ip_str = ip.to_string();
     for &port in &config.ports {
-        // Try to get version first (lightweight check)
-        if let Ok(_version_response) = send_command(
-            &ip.to_string(),
-            port,
-            "version",
-            config.timeout_ms,
-        ).await {
-            // Successfully connected! Now get detailed stats
-            let stats = get_summary(&ip.to_string(), port, config.timeout_ms)
-                .await
-                .unwrap_or_default();
-            
-            // Miner model is now handled by the Parser::get_summary -> parse_version_data / fetch_details
-            let model = stats.model.clone();
-            
-            // Determine status based on stats
-            let status = determine_status_from_stats(&stats);
-            
-            return Some(Miner {
-                ip: ip.to_string(),
-                model,
-                status,
-                stats,
-                last_updated: SystemTime::now()
-                    .duration_since(SystemTime::UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs(),
-            });
+        if let Ok(Ok(_stream)) = tokio::time::timeout(connect_timeout, source::connect(&ip_str, port)).await {
+            return Some(port);
         }
     }
-    
     None
 }
 
+/// What answered on an open port.  A miner comes with the events its
+/// sighting raised (moves, take-overs, conflicts), sent right after `Found`.
+#[allow(clippy::large_enum_variant)]
+enum Probe {
+    Miner(Miner, Vec<ScanEvent>),
+    NonMiner(Device),
+}
+
+/// Phase 2: decide what sits behind an open port.
+///
+/// The sweep's connect has just put the device in the ARP table (if it is on
+/// our segment), so its OUI is known before anything is sent and miners get
+/// their driver picked by vendor instead of from the summary.  The OUI alone
+/// never rules a device out: controllers on single-board computers and
+/// swapped network cards carry other vendors' MACs, so anything that doesn't
+/// answer the CGMiner probe is described as a non-miner afterwards.
+async fn probe_device(
+    ip: IpAddr,
+    port: u16,
+    swept_at: Instant,
+    config: &ScanConfig,
+    local_networks: &[Ipv4Network],
+    scanned_networks: &[Ipv4Network],
+) -> Probe {
+    let ip_str = ip.to_string();
+    let mac = arp::ARP_CACHE.lookup_after(&ip_str, swept_at).await;
+    let oui_vendor = mac.as_deref().and_then(oui::lookup);
+
+    match identify_miner(ip, port, config, oui_vendor.and_then(|vendor| vendor.driver)).await {
+        Some((mut miner, changes, vendor)) => {
+            // Routed miners only reveal their MAC through the inventory fetch
+            miner.vendor_hint = oui_vendor
+                .or_else(|| miner.stats.mac_address.as_deref().and_then(oui::lookup))
+                .map(|vendor| vendor.name.to_string());
+
+            let mut events: Vec<ScanEvent> = changes.into_iter().map(ScanEvent::from).collect();
+            events.extend(check_conflicts(&miner, port, vendor, mac, config, local_networks, scanned_networks).await);
+            Probe::Miner(miner, events)
+        }
+        None => Probe::NonMiner(devices::describe(&ip_str, Some(port), mac, None, &config.device_probe).await),
+    }
+}
+
+/// Conflict checks for a freshly identified miner: the factory-default list,
+/// its network config against the local subnet or scanned range and, with
+/// `detect_conflicts`, its inventory and a second ARP lookup against what
+/// the probe saw.
+async fn check_conflicts(
+    miner: &Miner,
+    port: u16,
+    vendor: MinerVendor,
+    swept_mac: Option<String>,
+    config: &ScanConfig,
+    local_networks: &[Ipv4Network],
+    scanned_networks: &[Ipv4Network],
+) -> Vec<ScanEvent> {
+    let ip = &miner.ip;
+    let mut events = Vec::new();
+
+    if config.detect_conflicts {
+        events.extend(check_duplicate(miner, port, vendor, swept_mac, config).await);
+    }
+
+    if conflicts::is_factory_default(ip, &config.factory_ips) {
+        events.push(ScanEvent::FactoryDefaultIp {
+            ip: ip.clone(),
+            id: miner.id.clone(),
+            mac_address: miner.stats.mac_address.clone(),
+        });
+    }
+
+    let netmask = miner.stats.netmask.as_deref();
+    let gateway = miner.stats.gateway.as_deref();
+    if let Some((expected_subnet, reason)) = conflicts::network_mismatch(ip, netmask, gateway, local_networks, scanned_networks) {
+        events.push(ScanEvent::NetworkMismatch {
+            ip: ip.clone(),
+            id: miner.id.clone(),
+            netmask: miner.stats.netmask.clone(),
+            gateway: miner.stats.gateway.clone(),
+            expected_subnet,
+            reason,
+        });
+    }
+    events
+}
+
+/// Compare the miner's inventory and a second ARP lookup with what the
+/// sweep and identification saw.  The inventory is the cached one if
+/// identification already read it.
+async fn check_duplicate(
+    miner: &Miner,
+    port: u16,
+    vendor: MinerVendor,
+    swept_mac: Option<String>,
+    config: &ScanConfig,
+) -> Option<ScanEvent> {
+    let ip = &miner.ip;
+    let mut sightings = vec![
+        conflicts::Sighting { mac_address: swept_mac, serial_number: None },
+        conflicts::Sighting {
+            mac_address: miner.stats.mac_address.clone(),
+            serial_number: miner.stats.serial_number.clone(),
+        },
+    ];
+    if let Some(inventory) = inventory::cached_inventory(ip, port, vendor, config.timeout_ms).await {
+        sightings.push(conflicts::Sighting { mac_address: inventory.mac_address, serial_number: inventory.serial_number });
+    }
+    let probed_at = Instant::now();
+    sightings.push(conflicts::Sighting {
+        mac_address: arp::ARP_CACHE.lookup_after(ip, probed_at).await,
+        serial_number: None,
+    });
+    let (mac_addresses, serial_numbers) = conflicts::conflicting(&sightings)?;
+    eprintln!("[scanner] {} answers as several devices: {:?} {:?}", ip, mac_addresses, serial_numbers);
+    Some(ScanEvent::DuplicateIp { ip: ip.clone(), mac_addresses, serial_numbers })
+}
+
+/// `scan_devices`: ask an address with no open miner port for its SNMP
+/// system description.  Only agents that answer are described further.
+async fn probe_silent_device(ip: IpAddr, swept_at: Instant, config: &ScanConfig) -> Option<Device> {
+    let ip_str = ip.to_string();
+    let sys = devices::snmp_system(&ip_str, &config.device_probe).await.ok()?;
+    let mac = arp::ARP_CACHE.lookup_after(&ip_str, swept_at).await;
+    Some(devices::describe(&ip_str, None, mac, Some(sys), &config.device_probe).await)
+}
+
+/// Identify the miner behind an open port, with `driver` chosen up front if known
+/// Returns Some(Miner) if it answers like a miner, None otherwise, along with
+/// any IP <-> id changes this sighting revealed and the driver it answered to
+async fn identify_miner(
+    ip: IpAddr,
+    port: u16,
+    config: &ScanConfig,
+    driver: Option<MinerVendor>,
+) -> Option<(Miner, Vec<IdentityChange>, MinerVendor)> {
+    let ip_str = ip.to_string();
+    let report = if config.fetch_details {
+        get_report(&ip_str, port, config.timeout_ms).await
+    } else {
+        identify_as(&ip_str, port, config.timeout_ms, driver).await
+    };
+    let report = report.ok()?;
+    
+    // Remember what kind of miner this is for later commands
+    crate::identity::record_report(&ip_str, port, &report);
+    let vendor = report.vendor;
+    let stats = report.stats;
+    
+    // Miner model is now handled by the Parser::parse_summary -> fetch_identity
+    let model = stats.model.clone();
+    
+    // Determine status based on stats
+    let status = determine_status_from_stats(&stats);
+    
+    let id = MinerId::derive(&stats);
+    let changes = id.as_ref()
+        .map(|id| crate::identity::observe(&ip_str, id))
+        .unwrap_or_default();
+    
+    let miner = Miner {
+        id,
+        ip: ip_str,
+        model,
+        status,
+        stats,
+        last_updated: SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        vendor_hint: None,
+        outlet: None,
+    };
+    Some((miner, changes, vendor))
+}
 
 /// Determine miner status based on stats
 fn determine_status_from_stats(stats: &crate::core::MinerStats) -> MinerStatus {
@@ -274,6 +576,27 @@ mod tests {
         assert_eq!(ips[0].to_string(), "192.168.1.100");
     }
     
+    #[test]
+    fn test_config_from_settings() {
+        let settings = AppSettings {
+            scan_thread_count: 16,
+            scan_timeout_ms: 1500,
+            scan_ports: vec![4028, 4029],
+            detect_ip_conflicts: true,
+            ..Default::default()
+        };
+        let config = ScanConfig::from_settings(&settings);
+        assert!(config.detect_conflicts);
+        assert!(!ScanConfig::default().detect_conflicts);
+        assert_eq!(config.max_concurrent, 16);
+        assert_eq!(config.timeout_ms, 1500);
+        assert_eq!(config.ports, vec![4028, 4029]);
+        
+        // Settings saved before ports existed fall back to the default port
+        let config = ScanConfig::from_settings(&AppSettings { scan_ports: Vec::new(), ..Default::default() });
+        assert_eq!(config.ports, vec![4028]);
+    }
+    
     #[test]
     fn test_invalid_range() {
         // End before start
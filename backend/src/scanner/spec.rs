@@ -0,0 +1,414 @@
+//! Scan-spec grammar.
+//!
+//! A spec is a list of entries separated by commas or newlines.  Each entry is
+//! one of:
+//!
+//! - a single address: `10.1.2.3`
+//! - a CIDR block: `10.1.2.0/24`
+//! - a full range: `10.1.2.250-10.1.3.5`
+//! - an octet pattern, where any octet may be `*` or `a-b`:
+//!   `10.1.2.10-50`, `10.1.*.*`, `10.1.2-3.*`
+//!
+//! Prefixing an entry with `!` excludes its addresses from the result, no
+//! matter where in the spec the exclusion appears.  Duplicates are dropped and
+//! addresses keep the order of the first entry that mentions them.
+
+use ipnetwork::{IpNetwork, Ipv4Network};
+use std::collections::HashSet;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use thiserror::Error;
+
+/// Most addresses a single spec may expand to (a /12 worth of IPv4).
+pub const MAX_SPEC_ADDRESSES: u128 = 1 << 20;
+
+/// Where in the spec an entry starts (both 1-based)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpecPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for SpecPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Why a scan spec could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ScanSpecError {
+    #[error("Scan spec is empty")]
+    Empty,
+
+    #[error("{at}: '{entry}' is not an IP address, range, CIDR block or octet pattern")]
+    InvalidEntry { entry: String, at: SpecPosition },
+
+    #[error("{at}: octet '{octet}' in '{entry}' must be 0-255, '*' or a range like 10-50")]
+    InvalidOctet { entry: String, octet: String, at: SpecPosition },
+
+    #[error("{at}: prefix length in '{entry}' is out of range")]
+    InvalidPrefix { entry: String, at: SpecPosition },
+
+    #[error("{at}: '{entry}' ends before it starts")]
+    ReversedRange { entry: String, at: SpecPosition },
+
+    #[error("{at}: '{entry}' mixes IPv4 and IPv6 addresses")]
+    MixedFamilies { entry: String, at: SpecPosition },
+
+    #[error("{at}: '!' must be followed by an address, range or pattern")]
+    EmptyExclusion { at: SpecPosition },
+
+    #[error("Scan spec covers {count} addresses, more than the limit of {limit}")]
+    TooManyAddresses { count: u128, limit: u128 },
+
+    #[error("Every address in the scan spec is excluded")]
+    AllExcluded,
+}
+
+/// A set of addresses described by one entry
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    /// Contiguous range (single address, CIDR block or full range)
+    Span { start: IpAddr, end: IpAddr },
+    /// IPv4 octet pattern; each octet is an inclusive range
+    Octets([(u8, u8); 4]),
+}
+
+impl Block {
+    /// Saturates at `u128::MAX`: `::/0` holds one address more than that.
+    fn count(&self) -> u128 {
+        match self {
+            Block::Span { start, end } => (to_u128(end) - to_u128(start)).saturating_add(1),
+            Block::Octets(octets) => octets
+                .iter()
+                .map(|(low, high)| (*high - *low) as u128 + 1)
+                .product(),
+        }
+    }
+
+    /// The IPv4 CIDR block this entry covers exactly, if it is one
+    fn network(&self) -> Option<Ipv4Network> {
+        let (start, end) = match self {
+            Block::Span { start: IpAddr::V4(start), end: IpAddr::V4(end) } => (u32::from(*start), u32::from(*end)),
+            Block::Span { .. } => return None,
+            Block::Octets(octets) => (
+                u32::from_be_bytes(octets.map(|(low, _)| low)),
+                u32::from_be_bytes(octets.map(|(_, high)| high)),
+            ),
+        };
+        let size = (end - start) as u64 + 1;
+        if !size.is_power_of_two() || !(start as u64).is_multiple_of(size) || self.count() != size as u128 {
+            return None;
+        }
+        Ipv4Network::new(Ipv4Addr::from(start), 32 - size.trailing_zeros() as u8).ok()
+    }
+
+    fn contains(&self, ip: &IpAddr) -> bool {
+        match self {
+            Block::Span { start, end } => {
+                start.is_ipv4() == ip.is_ipv4() && (to_u128(start)..=to_u128(end)).contains(&to_u128(ip))
+            }
+            Block::Octets(octets) => match ip {
+                IpAddr::V4(v4) => v4
+                    .octets()
+                    .iter()
+                    .zip(octets)
+                    .all(|(octet, (low, high))| (*low..=*high).contains(octet)),
+                IpAddr::V6(_) => false,
+            },
+        }
+    }
+
+    fn addresses(&self) -> Vec<IpAddr> {
+        match self {
+            Block::Span { start, end } => (to_u128(start)..=to_u128(end))
+                .map(|n| from_u128(n, start.is_ipv4()))
+                .collect(),
+            Block::Octets([a, b, c, d]) => {
+                let mut ips = Vec::with_capacity(self.count() as usize);
+                for o1 in a.0..=a.1 {
+                    for o2 in b.0..=b.1 {
+                        for o3 in c.0..=c.1 {
+                            for o4 in d.0..=d.1 {
+                                ips.push(IpAddr::V4(Ipv4Addr::new(o1, o2, o3, o4)));
+                            }
+                        }
+                    }
+                }
+                ips
+            }
+        }
+    }
+}
+
+/// A parsed scan spec: which addresses it includes and excludes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanSpec {
+    include: Vec<Block>,
+    exclude: Vec<Block>,
+}
+
+impl ScanSpec {
+    /// Parse a spec without expanding it.
+    pub fn parse(spec: &str) -> Result<Self, ScanSpecError> {
+        let mut include = Vec::new();
+        let mut exclude = Vec::new();
+
+        for (line_index, line) in spec.lines().enumerate() {
+            let mut offset = 0;
+            for raw in line.split(',') {
+                let leading = raw.len() - raw.trim_start().len();
+                let at = SpecPosition { line: line_index + 1, column: offset + leading + 1 };
+                offset += raw.len() + 1;
+
+                let entry = raw.trim();
+                if entry.is_empty() {
+                    continue;
+                }
+
+                match entry.strip_prefix('!') {
+                    Some(excluded) => {
+                        let excluded = excluded.trim();
+                        if excluded.is_empty() {
+                            return Err(ScanSpecError::EmptyExclusion { at });
+                        }
+                        exclude.push(parse_entry(excluded, at)?);
+                    }
+                    None => include.push(parse_entry(entry, at)?),
+                }
+            }
+        }
+
+        if include.is_empty() {
+            return Err(ScanSpecError::Empty);
+        }
+        Ok(Self { include, exclude })
+    }
+
+    /// Whether `ip` is covered by the spec (and not excluded).
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        self.include.iter().any(|block| block.contains(ip))
+            && !self.exclude.iter().any(|block| block.contains(ip))
+    }
+
+    /// Included IPv4 entries that are whole CIDR blocks, however they were
+    /// written (`10.1.2.0/24`, `10.1.2.*`, `10.1.2.0-10.1.2.255`).
+    pub fn networks(&self) -> Vec<Ipv4Network> {
+        self.include.iter().filter_map(Block::network).collect()
+    }
+
+    /// Expand to the addresses to probe, in spec order without duplicates.
+    pub fn addresses(&self) -> Result<Vec<IpAddr>, ScanSpecError> {
+        let count = self.include.iter().map(Block::count).fold(0u128, u128::saturating_add);
+        if count > MAX_SPEC_ADDRESSES {
+            return Err(ScanSpecError::TooManyAddresses { count, limit: MAX_SPEC_ADDRESSES });
+        }
+
+        let mut seen = HashSet::new();
+        let ips: Vec<IpAddr> = self
+            .include
+            .iter()
+            .flat_map(Block::addresses)
+            .filter(|ip| !self.exclude.iter().any(|block| block.contains(ip)))
+            .filter(|ip| seen.insert(*ip))
+            .collect();
+
+        if ips.is_empty() {
+            return Err(ScanSpecError::AllExcluded);
+        }
+        Ok(ips)
+    }
+}
+
+/// Parse a scan spec and expand it to the addresses to probe.
+pub fn parse_scan_spec(spec: &str) -> Result<Vec<IpAddr>, ScanSpecError> {
+    ScanSpec::parse(spec)?.addresses()
+}
+
+fn parse_entry(entry: &str, at: SpecPosition) -> Result<Block, ScanSpecError> {
+    let invalid = || ScanSpecError::InvalidEntry { entry: entry.to_string(), at };
+
+    // CIDR block
+    if entry.contains('/') {
+        let network: IpNetwork = entry.parse().map_err(|_| {
+            // Tell a bad prefix apart from a bad address
+            let (address, _) = entry.split_once('/').unwrap_or((entry, ""));
+            if address.trim().parse::<IpAddr>().is_ok() {
+                ScanSpecError::InvalidPrefix { entry: entry.to_string(), at }
+            } else {
+                invalid()
+            }
+        })?;
+        return Ok(Block::Span { start: network.network(), end: last_address(&network) });
+    }
+
+    // Single address (IPv4 or IPv6)
+    if let Ok(ip) = entry.parse::<IpAddr>() {
+        return Ok(Block::Span { start: ip, end: ip });
+    }
+
+    // Full range: both sides are complete addresses
+    if let Some((start, end)) = entry.split_once('-') {
+        if let (Ok(start), Ok(end)) = (start.trim().parse::<IpAddr>(), end.trim().parse::<IpAddr>()) {
+            if start.is_ipv4() != end.is_ipv4() {
+                return Err(ScanSpecError::MixedFamilies { entry: entry.to_string(), at });
+            }
+            if to_u128(&start) > to_u128(&end) {
+                return Err(ScanSpecError::ReversedRange { entry: entry.to_string(), at });
+            }
+            return Ok(Block::Span { start, end });
+        }
+    }
+
+    // IPv4 octet pattern
+    let parts: Vec<&str> = entry.split('.').collect();
+    if parts.len() != 4 {
+        return Err(invalid());
+    }
+
+    let mut octets = [(0u8, 0u8); 4];
+    for (slot, part) in octets.iter_mut().zip(&parts) {
+        *slot = parse_octet(part).map_err(|reversed| {
+            if reversed {
+                ScanSpecError::ReversedRange { entry: entry.to_string(), at }
+            } else {
+                ScanSpecError::InvalidOctet { entry: entry.to_string(), octet: part.to_string(), at }
+            }
+        })?;
+    }
+    Ok(Block::Octets(octets))
+}
+
+/// Parse `n`, `*` or `a-b`.  The error is `true` for a reversed range.
+fn parse_octet(part: &str) -> Result<(u8, u8), bool> {
+    let part = part.trim();
+    if part == "*" {
+        return Ok((0, 255));
+    }
+    match part.split_once('-') {
+        Some((low, high)) => {
+            let low: u8 = low.trim().parse().map_err(|_| false)?;
+            let high: u8 = high.trim().parse().map_err(|_| false)?;
+            if low > high {
+                return Err(true);
+            }
+            Ok((low, high))
+        }
+        None => {
+            let value: u8 = part.parse().map_err(|_| false)?;
+            Ok((value, value))
+        }
+    }
+}
+
+fn last_address(network: &IpNetwork) -> IpAddr {
+    let host_bits = match network {
+        IpNetwork::V4(v4) => 32 - v4.prefix() as u32,
+        IpNetwork::V6(v6) => 128 - v6.prefix() as u32,
+    };
+    let start = to_u128(&network.network());
+    let host_mask = if host_bits == 128 { u128::MAX } else { (1u128 << host_bits) - 1 };
+    from_u128(start | host_mask, network.is_ipv4())
+}
+
+fn to_u128(ip: &IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(v4) => u32::from(*v4) as u128,
+        IpAddr::V6(v6) => u128::from(*v6),
+    }
+}
+
+fn from_u128(n: u128, ipv4: bool) -> IpAddr {
+    if ipv4 {
+        IpAddr::V4(Ipv4Addr::from(n as u32))
+    } else {
+        IpAddr::V6(Ipv6Addr::from(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ips(spec: &str) -> Vec<String> {
+        parse_scan_spec(spec).unwrap().iter().map(IpAddr::to_string).collect()
+    }
+
+    #[test]
+    fn test_lists_and_short_ranges() {
+        let result = ips("10.1.2.10-12, 10.1.3.1\n10.1.4.0/31");
+        assert_eq!(result, vec!["10.1.2.10", "10.1.2.11", "10.1.2.12", "10.1.3.1", "10.1.4.0", "10.1.4.1"]);
+    }
+
+    #[test]
+    fn test_wildcards_and_exclusions() {
+        let result = parse_scan_spec("10.1.*.*").unwrap();
+        assert_eq!(result.len(), 65536);
+
+        let result = ips("!10.1.2.1, 10.1.2.*, !10.1.2.3-255");
+        assert_eq!(result, vec!["10.1.2.0", "10.1.2.2"]);
+    }
+
+    #[test]
+    fn test_duplicates_dropped() {
+        let result = ips("10.0.0.1-10.0.0.3\n10.0.0.2-4");
+        assert_eq!(result, vec!["10.0.0.1", "10.0.0.2", "10.0.0.3", "10.0.0.4"]);
+    }
+
+    #[test]
+    fn test_error_positions() {
+        let err = parse_scan_spec("10.0.0.1,\n  10.0.0.1, 10.0.300.*").unwrap_err();
+        assert_eq!(
+            err,
+            ScanSpecError::InvalidOctet {
+                entry: "10.0.300.*".to_string(),
+                octet: "300".to_string(),
+                at: SpecPosition { line: 2, column: 13 },
+            }
+        );
+        assert_eq!(err.to_string(), "line 2, column 13: octet '300' in '10.0.300.*' must be 0-255, '*' or a range like 10-50");
+
+        let err = parse_scan_spec("10.1.2.50-10").unwrap_err();
+        assert!(matches!(err, ScanSpecError::ReversedRange { at: SpecPosition { line: 1, column: 1 }, .. }));
+
+        assert!(matches!(parse_scan_spec("10.0.0.0/33"), Err(ScanSpecError::InvalidPrefix { .. })));
+        assert!(matches!(parse_scan_spec("miner-farm"), Err(ScanSpecError::InvalidEntry { .. })));
+        assert!(matches!(parse_scan_spec("10.0.0.1-::1"), Err(ScanSpecError::MixedFamilies { .. })));
+        assert!(matches!(parse_scan_spec("10.0.0.1, !"), Err(ScanSpecError::EmptyExclusion { .. })));
+    }
+
+    #[test]
+    fn test_contains() {
+        let spec = ScanSpec::parse("10.1.*.*, !10.1.0.0/24").unwrap();
+        assert!(spec.contains(&"10.1.7.9".parse().unwrap()));
+        assert!(!spec.contains(&"10.1.0.9".parse().unwrap()));
+        assert!(!spec.contains(&"10.2.7.9".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_networks() {
+        let spec = ScanSpec::parse("10.1.0.0/22, 10.2.3.*, 10.3.0.0-10.3.1.255, 10.4.1-2.*, 10.5.0.1-9, 10.6.0.7, ::/120").unwrap();
+        let networks: Vec<String> = spec.networks().iter().map(Ipv4Network::to_string).collect();
+        assert_eq!(networks, vec!["10.1.0.0/22", "10.2.3.0/24", "10.3.0.0/23", "10.6.0.7/32"]);
+    }
+
+    #[test]
+    fn test_empty_and_limits() {
+        assert_eq!(parse_scan_spec(" \n , "), Err(ScanSpecError::Empty));
+        assert_eq!(parse_scan_spec("!10.0.0.1"), Err(ScanSpecError::Empty));
+        assert_eq!(parse_scan_spec("10.0.0.1, !10.0.0.0/24"), Err(ScanSpecError::AllExcluded));
+        assert!(matches!(parse_scan_spec("10.*.*.*"), Err(ScanSpecError::TooManyAddresses { .. })));
+    }
+
+    #[test]
+    fn test_whole_ipv6_space_rejected() {
+        for spec in ["::/0", "::-ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", "::/1, 8000::/1"] {
+            assert_eq!(
+                parse_scan_spec(spec),
+                Err(ScanSpecError::TooManyAddresses { count: u128::MAX, limit: MAX_SPEC_ADDRESSES }),
+                "{spec}"
+            );
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use rust_lib_frontend::backup::{self, BackupStore, ConfigSnapshot, SnapshotContent, FORMAT_VERSION};
+use rust_lib_frontend::client::antminer_web::AntminerWebClient;
+use rust_lib_frontend::core::MinerId;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const CONF: &str = r#"{"pools":[{"url":"stratum+tcp://pool.example:3333","user":"acct.rig1","pass":"x"}],"api-listen":true,"bitmain-fan-ctrl":false,"bitmain-fan-pwm":"100","bitmain-work-mode":"3","bitmain-freq-level":"100"}"#;
+
+/// Mock Antminer web UI without auth: serves `CONF` and keeps the body of
+/// every `set_miner_conf.cgi` POST
+async fn start_mock_conf_server(port: u16) -> Arc<Mutex<Vec<String>>> {
+    let posted = Arc::new(Mutex::new(Vec::new()));
+    let listener = TcpListener::bind(format!("127.0.0.1:{}", port)).await.unwrap();
+    let bodies = posted.clone();
+
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else { continue };
+            let bodies = bodies.clone();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = vec![0; 4096];
+                loop {
+                    let Ok(n) = socket.read(&mut buffer).await else { return };
+                    if n == 0 {
+                        return;
+                    }
+                    request.extend_from_slice(&buffer[..n]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    let Some((head, body)) = text.split_once("\r\n\r\n") else { continue };
+                    let length = head
+                        .lines()
+                        .find_map(|l| l.to_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap_or(0)))
+                        .unwrap_or(0);
+                    if body.len() < length {
+                        continue;
+                    }
+
+                    let reply = if head.starts_with("POST /cgi-bin/set_miner_conf.cgi") {
+                        bodies.lock().unwrap().push(body.to_string());
+                        r#"{"stats":"success"}"#
+                    } else {
+                        CONF
+                    };
+                    let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", reply.len(), reply);
+                    let _ = socket.write_all(response.as_bytes()).await;
+                    request.clear();
+                }
+            });
+        }
+    });
+
+    posted
+}
+
+#[tokio::test]
+async fn test_antminer_conf_backup_and_restore() {
+    let posted = start_mock_conf_server(16016).await;
+    let host = "127.0.0.1:16016";
+
+    let conf = AntminerWebClient::get_conf_value(host, "root", "root").await.unwrap();
+    let root = std::env::temp_dir().join(format!("backup-it-{}", std::process::id()));
+    let store = BackupStore::new(&root);
+    let mut snapshot = ConfigSnapshot {
+        format: FORMAT_VERSION,
+        id: String::new(),
+        miner_id: MinerId::from_serial("SN1").unwrap(),
+        ip: host.to_string(),
+        model: None,
+        taken_at: 1_700_000_000_000,
+        note: Some("before the technician".into()),
+        content: SnapshotContent::Antminer { conf },
+    };
+    store.save(&mut snapshot).unwrap();
+
+    // Someone changed the fan by hand: the diff shows just that
+    let mut changed = snapshot.content.clone();
+    if let SnapshotContent::Antminer { conf } = &mut changed {
+        conf["bitmain-fan-ctrl"] = serde_json::Value::Bool(true);
+        conf["bitmain-fan-pwm"] = "40".into();
+    }
+    let keys: Vec<String> = backup::diff(&changed, &snapshot.content).into_iter().map(|c| c.key).collect();
+    assert_eq!(keys, vec!["bitmain-fan-ctrl", "bitmain-fan-pwm"]);
+
+    // Restore writes the whole conf back, power mode under its write key
+    let SnapshotContent::Antminer { conf } = store.load(&snapshot.id).unwrap().content else { panic!("not an Antminer snapshot") };
+    AntminerWebClient::set_conf(host, "root", "root", &conf).await.unwrap();
+
+    let bodies = posted.lock().unwrap().clone();
+    assert_eq!(bodies.len(), 1);
+    let written: serde_json::Value = serde_json::from_str(&bodies[0]).unwrap();
+    assert_eq!(written["miner-mode"], "3");
+    assert!(written.get("bitmain-work-mode").is_none());
+    assert_eq!(written["pools"][0]["user"], "acct.rig1");
+    assert_eq!(written["api-listen"], true);
+
+    let _ = std::fs::remove_dir_all(&root);
+}
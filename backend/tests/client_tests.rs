@@ -0,0 +1,291 @@
+use rust_lib_frontend::{get_summary, send_command, send_commands, MinerError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const SUMMARY: &str = r#"{"STATUS":[{"STATUS":"S","When":1738800000,"Code":11,"Msg":"Summary"}],"SUMMARY":[{"Elapsed":12345,"MHS av":95000000.0}],"id":1}"#;
+const POOLS: &str = r#"{"STATUS":[{"STATUS":"S","Msg":"1 Pool(s)"}],"POOLS":[{"URL":"stratum+tcp://pool.example.com:3333","User":"wallet.worker","Status":"Alive","Priority":0}],"id":1}"#;
+const VERSION: &str = r#"{"STATUS":[{"STATUS":"S","Msg":"BMMiner versions"}],"VERSION":[{"Type":"Antminer S19","Miner":"uart_trans.1.3","BMMiner":"1.0.0"}],"id":1}"#;
+const STATS: &str = r#"{"STATUS":[{"STATUS":"S","Msg":"CGMiner stats"}],"STATS":[{"fan1":3000,"temp_chip1":"60-70"}],"id":1}"#;
+const MINER_INFO: &str = r#"{"STATUS":"S","When":1738800000,"Code":131,"Msg":{"ip":"10.1.2.30","proto":"dhcp","netmask":"255.255.255.0","gateway":"10.1.2.1","dns":"10.1.2.1 8.8.8.8","hostname":"WhatsMiner","mac":"C4:82:E1:0A:0B:0C","minersn":"HTM3X10ABCDEF"},"Description":""}"#;
+const INVALID: &str = r#"{"STATUS":[{"STATUS":"E","Msg":"Invalid command"}],"id":1}"#;
+
+fn single_response(command: &str) -> &'static str {
+    match command {
+        "summary" => SUMMARY,
+        "pools" => POOLS,
+        "version" => VERSION,
+        "stats" => STATS,
+        "get_miner_info" => MINER_INFO,
+        _ => INVALID,
+    }
+}
+
+/// Mock bmminer that counts connections.
+/// With `joined` set it answers `a+b` commands with one combined object,
+/// otherwise it rejects them like older firmware.
+async fn start_mock_miner(port: u16, joined: bool) -> Arc<AtomicUsize> {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let listener = TcpListener::bind(format!("127.0.0.1:{}", port)).await.unwrap();
+    let counter = connections.clone();
+
+    tokio::spawn(async move {
+        loop {
+            if let Ok((mut socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut buffer = vec![0; 1024];
+                    if let Ok(n) = socket.read(&mut buffer).await {
+                        let request: serde_json::Value =
+                            serde_json::from_slice(&buffer[..n]).unwrap();
+                        let command = request["command"].as_str().unwrap_or_default();
+
+                        let response = if command.contains('+') {
+                            if joined {
+                                let sections: Vec<String> = command
+                                    .split('+')
+                                    .map(|c| format!(r#""{}":[{}]"#, c, single_response(c)))
+                                    .collect();
+                                format!("{{{},\"id\":1}}", sections.join(","))
+                            } else {
+                                INVALID.to_string()
+                            }
+                        } else {
+                            single_response(command).to_string()
+                        };
+
+                        let _ = socket.write_all(response.as_bytes()).await;
+                        let _ = socket.write_all(b"\0").await;
+                    }
+                });
+            }
+        }
+    });
+
+    connections
+}
+
+#[tokio::test]
+async fn test_send_commands_joined() {
+    let connections = start_mock_miner(16001, true).await;
+
+    let sections = send_commands("127.0.0.1", 16001, &["summary", "pools"], 1000)
+        .await
+        .unwrap();
+
+    assert_eq!(sections.len(), 2);
+    assert!(sections["summary"].contains("SUMMARY"));
+    assert!(sections["pools"].contains("wallet.worker"));
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_send_commands_falls_back_to_sequential() {
+    let connections = start_mock_miner(16002, false).await;
+
+    let sections = send_commands("127.0.0.1", 16002, &["summary", "pools"], 1000)
+        .await
+        .unwrap();
+
+    assert_eq!(sections.len(), 2);
+    assert!(sections["pools"].contains("wallet.worker"));
+    // One rejected joined request, then one per command
+    assert_eq!(connections.load(Ordering::SeqCst), 3);
+
+    // The rejection is remembered: no joined attempt on the next call
+    send_commands("127.0.0.1", 16002, &["summary", "pools"], 1000)
+        .await
+        .unwrap();
+    assert_eq!(connections.load(Ordering::SeqCst), 5);
+}
+
+#[tokio::test]
+async fn test_get_summary_single_round_trip() {
+    let connections = start_mock_miner(16003, true).await;
+
+    let stats = get_summary("127.0.0.1", 16003, 1000).await.unwrap();
+
+    assert_eq!(stats.uptime, 12345);
+    assert_eq!(stats.model.as_deref(), Some("Antminer S19"));
+    assert_eq!(stats.pool1.as_deref(), Some("stratum+tcp://pool.example.com:3333"));
+    assert_eq!(stats.fan_speeds.first().copied().flatten(), Some(3000));
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_send_command_does_not_wait_for_close() {
+    // Firmware that answers but keeps the socket open
+    let listener = TcpListener::bind("127.0.0.1:16004").await.unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buffer = vec![0; 1024];
+        let _ = socket.read(&mut buffer).await;
+        let _ = socket.write_all(SUMMARY.as_bytes()).await;
+        tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+    });
+
+    let start = std::time::Instant::now();
+    let response = send_command("127.0.0.1", 16004, "summary", 3000).await.unwrap();
+
+    assert_eq!(response, SUMMARY);
+    assert!(start.elapsed().as_millis() < 1000, "Read waited for socket close");
+}
+
+#[tokio::test]
+async fn test_send_command_truncated() {
+    let listener = TcpListener::bind("127.0.0.1:16005").await.unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buffer = vec![0; 1024];
+        let _ = socket.read(&mut buffer).await;
+        let _ = socket.write_all(&SUMMARY.as_bytes()[..40]).await;
+    });
+
+    let result = send_command("127.0.0.1", 16005, "summary", 1000).await;
+    assert!(matches!(result, Err(MinerError::TruncatedResponse { received: 40 })));
+}
+
+/// Minimal HTTP server that challenges requests without `Authorization`,
+/// answers signed ones with `body` and records the `nc` of each.
+async fn start_mock_digest_server(port: u16, body: &'static str) -> (Arc<AtomicUsize>, Arc<std::sync::Mutex<Vec<String>>>) {
+    let challenges = Arc::new(AtomicUsize::new(0));
+    let nonce_counts = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let listener = TcpListener::bind(format!("127.0.0.1:{}", port)).await.unwrap();
+    let (challenge_counter, nc_log) = (challenges.clone(), nonce_counts.clone());
+
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else { continue };
+            let (challenge_counter, nc_log) = (challenge_counter.clone(), nc_log.clone());
+            tokio::spawn(async move {
+                let mut buffer = vec![0; 4096];
+                // Keep-alive: serve requests until the client hangs up
+                while let Ok(n) = socket.read(&mut buffer).await {
+                    if n == 0 {
+                        break;
+                    }
+                    let request = String::from_utf8_lossy(&buffer[..n]).to_string();
+                    let auth = request
+                        .lines()
+                        .find(|l| l.to_lowercase().starts_with("authorization:"));
+
+                    let response = match auth {
+                        Some(line) => {
+                            let nc = line.split("nc=").nth(1).unwrap_or("").split(',').next().unwrap_or("");
+                            nc_log.lock().unwrap().push(nc.to_string());
+                            format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body)
+                        }
+                        None => {
+                            challenge_counter.fetch_add(1, Ordering::SeqCst);
+                            "HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Digest realm=\"antMiner Configuration\", nonce=\"abc123\", qop=\"auth\"\r\nContent-Length: 0\r\n\r\n".to_string()
+                        }
+                    };
+                    if socket.write_all(response.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    (challenges, nonce_counts)
+}
+
+#[tokio::test]
+async fn test_antminer_digest_challenge_reused() {
+    use rust_lib_frontend::client::antminer_web::AntminerWebClient;
+
+    let (challenges, nonce_counts) = start_mock_digest_server(16006, r#"{"blink":true}"#).await;
+    let host = "127.0.0.1:16006";
+
+    assert!(AntminerWebClient::get_led(host, "root", "root").await.unwrap());
+    assert!(AntminerWebClient::get_led(host, "root", "root").await.unwrap());
+
+    // Only the first call needed an unauthenticated probe
+    assert_eq!(challenges.load(Ordering::SeqCst), 1);
+    assert_eq!(*nonce_counts.lock().unwrap(), vec!["00000001", "00000002"]);
+}
+
+#[tokio::test]
+async fn test_antminer_conf_write_to_closed_port_fails() {
+    use rust_lib_frontend::client::antminer_web::AntminerWebClient;
+
+    // Serve one challenge and one signed answer, then stop listening
+    let listener = TcpListener::bind("127.0.0.1:16010").await.unwrap();
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buffer = vec![0; 4096];
+        for response in [
+            "HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Digest realm=\"antMiner Configuration\", nonce=\"abc123\", qop=\"auth\"\r\nContent-Length: 0\r\n\r\n",
+            "HTTP/1.1 200 OK\r\nContent-Length: 14\r\n\r\n{\"blink\":true}",
+        ] {
+            let n = socket.read(&mut buffer).await.unwrap();
+            assert!(n > 0);
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+    let host = "127.0.0.1:16010";
+    assert!(AntminerWebClient::get_led(host, "root", "root").await.unwrap());
+    server.await.unwrap();
+
+    // The cached challenge signs the write up front; the refused connection
+    // must not be mistaken for a miner rebooting mid-response
+    let result = AntminerWebClient::set_conf(host, "root", "root", &serde_json::json!({})).await;
+    assert!(matches!(result, Err(MinerError::Unreachable(_))), "{result:?}");
+}
+
+#[tokio::test]
+async fn test_identity_detection() {
+    use rust_lib_frontend::core::{FirmwareFamily, MinerVendor};
+    use rust_lib_frontend::identity;
+
+    start_mock_miner(16007, true).await;
+
+    let detected = identity::detect("127.0.0.1", 16007, 1000).await.unwrap();
+    assert_eq!(detected.vendor, MinerVendor::Antminer);
+    assert_eq!(detected.firmware_family, FirmwareFamily::Unknown);
+    assert_eq!(identity::IDENTITIES.get("127.0.0.1"), Some(detected));
+
+    // Nothing listening: a clear error instead of a default vendor
+    let result = identity::detect("127.0.0.1", 16099, 500).await;
+    assert!(matches!(result, Err(MinerError::DetectionFailed { .. })));
+    let error = result.unwrap_err();
+    assert_eq!(error.kind(), rust_lib_frontend::core::ErrorKind::Unreachable);
+    assert!(error.is_retryable());
+}
+
+#[tokio::test]
+async fn test_antminer_system_info() {
+    use rust_lib_frontend::client::antminer_web::AntminerWebClient;
+    use rust_lib_frontend::client::inventory::DeviceInventory;
+    use rust_lib_frontend::core::NetworkMode;
+
+    let body = r#"{"minertype":"Antminer S19j Pro","nettype":"Static","macaddr":"02:1A:2B:3C:4D:5E","hostname":"rack3-s19","ipaddress":"10.1.2.21","netmask":"255.255.255.0","gateway":"10.1.2.1","dnsservers":"10.1.2.1 ","firmware_type":"Release","serinum":"JYZZB2ABCDEF1234"}"#;
+    start_mock_digest_server(16008, body).await;
+
+    let info = AntminerWebClient::get_system_info("127.0.0.1:16008", "root", "root").await.unwrap();
+    let inventory = DeviceInventory::from_antminer(&info);
+    assert_eq!(inventory.mac_address.as_deref(), Some("02:1A:2B:3C:4D:5E"));
+    assert_eq!(inventory.hostname.as_deref(), Some("rack3-s19"));
+    assert_eq!(inventory.network_mode, Some(NetworkMode::Static));
+    assert_eq!(inventory.dns_servers, vec!["10.1.2.1"]);
+}
+
+#[tokio::test]
+async fn test_whatsminer_inventory_fills_stats() {
+    use rust_lib_frontend::client::inventory;
+    use rust_lib_frontend::core::{MinerStats, MinerVendor, NetworkMode};
+
+    start_mock_miner(16009, false).await;
+
+    let mut stats = MinerStats::default();
+    inventory::fill_inventory("127.0.0.1", 16009, MinerVendor::Whatsminer, 1000, &mut stats).await;
+
+    // Straight from the device, not from the ARP table
+    assert_eq!(stats.mac_address.as_deref(), Some("C4:82:E1:0A:0B:0C"));
+    assert_eq!(stats.serial_number.as_deref(), Some("HTM3X10ABCDEF"));
+    assert_eq!(stats.network_mode, Some(NetworkMode::Dhcp));
+    assert_eq!(stats.gateway.as_deref(), Some("10.1.2.1"));
+    assert_eq!(stats.dns_servers, vec!["10.1.2.1", "8.8.8.8"]);
+}
@@ -0,0 +1,192 @@
+use rust_lib_frontend::client::antminer_web::AntminerWebClient;
+use rust_lib_frontend::client::credentials::{self, Candidate};
+use rust_lib_frontend::core::{MinerCredentials, MinerError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const REALM: &str = "antMiner Configuration";
+const NONCE: &str = "abc123";
+
+fn md5_hex(input: String) -> String {
+    format!("{:x}", md5::compute(input))
+}
+
+fn param<'a>(header: &'a str, name: &str) -> &'a str {
+    header
+        .split(',')
+        .map(str::trim)
+        .find_map(|part| part.strip_prefix(name).and_then(|rest| rest.strip_prefix('=')))
+        .unwrap_or("")
+        .trim_matches('"')
+}
+
+/// What the mock does with `passwd.cgi`
+#[derive(Clone, Copy, PartialEq)]
+enum Passwd {
+    Change,
+    /// Answers P000 but keeps the old password
+    Ignore,
+    /// Answers with an error code
+    Refuse,
+}
+
+/// Mock Antminer web UI that only accepts `root` with its current password,
+/// counting the signed requests it refused
+async fn start_mock_digest_server(port: u16, password: &str, passwd: Passwd) -> (Arc<AtomicUsize>, Arc<Mutex<String>>) {
+    let refused = Arc::new(AtomicUsize::new(0));
+    let current = Arc::new(Mutex::new(password.to_string()));
+    let listener = TcpListener::bind(format!("127.0.0.1:{}", port)).await.unwrap();
+    let (counter, password) = (refused.clone(), current.clone());
+
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else { continue };
+            let (counter, password) = (counter.clone(), password.clone());
+            tokio::spawn(async move {
+                let mut buffer = vec![0; 4096];
+                while let Ok(n) = socket.read(&mut buffer).await {
+                    if n == 0 {
+                        break;
+                    }
+                    let request = String::from_utf8_lossy(&buffer[..n]).to_string();
+                    let (method, uri) = {
+                        let mut first = request.split_whitespace();
+                        (first.next().unwrap_or("").to_string(), first.next().unwrap_or("").to_string())
+                    };
+                    let auth = request
+                        .lines()
+                        .find(|l| l.to_lowercase().starts_with("authorization:"))
+                        .map(|l| l.split_once(':').unwrap().1.trim().trim_start_matches("Digest ").to_string());
+
+                    let accepted = auth.as_deref().is_some_and(|auth| {
+                        let ha1 = md5_hex(format!("{}:{}:{}", param(auth, "username"), REALM, password.lock().unwrap()));
+                        let ha2 = md5_hex(format!("{}:{}", method, uri));
+                        let expected = md5_hex(format!(
+                            "{}:{}:{}:{}:auth:{}",
+                            ha1,
+                            NONCE,
+                            param(auth, "nc"),
+                            param(auth, "cnonce"),
+                            ha2
+                        ));
+                        param(auth, "username") == "root" && param(auth, "response") == expected
+                    });
+                    if auth.is_some() && !accepted {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                    }
+
+                    let response = if accepted {
+                        let body = match uri.as_str() {
+                            "/cgi-bin/passwd.cgi" => {
+                                let json: serde_json::Value = serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap_or("")).unwrap();
+                                match passwd {
+                                    Passwd::Change => {
+                                        *password.lock().unwrap() = json["newPwd"].as_str().unwrap().to_string();
+                                        r#"{"code":"P000"}"#
+                                    }
+                                    Passwd::Ignore => r#"{"code":"P000"}"#,
+                                    Passwd::Refuse => r#"{"code":"P001"}"#,
+                                }
+                            }
+                            "/cgi-bin/get_system_info.cgi" => r#"{"minertype":"Antminer S19","serinum":"SN1"}"#,
+                            _ => r#"{"blink":false}"#,
+                        };
+                        format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body)
+                    } else {
+                        format!(
+                            "HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Digest realm=\"{}\", nonce=\"{}\", qop=\"auth\"\r\nContent-Length: 0\r\n\r\n",
+                            REALM, NONCE
+                        )
+                    };
+                    if socket.write_all(response.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    (refused, current)
+}
+
+fn candidate(set_name: Option<&str>, password: &str) -> Candidate {
+    Candidate {
+        set_name: set_name.map(str::to_string),
+        credentials: MinerCredentials::new("root".into(), password.into()),
+    }
+}
+
+async fn get_led(host: &str, list: Vec<Candidate>) -> Result<bool, MinerError> {
+    credentials::try_candidates(host, None, list, |c| async move {
+        AntminerWebClient::get_led(host, &c.username, &c.password).await
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_credential_fallback_and_flagging() {
+    let (refused, _) = start_mock_digest_server(16012, "site-b", Passwd::Change).await;
+    let host = "127.0.0.1:16012";
+    let list = vec![candidate(Some("fleet"), "fleet"), candidate(Some("site-b"), "site-b"), candidate(None, "root")];
+
+    // Falls back past the refused set and remembers the one that worked
+    assert!(!get_led(host, list.clone()).await.unwrap());
+    assert_eq!(credentials::remembered(host), Some(Some("site-b".to_string())));
+    let refused_first = refused.load(Ordering::SeqCst);
+    assert!(refused_first >= 1);
+
+    // The remembered set goes first: no more refusals
+    assert!(!get_led(host, list.clone()).await.unwrap());
+    assert_eq!(refused.load(Ordering::SeqCst), refused_first);
+    assert!(!credentials::is_flagged(host));
+
+    // Nothing matches: flagged, and later calls only try one candidate
+    let wrong = vec![candidate(Some("fleet"), "fleet"), candidate(None, "root")];
+    let error = get_led(host, wrong.clone()).await.unwrap_err();
+    assert!(matches!(error, MinerError::AuthenticationError));
+    assert_eq!(error.suggested_action(), "Check the web credentials for this miner in Settings");
+    assert!(credentials::is_flagged(host));
+    let failure = credentials::auth_failures().into_iter().find(|f| f.ip == host).unwrap();
+    assert_eq!(failure.tried, 2);
+
+    let before = refused.load(Ordering::SeqCst);
+    assert!(get_led(host, wrong).await.is_err());
+    // One candidate: signed with the cached challenge, then with a fresh one
+    assert_eq!(refused.load(Ordering::SeqCst) - before, 2);
+
+    // Once the right credential is back the flag clears
+    assert!(get_led(host, list).await.is_ok());
+    assert!(!credentials::is_flagged(host));
+}
+
+#[tokio::test]
+async fn test_password_change_verified_or_rolled_back() {
+    use rust_lib_frontend::client::rotation::{change_and_verify, RotationOutcome};
+    use rust_lib_frontend::core::{ErrorKind, MinerVendor};
+
+    let old = MinerCredentials::new("root".into(), "root".into());
+    let new = MinerCredentials::new("root".into(), "Xk7pQ2mN9vR4".into());
+
+    // Changed and verified with a fresh login
+    let (_, password) = start_mock_digest_server(16013, "root", Passwd::Change).await;
+    let (outcome, message) = change_and_verify("127.0.0.1:16013", MinerVendor::Antminer, &old, &new).await.unwrap();
+    assert_eq!(outcome, RotationOutcome::Rotated);
+    assert_eq!(message, None);
+    assert_eq!(*password.lock().unwrap(), "Xk7pQ2mN9vR4");
+
+    // Claims success but keeps the old password: reported, old still works
+    let (_, password) = start_mock_digest_server(16014, "root", Passwd::Ignore).await;
+    let (outcome, message) = change_and_verify("127.0.0.1:16014", MinerVendor::Antminer, &old, &new).await.unwrap();
+    assert_eq!(outcome, RotationOutcome::RolledBack);
+    assert!(message.unwrap().contains("kept the old one"));
+    assert_eq!(*password.lock().unwrap(), "root");
+
+    // Refused outright: nothing changed
+    let (_, password) = start_mock_digest_server(16015, "root", Passwd::Refuse).await;
+    let error = change_and_verify("127.0.0.1:16015", MinerVendor::Antminer, &old, &new).await.unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::RejectedByMiner { code: "P001".to_string() });
+    assert!(!error.is_retryable());
+    assert_eq!(*password.lock().unwrap(), "root");
+}
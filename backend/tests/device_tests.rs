@@ -0,0 +1,107 @@
+use rust_lib_frontend::core::DeviceKind;
+use rust_lib_frontend::devices::snmp::{Message, PduType, SnmpValue, SYS_DESCR, SYS_NAME};
+use rust_lib_frontend::devices::{self, DeviceProbe};
+use rust_lib_frontend::{scan_range, ScanConfig, ScanEvent};
+use std::collections::HashMap;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, UdpSocket};
+
+const APC_DESCR: &str = "APC Web/SNMP Management Card (MB:v4.1.0 PF:v6.4.6 PN:apc_hw05_aos_646.bin AF1:v6.4.6)";
+
+/// Local SNMP v2c agent answering GETs from a fixed set of objects.
+/// Requests with another community are dropped, like a real agent does.
+async fn start_snmp_simulator(port: u16, community: &'static str, objects: HashMap<&'static str, SnmpValue>) {
+    let socket = UdpSocket::bind(format!("127.0.0.1:{}", port)).await.unwrap();
+    tokio::spawn(async move {
+        let mut buffer = vec![0u8; 4096];
+        loop {
+            let Ok((n, peer)) = socket.recv_from(&mut buffer).await else { continue };
+            let Ok(request) = Message::decode(&buffer[..n]) else { continue };
+            if request.pdu_type != PduType::GetRequest || request.community != community {
+                continue;
+            }
+
+            let response = Message {
+                pdu_type: PduType::GetResponse,
+                varbinds: request
+                    .varbinds
+                    .iter()
+                    .map(|(oid, _)| (oid.clone(), objects.get(oid.as_str()).cloned().unwrap_or(SnmpValue::Missing)))
+                    .collect(),
+                ..request
+            };
+            let _ = socket.send_to(&response.encode().unwrap(), peer).await;
+        }
+    });
+}
+
+fn pdu_objects() -> HashMap<&'static str, SnmpValue> {
+    HashMap::from([
+        (SYS_DESCR, SnmpValue::OctetString(APC_DESCR.as_bytes().to_vec())),
+        (SYS_NAME, SnmpValue::OctetString(b"container-a-pdu-3".to_vec())),
+    ])
+}
+
+#[tokio::test]
+async fn test_snmp_system_info() {
+    start_snmp_simulator(16161, "public", pdu_objects()).await;
+
+    let probe = DeviceProbe { snmp_port: 16161, ..Default::default() };
+    let (descr, name) = devices::snmp_system("127.0.0.1", &probe).await.unwrap();
+    assert_eq!(descr.as_deref(), Some(APC_DESCR));
+    assert_eq!(name.as_deref(), Some("container-a-pdu-3"));
+
+    // Wrong community: the agent stays silent
+    let probe = DeviceProbe { community: "private".into(), timeout_ms: 300, ..probe };
+    assert!(devices::snmp_system("127.0.0.1", &probe).await.is_err());
+}
+
+#[tokio::test]
+async fn test_describe_with_banner() {
+    start_snmp_simulator(16162, "public", pdu_objects()).await;
+
+    let listener = TcpListener::bind("127.0.0.1:16011").await.unwrap();
+    tokio::spawn(async move {
+        loop {
+            if let Ok((mut socket, _)) = listener.accept().await {
+                let mut buffer = vec![0; 1024];
+                let _ = socket.read(&mut buffer).await;
+                let _ = socket
+                    .write_all(b"HTTP/1.1 200 OK\r\nServer: Allegro-Software-RomPager/4.34\r\n\r\n<title>Network Management Card</title>")
+                    .await;
+            }
+        }
+    });
+
+    let probe = DeviceProbe { snmp_port: 16162, http_port: 16011, ..Default::default() };
+    let device = devices::describe("127.0.0.1", None, None, None, &probe).await;
+    assert_eq!(device.kind, DeviceKind::Pdu);
+    assert_eq!(device.sys_name.as_deref(), Some("container-a-pdu-3"));
+    assert_eq!(device.http_banner.as_deref(), Some("Allegro-Software-RomPager/4.34 (Network Management Card)"));
+}
+
+#[tokio::test]
+async fn test_scan_finds_silent_pdu() {
+    start_snmp_simulator(16163, "public", pdu_objects()).await;
+
+    // Nothing listens on the miner port; only the SNMP agent answers
+    let config = ScanConfig {
+        ports: vec![14104],
+        scan_devices: true,
+        device_probe: DeviceProbe { snmp_port: 16163, http_port: 14104, timeout_ms: 500, ..Default::default() },
+        ..Default::default()
+    };
+    let mut rx = scan_range("127.0.0.1", config).await.unwrap();
+
+    let mut devices = Vec::new();
+    while let Some(event) = rx.recv().await {
+        if let ScanEvent::NonMiner(device) = event {
+            devices.push(device);
+        }
+    }
+
+    assert_eq!(devices.len(), 1);
+    assert_eq!(devices[0].kind, DeviceKind::Pdu);
+    assert_eq!(devices[0].open_port, None);
+    assert_eq!(devices[0].sys_name.as_deref(), Some("container-a-pdu-3"));
+}
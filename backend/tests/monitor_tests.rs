@@ -1,4 +1,5 @@
-use rust_lib_frontend::{start_monitor, MonitorConfig, MonitorEvent, MinerStatus, Miner, MinerStats};
+use rust_lib_frontend::{start_monitor, start_monitor_with_settings, MonitorConfig, MonitorEvent, MinerStatus, Miner, MinerStats};
+use rust_lib_frontend::core::config::AppSettings;
 use std::time::Duration;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpListener;
@@ -38,11 +39,14 @@ async fn start_mock_miner_with_control(port: u16, temp: f64, hashrate: f64) {
 
 fn create_test_miner(ip: &str) -> Miner {
     Miner {
+        id: None,
         ip: ip.to_string(),
         model: None,
         status: MinerStatus::Scanning,
         stats: MinerStats::default(),
         last_updated: 0,
+        vendor_hint: None,
+        outlet: None,
     }
 }
 
@@ -174,3 +178,74 @@ async fn test_monitor_dead_detection() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_monitor_applies_settings_live() {
+    start_mock_miner_with_control(15006, 70.0, 100.0).await;
+    
+    // Start with an interval far longer than the test
+    let initial = AppSettings { monitor_interval: 3600, ..Default::default() };
+    let (settings_tx, settings_rx) = tokio::sync::watch::channel(initial.clone());
+    let config = MonitorConfig {
+        port: 15006,
+        ..MonitorConfig::from_settings(&initial)
+    };
+    assert_eq!(config.poll_interval_ms, 3_600_000);
+    
+    let mut rx = start_monitor_with_settings(vec![create_test_miner("127.0.0.1")], config, settings_rx).await;
+    
+    // Skip initial snapshot
+    let _ = rx.recv().await;
+    
+    // Shorten the interval: the pending hour-long wait must not be waited out
+    settings_tx.send_replace(AppSettings { monitor_interval: 1, ..initial });
+    
+    let event = tokio::time::timeout(Duration::from_secs(3), async {
+        loop {
+            match rx.recv().await {
+                Some(MonitorEvent::FullSnapshot(miners)) => return miners,
+                Some(_) => continue,
+                None => panic!("Monitor stopped"),
+            }
+        }
+    })
+    .await
+    .expect("Monitor ignored the new poll interval");
+    
+    assert_eq!(event[0].status, MinerStatus::Active);
+}
+
+#[tokio::test]
+async fn test_monitor_attaches_outlet() {
+    use rust_lib_frontend::core::PduOutlet;
+    use rust_lib_frontend::MinerId;
+
+    start_mock_miner_with_control(15007, 70.0, 100.0).await;
+    
+    let id = MinerId::from_serial("JYZZ0001").unwrap();
+    let outlet = PduOutlet { pdu_ip: "10.1.2.250".into(), outlet: 7 };
+    let mut settings = AppSettings { monitor_interval: 1, ..Default::default() };
+    settings.link_outlet(id.clone(), outlet.clone());
+    
+    let config = MonitorConfig {
+        port: 15007,
+        ..MonitorConfig::from_settings(&settings)
+    };
+    let miner = Miner { id: Some(id), ..create_test_miner("127.0.0.1") };
+    let mut rx = start_monitor(vec![miner], config).await;
+    
+    // Skip initial snapshot
+    let _ = rx.recv().await;
+    
+    let miners = tokio::time::timeout(Duration::from_secs(3), async {
+        loop {
+            if let Some(MonitorEvent::FullSnapshot(miners)) = rx.recv().await {
+                return miners;
+            }
+        }
+    })
+    .await
+    .unwrap();
+    
+    assert_eq!(miners[0].outlet, Some(outlet));
+}
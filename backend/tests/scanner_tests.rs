@@ -1,4 +1,4 @@
-use rust_lib_frontend::{parse_ip_range, scan_range, ScanConfig, ScanEvent};
+use rust_lib_frontend::{parse_ip_range, scan_range, scan_range_cancellable, ScanConfig, ScanEvent};
 use std::time::Instant;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpListener;
@@ -64,6 +64,7 @@ async fn test_scan_with_single_miner() {
         timeout_ms: 1000,
         max_concurrent: 10,
         ports: vec![14028],
+        ..Default::default()
     };
     
     let mut rx = scan_range("127.0.0.1", config).await.unwrap();
@@ -97,6 +98,7 @@ async fn test_scan_with_multiple_miners() {
         timeout_ms: 1000,
         max_concurrent: 10,
         ports: vec![14030, 14031, 14032],
+        ..Default::default()
     };
     
     // Scan localhost (should find all 3 miners on different ports)
@@ -128,6 +130,7 @@ async fn test_scan_performance() {
         timeout_ms: 500, // Short timeout
         max_concurrent: 50,
         ports: vec![14099], // Unlikely to be used
+        ..Default::default()
     };
     
     let start = Instant::now();
@@ -156,6 +159,7 @@ async fn test_concurrent_limit() {
         timeout_ms: 100,
         max_concurrent: 5, // Very low limit
         ports: vec![14100],
+        ..Default::default()
     };
     
     let mut rx = scan_range("127.0.0.1-127.0.0.50", config).await.unwrap();
@@ -167,3 +171,164 @@ async fn test_concurrent_limit() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_scan_cancellation() {
+    // Accept connections but never answer, so every probe runs to its timeout
+    let listener = TcpListener::bind("0.0.0.0:14101").await.unwrap();
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            held.push(socket);
+        }
+    });
+    
+    let config = ScanConfig {
+        timeout_ms: 5000,
+        max_concurrent: 2,
+        ports: vec![14101],
+        ..Default::default()
+    };
+    
+    // 40 IPs two at a time would take well over a minute
+    let (mut rx, handle) = scan_range_cancellable("127.0.0.1-127.0.0.40", config).await.unwrap();
+    
+    let start = Instant::now();
+    let mut cancelled = None;
+    while let Some(event) = rx.recv().await {
+        match event {
+            ScanEvent::Started { .. } => handle.cancel(),
+            ScanEvent::Cancelled { scanned, found } => cancelled = Some((scanned, found)),
+            ScanEvent::Complete { .. } => panic!("Cancelled scan reported completion"),
+            _ => {}
+        }
+    }
+    
+    assert_eq!(cancelled, Some((0, 0)));
+    assert!(handle.is_cancelled());
+    assert!(start.elapsed().as_secs() < 2, "Cancellation took too long: {:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn test_sweep_skips_dead_addresses() {
+    // Closed ports are dropped by the connect sweep, so a large range never
+    // reaches identification and finishes quickly
+    let config = ScanConfig {
+        ports: vec![14102],
+        ..Default::default()
+    };
+    
+    let start = Instant::now();
+    let mut rx = scan_range("127.0.0.0/22", config).await.unwrap();
+    
+    let mut complete = None;
+    while let Some(event) = rx.recv().await {
+        if let ScanEvent::Complete { found, failed } = event {
+            complete = Some((found, failed));
+        }
+    }
+    
+    assert_eq!(complete, Some((0, 1024)));
+    assert!(start.elapsed().as_secs() < 5, "Sweep took too long: {:?}", start.elapsed());
+}
+
+#[tokio::test]
+async fn test_non_miner_reported() {
+    // A web server that happens to listen on the scanned port
+    let listener = TcpListener::bind("127.0.0.1:14103").await.unwrap();
+    tokio::spawn(async move {
+        loop {
+            if let Ok((mut socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buffer = vec![0; 1024];
+                    let _ = socket.read(&mut buffer).await;
+                    let _ = socket.write_all(b"HTTP/1.1 400 Bad Request\r\nServer: lighttpd\r\n\r\n").await;
+                });
+            }
+        }
+    });
+
+    let config = ScanConfig {
+        ports: vec![14103],
+        ..Default::default()
+    };
+    let mut rx = scan_range("127.0.0.1", config).await.unwrap();
+
+    let mut non_miners = Vec::new();
+    let mut found = None;
+    while let Some(event) = rx.recv().await {
+        match event {
+            ScanEvent::NonMiner(device) => non_miners.push((device.ip, device.open_port)),
+            ScanEvent::Complete { found: count, .. } => found = Some(count),
+            _ => {}
+        }
+    }
+
+    assert_eq!(non_miners, vec![("127.0.0.1".to_string(), Some(14103))]);
+    assert_eq!(found, Some(0));
+}
+
+#[tokio::test]
+async fn test_scan_reports_conflicts() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // A Whatsminer on a factory address whose gateway is off its subnet, and
+    // which answers `get_miner_info` as a different unit every other time.
+    // The conflict check reuses the inventory identification read, so it
+    // only asks once and sees one unit.
+    let reads = Arc::new(AtomicUsize::new(0));
+    let served = reads.clone();
+    let listener = TcpListener::bind("127.0.0.1:14105").await.unwrap();
+    tokio::spawn(async move {
+        loop {
+            if let Ok((mut socket, _)) = listener.accept().await {
+                let reads = served.clone();
+                tokio::spawn(async move {
+                    let mut buffer = vec![0; 1024];
+                    let Ok(n) = socket.read(&mut buffer).await else { return };
+                    let request = String::from_utf8_lossy(&buffer[..n]);
+
+                    let response = if request.contains('+') {
+                        r#"{"STATUS":[{"STATUS":"E","Msg":"Invalid command"}],"id":1}"#.to_string()
+                    } else if request.contains("get_miner_info") {
+                        let unit = reads.fetch_add(1, Ordering::SeqCst) % 2 + 1;
+                        format!(
+                            r#"{{"STATUS":"S","When":1738800000,"Code":131,"Msg":{{"ip":"127.0.0.1","proto":"static","netmask":"255.255.255.0","gateway":"192.168.1.1","mac":"C4:08:4A:00:00:0{unit}","minersn":"HTM3X10UNIT{unit}"}},"Description":""}}"#
+                        )
+                    } else if request.contains("summary") {
+                        r#"{"STATUS":[{"STATUS":"S","Msg":"Summary"}],"SUMMARY":[{"Elapsed":12345,"MHS av":95000000.0,"Firmware Version":"'20230620.15.Rel'"}],"id":1}"#.to_string()
+                    } else {
+                        r#"{"STATUS":[{"STATUS":"E","Msg":"Invalid command"}],"id":1}"#.to_string()
+                    };
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        }
+    });
+
+    let config = ScanConfig {
+        timeout_ms: 1000,
+        ports: vec![14105],
+        fetch_details: true,
+        factory_ips: vec!["127.0.0.1".to_string()],
+        detect_conflicts: true,
+        ..Default::default()
+    };
+    let mut rx = scan_range("127.0.0.1", config).await.unwrap();
+
+    let (mut duplicate, mut factory, mut mismatch) = (None, None, None);
+    while let Some(event) = rx.recv().await {
+        match event {
+            ScanEvent::DuplicateIp { serial_numbers, .. } => duplicate = Some(serial_numbers),
+            ScanEvent::FactoryDefaultIp { ip, .. } => factory = Some(ip),
+            ScanEvent::NetworkMismatch { reason, .. } => mismatch = Some(reason),
+            _ => {}
+        }
+    }
+
+    assert_eq!(reads.load(Ordering::SeqCst), 1);
+    assert_eq!(duplicate, None);
+    assert_eq!(factory.as_deref(), Some("127.0.0.1"));
+    assert_eq!(mismatch.as_deref(), Some("gateway 192.168.1.1 is outside the miner's own subnet 127.0.0.0/24"));
+}
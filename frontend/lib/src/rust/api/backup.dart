@@ -0,0 +1,96 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../backup.dart';
+import '../core/models.dart';
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Snapshot the full config of every miner in `target_ips` into the local
+/// backup store.  `note` is kept with each snapshot, e.g. "before firmware
+/// update".
+Future<List<BackupResult>> backupMiners({
+  required List<String> targetIps,
+  String? note,
+}) => RustLib.instance.api.crateApiBackupBackupMiners(
+  targetIps: targetIps,
+  note: note,
+);
+
+/// Stored snapshots of one miner, or of all miners; newest first
+Future<List<SnapshotInfo>> listConfigSnapshots({MinerId? minerId}) =>
+    RustLib.instance.api.crateApiBackupListConfigSnapshots(minerId: minerId);
+
+Future<void> deleteConfigSnapshot({required String snapshotId}) =>
+    RustLib.instance.api.crateApiBackupDeleteConfigSnapshot(
+      snapshotId: snapshotId,
+    );
+
+/// Settings that changed from snapshot `before_id` to `after_id`
+Future<List<ConfigChange>> diffConfigSnapshots({
+  required String beforeId,
+  required String afterId,
+}) => RustLib.instance.api.crateApiBackupDiffConfigSnapshots(
+  beforeId: beforeId,
+  afterId: afterId,
+);
+
+/// What changed on the miner at `ip` since snapshot `snapshot_id`, read
+/// live without storing anything
+Future<List<ConfigChange>> diffConfigWithMiner({
+  required String snapshotId,
+  required String ip,
+}) => RustLib.instance.api.crateApiBackupDiffConfigWithMiner(
+  snapshotId: snapshotId,
+  ip: ip,
+);
+
+/// Write snapshot `snapshot_id` back to every miner in `target_ips`, e.g.
+/// to roll back a config broken by hand, or copy one across miners of the
+/// same model (each keeps its own pool workers).  Each miner's current config is
+/// backed up first; a miner that can't be backed up isn't touched.  The
+/// restore itself runs through the batch executor with its retries.
+Future<List<CommandResult>> restoreConfigSnapshot({
+  required String snapshotId,
+  required List<String> targetIps,
+}) => RustLib.instance.api.crateApiBackupRestoreConfigSnapshot(
+  snapshotId: snapshotId,
+  targetIps: targetIps,
+);
+
+/// Outcome of backing up one miner
+class BackupResult {
+  final String ip;
+  final MinerId? minerId;
+  final SnapshotInfo? snapshot;
+  final String? error;
+
+  const BackupResult({
+    required this.ip,
+    this.minerId,
+    this.snapshot,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      ip.hashCode ^
+      minerId.hashCode ^
+      snapshot.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BackupResult &&
+          runtimeType == other.runtimeType &&
+          ip == other.ip &&
+          minerId == other.minerId &&
+          snapshot == other.snapshot &&
+          error == other.error;
+}
@@ -3,23 +3,34 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../client/retry.dart';
 import '../core/config.dart';
 import '../frb_generated.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `execute_antminer_command`, `execute_single_command`, `execute_whatsminer_command`
+// These functions are ignored because they are not marked as `pub`: `execute_antminer_command`, `execute_single_command`, `execute_whatsminer_command`, `precheck`, `wrong_vendor`
 
 /// Execute a command on multiple miners in parallel.
 /// Returns results for each IP (success/failure).
+///
+/// Each miner is logged in to with the credential that worked for it last,
+/// falling back through the matching credential sets (see
+/// `client::credentials`).  With the vault locked every miner fails.
+/// Explicit `credentials` are used as given on every miner instead.
+///
+/// Transient failures are retried per `retry_policy`, or the command's
+/// default policy if `None`; every attempt is listed in the result.
 Future<List<CommandResult>> executeBatchCommand({
   required List<String> targetIps,
   required MinerCommand command,
   MinerCredentials? credentials,
+  RetryPolicy? retryPolicy,
 }) => RustLib.instance.api.crateApiCommandsExecuteBatchCommand(
   targetIps: targetIps,
   command: command,
   credentials: credentials,
+  retryPolicy: retryPolicy,
 );
 
 /// Test connection to a single miner
@@ -30,17 +41,20 @@ Future<String> testConnection({required String ip}) =>
 /// Reads the current config first to preserve fan/frequency settings.
 /// The miner will automatically reboot ~2 minutes after applying the change.
 ///
-/// `pools` must have 1–3 entries.
+/// `pools` must have 1–3 valid entries (see `client::pools`).  Worker
+/// templates are expanded as for `MinerCommand::SetPools`.
 Future<CommandResult> setMinerPools({
   required String ip,
   required List<PoolConfig> pools,
 }) => RustLib.instance.api.crateApiCommandsSetMinerPools(ip: ip, pools: pools);
 
-/// Read the currently configured pools from a single Antminer.
+/// Read the currently configured pools from a single miner, to pre-fill the
+/// pool editor.  Vendor comes from the identity cache; a miner whose pools
+/// can't be read gets an empty list (the reason is logged).
 Future<List<PoolConfig>> getMinerPools({required String ip}) =>
     RustLib.instance.api.crateApiCommandsGetMinerPools(ip: ip);
 
-/// Set the power mode on a miner. Detects Whatsminer vs Antminer automatically.
+/// Set the power mode on a miner. Whatsminer vs Antminer comes from the identity cache.
 ///
 /// PowerMode mapping:
 ///   Antminer  — Normal=0, Sleep=1, Lpm=3 (via `miner-mode` field in set_miner_conf.cgi)
@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../client/credentials.dart';
+import '../client/rotation.dart';
+import '../core/config.dart';
+import '../core/models.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Scoped credential sets, tried before the per-brand credentials
+Future<List<CredentialSet>> listCredentialSets() =>
+    RustLib.instance.api.crateApiCredentialsListCredentialSets();
+
+/// Replace the scoped credential sets.  Names must be unique and ranges
+/// must parse; remembered logins and auth-failure flags are reset.
+Future<void> saveCredentialSets({required List<CredentialSet> sets}) =>
+    RustLib.instance.api.crateApiCredentialsSaveCredentialSets(sets: sets);
+
+/// Tags used to scope credential sets to a miner
+List<String> getMinerTags({required MinerId minerId}) =>
+    RustLib.instance.api.crateApiCredentialsGetMinerTags(minerId: minerId);
+
+void setMinerTags({required MinerId minerId, required List<String> tags}) =>
+    RustLib.instance.api.crateApiCredentialsSetMinerTags(
+      minerId: minerId,
+      tags: tags,
+    );
+
+/// Miners that refused every matching credential, most recent first
+List<AuthFailure> listAuthFailures() =>
+    RustLib.instance.api.crateApiCredentialsListAuthFailures();
+
+/// Give every miner in `target_ips` a new web password under `policy`,
+/// verifying each and keeping the new secrets in the vault (which must be
+/// unlocked)
+Future<List<RotationResult>> rotateWebPasswords({
+  required List<String> targetIps,
+  required PasswordPolicy policy,
+}) => RustLib.instance.api.crateApiCredentialsRotateWebPasswords(
+  targetIps: targetIps,
+  policy: policy,
+);
@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../core/config.dart';
+import '../core/models.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Identify the non-miner device at `ip` (SNMP system info, web banner, OUI)
+Future<Device> probeDevice({required String ip}) =>
+    RustLib.instance.api.crateApiDevicesProbeDevice(ip: ip);
+
+/// Record that PDU `pdu_ip`, outlet `outlet` (as printed, from 1) feeds the
+/// miner.  The monitor attaches the outlet to that miner from the next poll.
+void linkMinerOutlet({
+  required MinerId minerId,
+  required String pduIp,
+  required int outlet,
+}) => RustLib.instance.api.crateApiDevicesLinkMinerOutlet(
+  minerId: minerId,
+  pduIp: pduIp,
+  outlet: outlet,
+);
+
+/// Remove the outlet link of a miner. Returns false if it had none.
+bool unlinkMinerOutlet({required MinerId minerId}) =>
+    RustLib.instance.api.crateApiDevicesUnlinkMinerOutlet(minerId: minerId);
+
+List<OutletLink> listOutletLinks() =>
+    RustLib.instance.api.crateApiDevicesListOutletLinks();
+
+/// Record the rack and position of a miner, used in worker names.  Blank
+/// values remove the location.
+void setMinerLocation({
+  required MinerId minerId,
+  required String rack,
+  required String position,
+}) => RustLib.instance.api.crateApiDevicesSetMinerLocation(
+  minerId: minerId,
+  rack: rack,
+  position: position,
+);
+
+List<MinerLocation> listMinerLocations() =>
+    RustLib.instance.api.crateApiDevicesListMinerLocations();
@@ -3,23 +3,63 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../client/retry.dart';
+import '../core/error.dart';
+import '../core/models.dart';
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'models.freezed.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// Result of a batch command execution
 class CommandResult {
   final String ip;
+
+  /// Stable id of the miner last seen at `ip`, so results can be filed
+  /// against the right box even after DHCP moves it
+  final MinerId? minerId;
   final bool success;
   final String? error;
 
-  const CommandResult({required this.ip, required this.success, this.error});
+  /// What kind of failure `error` is, e.g. to tell auth from network problems
+  final ErrorKind? errorKind;
+
+  /// Whether sending the same command again may succeed
+  final bool retryable;
+  final String? suggestedAction;
+
+  /// Every attempt made, in order
+  final List<CommandAttempt> attempts;
+
+  /// Why the command (or its retry) wasn't sent, e.g. the miner had
+  /// just rebooted
+  final String? skipped;
+
+  const CommandResult({
+    required this.ip,
+    this.minerId,
+    required this.success,
+    this.error,
+    this.errorKind,
+    required this.retryable,
+    this.suggestedAction,
+    required this.attempts,
+    this.skipped,
+  });
 
   @override
-  int get hashCode => ip.hashCode ^ success.hashCode ^ error.hashCode;
+  int get hashCode =>
+      ip.hashCode ^
+      minerId.hashCode ^
+      success.hashCode ^
+      error.hashCode ^
+      errorKind.hashCode ^
+      retryable.hashCode ^
+      suggestedAction.hashCode ^
+      attempts.hashCode ^
+      skipped.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -27,8 +67,14 @@ class CommandResult {
       other is CommandResult &&
           runtimeType == other.runtimeType &&
           ip == other.ip &&
+          minerId == other.minerId &&
           success == other.success &&
-          error == other.error;
+          error == other.error &&
+          errorKind == other.errorKind &&
+          retryable == other.retryable &&
+          suggestedAction == other.suggestedAction &&
+          attempts == other.attempts &&
+          skipped == other.skipped;
 }
 
 @freezed
@@ -38,8 +84,23 @@ sealed class MinerCommand with _$MinerCommand {
   const factory MinerCommand.reboot() = MinerCommand_Reboot;
   const factory MinerCommand.blinkLed() = MinerCommand_BlinkLed;
   const factory MinerCommand.stopBlink() = MinerCommand_StopBlink;
+  /// Workers may be templates, e.g. `acct.{rack}x{position}`; see
+  /// `client::worker_name`
   const factory MinerCommand.setPools({required List<PoolConfig> pools}) =
       MinerCommand_SetPools;
+  /// Change several settings at once; `None` keeps the current value.
+  /// Antminers get a single conf write (one reboot).  Whatsminers support
+  /// pools and power mode only.
+  const factory MinerCommand.applyConfig({
+    List<PoolConfig>? pools,
+    PowerMode? powerMode,
+    FanMode? fanMode,
+    int? freqLevel,
+  }) = MinerCommand_ApplyConfig;
+  /// Write back a config snapshot (see `backup`); onto other miners than
+  /// the snapshot's own only if they are the same model
+  const factory MinerCommand.restoreConfig({required String snapshotId}) =
+      MinerCommand_RestoreConfig;
 }
 
 /// A mining pool configuration entry.
@@ -23,6 +23,14 @@ mixin _$MinerCommand {
     required TResult Function() blinkLed,
     required TResult Function() stopBlink,
     required TResult Function(List<PoolConfig> pools) setPools,
+    required TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )
+    applyConfig,
+    required TResult Function(String snapshotId) restoreConfig,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
@@ -30,6 +38,14 @@ mixin _$MinerCommand {
     TResult? Function()? blinkLed,
     TResult? Function()? stopBlink,
     TResult? Function(List<PoolConfig> pools)? setPools,
+    TResult? Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult? Function(String snapshotId)? restoreConfig,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
@@ -37,6 +53,14 @@ mixin _$MinerCommand {
     TResult Function()? blinkLed,
     TResult Function()? stopBlink,
     TResult Function(List<PoolConfig> pools)? setPools,
+    TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult Function(String snapshotId)? restoreConfig,
     required TResult orElse(),
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -45,6 +69,8 @@ mixin _$MinerCommand {
     required TResult Function(MinerCommand_BlinkLed value) blinkLed,
     required TResult Function(MinerCommand_StopBlink value) stopBlink,
     required TResult Function(MinerCommand_SetPools value) setPools,
+    required TResult Function(MinerCommand_ApplyConfig value) applyConfig,
+    required TResult Function(MinerCommand_RestoreConfig value) restoreConfig,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
@@ -52,6 +78,8 @@ mixin _$MinerCommand {
     TResult? Function(MinerCommand_BlinkLed value)? blinkLed,
     TResult? Function(MinerCommand_StopBlink value)? stopBlink,
     TResult? Function(MinerCommand_SetPools value)? setPools,
+    TResult? Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult? Function(MinerCommand_RestoreConfig value)? restoreConfig,
   }) => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
@@ -59,6 +87,8 @@ mixin _$MinerCommand {
     TResult Function(MinerCommand_BlinkLed value)? blinkLed,
     TResult Function(MinerCommand_StopBlink value)? stopBlink,
     TResult Function(MinerCommand_SetPools value)? setPools,
+    TResult Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult Function(MinerCommand_RestoreConfig value)? restoreConfig,
     required TResult orElse(),
   }) => throw _privateConstructorUsedError;
 }
@@ -133,6 +163,14 @@ class _$MinerCommand_RebootImpl extends MinerCommand_Reboot {
     required TResult Function() blinkLed,
     required TResult Function() stopBlink,
     required TResult Function(List<PoolConfig> pools) setPools,
+    required TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )
+    applyConfig,
+    required TResult Function(String snapshotId) restoreConfig,
   }) {
     return reboot();
   }
@@ -144,6 +182,14 @@ class _$MinerCommand_RebootImpl extends MinerCommand_Reboot {
     TResult? Function()? blinkLed,
     TResult? Function()? stopBlink,
     TResult? Function(List<PoolConfig> pools)? setPools,
+    TResult? Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult? Function(String snapshotId)? restoreConfig,
   }) {
     return reboot?.call();
   }
@@ -155,6 +201,14 @@ class _$MinerCommand_RebootImpl extends MinerCommand_Reboot {
     TResult Function()? blinkLed,
     TResult Function()? stopBlink,
     TResult Function(List<PoolConfig> pools)? setPools,
+    TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult Function(String snapshotId)? restoreConfig,
     required TResult orElse(),
   }) {
     if (reboot != null) {
@@ -170,6 +224,8 @@ class _$MinerCommand_RebootImpl extends MinerCommand_Reboot {
     required TResult Function(MinerCommand_BlinkLed value) blinkLed,
     required TResult Function(MinerCommand_StopBlink value) stopBlink,
     required TResult Function(MinerCommand_SetPools value) setPools,
+    required TResult Function(MinerCommand_ApplyConfig value) applyConfig,
+    required TResult Function(MinerCommand_RestoreConfig value) restoreConfig,
   }) {
     return reboot(this);
   }
@@ -181,6 +237,8 @@ class _$MinerCommand_RebootImpl extends MinerCommand_Reboot {
     TResult? Function(MinerCommand_BlinkLed value)? blinkLed,
     TResult? Function(MinerCommand_StopBlink value)? stopBlink,
     TResult? Function(MinerCommand_SetPools value)? setPools,
+    TResult? Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult? Function(MinerCommand_RestoreConfig value)? restoreConfig,
   }) {
     return reboot?.call(this);
   }
@@ -192,6 +250,8 @@ class _$MinerCommand_RebootImpl extends MinerCommand_Reboot {
     TResult Function(MinerCommand_BlinkLed value)? blinkLed,
     TResult Function(MinerCommand_StopBlink value)? stopBlink,
     TResult Function(MinerCommand_SetPools value)? setPools,
+    TResult Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult Function(MinerCommand_RestoreConfig value)? restoreConfig,
     required TResult orElse(),
   }) {
     if (reboot != null) {
@@ -254,6 +314,14 @@ class _$MinerCommand_BlinkLedImpl extends MinerCommand_BlinkLed {
     required TResult Function() blinkLed,
     required TResult Function() stopBlink,
     required TResult Function(List<PoolConfig> pools) setPools,
+    required TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )
+    applyConfig,
+    required TResult Function(String snapshotId) restoreConfig,
   }) {
     return blinkLed();
   }
@@ -265,6 +333,14 @@ class _$MinerCommand_BlinkLedImpl extends MinerCommand_BlinkLed {
     TResult? Function()? blinkLed,
     TResult? Function()? stopBlink,
     TResult? Function(List<PoolConfig> pools)? setPools,
+    TResult? Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult? Function(String snapshotId)? restoreConfig,
   }) {
     return blinkLed?.call();
   }
@@ -276,6 +352,14 @@ class _$MinerCommand_BlinkLedImpl extends MinerCommand_BlinkLed {
     TResult Function()? blinkLed,
     TResult Function()? stopBlink,
     TResult Function(List<PoolConfig> pools)? setPools,
+    TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult Function(String snapshotId)? restoreConfig,
     required TResult orElse(),
   }) {
     if (blinkLed != null) {
@@ -291,6 +375,8 @@ class _$MinerCommand_BlinkLedImpl extends MinerCommand_BlinkLed {
     required TResult Function(MinerCommand_BlinkLed value) blinkLed,
     required TResult Function(MinerCommand_StopBlink value) stopBlink,
     required TResult Function(MinerCommand_SetPools value) setPools,
+    required TResult Function(MinerCommand_ApplyConfig value) applyConfig,
+    required TResult Function(MinerCommand_RestoreConfig value) restoreConfig,
   }) {
     return blinkLed(this);
   }
@@ -302,6 +388,8 @@ class _$MinerCommand_BlinkLedImpl extends MinerCommand_BlinkLed {
     TResult? Function(MinerCommand_BlinkLed value)? blinkLed,
     TResult? Function(MinerCommand_StopBlink value)? stopBlink,
     TResult? Function(MinerCommand_SetPools value)? setPools,
+    TResult? Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult? Function(MinerCommand_RestoreConfig value)? restoreConfig,
   }) {
     return blinkLed?.call(this);
   }
@@ -313,6 +401,8 @@ class _$MinerCommand_BlinkLedImpl extends MinerCommand_BlinkLed {
     TResult Function(MinerCommand_BlinkLed value)? blinkLed,
     TResult Function(MinerCommand_StopBlink value)? stopBlink,
     TResult Function(MinerCommand_SetPools value)? setPools,
+    TResult Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult Function(MinerCommand_RestoreConfig value)? restoreConfig,
     required TResult orElse(),
   }) {
     if (blinkLed != null) {
@@ -375,6 +465,14 @@ class _$MinerCommand_StopBlinkImpl extends MinerCommand_StopBlink {
     required TResult Function() blinkLed,
     required TResult Function() stopBlink,
     required TResult Function(List<PoolConfig> pools) setPools,
+    required TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )
+    applyConfig,
+    required TResult Function(String snapshotId) restoreConfig,
   }) {
     return stopBlink();
   }
@@ -386,6 +484,14 @@ class _$MinerCommand_StopBlinkImpl extends MinerCommand_StopBlink {
     TResult? Function()? blinkLed,
     TResult? Function()? stopBlink,
     TResult? Function(List<PoolConfig> pools)? setPools,
+    TResult? Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult? Function(String snapshotId)? restoreConfig,
   }) {
     return stopBlink?.call();
   }
@@ -397,6 +503,14 @@ class _$MinerCommand_StopBlinkImpl extends MinerCommand_StopBlink {
     TResult Function()? blinkLed,
     TResult Function()? stopBlink,
     TResult Function(List<PoolConfig> pools)? setPools,
+    TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult Function(String snapshotId)? restoreConfig,
     required TResult orElse(),
   }) {
     if (stopBlink != null) {
@@ -412,6 +526,8 @@ class _$MinerCommand_StopBlinkImpl extends MinerCommand_StopBlink {
     required TResult Function(MinerCommand_BlinkLed value) blinkLed,
     required TResult Function(MinerCommand_StopBlink value) stopBlink,
     required TResult Function(MinerCommand_SetPools value) setPools,
+    required TResult Function(MinerCommand_ApplyConfig value) applyConfig,
+    required TResult Function(MinerCommand_RestoreConfig value) restoreConfig,
   }) {
     return stopBlink(this);
   }
@@ -423,6 +539,8 @@ class _$MinerCommand_StopBlinkImpl extends MinerCommand_StopBlink {
     TResult? Function(MinerCommand_BlinkLed value)? blinkLed,
     TResult? Function(MinerCommand_StopBlink value)? stopBlink,
     TResult? Function(MinerCommand_SetPools value)? setPools,
+    TResult? Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult? Function(MinerCommand_RestoreConfig value)? restoreConfig,
   }) {
     return stopBlink?.call(this);
   }
@@ -434,6 +552,8 @@ class _$MinerCommand_StopBlinkImpl extends MinerCommand_StopBlink {
     TResult Function(MinerCommand_BlinkLed value)? blinkLed,
     TResult Function(MinerCommand_StopBlink value)? stopBlink,
     TResult Function(MinerCommand_SetPools value)? setPools,
+    TResult Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult Function(MinerCommand_RestoreConfig value)? restoreConfig,
     required TResult orElse(),
   }) {
     if (stopBlink != null) {
@@ -534,6 +654,14 @@ class _$MinerCommand_SetPoolsImpl extends MinerCommand_SetPools {
     required TResult Function() blinkLed,
     required TResult Function() stopBlink,
     required TResult Function(List<PoolConfig> pools) setPools,
+    required TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )
+    applyConfig,
+    required TResult Function(String snapshotId) restoreConfig,
   }) {
     return setPools(pools);
   }
@@ -545,6 +673,14 @@ class _$MinerCommand_SetPoolsImpl extends MinerCommand_SetPools {
     TResult? Function()? blinkLed,
     TResult? Function()? stopBlink,
     TResult? Function(List<PoolConfig> pools)? setPools,
+    TResult? Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult? Function(String snapshotId)? restoreConfig,
   }) {
     return setPools?.call(pools);
   }
@@ -556,6 +692,14 @@ class _$MinerCommand_SetPoolsImpl extends MinerCommand_SetPools {
     TResult Function()? blinkLed,
     TResult Function()? stopBlink,
     TResult Function(List<PoolConfig> pools)? setPools,
+    TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult Function(String snapshotId)? restoreConfig,
     required TResult orElse(),
   }) {
     if (setPools != null) {
@@ -571,6 +715,8 @@ class _$MinerCommand_SetPoolsImpl extends MinerCommand_SetPools {
     required TResult Function(MinerCommand_BlinkLed value) blinkLed,
     required TResult Function(MinerCommand_StopBlink value) stopBlink,
     required TResult Function(MinerCommand_SetPools value) setPools,
+    required TResult Function(MinerCommand_ApplyConfig value) applyConfig,
+    required TResult Function(MinerCommand_RestoreConfig value) restoreConfig,
   }) {
     return setPools(this);
   }
@@ -582,6 +728,8 @@ class _$MinerCommand_SetPoolsImpl extends MinerCommand_SetPools {
     TResult? Function(MinerCommand_BlinkLed value)? blinkLed,
     TResult? Function(MinerCommand_StopBlink value)? stopBlink,
     TResult? Function(MinerCommand_SetPools value)? setPools,
+    TResult? Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult? Function(MinerCommand_RestoreConfig value)? restoreConfig,
   }) {
     return setPools?.call(this);
   }
@@ -593,6 +741,8 @@ class _$MinerCommand_SetPoolsImpl extends MinerCommand_SetPools {
     TResult Function(MinerCommand_BlinkLed value)? blinkLed,
     TResult Function(MinerCommand_StopBlink value)? stopBlink,
     TResult Function(MinerCommand_SetPools value)? setPools,
+    TResult Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult Function(MinerCommand_RestoreConfig value)? restoreConfig,
     required TResult orElse(),
   }) {
     if (setPools != null) {
@@ -615,3 +765,461 @@ abstract class MinerCommand_SetPools extends MinerCommand {
   _$$MinerCommand_SetPoolsImplCopyWith<_$MinerCommand_SetPoolsImpl>
   get copyWith => throw _privateConstructorUsedError;
 }
+
+/// @nodoc
+abstract class _$$MinerCommand_ApplyConfigImplCopyWith<$Res> {
+  factory _$$MinerCommand_ApplyConfigImplCopyWith(
+    _$MinerCommand_ApplyConfigImpl value,
+    $Res Function(_$MinerCommand_ApplyConfigImpl) then,
+  ) = __$$MinerCommand_ApplyConfigImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({
+    List<PoolConfig>? pools,
+    PowerMode? powerMode,
+    FanMode? fanMode,
+    int? freqLevel,
+  });
+
+  $FanModeCopyWith<$Res>? get fanMode;
+}
+
+/// @nodoc
+class __$$MinerCommand_ApplyConfigImplCopyWithImpl<$Res>
+    extends _$MinerCommandCopyWithImpl<$Res, _$MinerCommand_ApplyConfigImpl>
+    implements _$$MinerCommand_ApplyConfigImplCopyWith<$Res> {
+  __$$MinerCommand_ApplyConfigImplCopyWithImpl(
+    _$MinerCommand_ApplyConfigImpl _value,
+    $Res Function(_$MinerCommand_ApplyConfigImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of MinerCommand
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? pools = freezed,
+    Object? powerMode = freezed,
+    Object? fanMode = freezed,
+    Object? freqLevel = freezed,
+  }) {
+    return _then(
+      _$MinerCommand_ApplyConfigImpl(
+        pools: freezed == pools
+            ? _value._pools
+            : pools // ignore: cast_nullable_to_non_nullable
+                  as List<PoolConfig>?,
+        powerMode: freezed == powerMode
+            ? _value.powerMode
+            : powerMode // ignore: cast_nullable_to_non_nullable
+                  as PowerMode?,
+        fanMode: freezed == fanMode
+            ? _value.fanMode
+            : fanMode // ignore: cast_nullable_to_non_nullable
+                  as FanMode?,
+        freqLevel: freezed == freqLevel
+            ? _value.freqLevel
+            : freqLevel // ignore: cast_nullable_to_non_nullable
+                  as int?,
+      ),
+    );
+  }
+
+  /// Create a copy of MinerCommand
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $FanModeCopyWith<$Res>? get fanMode {
+    if (_value.fanMode == null) {
+      return null;
+    }
+
+    return $FanModeCopyWith<$Res>(_value.fanMode!, (value) {
+      return _then(_value.copyWith(fanMode: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class _$MinerCommand_ApplyConfigImpl extends MinerCommand_ApplyConfig {
+  const _$MinerCommand_ApplyConfigImpl({
+    final List<PoolConfig>? pools,
+    this.powerMode,
+    this.fanMode,
+    this.freqLevel,
+  }) : _pools = pools,
+       super._();
+
+  final List<PoolConfig>? _pools;
+  @override
+  List<PoolConfig>? get pools {
+    final value = _pools;
+    if (value == null) return null;
+    if (_pools is EqualUnmodifiableListView) return _pools;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(value);
+  }
+
+  @override
+  final PowerMode? powerMode;
+  @override
+  final FanMode? fanMode;
+  @override
+  final int? freqLevel;
+
+  @override
+  String toString() {
+    return 'MinerCommand.applyConfig(pools: $pools, powerMode: $powerMode, fanMode: $fanMode, freqLevel: $freqLevel)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MinerCommand_ApplyConfigImpl &&
+            const DeepCollectionEquality().equals(other._pools, _pools) &&
+            (identical(other.powerMode, powerMode) ||
+                other.powerMode == powerMode) &&
+            (identical(other.fanMode, fanMode) || other.fanMode == fanMode) &&
+            (identical(other.freqLevel, freqLevel) ||
+                other.freqLevel == freqLevel));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    const DeepCollectionEquality().hash(_pools),
+    powerMode,
+    fanMode,
+    freqLevel,
+  );
+
+  /// Create a copy of MinerCommand
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MinerCommand_ApplyConfigImplCopyWith<_$MinerCommand_ApplyConfigImpl>
+  get copyWith =>
+      __$$MinerCommand_ApplyConfigImplCopyWithImpl<_$MinerCommand_ApplyConfigImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() reboot,
+    required TResult Function() blinkLed,
+    required TResult Function() stopBlink,
+    required TResult Function(List<PoolConfig> pools) setPools,
+    required TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )
+    applyConfig,
+    required TResult Function(String snapshotId) restoreConfig,
+  }) {
+    return applyConfig(pools, powerMode, fanMode, freqLevel);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? reboot,
+    TResult? Function()? blinkLed,
+    TResult? Function()? stopBlink,
+    TResult? Function(List<PoolConfig> pools)? setPools,
+    TResult? Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult? Function(String snapshotId)? restoreConfig,
+  }) {
+    return applyConfig?.call(pools, powerMode, fanMode, freqLevel);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? reboot,
+    TResult Function()? blinkLed,
+    TResult Function()? stopBlink,
+    TResult Function(List<PoolConfig> pools)? setPools,
+    TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult Function(String snapshotId)? restoreConfig,
+    required TResult orElse(),
+  }) {
+    if (applyConfig != null) {
+      return applyConfig(pools, powerMode, fanMode, freqLevel);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MinerCommand_Reboot value) reboot,
+    required TResult Function(MinerCommand_BlinkLed value) blinkLed,
+    required TResult Function(MinerCommand_StopBlink value) stopBlink,
+    required TResult Function(MinerCommand_SetPools value) setPools,
+    required TResult Function(MinerCommand_ApplyConfig value) applyConfig,
+    required TResult Function(MinerCommand_RestoreConfig value) restoreConfig,
+  }) {
+    return applyConfig(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MinerCommand_Reboot value)? reboot,
+    TResult? Function(MinerCommand_BlinkLed value)? blinkLed,
+    TResult? Function(MinerCommand_StopBlink value)? stopBlink,
+    TResult? Function(MinerCommand_SetPools value)? setPools,
+    TResult? Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult? Function(MinerCommand_RestoreConfig value)? restoreConfig,
+  }) {
+    return applyConfig?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MinerCommand_Reboot value)? reboot,
+    TResult Function(MinerCommand_BlinkLed value)? blinkLed,
+    TResult Function(MinerCommand_StopBlink value)? stopBlink,
+    TResult Function(MinerCommand_SetPools value)? setPools,
+    TResult Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult Function(MinerCommand_RestoreConfig value)? restoreConfig,
+    required TResult orElse(),
+  }) {
+    if (applyConfig != null) {
+      return applyConfig(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MinerCommand_ApplyConfig extends MinerCommand {
+  const factory MinerCommand_ApplyConfig({
+    final List<PoolConfig>? pools,
+    final PowerMode? powerMode,
+    final FanMode? fanMode,
+    final int? freqLevel,
+  }) = _$MinerCommand_ApplyConfigImpl;
+  const MinerCommand_ApplyConfig._() : super._();
+
+  List<PoolConfig>? get pools;
+  PowerMode? get powerMode;
+  FanMode? get fanMode;
+  int? get freqLevel;
+
+  /// Create a copy of MinerCommand
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MinerCommand_ApplyConfigImplCopyWith<_$MinerCommand_ApplyConfigImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$MinerCommand_RestoreConfigImplCopyWith<$Res> {
+  factory _$$MinerCommand_RestoreConfigImplCopyWith(
+    _$MinerCommand_RestoreConfigImpl value,
+    $Res Function(_$MinerCommand_RestoreConfigImpl) then,
+  ) = __$$MinerCommand_RestoreConfigImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String snapshotId});
+}
+
+/// @nodoc
+class __$$MinerCommand_RestoreConfigImplCopyWithImpl<$Res>
+    extends _$MinerCommandCopyWithImpl<$Res, _$MinerCommand_RestoreConfigImpl>
+    implements _$$MinerCommand_RestoreConfigImplCopyWith<$Res> {
+  __$$MinerCommand_RestoreConfigImplCopyWithImpl(
+    _$MinerCommand_RestoreConfigImpl _value,
+    $Res Function(_$MinerCommand_RestoreConfigImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of MinerCommand
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? snapshotId = null}) {
+    return _then(
+      _$MinerCommand_RestoreConfigImpl(
+        snapshotId: null == snapshotId
+            ? _value.snapshotId
+            : snapshotId // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$MinerCommand_RestoreConfigImpl extends MinerCommand_RestoreConfig {
+  const _$MinerCommand_RestoreConfigImpl({required this.snapshotId})
+    : super._();
+
+  @override
+  final String snapshotId;
+
+  @override
+  String toString() {
+    return 'MinerCommand.restoreConfig(snapshotId: $snapshotId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$MinerCommand_RestoreConfigImpl &&
+            (identical(other.snapshotId, snapshotId) ||
+                other.snapshotId == snapshotId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, snapshotId);
+
+  /// Create a copy of MinerCommand
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$MinerCommand_RestoreConfigImplCopyWith<_$MinerCommand_RestoreConfigImpl>
+  get copyWith =>
+      __$$MinerCommand_RestoreConfigImplCopyWithImpl<_$MinerCommand_RestoreConfigImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() reboot,
+    required TResult Function() blinkLed,
+    required TResult Function() stopBlink,
+    required TResult Function(List<PoolConfig> pools) setPools,
+    required TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )
+    applyConfig,
+    required TResult Function(String snapshotId) restoreConfig,
+  }) {
+    return restoreConfig(snapshotId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? reboot,
+    TResult? Function()? blinkLed,
+    TResult? Function()? stopBlink,
+    TResult? Function(List<PoolConfig> pools)? setPools,
+    TResult? Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult? Function(String snapshotId)? restoreConfig,
+  }) {
+    return restoreConfig?.call(snapshotId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? reboot,
+    TResult Function()? blinkLed,
+    TResult Function()? stopBlink,
+    TResult Function(List<PoolConfig> pools)? setPools,
+    TResult Function(
+      List<PoolConfig>? pools,
+      PowerMode? powerMode,
+      FanMode? fanMode,
+      int? freqLevel,
+    )?
+    applyConfig,
+    TResult Function(String snapshotId)? restoreConfig,
+    required TResult orElse(),
+  }) {
+    if (restoreConfig != null) {
+      return restoreConfig(snapshotId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(MinerCommand_Reboot value) reboot,
+    required TResult Function(MinerCommand_BlinkLed value) blinkLed,
+    required TResult Function(MinerCommand_StopBlink value) stopBlink,
+    required TResult Function(MinerCommand_SetPools value) setPools,
+    required TResult Function(MinerCommand_ApplyConfig value) applyConfig,
+    required TResult Function(MinerCommand_RestoreConfig value) restoreConfig,
+  }) {
+    return restoreConfig(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(MinerCommand_Reboot value)? reboot,
+    TResult? Function(MinerCommand_BlinkLed value)? blinkLed,
+    TResult? Function(MinerCommand_StopBlink value)? stopBlink,
+    TResult? Function(MinerCommand_SetPools value)? setPools,
+    TResult? Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult? Function(MinerCommand_RestoreConfig value)? restoreConfig,
+  }) {
+    return restoreConfig?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(MinerCommand_Reboot value)? reboot,
+    TResult Function(MinerCommand_BlinkLed value)? blinkLed,
+    TResult Function(MinerCommand_StopBlink value)? stopBlink,
+    TResult Function(MinerCommand_SetPools value)? setPools,
+    TResult Function(MinerCommand_ApplyConfig value)? applyConfig,
+    TResult Function(MinerCommand_RestoreConfig value)? restoreConfig,
+    required TResult orElse(),
+  }) {
+    if (restoreConfig != null) {
+      return restoreConfig(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class MinerCommand_RestoreConfig extends MinerCommand {
+  const factory MinerCommand_RestoreConfig({required final String snapshotId}) =
+      _$MinerCommand_RestoreConfigImpl;
+  const MinerCommand_RestoreConfig._() : super._();
+
+  String get snapshotId;
+
+  /// Create a copy of MinerCommand
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$MinerCommand_RestoreConfigImplCopyWith<_$MinerCommand_RestoreConfigImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
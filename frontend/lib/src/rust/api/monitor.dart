@@ -5,14 +5,16 @@
 
 import '../core/models.dart';
 import '../frb_generated.dart';
+import '../monitor/drift.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 // These functions are ignored because they are not marked as `pub`: `update_miners_loop`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CURRENT_MINERS`, `MONITOR_RX`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `deref`, `initialize`, `initialize`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CURRENT_DRIFT`, `CURRENT_MINERS`, `MONITOR_RX`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `deref`, `deref`, `initialize`, `initialize`, `initialize`
 
 /// Start monitoring a list of miners
-/// This initializes the background polling loop
+/// This initializes the background polling loop.  The poll interval follows
+/// `AppSettings::monitor_interval`, including changes saved while running.
 Future<void> startMonitoring({required List<Miner> miners}) =>
     RustLib.instance.api.crateApiMonitorStartMonitoring(miners: miners);
 
@@ -20,6 +22,10 @@ Future<void> startMonitoring({required List<Miner> miners}) =>
 Future<List<Miner>> getCurrentMiners() =>
     RustLib.instance.api.crateApiMonitorGetCurrentMiners();
 
+/// Miners that currently differ from their desired state
+Future<List<DriftReport>> getDriftReports() =>
+    RustLib.instance.api.crateApiMonitorGetDriftReports();
+
 /// Stop the monitor
 Future<void> stopMonitoring() =>
     RustLib.instance.api.crateApiMonitorStopMonitoring();
@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../core/config.dart';
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Desired state per group, compared with what the miners report
+List<DesiredState> listDesiredStates() =>
+    RustLib.instance.api.crateApiPolicyListDesiredStates();
+
+/// Replace the desired states.  Names must be unique, ranges must parse and
+/// pools must pass the same checks as `SetPools`.  A running monitor picks
+/// up the change on its next poll.
+void saveDesiredStates({required List<DesiredState> states}) =>
+    RustLib.instance.api.crateApiPolicySaveDesiredStates(states: states);
+
+/// Put the drifted settings of `target_ips` back to their desired state.
+/// Works from the monitor's latest drift reports; miners without drift are
+/// left alone.  Miners needing the same correction are sent it as one batch.
+Future<List<CommandResult>> reconcileDrift({required List<String> targetIps}) =>
+    RustLib.instance.api.crateApiPolicyReconcileDrift(targetIps: targetIps);
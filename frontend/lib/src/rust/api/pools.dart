@@ -0,0 +1,129 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../client/pools.dart';
+import '../core/config.dart';
+import '../core/models.dart';
+import '../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `diff_miner`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Expand the worker templates of `pools` for every miner in `target_ips`
+/// without changing anything, so the names can be checked before sending
+/// `SetPools`
+Future<List<WorkerPreview>> previewWorkerNames({
+  required List<String> targetIps,
+  required List<PoolConfig> pools,
+}) => RustLib.instance.api.crateApiPoolsPreviewWorkerNames(
+  targetIps: targetIps,
+  pools: pools,
+);
+
+List<PoolTemplate> listPoolTemplates() =>
+    RustLib.instance.api.crateApiPoolsListPoolTemplates();
+
+/// Replace the pool templates.  Names must be unique and every template
+/// must pass the same checks as `SetPools`.
+void savePoolTemplates({required List<PoolTemplate> templates}) =>
+    RustLib.instance.api.crateApiPoolsSavePoolTemplates(templates: templates);
+
+/// Show what sending `pools` with `SetPools` would change, without
+/// changing anything.  Miners that already have these pools are left out;
+/// miners whose pools couldn't be read are listed with the error.
+Future<List<PoolDiff>> dryRunSetPools({
+  required List<String> targetIps,
+  required List<PoolConfig> pools,
+}) => RustLib.instance.api.crateApiPoolsDryRunSetPools(
+  targetIps: targetIps,
+  pools: pools,
+);
+
+/// `dry_run_set_pools` with a saved template
+Future<List<PoolDiff>> dryRunPoolTemplate({
+  required List<String> targetIps,
+  required String templateName,
+}) => RustLib.instance.api.crateApiPoolsDryRunPoolTemplate(
+  targetIps: targetIps,
+  templateName: templateName,
+);
+
+/// What `SetPools` would change on one miner
+class PoolDiff {
+  final String ip;
+  final MinerId? minerId;
+
+  /// Slots that would change; empty on error
+  final List<PoolSlotChange> changes;
+
+  /// Whether the current passwords could be read and compared
+  final bool passwordsCompared;
+
+  /// Why the miner's pools couldn't be read or prepared
+  final String? error;
+
+  const PoolDiff({
+    required this.ip,
+    this.minerId,
+    required this.changes,
+    required this.passwordsCompared,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      ip.hashCode ^
+      minerId.hashCode ^
+      changes.hashCode ^
+      passwordsCompared.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PoolDiff &&
+          runtimeType == other.runtimeType &&
+          ip == other.ip &&
+          minerId == other.minerId &&
+          changes == other.changes &&
+          passwordsCompared == other.passwordsCompared &&
+          error == other.error;
+}
+
+/// Worker names one miner would get
+class WorkerPreview {
+  final String ip;
+  final MinerId? minerId;
+
+  /// Expanded worker of each pool, in order; empty on error
+  final List<String> workers;
+  final String? error;
+
+  const WorkerPreview({
+    required this.ip,
+    this.minerId,
+    required this.workers,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      ip.hashCode ^
+      minerId.hashCode ^
+      workers.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkerPreview &&
+          runtimeType == other.runtimeType &&
+          ip == other.ip &&
+          minerId == other.minerId &&
+          workers == other.workers &&
+          error == other.error;
+}
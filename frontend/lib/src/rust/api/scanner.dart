@@ -5,18 +5,67 @@
 
 import '../core/models.dart';
 import '../frb_generated.dart';
+import '../scanner.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Start scanning a network range for miners
+// These functions are ignored because they are not marked as `pub`: `begin_scan`, `finish_scan`, `resolve_range`, `subnet_of`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ACTIVE_SCAN`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `deref`, `fmt`, `initialize`
+
+/// Start scanning a network range for miners.
+/// `ip_range` is a scan spec or the name of a saved range.
 /// Returns all discovered miners after scan completes
 Future<List<Miner>> startScan({required String ipRange}) =>
     RustLib.instance.api.crateApiScannerStartScan(ipRange: ipRange);
 
-/// Validate an IP range string without starting a scan
+/// Start scanning a network range (spec or saved range name), streaming
+/// every `ScanEvent` as it happens.
+/// The stream ends after `Complete`, or `Cancelled` if `stop_scan` was called.
+Stream<ScanEvent> startScanStream({required String ipRange}) =>
+    RustLib.instance.api.crateApiScannerStartScanStream(ipRange: ipRange);
+
+/// Stop the running scan. Returns false if no scan was running.
+bool stopScan() => RustLib.instance.api.crateApiScannerStopScan();
+
+/// Validate a scan spec (or the name of a saved range) without starting a scan.
+/// Errors name the offending entry and its line/column.
 Future<String> validateIpRange({required String range}) =>
     RustLib.instance.api.crateApiScannerValidateIpRange(range: range);
 
-/// Detect local network interfaces and return their /24 subnet ranges.
+/// Detect local network interfaces with their IPv4 address and subnet.
 /// Filters out loopback (127.x.x.x) and link-local (169.254.x.x) addresses.
+Future<List<LocalInterface>> listLocalInterfaces() =>
+    RustLib.instance.api.crateApiScannerListLocalInterfaces();
+
+/// Detect local network interfaces and return their subnets in CIDR notation,
+/// using each interface's real prefix length.
 Future<List<String>> detectLocalRanges() =>
     RustLib.instance.api.crateApiScannerDetectLocalRanges();
+
+/// A local IPv4 address that scans can be bound to
+class LocalInterface {
+  /// Interface name, e.g. "eth0" or "en1"
+  final String name;
+  final String ip;
+
+  /// The interface's subnet in CIDR notation, e.g. "10.1.0.0/22"
+  final String range;
+
+  const LocalInterface({
+    required this.name,
+    required this.ip,
+    required this.range,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ ip.hashCode ^ range.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LocalInterface &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          ip == other.ip &&
+          range == other.range;
+}
@@ -7,8 +7,12 @@ import '../core/config.dart';
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+/// Saved settings.  Credentials come from the vault if there is one, and
+/// are blank while it is locked.
 AppSettings getAppSettings() =>
     RustLib.instance.api.crateApiSettingsGetAppSettings();
 
+/// Save settings.  With an unlocked vault the credentials go into it; the
+/// settings file only holds them while no vault exists.
 void saveAppSettings({required AppSettings settings}) =>
     RustLib.instance.api.crateApiSettingsSaveAppSettings(settings: settings);
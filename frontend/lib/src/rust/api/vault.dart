@@ -0,0 +1,32 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../core/vault.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Whether a credential vault exists and is unlocked
+VaultStatus getVaultStatus() =>
+    RustLib.instance.api.crateApiVaultGetVaultStatus();
+
+/// Create the credential vault under `passphrase`, moving the credentials
+/// out of the plaintext settings file.  The vault starts unlocked.
+Future<void> createVault({required String passphrase}) =>
+    RustLib.instance.api.crateApiVaultCreateVault(passphrase: passphrase);
+
+/// Unlock the vault for this session
+Future<void> unlockVault({required String passphrase}) =>
+    RustLib.instance.api.crateApiVaultUnlockVault(passphrase: passphrase);
+
+/// Forget the vault key; commands fail until it is unlocked again
+void lockVault() => RustLib.instance.api.crateApiVaultLockVault();
+
+Future<void> changeVaultPassphrase({
+  required String current,
+  required String newPassphrase,
+}) => RustLib.instance.api.crateApiVaultChangeVaultPassphrase(
+  current: current,
+  newPassphrase: newPassphrase,
+);
@@ -0,0 +1,76 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'core/models.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// One setting that differs between two snapshots; `None` where a side
+/// doesn't have it.  Passwords are masked.
+class ConfigChange {
+  final String key;
+  final String? before;
+  final String? after;
+
+  const ConfigChange({required this.key, this.before, this.after});
+
+  @override
+  int get hashCode => key.hashCode ^ before.hashCode ^ after.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConfigChange &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          before == other.before &&
+          after == other.after;
+}
+
+/// A snapshot without its content, for listings
+class SnapshotInfo {
+  final String id;
+  final MinerId minerId;
+  final String ip;
+  final MinerVendor vendor;
+  final String? model;
+
+  /// Unix timestamp in milliseconds
+  final BigInt takenAt;
+  final String? note;
+
+  const SnapshotInfo({
+    required this.id,
+    required this.minerId,
+    required this.ip,
+    required this.vendor,
+    this.model,
+    required this.takenAt,
+    this.note,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      minerId.hashCode ^
+      ip.hashCode ^
+      vendor.hashCode ^
+      model.hashCode ^
+      takenAt.hashCode ^
+      note.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SnapshotInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          minerId == other.minerId &&
+          ip == other.ip &&
+          vendor == other.vendor &&
+          model == other.model &&
+          takenAt == other.takenAt &&
+          note == other.note;
+}
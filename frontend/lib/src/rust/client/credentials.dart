@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../core/models.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// A miner that accepted none of the candidates
+class AuthFailure {
+  final String ip;
+  final MinerId? minerId;
+
+  /// How many distinct credentials were refused
+  final int tried;
+
+  /// Unix timestamp of the last failed round
+  final BigInt failedAt;
+
+  const AuthFailure({
+    required this.ip,
+    this.minerId,
+    required this.tried,
+    required this.failedAt,
+  });
+
+  @override
+  int get hashCode =>
+      ip.hashCode ^
+      minerId.hashCode ^
+      tried.hashCode ^
+      failedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AuthFailure &&
+          runtimeType == other.runtimeType &&
+          ip == other.ip &&
+          minerId == other.minerId &&
+          tried == other.tried &&
+          failedAt == other.failedAt;
+}
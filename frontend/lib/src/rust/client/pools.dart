@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../api/models.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// One pool slot whose setting would change (slots are 1-based)
+class PoolSlotChange {
+  final int slot;
+
+  /// `None` if the slot is empty now
+  final PoolConfig? current;
+
+  /// `None` if the slot would be cleared
+  final PoolConfig? intended;
+
+  const PoolSlotChange({required this.slot, this.current, this.intended});
+
+  @override
+  int get hashCode => slot.hashCode ^ current.hashCode ^ intended.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PoolSlotChange &&
+          runtimeType == other.runtimeType &&
+          slot == other.slot &&
+          current == other.current &&
+          intended == other.intended;
+}
@@ -0,0 +1,91 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../core/error.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// One try of a command
+class CommandAttempt {
+  /// 1-based
+  final int attempt;
+
+  /// Unix timestamp
+  final BigInt startedAt;
+  final BigInt durationMs;
+  final String? error;
+  final ErrorKind? errorKind;
+
+  const CommandAttempt({
+    required this.attempt,
+    required this.startedAt,
+    required this.durationMs,
+    this.error,
+    this.errorKind,
+  });
+
+  @override
+  int get hashCode =>
+      attempt.hashCode ^
+      startedAt.hashCode ^
+      durationMs.hashCode ^
+      error.hashCode ^
+      errorKind.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CommandAttempt &&
+          runtimeType == other.runtimeType &&
+          attempt == other.attempt &&
+          startedAt == other.startedAt &&
+          durationMs == other.durationMs &&
+          error == other.error &&
+          errorKind == other.errorKind;
+}
+
+/// How a command is retried
+class RetryPolicy {
+  /// Total attempts, including the first; 1 disables retries
+  final int maxAttempts;
+
+  /// Wait before the first retry; doubled for each further one
+  final BigInt initialBackoffMs;
+  final BigInt maxBackoffMs;
+
+  /// Error kinds that are retried; `RejectedByMiner` matches any code
+  final List<ErrorKind> retryOn;
+
+  /// Non-idempotent commands only: a miner up for less than this is
+  /// taken to have done the command already and is skipped; 0 disables
+  final BigInt minUptimeSecs;
+
+  const RetryPolicy({
+    required this.maxAttempts,
+    required this.initialBackoffMs,
+    required this.maxBackoffMs,
+    required this.retryOn,
+    required this.minUptimeSecs,
+  });
+
+  @override
+  int get hashCode =>
+      maxAttempts.hashCode ^
+      initialBackoffMs.hashCode ^
+      maxBackoffMs.hashCode ^
+      retryOn.hashCode ^
+      minUptimeSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RetryPolicy &&
+          runtimeType == other.runtimeType &&
+          maxAttempts == other.maxAttempts &&
+          initialBackoffMs == other.initialBackoffMs &&
+          maxBackoffMs == other.maxBackoffMs &&
+          retryOn == other.retryOn &&
+          minUptimeSecs == other.minUptimeSecs;
+}
@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../core/error.dart';
+import '../core/models.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'rotation.freezed.dart';
+
+@freezed
+sealed class PasswordPolicy with _$PasswordPolicy {
+  const PasswordPolicy._();
+
+  /// A different random password per miner
+  const factory PasswordPolicy.generate({required int length}) =
+      PasswordPolicy_Generate;
+  /// The same password on every miner, e.g. a site standard
+  const factory PasswordPolicy.fixed({required String password}) =
+      PasswordPolicy_Fixed;
+}
+
+/// How the rotation of one miner ended
+enum RotationOutcome {
+  /// The miner has the new password and the vault holds it
+  rotated,
+  /// The miner already had the policy's password
+  alreadyCurrent,
+  /// Nothing was changed: unreachable, refused login or refused the change
+  failed,
+  /// The new password didn't verify; the miner is back on the old one
+  rolledBack,
+  /// Neither password verified after the change; both are kept in the vault
+  needsAttention,
+}
+
+class RotationResult {
+  final String ip;
+  final MinerId? minerId;
+  final RotationOutcome outcome;
+  final String? message;
+
+  /// Why a `Failed` rotation failed
+  final ErrorKind? errorKind;
+
+  const RotationResult({
+    required this.ip,
+    this.minerId,
+    required this.outcome,
+    this.message,
+    this.errorKind,
+  });
+
+  @override
+  int get hashCode =>
+      ip.hashCode ^
+      minerId.hashCode ^
+      outcome.hashCode ^
+      message.hashCode ^
+      errorKind.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RotationResult &&
+          runtimeType == other.runtimeType &&
+          ip == other.ip &&
+          minerId == other.minerId &&
+          outcome == other.outcome &&
+          message == other.message &&
+          errorKind == other.errorKind;
+}
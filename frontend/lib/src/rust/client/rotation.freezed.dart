@@ -0,0 +1,371 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'rotation.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$PasswordPolicy {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int length) generate,
+    required TResult Function(String password) fixed,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int length)? generate,
+    TResult? Function(String password)? fixed,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int length)? generate,
+    TResult Function(String password)? fixed,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordPolicy_Generate value) generate,
+    required TResult Function(PasswordPolicy_Fixed value) fixed,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordPolicy_Generate value)? generate,
+    TResult? Function(PasswordPolicy_Fixed value)? fixed,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordPolicy_Generate value)? generate,
+    TResult Function(PasswordPolicy_Fixed value)? fixed,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PasswordPolicyCopyWith<$Res> {
+  factory $PasswordPolicyCopyWith(
+    PasswordPolicy value,
+    $Res Function(PasswordPolicy) then,
+  ) = _$PasswordPolicyCopyWithImpl<$Res, PasswordPolicy>;
+}
+
+/// @nodoc
+class _$PasswordPolicyCopyWithImpl<$Res, $Val extends PasswordPolicy>
+    implements $PasswordPolicyCopyWith<$Res> {
+  _$PasswordPolicyCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$PasswordPolicy_GenerateImplCopyWith<$Res> {
+  factory _$$PasswordPolicy_GenerateImplCopyWith(
+    _$PasswordPolicy_GenerateImpl value,
+    $Res Function(_$PasswordPolicy_GenerateImpl) then,
+  ) = __$$PasswordPolicy_GenerateImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int length});
+}
+
+/// @nodoc
+class __$$PasswordPolicy_GenerateImplCopyWithImpl<$Res>
+    extends _$PasswordPolicyCopyWithImpl<$Res, _$PasswordPolicy_GenerateImpl>
+    implements _$$PasswordPolicy_GenerateImplCopyWith<$Res> {
+  __$$PasswordPolicy_GenerateImplCopyWithImpl(
+    _$PasswordPolicy_GenerateImpl _value,
+    $Res Function(_$PasswordPolicy_GenerateImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? length = null}) {
+    return _then(
+      _$PasswordPolicy_GenerateImpl(
+        length: null == length
+            ? _value.length
+            : length // ignore: cast_nullable_to_non_nullable
+                  as int,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$PasswordPolicy_GenerateImpl extends PasswordPolicy_Generate {
+  const _$PasswordPolicy_GenerateImpl({required this.length}) : super._();
+
+  @override
+  final int length;
+
+  @override
+  String toString() {
+    return 'PasswordPolicy.generate(length: $length)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordPolicy_GenerateImpl &&
+            (identical(other.length, length) || other.length == length));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, length);
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordPolicy_GenerateImplCopyWith<_$PasswordPolicy_GenerateImpl>
+  get copyWith =>
+      __$$PasswordPolicy_GenerateImplCopyWithImpl<_$PasswordPolicy_GenerateImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int length) generate,
+    required TResult Function(String password) fixed,
+  }) {
+    return generate(length);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int length)? generate,
+    TResult? Function(String password)? fixed,
+  }) {
+    return generate?.call(length);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int length)? generate,
+    TResult Function(String password)? fixed,
+    required TResult orElse(),
+  }) {
+    if (generate != null) {
+      return generate(length);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordPolicy_Generate value) generate,
+    required TResult Function(PasswordPolicy_Fixed value) fixed,
+  }) {
+    return generate(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordPolicy_Generate value)? generate,
+    TResult? Function(PasswordPolicy_Fixed value)? fixed,
+  }) {
+    return generate?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordPolicy_Generate value)? generate,
+    TResult Function(PasswordPolicy_Fixed value)? fixed,
+    required TResult orElse(),
+  }) {
+    if (generate != null) {
+      return generate(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordPolicy_Generate extends PasswordPolicy {
+  const factory PasswordPolicy_Generate({required final int length}) =
+      _$PasswordPolicy_GenerateImpl;
+  const PasswordPolicy_Generate._() : super._();
+
+  int get length;
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordPolicy_GenerateImplCopyWith<_$PasswordPolicy_GenerateImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PasswordPolicy_FixedImplCopyWith<$Res> {
+  factory _$$PasswordPolicy_FixedImplCopyWith(
+    _$PasswordPolicy_FixedImpl value,
+    $Res Function(_$PasswordPolicy_FixedImpl) then,
+  ) = __$$PasswordPolicy_FixedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String password});
+}
+
+/// @nodoc
+class __$$PasswordPolicy_FixedImplCopyWithImpl<$Res>
+    extends _$PasswordPolicyCopyWithImpl<$Res, _$PasswordPolicy_FixedImpl>
+    implements _$$PasswordPolicy_FixedImplCopyWith<$Res> {
+  __$$PasswordPolicy_FixedImplCopyWithImpl(
+    _$PasswordPolicy_FixedImpl _value,
+    $Res Function(_$PasswordPolicy_FixedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? password = null}) {
+    return _then(
+      _$PasswordPolicy_FixedImpl(
+        password: null == password
+            ? _value.password
+            : password // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$PasswordPolicy_FixedImpl extends PasswordPolicy_Fixed {
+  const _$PasswordPolicy_FixedImpl({required this.password}) : super._();
+
+  @override
+  final String password;
+
+  @override
+  String toString() {
+    return 'PasswordPolicy.fixed(password: $password)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PasswordPolicy_FixedImpl &&
+            (identical(other.password, password) ||
+                other.password == password));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, password);
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PasswordPolicy_FixedImplCopyWith<_$PasswordPolicy_FixedImpl>
+  get copyWith =>
+      __$$PasswordPolicy_FixedImplCopyWithImpl<_$PasswordPolicy_FixedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int length) generate,
+    required TResult Function(String password) fixed,
+  }) {
+    return fixed(password);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int length)? generate,
+    TResult? Function(String password)? fixed,
+  }) {
+    return fixed?.call(password);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int length)? generate,
+    TResult Function(String password)? fixed,
+    required TResult orElse(),
+  }) {
+    if (fixed != null) {
+      return fixed(password);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(PasswordPolicy_Generate value) generate,
+    required TResult Function(PasswordPolicy_Fixed value) fixed,
+  }) {
+    return fixed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(PasswordPolicy_Generate value)? generate,
+    TResult? Function(PasswordPolicy_Fixed value)? fixed,
+  }) {
+    return fixed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(PasswordPolicy_Generate value)? generate,
+    TResult Function(PasswordPolicy_Fixed value)? fixed,
+    required TResult orElse(),
+  }) {
+    if (fixed != null) {
+      return fixed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class PasswordPolicy_Fixed extends PasswordPolicy {
+  const factory PasswordPolicy_Fixed({required final String password}) =
+      _$PasswordPolicy_FixedImpl;
+  const PasswordPolicy_Fixed._() : super._();
+
+  String get password;
+
+  /// Create a copy of PasswordPolicy
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PasswordPolicy_FixedImplCopyWith<_$PasswordPolicy_FixedImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
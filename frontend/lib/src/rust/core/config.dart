@@ -3,20 +3,72 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../api/models.dart';
 import '../frb_generated.dart';
+import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'config.freezed.dart';
 
 class AppSettings {
+  /// Only kept in this file until a credential vault is created; see `vault`
   final MinerCredentials antminerCredentials;
   final MinerCredentials whatsminerCredentials;
   final int scanThreadCount;
+
+  /// Seconds between monitor polls
   final BigInt monitorInterval;
+  final List<NamedRange> namedRanges;
+
+  /// Per-command timeout while identifying miners during a scan
+  final BigInt scanTimeoutMs;
+
+  /// CGMiner API ports probed during a scan
+  final Uint16List scanPorts;
+
+  /// Also probe addresses without an open miner port over SNMP, to find
+  /// PDUs and switches
+  final bool scanDevices;
+
+  /// SNMP v2c community used for device probes
+  final String snmpCommunity;
+  final List<OutletLink> outletLinks;
+
+  /// Addresses miners fall back to when reset or unconfigured.  A miner
+  /// found on one of them is reported as `ScanEvent::FactoryDefaultIp`;
+  /// include them in a scan range to catch miners stuck there.
+  final List<String> factoryDefaultIps;
+
+  /// Read each miner's inventory during scans to catch two devices
+  /// sharing an address.  Off by default: it adds a web API read per miner.
+  final bool detectIpConflicts;
+
+  /// Scoped credentials tried before the per-vendor ones.  Kept in the
+  /// vault like them once one exists.
+  final List<CredentialSet> credentialSets;
+  final List<MinerTags> minerTags;
+  final List<MinerLocation> minerLocations;
+  final List<PoolTemplate> poolTemplates;
+  final List<DesiredState> desiredStates;
 
   const AppSettings({
     required this.antminerCredentials,
     required this.whatsminerCredentials,
     required this.scanThreadCount,
     required this.monitorInterval,
+    required this.namedRanges,
+    required this.scanTimeoutMs,
+    required this.scanPorts,
+    required this.scanDevices,
+    required this.snmpCommunity,
+    required this.outletLinks,
+    required this.factoryDefaultIps,
+    required this.detectIpConflicts,
+    required this.credentialSets,
+    required this.minerTags,
+    required this.minerLocations,
+    required this.poolTemplates,
+    required this.desiredStates,
   });
 
   @override
@@ -24,7 +76,20 @@ class AppSettings {
       antminerCredentials.hashCode ^
       whatsminerCredentials.hashCode ^
       scanThreadCount.hashCode ^
-      monitorInterval.hashCode;
+      monitorInterval.hashCode ^
+      namedRanges.hashCode ^
+      scanTimeoutMs.hashCode ^
+      scanPorts.hashCode ^
+      scanDevices.hashCode ^
+      snmpCommunity.hashCode ^
+      outletLinks.hashCode ^
+      factoryDefaultIps.hashCode ^
+      detectIpConflicts.hashCode ^
+      credentialSets.hashCode ^
+      minerTags.hashCode ^
+      minerLocations.hashCode ^
+      poolTemplates.hashCode ^
+      desiredStates.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -34,7 +99,121 @@ class AppSettings {
           antminerCredentials == other.antminerCredentials &&
           whatsminerCredentials == other.whatsminerCredentials &&
           scanThreadCount == other.scanThreadCount &&
-          monitorInterval == other.monitorInterval;
+          monitorInterval == other.monitorInterval &&
+          namedRanges == other.namedRanges &&
+          scanTimeoutMs == other.scanTimeoutMs &&
+          scanPorts == other.scanPorts &&
+          scanDevices == other.scanDevices &&
+          snmpCommunity == other.snmpCommunity &&
+          outletLinks == other.outletLinks &&
+          factoryDefaultIps == other.factoryDefaultIps &&
+          detectIpConflicts == other.detectIpConflicts &&
+          credentialSets == other.credentialSets &&
+          minerTags == other.minerTags &&
+          minerLocations == other.minerLocations &&
+          poolTemplates == other.poolTemplates &&
+          desiredStates == other.desiredStates;
+}
+
+@freezed
+sealed class CredentialScope with _$CredentialScope {
+  const CredentialScope._();
+
+  const factory CredentialScope.all() = CredentialScope_All;
+  /// Miners inside a scan spec, e.g. "10.2.0.0/16, !10.2.0.1"
+  const factory CredentialScope.range(String field0) = CredentialScope_Range;
+  /// Miners carrying this tag (case-insensitive)
+  const factory CredentialScope.tag(String field0) = CredentialScope_Tag;
+  const factory CredentialScope.miner(MinerId field0) = CredentialScope_Miner;
+}
+
+/// Credentials to try on the miners in `scope`, e.g. the old password of an
+/// acquired site.  The per-vendor credentials are the last fallback.
+class CredentialSet {
+  final String name;
+  final CredentialScope scope;
+
+  /// Only tried on this vendor's miners; any vendor if unset
+  final MinerVendor? vendor;
+
+  /// Lower is tried first
+  final int priority;
+  final MinerCredentials credentials;
+
+  const CredentialSet({
+    required this.name,
+    required this.scope,
+    this.vendor,
+    required this.priority,
+    required this.credentials,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      scope.hashCode ^
+      vendor.hashCode ^
+      priority.hashCode ^
+      credentials.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CredentialSet &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          scope == other.scope &&
+          vendor == other.vendor &&
+          priority == other.priority &&
+          credentials == other.credentials;
+}
+
+/// Desired state of a group of miners, compared with what they report by
+/// the monitor (see `monitor::drift`).  Unset fields aren't checked.  A
+/// miner in several groups follows the most specific one.
+class DesiredState {
+  final String name;
+
+  /// Which miners the policy covers, as for credential sets
+  final CredentialScope scope;
+
+  /// Workers may be templates (see `client::worker_name`)
+  final List<PoolConfig>? pools;
+  final PowerMode? powerMode;
+  final FanMode? fanMode;
+
+  /// `bitmain-freq-level`, percent
+  final int? freqLevel;
+
+  const DesiredState({
+    required this.name,
+    required this.scope,
+    this.pools,
+    this.powerMode,
+    this.fanMode,
+    this.freqLevel,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      scope.hashCode ^
+      pools.hashCode ^
+      powerMode.hashCode ^
+      fanMode.hashCode ^
+      freqLevel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DesiredState &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          scope == other.scope &&
+          pools == other.pools &&
+          powerMode == other.powerMode &&
+          fanMode == other.fanMode &&
+          freqLevel == other.freqLevel;
 }
 
 /// Configuration for miner authentication and connection settings
@@ -55,3 +234,110 @@ class MinerCredentials {
           username == other.username &&
           password == other.password;
 }
+
+/// Where a miner sits, e.g. rack "B12", position "3".  Used in worker names.
+class MinerLocation {
+  final MinerId minerId;
+  final String rack;
+  final String position;
+
+  const MinerLocation({
+    required this.minerId,
+    required this.rack,
+    required this.position,
+  });
+
+  @override
+  int get hashCode => minerId.hashCode ^ rack.hashCode ^ position.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MinerLocation &&
+          runtimeType == other.runtimeType &&
+          minerId == other.minerId &&
+          rack == other.rack &&
+          position == other.position;
+}
+
+/// Free-form tags of a miner, e.g. "site-b", "rack-12"
+class MinerTags {
+  final MinerId minerId;
+  final List<String> tags;
+
+  const MinerTags({required this.minerId, required this.tags});
+
+  @override
+  int get hashCode => minerId.hashCode ^ tags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MinerTags &&
+          runtimeType == other.runtimeType &&
+          minerId == other.minerId &&
+          tags == other.tags;
+}
+
+/// A saved scan spec, e.g. "Container A" -> "10.1.2.*, !10.1.2.1"
+class NamedRange {
+  final String name;
+  final String spec;
+
+  /// Interface name or local IP that connections to this range go out from
+  /// (OS default route if unset)
+  final String? source;
+
+  const NamedRange({required this.name, required this.spec, this.source});
+
+  @override
+  int get hashCode => name.hashCode ^ spec.hashCode ^ source.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NamedRange &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          spec == other.spec &&
+          source == other.source;
+}
+
+/// Which PDU outlet feeds a miner
+class OutletLink {
+  final MinerId minerId;
+  final PduOutlet outlet;
+
+  const OutletLink({required this.minerId, required this.outlet});
+
+  @override
+  int get hashCode => minerId.hashCode ^ outlet.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OutletLink &&
+          runtimeType == other.runtimeType &&
+          minerId == other.minerId &&
+          outlet == other.outlet;
+}
+
+/// Named pool setup, e.g. "Main + backup", sent with `SetPools`.  Workers
+/// may be templates (see `client::worker_name`).
+class PoolTemplate {
+  final String name;
+  final List<PoolConfig> pools;
+
+  const PoolTemplate({required this.name, required this.pools});
+
+  @override
+  int get hashCode => name.hashCode ^ pools.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PoolTemplate &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          pools == other.pools;
+}
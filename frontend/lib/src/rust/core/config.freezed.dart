@@ -0,0 +1,686 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'config.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$CredentialScope {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() all,
+    required TResult Function(String field0) range,
+    required TResult Function(String field0) tag,
+    required TResult Function(MinerId field0) miner,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? all,
+    TResult? Function(String field0)? range,
+    TResult? Function(String field0)? tag,
+    TResult? Function(MinerId field0)? miner,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? all,
+    TResult Function(String field0)? range,
+    TResult Function(String field0)? tag,
+    TResult Function(MinerId field0)? miner,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CredentialScope_All value) all,
+    required TResult Function(CredentialScope_Range value) range,
+    required TResult Function(CredentialScope_Tag value) tag,
+    required TResult Function(CredentialScope_Miner value) miner,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CredentialScope_All value)? all,
+    TResult? Function(CredentialScope_Range value)? range,
+    TResult? Function(CredentialScope_Tag value)? tag,
+    TResult? Function(CredentialScope_Miner value)? miner,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CredentialScope_All value)? all,
+    TResult Function(CredentialScope_Range value)? range,
+    TResult Function(CredentialScope_Tag value)? tag,
+    TResult Function(CredentialScope_Miner value)? miner,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $CredentialScopeCopyWith<$Res> {
+  factory $CredentialScopeCopyWith(
+    CredentialScope value,
+    $Res Function(CredentialScope) then,
+  ) = _$CredentialScopeCopyWithImpl<$Res, CredentialScope>;
+}
+
+/// @nodoc
+class _$CredentialScopeCopyWithImpl<$Res, $Val extends CredentialScope>
+    implements $CredentialScopeCopyWith<$Res> {
+  _$CredentialScopeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$CredentialScope_AllImplCopyWith<$Res> {
+  factory _$$CredentialScope_AllImplCopyWith(
+    _$CredentialScope_AllImpl value,
+    $Res Function(_$CredentialScope_AllImpl) then,
+  ) = __$$CredentialScope_AllImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$CredentialScope_AllImplCopyWithImpl<$Res>
+    extends _$CredentialScopeCopyWithImpl<$Res, _$CredentialScope_AllImpl>
+    implements _$$CredentialScope_AllImplCopyWith<$Res> {
+  __$$CredentialScope_AllImplCopyWithImpl(
+    _$CredentialScope_AllImpl _value,
+    $Res Function(_$CredentialScope_AllImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$CredentialScope_AllImpl extends CredentialScope_All {
+  const _$CredentialScope_AllImpl() : super._();
+
+  @override
+  String toString() {
+    return 'CredentialScope.all()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CredentialScope_AllImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() all,
+    required TResult Function(String field0) range,
+    required TResult Function(String field0) tag,
+    required TResult Function(MinerId field0) miner,
+  }) {
+    return all();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? all,
+    TResult? Function(String field0)? range,
+    TResult? Function(String field0)? tag,
+    TResult? Function(MinerId field0)? miner,
+  }) {
+    return all?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? all,
+    TResult Function(String field0)? range,
+    TResult Function(String field0)? tag,
+    TResult Function(MinerId field0)? miner,
+    required TResult orElse(),
+  }) {
+    if (all != null) {
+      return all();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CredentialScope_All value) all,
+    required TResult Function(CredentialScope_Range value) range,
+    required TResult Function(CredentialScope_Tag value) tag,
+    required TResult Function(CredentialScope_Miner value) miner,
+  }) {
+    return all(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CredentialScope_All value)? all,
+    TResult? Function(CredentialScope_Range value)? range,
+    TResult? Function(CredentialScope_Tag value)? tag,
+    TResult? Function(CredentialScope_Miner value)? miner,
+  }) {
+    return all?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CredentialScope_All value)? all,
+    TResult Function(CredentialScope_Range value)? range,
+    TResult Function(CredentialScope_Tag value)? tag,
+    TResult Function(CredentialScope_Miner value)? miner,
+    required TResult orElse(),
+  }) {
+    if (all != null) {
+      return all(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CredentialScope_All extends CredentialScope {
+  const factory CredentialScope_All() = _$CredentialScope_AllImpl;
+  const CredentialScope_All._() : super._();
+}
+
+/// @nodoc
+abstract class _$$CredentialScope_RangeImplCopyWith<$Res> {
+  factory _$$CredentialScope_RangeImplCopyWith(
+    _$CredentialScope_RangeImpl value,
+    $Res Function(_$CredentialScope_RangeImpl) then,
+  ) = __$$CredentialScope_RangeImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$CredentialScope_RangeImplCopyWithImpl<$Res>
+    extends _$CredentialScopeCopyWithImpl<$Res, _$CredentialScope_RangeImpl>
+    implements _$$CredentialScope_RangeImplCopyWith<$Res> {
+  __$$CredentialScope_RangeImplCopyWithImpl(
+    _$CredentialScope_RangeImpl _value,
+    $Res Function(_$CredentialScope_RangeImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$CredentialScope_RangeImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$CredentialScope_RangeImpl extends CredentialScope_Range {
+  const _$CredentialScope_RangeImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'CredentialScope.range(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CredentialScope_RangeImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CredentialScope_RangeImplCopyWith<_$CredentialScope_RangeImpl>
+  get copyWith =>
+      __$$CredentialScope_RangeImplCopyWithImpl<_$CredentialScope_RangeImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() all,
+    required TResult Function(String field0) range,
+    required TResult Function(String field0) tag,
+    required TResult Function(MinerId field0) miner,
+  }) {
+    return range(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? all,
+    TResult? Function(String field0)? range,
+    TResult? Function(String field0)? tag,
+    TResult? Function(MinerId field0)? miner,
+  }) {
+    return range?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? all,
+    TResult Function(String field0)? range,
+    TResult Function(String field0)? tag,
+    TResult Function(MinerId field0)? miner,
+    required TResult orElse(),
+  }) {
+    if (range != null) {
+      return range(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CredentialScope_All value) all,
+    required TResult Function(CredentialScope_Range value) range,
+    required TResult Function(CredentialScope_Tag value) tag,
+    required TResult Function(CredentialScope_Miner value) miner,
+  }) {
+    return range(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CredentialScope_All value)? all,
+    TResult? Function(CredentialScope_Range value)? range,
+    TResult? Function(CredentialScope_Tag value)? tag,
+    TResult? Function(CredentialScope_Miner value)? miner,
+  }) {
+    return range?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CredentialScope_All value)? all,
+    TResult Function(CredentialScope_Range value)? range,
+    TResult Function(CredentialScope_Tag value)? tag,
+    TResult Function(CredentialScope_Miner value)? miner,
+    required TResult orElse(),
+  }) {
+    if (range != null) {
+      return range(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CredentialScope_Range extends CredentialScope {
+  const factory CredentialScope_Range(final String field0) =
+      _$CredentialScope_RangeImpl;
+  const CredentialScope_Range._() : super._();
+
+  String get field0;
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CredentialScope_RangeImplCopyWith<_$CredentialScope_RangeImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CredentialScope_TagImplCopyWith<$Res> {
+  factory _$$CredentialScope_TagImplCopyWith(
+    _$CredentialScope_TagImpl value,
+    $Res Function(_$CredentialScope_TagImpl) then,
+  ) = __$$CredentialScope_TagImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$CredentialScope_TagImplCopyWithImpl<$Res>
+    extends _$CredentialScopeCopyWithImpl<$Res, _$CredentialScope_TagImpl>
+    implements _$$CredentialScope_TagImplCopyWith<$Res> {
+  __$$CredentialScope_TagImplCopyWithImpl(
+    _$CredentialScope_TagImpl _value,
+    $Res Function(_$CredentialScope_TagImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$CredentialScope_TagImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$CredentialScope_TagImpl extends CredentialScope_Tag {
+  const _$CredentialScope_TagImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'CredentialScope.tag(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CredentialScope_TagImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CredentialScope_TagImplCopyWith<_$CredentialScope_TagImpl> get copyWith =>
+      __$$CredentialScope_TagImplCopyWithImpl<_$CredentialScope_TagImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() all,
+    required TResult Function(String field0) range,
+    required TResult Function(String field0) tag,
+    required TResult Function(MinerId field0) miner,
+  }) {
+    return tag(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? all,
+    TResult? Function(String field0)? range,
+    TResult? Function(String field0)? tag,
+    TResult? Function(MinerId field0)? miner,
+  }) {
+    return tag?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? all,
+    TResult Function(String field0)? range,
+    TResult Function(String field0)? tag,
+    TResult Function(MinerId field0)? miner,
+    required TResult orElse(),
+  }) {
+    if (tag != null) {
+      return tag(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CredentialScope_All value) all,
+    required TResult Function(CredentialScope_Range value) range,
+    required TResult Function(CredentialScope_Tag value) tag,
+    required TResult Function(CredentialScope_Miner value) miner,
+  }) {
+    return tag(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CredentialScope_All value)? all,
+    TResult? Function(CredentialScope_Range value)? range,
+    TResult? Function(CredentialScope_Tag value)? tag,
+    TResult? Function(CredentialScope_Miner value)? miner,
+  }) {
+    return tag?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CredentialScope_All value)? all,
+    TResult Function(CredentialScope_Range value)? range,
+    TResult Function(CredentialScope_Tag value)? tag,
+    TResult Function(CredentialScope_Miner value)? miner,
+    required TResult orElse(),
+  }) {
+    if (tag != null) {
+      return tag(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CredentialScope_Tag extends CredentialScope {
+  const factory CredentialScope_Tag(final String field0) =
+      _$CredentialScope_TagImpl;
+  const CredentialScope_Tag._() : super._();
+
+  String get field0;
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CredentialScope_TagImplCopyWith<_$CredentialScope_TagImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CredentialScope_MinerImplCopyWith<$Res> {
+  factory _$$CredentialScope_MinerImplCopyWith(
+    _$CredentialScope_MinerImpl value,
+    $Res Function(_$CredentialScope_MinerImpl) then,
+  ) = __$$CredentialScope_MinerImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({MinerId field0});
+}
+
+/// @nodoc
+class __$$CredentialScope_MinerImplCopyWithImpl<$Res>
+    extends _$CredentialScopeCopyWithImpl<$Res, _$CredentialScope_MinerImpl>
+    implements _$$CredentialScope_MinerImplCopyWith<$Res> {
+  __$$CredentialScope_MinerImplCopyWithImpl(
+    _$CredentialScope_MinerImpl _value,
+    $Res Function(_$CredentialScope_MinerImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$CredentialScope_MinerImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as MinerId,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$CredentialScope_MinerImpl extends CredentialScope_Miner {
+  const _$CredentialScope_MinerImpl(this.field0) : super._();
+
+  @override
+  final MinerId field0;
+
+  @override
+  String toString() {
+    return 'CredentialScope.miner(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CredentialScope_MinerImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CredentialScope_MinerImplCopyWith<_$CredentialScope_MinerImpl>
+  get copyWith =>
+      __$$CredentialScope_MinerImplCopyWithImpl<_$CredentialScope_MinerImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() all,
+    required TResult Function(String field0) range,
+    required TResult Function(String field0) tag,
+    required TResult Function(MinerId field0) miner,
+  }) {
+    return miner(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? all,
+    TResult? Function(String field0)? range,
+    TResult? Function(String field0)? tag,
+    TResult? Function(MinerId field0)? miner,
+  }) {
+    return miner?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? all,
+    TResult Function(String field0)? range,
+    TResult Function(String field0)? tag,
+    TResult Function(MinerId field0)? miner,
+    required TResult orElse(),
+  }) {
+    if (miner != null) {
+      return miner(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CredentialScope_All value) all,
+    required TResult Function(CredentialScope_Range value) range,
+    required TResult Function(CredentialScope_Tag value) tag,
+    required TResult Function(CredentialScope_Miner value) miner,
+  }) {
+    return miner(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CredentialScope_All value)? all,
+    TResult? Function(CredentialScope_Range value)? range,
+    TResult? Function(CredentialScope_Tag value)? tag,
+    TResult? Function(CredentialScope_Miner value)? miner,
+  }) {
+    return miner?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CredentialScope_All value)? all,
+    TResult Function(CredentialScope_Range value)? range,
+    TResult Function(CredentialScope_Tag value)? tag,
+    TResult Function(CredentialScope_Miner value)? miner,
+    required TResult orElse(),
+  }) {
+    if (miner != null) {
+      return miner(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CredentialScope_Miner extends CredentialScope {
+  const factory CredentialScope_Miner(final MinerId field0) =
+      _$CredentialScope_MinerImpl;
+  const CredentialScope_Miner._() : super._();
+
+  MinerId get field0;
+
+  /// Create a copy of CredentialScope
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$CredentialScope_MinerImplCopyWith<_$CredentialScope_MinerImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+@freezed
+sealed class ErrorKind with _$ErrorKind {
+  const ErrorKind._();
+
+  /// Wrong or missing credentials (including a locked vault)
+  const factory ErrorKind.authFailed() = ErrorKind_AuthFailed;
+  /// Connection refused, no route, connection dropped
+  const factory ErrorKind.unreachable() = ErrorKind_Unreachable;
+  const factory ErrorKind.timeout() = ErrorKind_Timeout;
+  /// The model or firmware lacks the command
+  const factory ErrorKind.unsupported() = ErrorKind_Unsupported;
+  /// The miner refused the request with `code`
+  const factory ErrorKind.rejectedByMiner({required String code}) =
+      ErrorKind_RejectedByMiner;
+  /// The miner answered something we couldn't read
+  const factory ErrorKind.parseFailed() = ErrorKind_ParseFailed;
+  /// Part of the change was applied
+  const factory ErrorKind.partialSuccess() = ErrorKind_PartialSuccess;
+  const factory ErrorKind.other() = ErrorKind_Other;
+}
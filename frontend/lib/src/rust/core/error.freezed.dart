@@ -0,0 +1,1309 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'error.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$ErrorKind {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() authFailed,
+    required TResult Function() unreachable,
+    required TResult Function() timeout,
+    required TResult Function() unsupported,
+    required TResult Function(String code) rejectedByMiner,
+    required TResult Function() parseFailed,
+    required TResult Function() partialSuccess,
+    required TResult Function() other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? authFailed,
+    TResult? Function()? unreachable,
+    TResult? Function()? timeout,
+    TResult? Function()? unsupported,
+    TResult? Function(String code)? rejectedByMiner,
+    TResult? Function()? parseFailed,
+    TResult? Function()? partialSuccess,
+    TResult? Function()? other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? authFailed,
+    TResult Function()? unreachable,
+    TResult Function()? timeout,
+    TResult Function()? unsupported,
+    TResult Function(String code)? rejectedByMiner,
+    TResult Function()? parseFailed,
+    TResult Function()? partialSuccess,
+    TResult Function()? other,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ErrorKind_AuthFailed value) authFailed,
+    required TResult Function(ErrorKind_Unreachable value) unreachable,
+    required TResult Function(ErrorKind_Timeout value) timeout,
+    required TResult Function(ErrorKind_Unsupported value) unsupported,
+    required TResult Function(ErrorKind_RejectedByMiner value) rejectedByMiner,
+    required TResult Function(ErrorKind_ParseFailed value) parseFailed,
+    required TResult Function(ErrorKind_PartialSuccess value) partialSuccess,
+    required TResult Function(ErrorKind_Other value) other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult? Function(ErrorKind_Unreachable value)? unreachable,
+    TResult? Function(ErrorKind_Timeout value)? timeout,
+    TResult? Function(ErrorKind_Unsupported value)? unsupported,
+    TResult? Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult? Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult? Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult? Function(ErrorKind_Other value)? other,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult Function(ErrorKind_Unreachable value)? unreachable,
+    TResult Function(ErrorKind_Timeout value)? timeout,
+    TResult Function(ErrorKind_Unsupported value)? unsupported,
+    TResult Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult Function(ErrorKind_Other value)? other,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ErrorKindCopyWith<$Res> {
+  factory $ErrorKindCopyWith(
+    ErrorKind value,
+    $Res Function(ErrorKind) then,
+  ) = _$ErrorKindCopyWithImpl<$Res, ErrorKind>;
+}
+
+/// @nodoc
+class _$ErrorKindCopyWithImpl<$Res, $Val extends ErrorKind>
+    implements $ErrorKindCopyWith<$Res> {
+  _$ErrorKindCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$ErrorKind_AuthFailedImplCopyWith<$Res> {
+  factory _$$ErrorKind_AuthFailedImplCopyWith(
+    _$ErrorKind_AuthFailedImpl value,
+    $Res Function(_$ErrorKind_AuthFailedImpl) then,
+  ) = __$$ErrorKind_AuthFailedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$ErrorKind_AuthFailedImplCopyWithImpl<$Res>
+    extends _$ErrorKindCopyWithImpl<$Res, _$ErrorKind_AuthFailedImpl>
+    implements _$$ErrorKind_AuthFailedImplCopyWith<$Res> {
+  __$$ErrorKind_AuthFailedImplCopyWithImpl(
+    _$ErrorKind_AuthFailedImpl _value,
+    $Res Function(_$ErrorKind_AuthFailedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$ErrorKind_AuthFailedImpl extends ErrorKind_AuthFailed {
+  const _$ErrorKind_AuthFailedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'ErrorKind.authFailed()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ErrorKind_AuthFailedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() authFailed,
+    required TResult Function() unreachable,
+    required TResult Function() timeout,
+    required TResult Function() unsupported,
+    required TResult Function(String code) rejectedByMiner,
+    required TResult Function() parseFailed,
+    required TResult Function() partialSuccess,
+    required TResult Function() other,
+  }) {
+    return authFailed();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? authFailed,
+    TResult? Function()? unreachable,
+    TResult? Function()? timeout,
+    TResult? Function()? unsupported,
+    TResult? Function(String code)? rejectedByMiner,
+    TResult? Function()? parseFailed,
+    TResult? Function()? partialSuccess,
+    TResult? Function()? other,
+  }) {
+    return authFailed?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? authFailed,
+    TResult Function()? unreachable,
+    TResult Function()? timeout,
+    TResult Function()? unsupported,
+    TResult Function(String code)? rejectedByMiner,
+    TResult Function()? parseFailed,
+    TResult Function()? partialSuccess,
+    TResult Function()? other,
+    required TResult orElse(),
+  }) {
+    if (authFailed != null) {
+      return authFailed();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ErrorKind_AuthFailed value) authFailed,
+    required TResult Function(ErrorKind_Unreachable value) unreachable,
+    required TResult Function(ErrorKind_Timeout value) timeout,
+    required TResult Function(ErrorKind_Unsupported value) unsupported,
+    required TResult Function(ErrorKind_RejectedByMiner value) rejectedByMiner,
+    required TResult Function(ErrorKind_ParseFailed value) parseFailed,
+    required TResult Function(ErrorKind_PartialSuccess value) partialSuccess,
+    required TResult Function(ErrorKind_Other value) other,
+  }) {
+    return authFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult? Function(ErrorKind_Unreachable value)? unreachable,
+    TResult? Function(ErrorKind_Timeout value)? timeout,
+    TResult? Function(ErrorKind_Unsupported value)? unsupported,
+    TResult? Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult? Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult? Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult? Function(ErrorKind_Other value)? other,
+  }) {
+    return authFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult Function(ErrorKind_Unreachable value)? unreachable,
+    TResult Function(ErrorKind_Timeout value)? timeout,
+    TResult Function(ErrorKind_Unsupported value)? unsupported,
+    TResult Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult Function(ErrorKind_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (authFailed != null) {
+      return authFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ErrorKind_AuthFailed extends ErrorKind {
+  const factory ErrorKind_AuthFailed() = _$ErrorKind_AuthFailedImpl;
+  const ErrorKind_AuthFailed._() : super._();
+}
+
+/// @nodoc
+abstract class _$$ErrorKind_UnreachableImplCopyWith<$Res> {
+  factory _$$ErrorKind_UnreachableImplCopyWith(
+    _$ErrorKind_UnreachableImpl value,
+    $Res Function(_$ErrorKind_UnreachableImpl) then,
+  ) = __$$ErrorKind_UnreachableImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$ErrorKind_UnreachableImplCopyWithImpl<$Res>
+    extends _$ErrorKindCopyWithImpl<$Res, _$ErrorKind_UnreachableImpl>
+    implements _$$ErrorKind_UnreachableImplCopyWith<$Res> {
+  __$$ErrorKind_UnreachableImplCopyWithImpl(
+    _$ErrorKind_UnreachableImpl _value,
+    $Res Function(_$ErrorKind_UnreachableImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$ErrorKind_UnreachableImpl extends ErrorKind_Unreachable {
+  const _$ErrorKind_UnreachableImpl() : super._();
+
+  @override
+  String toString() {
+    return 'ErrorKind.unreachable()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ErrorKind_UnreachableImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() authFailed,
+    required TResult Function() unreachable,
+    required TResult Function() timeout,
+    required TResult Function() unsupported,
+    required TResult Function(String code) rejectedByMiner,
+    required TResult Function() parseFailed,
+    required TResult Function() partialSuccess,
+    required TResult Function() other,
+  }) {
+    return unreachable();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? authFailed,
+    TResult? Function()? unreachable,
+    TResult? Function()? timeout,
+    TResult? Function()? unsupported,
+    TResult? Function(String code)? rejectedByMiner,
+    TResult? Function()? parseFailed,
+    TResult? Function()? partialSuccess,
+    TResult? Function()? other,
+  }) {
+    return unreachable?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? authFailed,
+    TResult Function()? unreachable,
+    TResult Function()? timeout,
+    TResult Function()? unsupported,
+    TResult Function(String code)? rejectedByMiner,
+    TResult Function()? parseFailed,
+    TResult Function()? partialSuccess,
+    TResult Function()? other,
+    required TResult orElse(),
+  }) {
+    if (unreachable != null) {
+      return unreachable();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ErrorKind_AuthFailed value) authFailed,
+    required TResult Function(ErrorKind_Unreachable value) unreachable,
+    required TResult Function(ErrorKind_Timeout value) timeout,
+    required TResult Function(ErrorKind_Unsupported value) unsupported,
+    required TResult Function(ErrorKind_RejectedByMiner value) rejectedByMiner,
+    required TResult Function(ErrorKind_ParseFailed value) parseFailed,
+    required TResult Function(ErrorKind_PartialSuccess value) partialSuccess,
+    required TResult Function(ErrorKind_Other value) other,
+  }) {
+    return unreachable(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult? Function(ErrorKind_Unreachable value)? unreachable,
+    TResult? Function(ErrorKind_Timeout value)? timeout,
+    TResult? Function(ErrorKind_Unsupported value)? unsupported,
+    TResult? Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult? Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult? Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult? Function(ErrorKind_Other value)? other,
+  }) {
+    return unreachable?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult Function(ErrorKind_Unreachable value)? unreachable,
+    TResult Function(ErrorKind_Timeout value)? timeout,
+    TResult Function(ErrorKind_Unsupported value)? unsupported,
+    TResult Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult Function(ErrorKind_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (unreachable != null) {
+      return unreachable(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ErrorKind_Unreachable extends ErrorKind {
+  const factory ErrorKind_Unreachable() = _$ErrorKind_UnreachableImpl;
+  const ErrorKind_Unreachable._() : super._();
+}
+
+/// @nodoc
+abstract class _$$ErrorKind_TimeoutImplCopyWith<$Res> {
+  factory _$$ErrorKind_TimeoutImplCopyWith(
+    _$ErrorKind_TimeoutImpl value,
+    $Res Function(_$ErrorKind_TimeoutImpl) then,
+  ) = __$$ErrorKind_TimeoutImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$ErrorKind_TimeoutImplCopyWithImpl<$Res>
+    extends _$ErrorKindCopyWithImpl<$Res, _$ErrorKind_TimeoutImpl>
+    implements _$$ErrorKind_TimeoutImplCopyWith<$Res> {
+  __$$ErrorKind_TimeoutImplCopyWithImpl(
+    _$ErrorKind_TimeoutImpl _value,
+    $Res Function(_$ErrorKind_TimeoutImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$ErrorKind_TimeoutImpl extends ErrorKind_Timeout {
+  const _$ErrorKind_TimeoutImpl() : super._();
+
+  @override
+  String toString() {
+    return 'ErrorKind.timeout()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ErrorKind_TimeoutImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() authFailed,
+    required TResult Function() unreachable,
+    required TResult Function() timeout,
+    required TResult Function() unsupported,
+    required TResult Function(String code) rejectedByMiner,
+    required TResult Function() parseFailed,
+    required TResult Function() partialSuccess,
+    required TResult Function() other,
+  }) {
+    return timeout();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? authFailed,
+    TResult? Function()? unreachable,
+    TResult? Function()? timeout,
+    TResult? Function()? unsupported,
+    TResult? Function(String code)? rejectedByMiner,
+    TResult? Function()? parseFailed,
+    TResult? Function()? partialSuccess,
+    TResult? Function()? other,
+  }) {
+    return timeout?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? authFailed,
+    TResult Function()? unreachable,
+    TResult Function()? timeout,
+    TResult Function()? unsupported,
+    TResult Function(String code)? rejectedByMiner,
+    TResult Function()? parseFailed,
+    TResult Function()? partialSuccess,
+    TResult Function()? other,
+    required TResult orElse(),
+  }) {
+    if (timeout != null) {
+      return timeout();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ErrorKind_AuthFailed value) authFailed,
+    required TResult Function(ErrorKind_Unreachable value) unreachable,
+    required TResult Function(ErrorKind_Timeout value) timeout,
+    required TResult Function(ErrorKind_Unsupported value) unsupported,
+    required TResult Function(ErrorKind_RejectedByMiner value) rejectedByMiner,
+    required TResult Function(ErrorKind_ParseFailed value) parseFailed,
+    required TResult Function(ErrorKind_PartialSuccess value) partialSuccess,
+    required TResult Function(ErrorKind_Other value) other,
+  }) {
+    return timeout(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult? Function(ErrorKind_Unreachable value)? unreachable,
+    TResult? Function(ErrorKind_Timeout value)? timeout,
+    TResult? Function(ErrorKind_Unsupported value)? unsupported,
+    TResult? Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult? Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult? Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult? Function(ErrorKind_Other value)? other,
+  }) {
+    return timeout?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult Function(ErrorKind_Unreachable value)? unreachable,
+    TResult Function(ErrorKind_Timeout value)? timeout,
+    TResult Function(ErrorKind_Unsupported value)? unsupported,
+    TResult Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult Function(ErrorKind_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (timeout != null) {
+      return timeout(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ErrorKind_Timeout extends ErrorKind {
+  const factory ErrorKind_Timeout() = _$ErrorKind_TimeoutImpl;
+  const ErrorKind_Timeout._() : super._();
+}
+
+/// @nodoc
+abstract class _$$ErrorKind_UnsupportedImplCopyWith<$Res> {
+  factory _$$ErrorKind_UnsupportedImplCopyWith(
+    _$ErrorKind_UnsupportedImpl value,
+    $Res Function(_$ErrorKind_UnsupportedImpl) then,
+  ) = __$$ErrorKind_UnsupportedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$ErrorKind_UnsupportedImplCopyWithImpl<$Res>
+    extends _$ErrorKindCopyWithImpl<$Res, _$ErrorKind_UnsupportedImpl>
+    implements _$$ErrorKind_UnsupportedImplCopyWith<$Res> {
+  __$$ErrorKind_UnsupportedImplCopyWithImpl(
+    _$ErrorKind_UnsupportedImpl _value,
+    $Res Function(_$ErrorKind_UnsupportedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$ErrorKind_UnsupportedImpl extends ErrorKind_Unsupported {
+  const _$ErrorKind_UnsupportedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'ErrorKind.unsupported()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ErrorKind_UnsupportedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() authFailed,
+    required TResult Function() unreachable,
+    required TResult Function() timeout,
+    required TResult Function() unsupported,
+    required TResult Function(String code) rejectedByMiner,
+    required TResult Function() parseFailed,
+    required TResult Function() partialSuccess,
+    required TResult Function() other,
+  }) {
+    return unsupported();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? authFailed,
+    TResult? Function()? unreachable,
+    TResult? Function()? timeout,
+    TResult? Function()? unsupported,
+    TResult? Function(String code)? rejectedByMiner,
+    TResult? Function()? parseFailed,
+    TResult? Function()? partialSuccess,
+    TResult? Function()? other,
+  }) {
+    return unsupported?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? authFailed,
+    TResult Function()? unreachable,
+    TResult Function()? timeout,
+    TResult Function()? unsupported,
+    TResult Function(String code)? rejectedByMiner,
+    TResult Function()? parseFailed,
+    TResult Function()? partialSuccess,
+    TResult Function()? other,
+    required TResult orElse(),
+  }) {
+    if (unsupported != null) {
+      return unsupported();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ErrorKind_AuthFailed value) authFailed,
+    required TResult Function(ErrorKind_Unreachable value) unreachable,
+    required TResult Function(ErrorKind_Timeout value) timeout,
+    required TResult Function(ErrorKind_Unsupported value) unsupported,
+    required TResult Function(ErrorKind_RejectedByMiner value) rejectedByMiner,
+    required TResult Function(ErrorKind_ParseFailed value) parseFailed,
+    required TResult Function(ErrorKind_PartialSuccess value) partialSuccess,
+    required TResult Function(ErrorKind_Other value) other,
+  }) {
+    return unsupported(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult? Function(ErrorKind_Unreachable value)? unreachable,
+    TResult? Function(ErrorKind_Timeout value)? timeout,
+    TResult? Function(ErrorKind_Unsupported value)? unsupported,
+    TResult? Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult? Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult? Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult? Function(ErrorKind_Other value)? other,
+  }) {
+    return unsupported?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult Function(ErrorKind_Unreachable value)? unreachable,
+    TResult Function(ErrorKind_Timeout value)? timeout,
+    TResult Function(ErrorKind_Unsupported value)? unsupported,
+    TResult Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult Function(ErrorKind_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (unsupported != null) {
+      return unsupported(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ErrorKind_Unsupported extends ErrorKind {
+  const factory ErrorKind_Unsupported() = _$ErrorKind_UnsupportedImpl;
+  const ErrorKind_Unsupported._() : super._();
+}
+
+/// @nodoc
+abstract class _$$ErrorKind_RejectedByMinerImplCopyWith<$Res> {
+  factory _$$ErrorKind_RejectedByMinerImplCopyWith(
+    _$ErrorKind_RejectedByMinerImpl value,
+    $Res Function(_$ErrorKind_RejectedByMinerImpl) then,
+  ) = __$$ErrorKind_RejectedByMinerImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String code});
+}
+
+/// @nodoc
+class __$$ErrorKind_RejectedByMinerImplCopyWithImpl<$Res>
+    extends _$ErrorKindCopyWithImpl<$Res, _$ErrorKind_RejectedByMinerImpl>
+    implements _$$ErrorKind_RejectedByMinerImplCopyWith<$Res> {
+  __$$ErrorKind_RejectedByMinerImplCopyWithImpl(
+    _$ErrorKind_RejectedByMinerImpl _value,
+    $Res Function(_$ErrorKind_RejectedByMinerImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? code = null}) {
+    return _then(
+      _$ErrorKind_RejectedByMinerImpl(
+        code: null == code
+            ? _value.code
+            : code // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ErrorKind_RejectedByMinerImpl extends ErrorKind_RejectedByMiner {
+  const _$ErrorKind_RejectedByMinerImpl({required this.code}) : super._();
+
+  @override
+  final String code;
+
+  @override
+  String toString() {
+    return 'ErrorKind.rejectedByMiner(code: $code)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ErrorKind_RejectedByMinerImpl &&
+            (identical(other.code, code) || other.code == code));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, code);
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ErrorKind_RejectedByMinerImplCopyWith<_$ErrorKind_RejectedByMinerImpl>
+  get copyWith =>
+      __$$ErrorKind_RejectedByMinerImplCopyWithImpl<_$ErrorKind_RejectedByMinerImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() authFailed,
+    required TResult Function() unreachable,
+    required TResult Function() timeout,
+    required TResult Function() unsupported,
+    required TResult Function(String code) rejectedByMiner,
+    required TResult Function() parseFailed,
+    required TResult Function() partialSuccess,
+    required TResult Function() other,
+  }) {
+    return rejectedByMiner(code);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? authFailed,
+    TResult? Function()? unreachable,
+    TResult? Function()? timeout,
+    TResult? Function()? unsupported,
+    TResult? Function(String code)? rejectedByMiner,
+    TResult? Function()? parseFailed,
+    TResult? Function()? partialSuccess,
+    TResult? Function()? other,
+  }) {
+    return rejectedByMiner?.call(code);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? authFailed,
+    TResult Function()? unreachable,
+    TResult Function()? timeout,
+    TResult Function()? unsupported,
+    TResult Function(String code)? rejectedByMiner,
+    TResult Function()? parseFailed,
+    TResult Function()? partialSuccess,
+    TResult Function()? other,
+    required TResult orElse(),
+  }) {
+    if (rejectedByMiner != null) {
+      return rejectedByMiner(code);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ErrorKind_AuthFailed value) authFailed,
+    required TResult Function(ErrorKind_Unreachable value) unreachable,
+    required TResult Function(ErrorKind_Timeout value) timeout,
+    required TResult Function(ErrorKind_Unsupported value) unsupported,
+    required TResult Function(ErrorKind_RejectedByMiner value) rejectedByMiner,
+    required TResult Function(ErrorKind_ParseFailed value) parseFailed,
+    required TResult Function(ErrorKind_PartialSuccess value) partialSuccess,
+    required TResult Function(ErrorKind_Other value) other,
+  }) {
+    return rejectedByMiner(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult? Function(ErrorKind_Unreachable value)? unreachable,
+    TResult? Function(ErrorKind_Timeout value)? timeout,
+    TResult? Function(ErrorKind_Unsupported value)? unsupported,
+    TResult? Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult? Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult? Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult? Function(ErrorKind_Other value)? other,
+  }) {
+    return rejectedByMiner?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult Function(ErrorKind_Unreachable value)? unreachable,
+    TResult Function(ErrorKind_Timeout value)? timeout,
+    TResult Function(ErrorKind_Unsupported value)? unsupported,
+    TResult Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult Function(ErrorKind_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (rejectedByMiner != null) {
+      return rejectedByMiner(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ErrorKind_RejectedByMiner extends ErrorKind {
+  const factory ErrorKind_RejectedByMiner({required final String code}) =
+      _$ErrorKind_RejectedByMinerImpl;
+  const ErrorKind_RejectedByMiner._() : super._();
+
+  String get code;
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ErrorKind_RejectedByMinerImplCopyWith<_$ErrorKind_RejectedByMinerImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ErrorKind_ParseFailedImplCopyWith<$Res> {
+  factory _$$ErrorKind_ParseFailedImplCopyWith(
+    _$ErrorKind_ParseFailedImpl value,
+    $Res Function(_$ErrorKind_ParseFailedImpl) then,
+  ) = __$$ErrorKind_ParseFailedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$ErrorKind_ParseFailedImplCopyWithImpl<$Res>
+    extends _$ErrorKindCopyWithImpl<$Res, _$ErrorKind_ParseFailedImpl>
+    implements _$$ErrorKind_ParseFailedImplCopyWith<$Res> {
+  __$$ErrorKind_ParseFailedImplCopyWithImpl(
+    _$ErrorKind_ParseFailedImpl _value,
+    $Res Function(_$ErrorKind_ParseFailedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$ErrorKind_ParseFailedImpl extends ErrorKind_ParseFailed {
+  const _$ErrorKind_ParseFailedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'ErrorKind.parseFailed()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ErrorKind_ParseFailedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() authFailed,
+    required TResult Function() unreachable,
+    required TResult Function() timeout,
+    required TResult Function() unsupported,
+    required TResult Function(String code) rejectedByMiner,
+    required TResult Function() parseFailed,
+    required TResult Function() partialSuccess,
+    required TResult Function() other,
+  }) {
+    return parseFailed();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? authFailed,
+    TResult? Function()? unreachable,
+    TResult? Function()? timeout,
+    TResult? Function()? unsupported,
+    TResult? Function(String code)? rejectedByMiner,
+    TResult? Function()? parseFailed,
+    TResult? Function()? partialSuccess,
+    TResult? Function()? other,
+  }) {
+    return parseFailed?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? authFailed,
+    TResult Function()? unreachable,
+    TResult Function()? timeout,
+    TResult Function()? unsupported,
+    TResult Function(String code)? rejectedByMiner,
+    TResult Function()? parseFailed,
+    TResult Function()? partialSuccess,
+    TResult Function()? other,
+    required TResult orElse(),
+  }) {
+    if (parseFailed != null) {
+      return parseFailed();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ErrorKind_AuthFailed value) authFailed,
+    required TResult Function(ErrorKind_Unreachable value) unreachable,
+    required TResult Function(ErrorKind_Timeout value) timeout,
+    required TResult Function(ErrorKind_Unsupported value) unsupported,
+    required TResult Function(ErrorKind_RejectedByMiner value) rejectedByMiner,
+    required TResult Function(ErrorKind_ParseFailed value) parseFailed,
+    required TResult Function(ErrorKind_PartialSuccess value) partialSuccess,
+    required TResult Function(ErrorKind_Other value) other,
+  }) {
+    return parseFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult? Function(ErrorKind_Unreachable value)? unreachable,
+    TResult? Function(ErrorKind_Timeout value)? timeout,
+    TResult? Function(ErrorKind_Unsupported value)? unsupported,
+    TResult? Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult? Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult? Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult? Function(ErrorKind_Other value)? other,
+  }) {
+    return parseFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult Function(ErrorKind_Unreachable value)? unreachable,
+    TResult Function(ErrorKind_Timeout value)? timeout,
+    TResult Function(ErrorKind_Unsupported value)? unsupported,
+    TResult Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult Function(ErrorKind_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (parseFailed != null) {
+      return parseFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ErrorKind_ParseFailed extends ErrorKind {
+  const factory ErrorKind_ParseFailed() = _$ErrorKind_ParseFailedImpl;
+  const ErrorKind_ParseFailed._() : super._();
+}
+
+/// @nodoc
+abstract class _$$ErrorKind_PartialSuccessImplCopyWith<$Res> {
+  factory _$$ErrorKind_PartialSuccessImplCopyWith(
+    _$ErrorKind_PartialSuccessImpl value,
+    $Res Function(_$ErrorKind_PartialSuccessImpl) then,
+  ) = __$$ErrorKind_PartialSuccessImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$ErrorKind_PartialSuccessImplCopyWithImpl<$Res>
+    extends _$ErrorKindCopyWithImpl<$Res, _$ErrorKind_PartialSuccessImpl>
+    implements _$$ErrorKind_PartialSuccessImplCopyWith<$Res> {
+  __$$ErrorKind_PartialSuccessImplCopyWithImpl(
+    _$ErrorKind_PartialSuccessImpl _value,
+    $Res Function(_$ErrorKind_PartialSuccessImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$ErrorKind_PartialSuccessImpl extends ErrorKind_PartialSuccess {
+  const _$ErrorKind_PartialSuccessImpl() : super._();
+
+  @override
+  String toString() {
+    return 'ErrorKind.partialSuccess()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ErrorKind_PartialSuccessImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() authFailed,
+    required TResult Function() unreachable,
+    required TResult Function() timeout,
+    required TResult Function() unsupported,
+    required TResult Function(String code) rejectedByMiner,
+    required TResult Function() parseFailed,
+    required TResult Function() partialSuccess,
+    required TResult Function() other,
+  }) {
+    return partialSuccess();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? authFailed,
+    TResult? Function()? unreachable,
+    TResult? Function()? timeout,
+    TResult? Function()? unsupported,
+    TResult? Function(String code)? rejectedByMiner,
+    TResult? Function()? parseFailed,
+    TResult? Function()? partialSuccess,
+    TResult? Function()? other,
+  }) {
+    return partialSuccess?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? authFailed,
+    TResult Function()? unreachable,
+    TResult Function()? timeout,
+    TResult Function()? unsupported,
+    TResult Function(String code)? rejectedByMiner,
+    TResult Function()? parseFailed,
+    TResult Function()? partialSuccess,
+    TResult Function()? other,
+    required TResult orElse(),
+  }) {
+    if (partialSuccess != null) {
+      return partialSuccess();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ErrorKind_AuthFailed value) authFailed,
+    required TResult Function(ErrorKind_Unreachable value) unreachable,
+    required TResult Function(ErrorKind_Timeout value) timeout,
+    required TResult Function(ErrorKind_Unsupported value) unsupported,
+    required TResult Function(ErrorKind_RejectedByMiner value) rejectedByMiner,
+    required TResult Function(ErrorKind_ParseFailed value) parseFailed,
+    required TResult Function(ErrorKind_PartialSuccess value) partialSuccess,
+    required TResult Function(ErrorKind_Other value) other,
+  }) {
+    return partialSuccess(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult? Function(ErrorKind_Unreachable value)? unreachable,
+    TResult? Function(ErrorKind_Timeout value)? timeout,
+    TResult? Function(ErrorKind_Unsupported value)? unsupported,
+    TResult? Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult? Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult? Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult? Function(ErrorKind_Other value)? other,
+  }) {
+    return partialSuccess?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult Function(ErrorKind_Unreachable value)? unreachable,
+    TResult Function(ErrorKind_Timeout value)? timeout,
+    TResult Function(ErrorKind_Unsupported value)? unsupported,
+    TResult Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult Function(ErrorKind_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (partialSuccess != null) {
+      return partialSuccess(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ErrorKind_PartialSuccess extends ErrorKind {
+  const factory ErrorKind_PartialSuccess() = _$ErrorKind_PartialSuccessImpl;
+  const ErrorKind_PartialSuccess._() : super._();
+}
+
+/// @nodoc
+abstract class _$$ErrorKind_OtherImplCopyWith<$Res> {
+  factory _$$ErrorKind_OtherImplCopyWith(
+    _$ErrorKind_OtherImpl value,
+    $Res Function(_$ErrorKind_OtherImpl) then,
+  ) = __$$ErrorKind_OtherImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$ErrorKind_OtherImplCopyWithImpl<$Res>
+    extends _$ErrorKindCopyWithImpl<$Res, _$ErrorKind_OtherImpl>
+    implements _$$ErrorKind_OtherImplCopyWith<$Res> {
+  __$$ErrorKind_OtherImplCopyWithImpl(
+    _$ErrorKind_OtherImpl _value,
+    $Res Function(_$ErrorKind_OtherImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ErrorKind
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$ErrorKind_OtherImpl extends ErrorKind_Other {
+  const _$ErrorKind_OtherImpl() : super._();
+
+  @override
+  String toString() {
+    return 'ErrorKind.other()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ErrorKind_OtherImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() authFailed,
+    required TResult Function() unreachable,
+    required TResult Function() timeout,
+    required TResult Function() unsupported,
+    required TResult Function(String code) rejectedByMiner,
+    required TResult Function() parseFailed,
+    required TResult Function() partialSuccess,
+    required TResult Function() other,
+  }) {
+    return other();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? authFailed,
+    TResult? Function()? unreachable,
+    TResult? Function()? timeout,
+    TResult? Function()? unsupported,
+    TResult? Function(String code)? rejectedByMiner,
+    TResult? Function()? parseFailed,
+    TResult? Function()? partialSuccess,
+    TResult? Function()? other,
+  }) {
+    return other?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? authFailed,
+    TResult Function()? unreachable,
+    TResult Function()? timeout,
+    TResult Function()? unsupported,
+    TResult Function(String code)? rejectedByMiner,
+    TResult Function()? parseFailed,
+    TResult Function()? partialSuccess,
+    TResult Function()? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ErrorKind_AuthFailed value) authFailed,
+    required TResult Function(ErrorKind_Unreachable value) unreachable,
+    required TResult Function(ErrorKind_Timeout value) timeout,
+    required TResult Function(ErrorKind_Unsupported value) unsupported,
+    required TResult Function(ErrorKind_RejectedByMiner value) rejectedByMiner,
+    required TResult Function(ErrorKind_ParseFailed value) parseFailed,
+    required TResult Function(ErrorKind_PartialSuccess value) partialSuccess,
+    required TResult Function(ErrorKind_Other value) other,
+  }) {
+    return other(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult? Function(ErrorKind_Unreachable value)? unreachable,
+    TResult? Function(ErrorKind_Timeout value)? timeout,
+    TResult? Function(ErrorKind_Unsupported value)? unsupported,
+    TResult? Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult? Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult? Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult? Function(ErrorKind_Other value)? other,
+  }) {
+    return other?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ErrorKind_AuthFailed value)? authFailed,
+    TResult Function(ErrorKind_Unreachable value)? unreachable,
+    TResult Function(ErrorKind_Timeout value)? timeout,
+    TResult Function(ErrorKind_Unsupported value)? unsupported,
+    TResult Function(ErrorKind_RejectedByMiner value)? rejectedByMiner,
+    TResult Function(ErrorKind_ParseFailed value)? parseFailed,
+    TResult Function(ErrorKind_PartialSuccess value)? partialSuccess,
+    TResult Function(ErrorKind_Other value)? other,
+    required TResult orElse(),
+  }) {
+    if (other != null) {
+      return other(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ErrorKind_Other extends ErrorKind {
+  const factory ErrorKind_Other() = _$ErrorKind_OtherImpl;
+  const ErrorKind_Other._() : super._();
+}
@@ -5,41 +5,163 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'models.freezed.dart';
+
+/// Something on the network that isn't a miner: PDUs, switches, cameras
+class Device {
+  final String ip;
+  final DeviceKind kind;
+  final String? macAddress;
+
+  /// Vendor according to the MAC OUI table
+  final String? vendorHint;
+
+  /// SNMP `sysName.0`
+  final String? sysName;
+
+  /// SNMP `sysDescr.0`
+  final String? sysDescr;
+
+  /// `Server` header and page title of its web interface
+  final String? httpBanner;
+
+  /// Miner port it accepted a connection on, if any
+  final int? openPort;
+  final BigInt lastUpdated;
+
+  const Device({
+    required this.ip,
+    required this.kind,
+    this.macAddress,
+    this.vendorHint,
+    this.sysName,
+    this.sysDescr,
+    this.httpBanner,
+    this.openPort,
+    required this.lastUpdated,
+  });
+
+  @override
+  int get hashCode =>
+      ip.hashCode ^
+      kind.hashCode ^
+      macAddress.hashCode ^
+      vendorHint.hashCode ^
+      sysName.hashCode ^
+      sysDescr.hashCode ^
+      httpBanner.hashCode ^
+      openPort.hashCode ^
+      lastUpdated.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Device &&
+          runtimeType == other.runtimeType &&
+          ip == other.ip &&
+          kind == other.kind &&
+          macAddress == other.macAddress &&
+          vendorHint == other.vendorHint &&
+          sysName == other.sysName &&
+          sysDescr == other.sysDescr &&
+          httpBanner == other.httpBanner &&
+          openPort == other.openPort &&
+          lastUpdated == other.lastUpdated;
+}
+
+/// What kind of non-miner device this is
+enum DeviceKind {
+  pdu,
+  /// Switches, routers, access points
+  network,
+  camera,
+  computer,
+  unknown,
+}
+
+@freezed
+sealed class FanMode with _$FanMode {
+  const FanMode._();
+
+  /// Firmware regulates fan speed by temperature
+  const factory FanMode.auto() = FanMode_Auto;
+  /// Fans held at `percent` PWM
+  const factory FanMode.fixed({required int percent}) = FanMode_Fixed;
+}
 
 /// Represents a discovered miner on the network
 class Miner {
+  /// Stable identity; `None` until a MAC, serial or hostname is known
+  final MinerId? id;
   final String ip;
   final String? model;
   final MinerStatus status;
   final MinerStats stats;
   final BigInt lastUpdated;
 
+  /// Vendor according to the MAC OUI table, e.g. "Bitmain"
+  final String? vendorHint;
+
+  /// PDU outlet feeding this miner, if linked in the settings
+  final PduOutlet? outlet;
+
   const Miner({
+    this.id,
     required this.ip,
     this.model,
     required this.status,
     required this.stats,
     required this.lastUpdated,
+    this.vendorHint,
+    this.outlet,
   });
 
   @override
   int get hashCode =>
+      id.hashCode ^
       ip.hashCode ^
       model.hashCode ^
       status.hashCode ^
       stats.hashCode ^
-      lastUpdated.hashCode;
+      lastUpdated.hashCode ^
+      vendorHint.hashCode ^
+      outlet.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
       other is Miner &&
           runtimeType == other.runtimeType &&
+          id == other.id &&
           ip == other.ip &&
           model == other.model &&
           status == other.status &&
           stats == other.stats &&
-          lastUpdated == other.lastUpdated;
+          lastUpdated == other.lastUpdated &&
+          vendorHint == other.vendorHint &&
+          outlet == other.outlet;
+}
+
+/// Stable miner identity that survives DHCP handing out new addresses.
+///
+/// Derived from the MAC address, falling back to the serial number and then
+/// the hostname.  The source is kept as a prefix (`mac:`, `sn:`, `host:`) so
+/// ids from different sources never collide.
+class MinerId {
+  final String field0;
+
+  const MinerId({required this.field0});
+
+  @override
+  int get hashCode => field0.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MinerId &&
+          runtimeType == other.runtimeType &&
+          field0 == other.field0;
 }
 
 /// Performance metrics for a miner
@@ -63,12 +185,26 @@ class MinerStats {
   final String? software;
   final String? hardware;
   final String? macAddress;
+  final String? serialNumber;
+  final String? hostname;
+
+  /// How the miner gets its address, as configured on the device
+  final NetworkMode? networkMode;
+  final String? netmask;
+  final String? gateway;
+  final List<String> dnsServers;
 
   /// Current power mode as raw Antminer `miner-mode` value:
   ///   0 = Normal, 1 = Sleep, 2 = LPM
   /// `None` if not yet read or unsupported.
   final int? powerMode;
 
+  /// Fan control from the miner conf (Antminer only); `None` if not read
+  final FanMode? fanMode;
+
+  /// `bitmain-freq-level` from the miner conf, percent (Antminer only)
+  final int? freqLevel;
+
   const MinerStats({
     required this.hashrateRt,
     required this.hashrateAvg,
@@ -89,7 +225,15 @@ class MinerStats {
     this.software,
     this.hardware,
     this.macAddress,
+    this.serialNumber,
+    this.hostname,
+    this.networkMode,
+    this.netmask,
+    this.gateway,
+    required this.dnsServers,
     this.powerMode,
+    this.fanMode,
+    this.freqLevel,
   });
 
   @override
@@ -113,7 +257,15 @@ class MinerStats {
       software.hashCode ^
       hardware.hashCode ^
       macAddress.hashCode ^
-      powerMode.hashCode;
+      serialNumber.hashCode ^
+      hostname.hashCode ^
+      networkMode.hashCode ^
+      netmask.hashCode ^
+      gateway.hashCode ^
+      dnsServers.hashCode ^
+      powerMode.hashCode ^
+      fanMode.hashCode ^
+      freqLevel.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -139,8 +291,42 @@ class MinerStats {
           software == other.software &&
           hardware == other.hardware &&
           macAddress == other.macAddress &&
-          powerMode == other.powerMode;
+          serialNumber == other.serialNumber &&
+          hostname == other.hostname &&
+          networkMode == other.networkMode &&
+          netmask == other.netmask &&
+          gateway == other.gateway &&
+          dnsServers == other.dnsServers &&
+          powerMode == other.powerMode &&
+          fanMode == other.fanMode &&
+          freqLevel == other.freqLevel;
 }
 
 /// Status of a miner
 enum MinerStatus { active, warning, dead, scanning }
+
+/// Miner vendor — decides which CGMiner parser and web client to use
+enum MinerVendor { antminer, whatsminer }
+
+/// Address configuration of a miner's network interface
+enum NetworkMode { dhcp, static }
+
+/// A PDU outlet, identified by the PDU's address and the outlet number
+/// printed on it (1-based)
+class PduOutlet {
+  final String pduIp;
+  final int outlet;
+
+  const PduOutlet({required this.pduIp, required this.outlet});
+
+  @override
+  int get hashCode => pduIp.hashCode ^ outlet.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PduOutlet &&
+          runtimeType == other.runtimeType &&
+          pduIp == other.pduIp &&
+          outlet == other.outlet;
+}
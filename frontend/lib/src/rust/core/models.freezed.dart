@@ -0,0 +1,327 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'models.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$FanMode {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() auto,
+    required TResult Function(int percent) fixed,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? auto,
+    TResult? Function(int percent)? fixed,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? auto,
+    TResult Function(int percent)? fixed,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FanMode_Auto value) auto,
+    required TResult Function(FanMode_Fixed value) fixed,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FanMode_Auto value)? auto,
+    TResult? Function(FanMode_Fixed value)? fixed,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FanMode_Auto value)? auto,
+    TResult Function(FanMode_Fixed value)? fixed,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $FanModeCopyWith<$Res> {
+  factory $FanModeCopyWith(
+    FanMode value,
+    $Res Function(FanMode) then,
+  ) = _$FanModeCopyWithImpl<$Res, FanMode>;
+}
+
+/// @nodoc
+class _$FanModeCopyWithImpl<$Res, $Val extends FanMode>
+    implements $FanModeCopyWith<$Res> {
+  _$FanModeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of FanMode
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$FanMode_AutoImplCopyWith<$Res> {
+  factory _$$FanMode_AutoImplCopyWith(
+    _$FanMode_AutoImpl value,
+    $Res Function(_$FanMode_AutoImpl) then,
+  ) = __$$FanMode_AutoImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$FanMode_AutoImplCopyWithImpl<$Res>
+    extends _$FanModeCopyWithImpl<$Res, _$FanMode_AutoImpl>
+    implements _$$FanMode_AutoImplCopyWith<$Res> {
+  __$$FanMode_AutoImplCopyWithImpl(
+    _$FanMode_AutoImpl _value,
+    $Res Function(_$FanMode_AutoImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of FanMode
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$FanMode_AutoImpl extends FanMode_Auto {
+  const _$FanMode_AutoImpl() : super._();
+
+  @override
+  String toString() {
+    return 'FanMode.auto()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FanMode_AutoImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() auto,
+    required TResult Function(int percent) fixed,
+  }) {
+    return auto();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? auto,
+    TResult? Function(int percent)? fixed,
+  }) {
+    return auto?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? auto,
+    TResult Function(int percent)? fixed,
+    required TResult orElse(),
+  }) {
+    if (auto != null) {
+      return auto();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FanMode_Auto value) auto,
+    required TResult Function(FanMode_Fixed value) fixed,
+  }) {
+    return auto(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FanMode_Auto value)? auto,
+    TResult? Function(FanMode_Fixed value)? fixed,
+  }) {
+    return auto?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FanMode_Auto value)? auto,
+    TResult Function(FanMode_Fixed value)? fixed,
+    required TResult orElse(),
+  }) {
+    if (auto != null) {
+      return auto(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FanMode_Auto extends FanMode {
+  const factory FanMode_Auto() = _$FanMode_AutoImpl;
+  const FanMode_Auto._() : super._();
+}
+
+/// @nodoc
+abstract class _$$FanMode_FixedImplCopyWith<$Res> {
+  factory _$$FanMode_FixedImplCopyWith(
+    _$FanMode_FixedImpl value,
+    $Res Function(_$FanMode_FixedImpl) then,
+  ) = __$$FanMode_FixedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int percent});
+}
+
+/// @nodoc
+class __$$FanMode_FixedImplCopyWithImpl<$Res>
+    extends _$FanModeCopyWithImpl<$Res, _$FanMode_FixedImpl>
+    implements _$$FanMode_FixedImplCopyWith<$Res> {
+  __$$FanMode_FixedImplCopyWithImpl(
+    _$FanMode_FixedImpl _value,
+    $Res Function(_$FanMode_FixedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of FanMode
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? percent = null}) {
+    return _then(
+      _$FanMode_FixedImpl(
+        percent: null == percent
+            ? _value.percent
+            : percent // ignore: cast_nullable_to_non_nullable
+                  as int,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$FanMode_FixedImpl extends FanMode_Fixed {
+  const _$FanMode_FixedImpl({required this.percent}) : super._();
+
+  @override
+  final int percent;
+
+  @override
+  String toString() {
+    return 'FanMode.fixed(percent: $percent)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$FanMode_FixedImpl &&
+            (identical(other.percent, percent) || other.percent == percent));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, percent);
+
+  /// Create a copy of FanMode
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$FanMode_FixedImplCopyWith<_$FanMode_FixedImpl> get copyWith =>
+      __$$FanMode_FixedImplCopyWithImpl<_$FanMode_FixedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() auto,
+    required TResult Function(int percent) fixed,
+  }) {
+    return fixed(percent);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? auto,
+    TResult? Function(int percent)? fixed,
+  }) {
+    return fixed?.call(percent);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? auto,
+    TResult Function(int percent)? fixed,
+    required TResult orElse(),
+  }) {
+    if (fixed != null) {
+      return fixed(percent);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(FanMode_Auto value) auto,
+    required TResult Function(FanMode_Fixed value) fixed,
+  }) {
+    return fixed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(FanMode_Auto value)? auto,
+    TResult? Function(FanMode_Fixed value)? fixed,
+  }) {
+    return fixed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(FanMode_Auto value)? auto,
+    TResult Function(FanMode_Fixed value)? fixed,
+    required TResult orElse(),
+  }) {
+    if (fixed != null) {
+      return fixed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class FanMode_Fixed extends FanMode {
+  const factory FanMode_Fixed({required final int percent}) =
+      _$FanMode_FixedImpl;
+  const FanMode_Fixed._() : super._();
+
+  int get percent;
+
+  /// Create a copy of FanMode
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$FanMode_FixedImplCopyWith<_$FanMode_FixedImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
@@ -0,0 +1,26 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Whether a vault exists and is unlocked in this session
+class VaultStatus {
+  final bool exists;
+  final bool unlocked;
+
+  const VaultStatus({required this.exists, required this.unlocked});
+
+  @override
+  int get hashCode => exists.hashCode ^ unlocked.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VaultStatus &&
+          runtimeType == other.runtimeType &&
+          exists == other.exists &&
+          unlocked == other.unlocked;
+}
@@ -3,20 +3,35 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/backup.dart';
 import 'api/commands.dart';
+import 'api/credentials.dart';
+import 'api/devices.dart';
 import 'api/models.dart';
 import 'api/monitor.dart';
+import 'api/policy.dart';
+import 'api/pools.dart';
 import 'api/scanner.dart';
 import 'api/settings.dart';
 import 'api/simple.dart';
+import 'api/vault.dart';
+import 'backup.dart';
+import 'client/credentials.dart';
+import 'client/pools.dart';
+import 'client/retry.dart';
+import 'client/rotation.dart';
 import 'core/config.dart';
+import 'core/error.dart';
 import 'core/models.dart';
+import 'core/vault.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'monitor/drift.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'scanner.dart';
 
 /// Main entrypoint of the Rust API
 class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
@@ -73,7 +88,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 225659152;
+  int get rustContentHash => 671313466;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -84,26 +99,128 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<List<BackupResult>> crateApiBackupBackupMiners({
+    required List<String> targetIps,
+    String? note,
+  });
+
+  Future<void> crateApiVaultChangeVaultPassphrase({
+    required String current,
+    required String newPassphrase,
+  });
+
+  Future<void> crateApiVaultCreateVault({required String passphrase});
+
+  Future<void> crateApiBackupDeleteConfigSnapshot({required String snapshotId});
+
   Future<List<String>> crateApiScannerDetectLocalRanges();
 
+  Future<List<ConfigChange>> crateApiBackupDiffConfigSnapshots({
+    required String beforeId,
+    required String afterId,
+  });
+
+  Future<List<ConfigChange>> crateApiBackupDiffConfigWithMiner({
+    required String snapshotId,
+    required String ip,
+  });
+
+  Future<List<PoolDiff>> crateApiPoolsDryRunPoolTemplate({
+    required List<String> targetIps,
+    required String templateName,
+  });
+
+  Future<List<PoolDiff>> crateApiPoolsDryRunSetPools({
+    required List<String> targetIps,
+    required List<PoolConfig> pools,
+  });
+
   Future<List<CommandResult>> crateApiCommandsExecuteBatchCommand({
     required List<String> targetIps,
     required MinerCommand command,
     MinerCredentials? credentials,
+    RetryPolicy? retryPolicy,
   });
 
   AppSettings crateApiSettingsGetAppSettings();
 
   Future<List<Miner>> crateApiMonitorGetCurrentMiners();
 
+  Future<List<DriftReport>> crateApiMonitorGetDriftReports();
+
   Future<List<PoolConfig>> crateApiCommandsGetMinerPools({required String ip});
 
+  List<String> crateApiCredentialsGetMinerTags({required MinerId minerId});
+
+  VaultStatus crateApiVaultGetVaultStatus();
+
   String crateApiSimpleGreet({required String name});
 
   Future<void> crateApiSimpleInitApp();
 
+  void crateApiDevicesLinkMinerOutlet({
+    required MinerId minerId,
+    required String pduIp,
+    required int outlet,
+  });
+
+  List<AuthFailure> crateApiCredentialsListAuthFailures();
+
+  Future<List<SnapshotInfo>> crateApiBackupListConfigSnapshots({
+    MinerId? minerId,
+  });
+
+  Future<List<CredentialSet>> crateApiCredentialsListCredentialSets();
+
+  List<DesiredState> crateApiPolicyListDesiredStates();
+
+  Future<List<LocalInterface>> crateApiScannerListLocalInterfaces();
+
+  List<MinerLocation> crateApiDevicesListMinerLocations();
+
+  List<OutletLink> crateApiDevicesListOutletLinks();
+
+  List<PoolTemplate> crateApiPoolsListPoolTemplates();
+
+  void crateApiVaultLockVault();
+
+  Future<List<WorkerPreview>> crateApiPoolsPreviewWorkerNames({
+    required List<String> targetIps,
+    required List<PoolConfig> pools,
+  });
+
+  Future<Device> crateApiDevicesProbeDevice({required String ip});
+
+  Future<List<CommandResult>> crateApiPolicyReconcileDrift({
+    required List<String> targetIps,
+  });
+
+  Future<List<CommandResult>> crateApiBackupRestoreConfigSnapshot({
+    required String snapshotId,
+    required List<String> targetIps,
+  });
+
+  Future<List<RotationResult>> crateApiCredentialsRotateWebPasswords({
+    required List<String> targetIps,
+    required PasswordPolicy policy,
+  });
+
   void crateApiSettingsSaveAppSettings({required AppSettings settings});
 
+  Future<void> crateApiCredentialsSaveCredentialSets({
+    required List<CredentialSet> sets,
+  });
+
+  void crateApiPolicySaveDesiredStates({required List<DesiredState> states});
+
+  void crateApiPoolsSavePoolTemplates({required List<PoolTemplate> templates});
+
+  void crateApiDevicesSetMinerLocation({
+    required MinerId minerId,
+    required String rack,
+    required String position,
+  });
+
   Future<CommandResult> crateApiCommandsSetMinerPools({
     required String ip,
     required List<PoolConfig> pools,
@@ -114,14 +231,27 @@ abstract class RustLibApi extends BaseApi {
     required PowerMode mode,
   });
 
+  void crateApiCredentialsSetMinerTags({
+    required MinerId minerId,
+    required List<String> tags,
+  });
+
   Future<void> crateApiMonitorStartMonitoring({required List<Miner> miners});
 
   Future<List<Miner>> crateApiScannerStartScan({required String ipRange});
 
+  Stream<ScanEvent> crateApiScannerStartScanStream({required String ipRange});
+
   Future<void> crateApiMonitorStopMonitoring();
 
+  bool crateApiScannerStopScan();
+
   Future<String> crateApiCommandsTestConnection({required String ip});
 
+  bool crateApiDevicesUnlinkMinerOutlet({required MinerId minerId});
+
+  Future<void> crateApiVaultUnlockVault({required String passphrase});
+
   Future<String> crateApiScannerValidateIpRange({required String range});
 }
 
@@ -134,11 +264,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<List<String>> crateApiScannerDetectLocalRanges() {
+  Future<List<BackupResult>> crateApiBackupBackupMiners({
+    required List<String> targetIps,
+    String? note,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(targetIps, serializer);
+          sse_encode_opt_String(note, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -147,32 +282,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+          decodeSuccessData: sse_decode_list_backup_result,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiScannerDetectLocalRangesConstMeta,
-        argValues: [],
+        constMeta: kCrateApiBackupBackupMinersConstMeta,
+        argValues: [targetIps, note],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiScannerDetectLocalRangesConstMeta =>
-      const TaskConstMeta(debugName: "detect_local_ranges", argNames: []);
+  TaskConstMeta get kCrateApiBackupBackupMinersConstMeta =>
+      const TaskConstMeta(
+        debugName: "backup_miners",
+        argNames: ["targetIps", "note"],
+      );
 
   @override
-  Future<List<CommandResult>> crateApiCommandsExecuteBatchCommand({
-    required List<String> targetIps,
-    required MinerCommand command,
-    MinerCredentials? credentials,
+  Future<void> crateApiVaultChangeVaultPassphrase({
+    required String current,
+    required String newPassphrase,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(targetIps, serializer);
-          sse_encode_box_autoadd_miner_command(command, serializer);
-          sse_encode_opt_box_autoadd_miner_credentials(credentials, serializer);
+          sse_encode_String(current, serializer);
+          sse_encode_String(newPassphrase, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -181,50 +317,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_command_result,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCommandsExecuteBatchCommandConstMeta,
-        argValues: [targetIps, command, credentials],
+        constMeta: kCrateApiVaultChangeVaultPassphraseConstMeta,
+        argValues: [current, newPassphrase],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCommandsExecuteBatchCommandConstMeta =>
+  TaskConstMeta get kCrateApiVaultChangeVaultPassphraseConstMeta =>
       const TaskConstMeta(
-        debugName: "execute_batch_command",
-        argNames: ["targetIps", "command", "credentials"],
+        debugName: "change_vault_passphrase",
+        argNames: ["current", "newPassphrase"],
       );
 
   @override
-  AppSettings crateApiSettingsGetAppSettings() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<void> crateApiVaultCreateVault({required String passphrase}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+          sse_encode_String(passphrase, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_app_settings,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSettingsGetAppSettingsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiVaultCreateVaultConstMeta,
+        argValues: [passphrase],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsGetAppSettingsConstMeta =>
-      const TaskConstMeta(debugName: "get_app_settings", argNames: []);
+  TaskConstMeta get kCrateApiVaultCreateVaultConstMeta =>
+      const TaskConstMeta(debugName: "create_vault", argNames: ["passphrase"]);
 
   @override
-  Future<List<Miner>> crateApiMonitorGetCurrentMiners() {
+  Future<void> crateApiBackupDeleteConfigSnapshot({
+    required String snapshotId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(snapshotId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -233,26 +378,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_miner,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiMonitorGetCurrentMinersConstMeta,
-        argValues: [],
+        constMeta: kCrateApiBackupDeleteConfigSnapshotConstMeta,
+        argValues: [snapshotId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMonitorGetCurrentMinersConstMeta =>
-      const TaskConstMeta(debugName: "get_current_miners", argNames: []);
+  TaskConstMeta get kCrateApiBackupDeleteConfigSnapshotConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_config_snapshot",
+        argNames: ["snapshotId"],
+      );
 
   @override
-  Future<List<PoolConfig>> crateApiCommandsGetMinerPools({required String ip}) {
+  Future<List<String>> crateApiScannerDetectLocalRanges() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ip, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -261,48 +408,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_pool_config,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCommandsGetMinerPoolsConstMeta,
-        argValues: [ip],
+        constMeta: kCrateApiScannerDetectLocalRangesConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCommandsGetMinerPoolsConstMeta =>
-      const TaskConstMeta(debugName: "get_miner_pools", argNames: ["ip"]);
+  TaskConstMeta get kCrateApiScannerDetectLocalRangesConstMeta =>
+      const TaskConstMeta(debugName: "detect_local_ranges", argNames: []);
 
   @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<List<ConfigChange>> crateApiBackupDiffConfigSnapshots({
+    required String beforeId,
+    required String afterId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+          sse_encode_String(beforeId, serializer);
+          sse_encode_String(afterId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_config_change,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiBackupDiffConfigSnapshotsConstMeta,
+        argValues: [beforeId, afterId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiBackupDiffConfigSnapshotsConstMeta =>
+      const TaskConstMeta(
+        debugName: "diff_config_snapshots",
+        argNames: ["beforeId", "afterId"],
+      );
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<List<ConfigChange>> crateApiBackupDiffConfigWithMiner({
+    required String snapshotId,
+    required String ip,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(snapshotId, serializer);
+          sse_encode_String(ip, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -311,55 +475,67 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_config_change,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiBackupDiffConfigWithMinerConstMeta,
+        argValues: [snapshotId, ip],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiBackupDiffConfigWithMinerConstMeta =>
+      const TaskConstMeta(
+        debugName: "diff_config_with_miner",
+        argNames: ["snapshotId", "ip"],
+      );
 
   @override
-  void crateApiSettingsSaveAppSettings({required AppSettings settings}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<List<PoolDiff>> crateApiPoolsDryRunPoolTemplate({
+    required List<String> targetIps,
+    required String templateName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_app_settings(settings, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+          sse_encode_list_String(targetIps, serializer);
+          sse_encode_String(templateName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_pool_diff,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSettingsSaveAppSettingsConstMeta,
-        argValues: [settings],
+        constMeta: kCrateApiPoolsDryRunPoolTemplateConstMeta,
+        argValues: [targetIps, templateName],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSettingsSaveAppSettingsConstMeta =>
+  TaskConstMeta get kCrateApiPoolsDryRunPoolTemplateConstMeta =>
       const TaskConstMeta(
-        debugName: "save_app_settings",
-        argNames: ["settings"],
+        debugName: "dry_run_pool_template",
+        argNames: ["targetIps", "templateName"],
       );
 
   @override
-  Future<CommandResult> crateApiCommandsSetMinerPools({
-    required String ip,
+  Future<List<PoolDiff>> crateApiPoolsDryRunSetPools({
+    required List<String> targetIps,
     required List<PoolConfig> pools,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ip, serializer);
+          sse_encode_list_String(targetIps, serializer);
           sse_encode_list_pool_config(pools, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
@@ -369,33 +545,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_command_result,
+          decodeSuccessData: sse_decode_list_pool_diff,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCommandsSetMinerPoolsConstMeta,
-        argValues: [ip, pools],
+        constMeta: kCrateApiPoolsDryRunSetPoolsConstMeta,
+        argValues: [targetIps, pools],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCommandsSetMinerPoolsConstMeta =>
+  TaskConstMeta get kCrateApiPoolsDryRunSetPoolsConstMeta =>
       const TaskConstMeta(
-        debugName: "set_miner_pools",
-        argNames: ["ip", "pools"],
+        debugName: "dry_run_set_pools",
+        argNames: ["targetIps", "pools"],
       );
 
   @override
-  Future<CommandResult> crateApiCommandsSetMinerPowerMode({
-    required String ip,
-    required PowerMode mode,
+  Future<List<CommandResult>> crateApiCommandsExecuteBatchCommand({
+    required List<String> targetIps,
+    required MinerCommand command,
+    MinerCredentials? credentials,
+    RetryPolicy? retryPolicy,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ip, serializer);
-          sse_encode_power_mode(mode, serializer);
+          sse_encode_list_String(targetIps, serializer);
+          sse_encode_box_autoadd_miner_command(command, serializer);
+          sse_encode_opt_box_autoadd_miner_credentials(credentials, serializer);
+          sse_encode_opt_box_autoadd_retry_policy(retryPolicy, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -404,57 +584,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_command_result,
+          decodeSuccessData: sse_decode_list_command_result,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCommandsSetMinerPowerModeConstMeta,
-        argValues: [ip, mode],
+        constMeta: kCrateApiCommandsExecuteBatchCommandConstMeta,
+        argValues: [targetIps, command, credentials, retryPolicy],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCommandsSetMinerPowerModeConstMeta =>
+  TaskConstMeta get kCrateApiCommandsExecuteBatchCommandConstMeta =>
       const TaskConstMeta(
-        debugName: "set_miner_power_mode",
-        argNames: ["ip", "mode"],
+        debugName: "execute_batch_command",
+        argNames: ["targetIps", "command", "credentials", "retryPolicy"],
       );
 
   @override
-  Future<void> crateApiMonitorStartMonitoring({required List<Miner> miners}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  AppSettings crateApiSettingsGetAppSettings() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_miner(miners, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_app_settings,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMonitorStartMonitoringConstMeta,
-        argValues: [miners],
+        constMeta: kCrateApiSettingsGetAppSettingsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMonitorStartMonitoringConstMeta =>
-      const TaskConstMeta(debugName: "start_monitoring", argNames: ["miners"]);
+  TaskConstMeta get kCrateApiSettingsGetAppSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_app_settings", argNames: []);
 
   @override
-  Future<List<Miner>> crateApiScannerStartScan({required String ipRange}) {
+  Future<List<Miner>> crateApiMonitorGetCurrentMiners() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ipRange, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -464,20 +637,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_miner,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiScannerStartScanConstMeta,
-        argValues: [ipRange],
+        constMeta: kCrateApiMonitorGetCurrentMinersConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiScannerStartScanConstMeta =>
-      const TaskConstMeta(debugName: "start_scan", argNames: ["ipRange"]);
+  TaskConstMeta get kCrateApiMonitorGetCurrentMinersConstMeta =>
+      const TaskConstMeta(debugName: "get_current_miners", argNames: []);
 
   @override
-  Future<void> crateApiMonitorStopMonitoring() {
+  Future<List<DriftReport>> crateApiMonitorGetDriftReports() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -490,21 +663,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_drift_report,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMonitorStopMonitoringConstMeta,
+        constMeta: kCrateApiMonitorGetDriftReportsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMonitorStopMonitoringConstMeta =>
-      const TaskConstMeta(debugName: "stop_monitoring", argNames: []);
+  TaskConstMeta get kCrateApiMonitorGetDriftReportsConstMeta =>
+      const TaskConstMeta(debugName: "get_drift_reports", argNames: []);
 
   @override
-  Future<String> crateApiCommandsTestConnection({required String ip}) {
+  Future<List<PoolConfig>> crateApiCommandsGetMinerPools({required String ip}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -518,894 +691,4373 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_pool_config,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCommandsTestConnectionConstMeta,
+        constMeta: kCrateApiCommandsGetMinerPoolsConstMeta,
         argValues: [ip],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCommandsTestConnectionConstMeta =>
-      const TaskConstMeta(debugName: "test_connection", argNames: ["ip"]);
+  TaskConstMeta get kCrateApiCommandsGetMinerPoolsConstMeta =>
+      const TaskConstMeta(debugName: "get_miner_pools", argNames: ["ip"]);
 
   @override
-  Future<String> crateApiScannerValidateIpRange({required String range}) {
+  List<String> crateApiCredentialsGetMinerTags({required MinerId minerId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_miner_id(minerId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCredentialsGetMinerTagsConstMeta,
+        argValues: [minerId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialsGetMinerTagsConstMeta =>
+      const TaskConstMeta(debugName: "get_miner_tags", argNames: ["minerId"]);
+
+  @override
+  VaultStatus crateApiVaultGetVaultStatus() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_vault_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiVaultGetVaultStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVaultGetVaultStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_vault_status", argNames: []);
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(range, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 18,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  void crateApiDevicesLinkMinerOutlet({
+    required MinerId minerId,
+    required String pduIp,
+    required int outlet,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_miner_id(minerId, serializer);
+          sse_encode_String(pduIp, serializer);
+          sse_encode_u_32(outlet, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiScannerValidateIpRangeConstMeta,
-        argValues: [range],
+        constMeta: kCrateApiDevicesLinkMinerOutletConstMeta,
+        argValues: [minerId, pduIp, outlet],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiScannerValidateIpRangeConstMeta =>
-      const TaskConstMeta(debugName: "validate_ip_range", argNames: ["range"]);
+  TaskConstMeta get kCrateApiDevicesLinkMinerOutletConstMeta =>
+      const TaskConstMeta(
+        debugName: "link_miner_outlet",
+        argNames: ["minerId", "pduIp", "outlet"],
+      );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  List<AuthFailure> crateApiCredentialsListAuthFailures() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_auth_failure,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCredentialsListAuthFailuresConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  TaskConstMeta get kCrateApiCredentialsListAuthFailuresConstMeta =>
+      const TaskConstMeta(debugName: "list_auth_failures", argNames: []);
+
+  @override
+  Future<List<SnapshotInfo>> crateApiBackupListConfigSnapshots({
+    MinerId? minerId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_miner_id(minerId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_snapshot_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiBackupListConfigSnapshotsConstMeta,
+        argValues: [minerId],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  AppSettings dco_decode_app_settings(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return AppSettings(
-      antminerCredentials: dco_decode_miner_credentials(arr[0]),
-      whatsminerCredentials: dco_decode_miner_credentials(arr[1]),
-      scanThreadCount: dco_decode_u_32(arr[2]),
-      monitorInterval: dco_decode_u_64(arr[3]),
+  TaskConstMeta get kCrateApiBackupListConfigSnapshotsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_config_snapshots",
+        argNames: ["minerId"],
+      );
+
+  @override
+  Future<List<CredentialSet>> crateApiCredentialsListCredentialSets() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_credential_set,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCredentialsListCredentialSetsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  TaskConstMeta get kCrateApiCredentialsListCredentialSetsConstMeta =>
+      const TaskConstMeta(debugName: "list_credential_sets", argNames: []);
+
+  @override
+  List<DesiredState> crateApiPolicyListDesiredStates() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_desired_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPolicyListDesiredStatesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPolicyListDesiredStatesConstMeta =>
+      const TaskConstMeta(debugName: "list_desired_states", argNames: []);
+
+  @override
+  Future<List<LocalInterface>> crateApiScannerListLocalInterfaces() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_local_interface,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiScannerListLocalInterfacesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScannerListLocalInterfacesConstMeta =>
+      const TaskConstMeta(debugName: "list_local_interfaces", argNames: []);
+
+  @override
+  List<MinerLocation> crateApiDevicesListMinerLocations() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_miner_location,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDevicesListMinerLocationsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDevicesListMinerLocationsConstMeta =>
+      const TaskConstMeta(debugName: "list_miner_locations", argNames: []);
+
+  @override
+  List<OutletLink> crateApiDevicesListOutletLinks() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_outlet_link,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDevicesListOutletLinksConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDevicesListOutletLinksConstMeta =>
+      const TaskConstMeta(debugName: "list_outlet_links", argNames: []);
+
+  @override
+  List<PoolTemplate> crateApiPoolsListPoolTemplates() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_pool_template,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPoolsListPoolTemplatesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPoolsListPoolTemplatesConstMeta =>
+      const TaskConstMeta(debugName: "list_pool_templates", argNames: []);
+
+  @override
+  void crateApiVaultLockVault() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiVaultLockVaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVaultLockVaultConstMeta =>
+      const TaskConstMeta(debugName: "lock_vault", argNames: []);
+
+  @override
+  Future<List<WorkerPreview>> crateApiPoolsPreviewWorkerNames({
+    required List<String> targetIps,
+    required List<PoolConfig> pools,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(targetIps, serializer);
+          sse_encode_list_pool_config(pools, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_worker_preview,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPoolsPreviewWorkerNamesConstMeta,
+        argValues: [targetIps, pools],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPoolsPreviewWorkerNamesConstMeta =>
+      const TaskConstMeta(
+        debugName: "preview_worker_names",
+        argNames: ["targetIps", "pools"],
+      );
+
+  @override
+  Future<Device> crateApiDevicesProbeDevice({required String ip}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ip, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_device,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDevicesProbeDeviceConstMeta,
+        argValues: [ip],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDevicesProbeDeviceConstMeta =>
+      const TaskConstMeta(debugName: "probe_device", argNames: ["ip"]);
+
+  @override
+  Future<List<CommandResult>> crateApiPolicyReconcileDrift({
+    required List<String> targetIps,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(targetIps, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_command_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPolicyReconcileDriftConstMeta,
+        argValues: [targetIps],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPolicyReconcileDriftConstMeta =>
+      const TaskConstMeta(
+        debugName: "reconcile_drift",
+        argNames: ["targetIps"],
+      );
+
+  @override
+  Future<List<CommandResult>> crateApiBackupRestoreConfigSnapshot({
+    required String snapshotId,
+    required List<String> targetIps,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(snapshotId, serializer);
+          sse_encode_list_String(targetIps, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_command_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBackupRestoreConfigSnapshotConstMeta,
+        argValues: [snapshotId, targetIps],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBackupRestoreConfigSnapshotConstMeta =>
+      const TaskConstMeta(
+        debugName: "restore_config_snapshot",
+        argNames: ["snapshotId", "targetIps"],
+      );
+
+  @override
+  Future<List<RotationResult>> crateApiCredentialsRotateWebPasswords({
+    required List<String> targetIps,
+    required PasswordPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(targetIps, serializer);
+          sse_encode_box_autoadd_password_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_rotation_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCredentialsRotateWebPasswordsConstMeta,
+        argValues: [targetIps, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialsRotateWebPasswordsConstMeta =>
+      const TaskConstMeta(
+        debugName: "rotate_web_passwords",
+        argNames: ["targetIps", "policy"],
+      );
+
+  @override
+  void crateApiSettingsSaveAppSettings({required AppSettings settings}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_app_settings(settings, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSettingsSaveAppSettingsConstMeta,
+        argValues: [settings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsSaveAppSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_app_settings",
+        argNames: ["settings"],
+      );
+
+  @override
+  Future<void> crateApiCredentialsSaveCredentialSets({
+    required List<CredentialSet> sets,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_credential_set(sets, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCredentialsSaveCredentialSetsConstMeta,
+        argValues: [sets],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialsSaveCredentialSetsConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_credential_sets",
+        argNames: ["sets"],
+      );
+
+  @override
+  void crateApiPolicySaveDesiredStates({required List<DesiredState> states}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_desired_state(states, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPolicySaveDesiredStatesConstMeta,
+        argValues: [states],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPolicySaveDesiredStatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_desired_states",
+        argNames: ["states"],
+      );
+
+  @override
+  void crateApiPoolsSavePoolTemplates({required List<PoolTemplate> templates}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_pool_template(templates, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiPoolsSavePoolTemplatesConstMeta,
+        argValues: [templates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPoolsSavePoolTemplatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_pool_templates",
+        argNames: ["templates"],
+      );
+
+  @override
+  void crateApiDevicesSetMinerLocation({
+    required MinerId minerId,
+    required String rack,
+    required String position,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_miner_id(minerId, serializer);
+          sse_encode_String(rack, serializer);
+          sse_encode_String(position, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDevicesSetMinerLocationConstMeta,
+        argValues: [minerId, rack, position],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDevicesSetMinerLocationConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_miner_location",
+        argNames: ["minerId", "rack", "position"],
+      );
+
+  @override
+  Future<CommandResult> crateApiCommandsSetMinerPools({
+    required String ip,
+    required List<PoolConfig> pools,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ip, serializer);
+          sse_encode_list_pool_config(pools, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_command_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCommandsSetMinerPoolsConstMeta,
+        argValues: [ip, pools],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCommandsSetMinerPoolsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_miner_pools",
+        argNames: ["ip", "pools"],
+      );
+
+  @override
+  Future<CommandResult> crateApiCommandsSetMinerPowerMode({
+    required String ip,
+    required PowerMode mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ip, serializer);
+          sse_encode_power_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_command_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCommandsSetMinerPowerModeConstMeta,
+        argValues: [ip, mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCommandsSetMinerPowerModeConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_miner_power_mode",
+        argNames: ["ip", "mode"],
+      );
+
+  @override
+  void crateApiCredentialsSetMinerTags({
+    required MinerId minerId,
+    required List<String> tags,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_miner_id(minerId, serializer);
+          sse_encode_list_String(tags, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiCredentialsSetMinerTagsConstMeta,
+        argValues: [minerId, tags],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCredentialsSetMinerTagsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_miner_tags",
+        argNames: ["minerId", "tags"],
+      );
+
+  @override
+  Future<void> crateApiMonitorStartMonitoring({required List<Miner> miners}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_miner(miners, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMonitorStartMonitoringConstMeta,
+        argValues: [miners],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMonitorStartMonitoringConstMeta =>
+      const TaskConstMeta(debugName: "start_monitoring", argNames: ["miners"]);
+
+  @override
+  Future<List<Miner>> crateApiScannerStartScan({required String ipRange}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ipRange, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_miner,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiScannerStartScanConstMeta,
+        argValues: [ipRange],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScannerStartScanConstMeta =>
+      const TaskConstMeta(debugName: "start_scan", argNames: ["ipRange"]);
+
+  @override
+  Stream<ScanEvent> crateApiScannerStartScanStream({required String ipRange}) {
+    final sink = RustStreamSink<ScanEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(ipRange, serializer);
+            sse_encode_StreamSink_scan_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 44,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiScannerStartScanStreamConstMeta,
+          argValues: [ipRange, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiScannerStartScanStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_scan_stream",
+        argNames: ["ipRange", "sink"],
+      );
+
+  @override
+  Future<void> crateApiMonitorStopMonitoring() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMonitorStopMonitoringConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMonitorStopMonitoringConstMeta =>
+      const TaskConstMeta(debugName: "stop_monitoring", argNames: []);
+
+  @override
+  bool crateApiScannerStopScan() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiScannerStopScanConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScannerStopScanConstMeta =>
+      const TaskConstMeta(debugName: "stop_scan", argNames: []);
+
+  @override
+  Future<String> crateApiCommandsTestConnection({required String ip}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ip, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCommandsTestConnectionConstMeta,
+        argValues: [ip],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCommandsTestConnectionConstMeta =>
+      const TaskConstMeta(debugName: "test_connection", argNames: ["ip"]);
+
+  @override
+  bool crateApiDevicesUnlinkMinerOutlet({required MinerId minerId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_miner_id(minerId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDevicesUnlinkMinerOutletConstMeta,
+        argValues: [minerId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDevicesUnlinkMinerOutletConstMeta =>
+      const TaskConstMeta(
+        debugName: "unlink_miner_outlet",
+        argNames: ["minerId"],
+      );
+
+  @override
+  Future<void> crateApiVaultUnlockVault({required String passphrase}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(passphrase, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiVaultUnlockVaultConstMeta,
+        argValues: [passphrase],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVaultUnlockVaultConstMeta =>
+      const TaskConstMeta(debugName: "unlock_vault", argNames: ["passphrase"]);
+
+  @override
+  Future<String> crateApiScannerValidateIpRange({required String range}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(range, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiScannerValidateIpRangeConstMeta,
+        argValues: [range],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScannerValidateIpRangeConstMeta =>
+      const TaskConstMeta(debugName: "validate_ip_range", argNames: ["range"]);
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<ScanEvent> dco_decode_StreamSink_scan_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AppSettings dco_decode_app_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17)
+      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return AppSettings(
+      antminerCredentials: dco_decode_miner_credentials(arr[0]),
+      whatsminerCredentials: dco_decode_miner_credentials(arr[1]),
+      scanThreadCount: dco_decode_u_32(arr[2]),
+      monitorInterval: dco_decode_u_64(arr[3]),
+      namedRanges: dco_decode_list_named_range(arr[4]),
+      scanTimeoutMs: dco_decode_u_64(arr[5]),
+      scanPorts: dco_decode_list_prim_u_16_strict(arr[6]),
+      scanDevices: dco_decode_bool(arr[7]),
+      snmpCommunity: dco_decode_String(arr[8]),
+      outletLinks: dco_decode_list_outlet_link(arr[9]),
+      factoryDefaultIps: dco_decode_list_String(arr[10]),
+      detectIpConflicts: dco_decode_bool(arr[11]),
+      credentialSets: dco_decode_list_credential_set(arr[12]),
+      minerTags: dco_decode_list_miner_tags(arr[13]),
+      minerLocations: dco_decode_list_miner_location(arr[14]),
+      poolTemplates: dco_decode_list_pool_template(arr[15]),
+      desiredStates: dco_decode_list_desired_state(arr[16]),
+    );
+  }
+
+  @protected
+  AuthFailure dco_decode_auth_failure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AuthFailure(
+      ip: dco_decode_String(arr[0]),
+      minerId: dco_decode_opt_box_autoadd_miner_id(arr[1]),
+      tried: dco_decode_u_32(arr[2]),
+      failedAt: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  BackupResult dco_decode_backup_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BackupResult(
+      ip: dco_decode_String(arr[0]),
+      minerId: dco_decode_opt_box_autoadd_miner_id(arr[1]),
+      snapshot: dco_decode_opt_box_autoadd_snapshot_info(arr[2]),
+      error: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AppSettings dco_decode_box_autoadd_app_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_app_settings(raw);
+  }
+
+  @protected
+  ErrorKind dco_decode_box_autoadd_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_error_kind(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FanMode dco_decode_box_autoadd_fan_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_fan_mode(raw);
+  }
+
+  @protected
+  MinerCommand dco_decode_box_autoadd_miner_command(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_miner_command(raw);
+  }
+
+  @protected
+  MinerCredentials dco_decode_box_autoadd_miner_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_miner_credentials(raw);
+  }
+
+  @protected
+  MinerId dco_decode_box_autoadd_miner_id(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_miner_id(raw);
+  }
+
+  @protected
+  MinerVendor dco_decode_box_autoadd_miner_vendor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_miner_vendor(raw);
+  }
+
+  @protected
+  NetworkMode dco_decode_box_autoadd_network_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_network_mode(raw);
+  }
+
+  @protected
+  PasswordPolicy dco_decode_box_autoadd_password_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_password_policy(raw);
+  }
+
+  @protected
+  PduOutlet dco_decode_box_autoadd_pdu_outlet(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pdu_outlet(raw);
+  }
+
+  @protected
+  PoolConfig dco_decode_box_autoadd_pool_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pool_config(raw);
+  }
+
+  @protected
+  PowerMode dco_decode_box_autoadd_power_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_power_mode(raw);
+  }
+
+  @protected
+  RetryPolicy dco_decode_box_autoadd_retry_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_retry_policy(raw);
+  }
+
+  @protected
+  SnapshotInfo dco_decode_box_autoadd_snapshot_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_snapshot_info(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  CommandAttempt dco_decode_command_attempt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CommandAttempt(
+      attempt: dco_decode_u_32(arr[0]),
+      startedAt: dco_decode_u_64(arr[1]),
+      durationMs: dco_decode_u_64(arr[2]),
+      error: dco_decode_opt_String(arr[3]),
+      errorKind: dco_decode_opt_box_autoadd_error_kind(arr[4]),
+    );
+  }
+
+  @protected
+  CommandResult dco_decode_command_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return CommandResult(
+      ip: dco_decode_String(arr[0]),
+      minerId: dco_decode_opt_box_autoadd_miner_id(arr[1]),
+      success: dco_decode_bool(arr[2]),
+      error: dco_decode_opt_String(arr[3]),
+      errorKind: dco_decode_opt_box_autoadd_error_kind(arr[4]),
+      retryable: dco_decode_bool(arr[5]),
+      suggestedAction: dco_decode_opt_String(arr[6]),
+      attempts: dco_decode_list_command_attempt(arr[7]),
+      skipped: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  ConfigChange dco_decode_config_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ConfigChange(
+      key: dco_decode_String(arr[0]),
+      before: dco_decode_opt_String(arr[1]),
+      after: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  CredentialScope dco_decode_credential_scope(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CredentialScope_All();
+      case 1:
+        return CredentialScope_Range(dco_decode_String(raw[1]));
+      case 2:
+        return CredentialScope_Tag(dco_decode_String(raw[1]));
+      case 3:
+        return CredentialScope_Miner(dco_decode_miner_id(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CredentialSet dco_decode_credential_set(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CredentialSet(
+      name: dco_decode_String(arr[0]),
+      scope: dco_decode_credential_scope(arr[1]),
+      vendor: dco_decode_opt_box_autoadd_miner_vendor(arr[2]),
+      priority: dco_decode_i_32(arr[3]),
+      credentials: dco_decode_miner_credentials(arr[4]),
+    );
+  }
+
+  @protected
+  DesiredState dco_decode_desired_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return DesiredState(
+      name: dco_decode_String(arr[0]),
+      scope: dco_decode_credential_scope(arr[1]),
+      pools: dco_decode_opt_list_pool_config(arr[2]),
+      powerMode: dco_decode_opt_box_autoadd_power_mode(arr[3]),
+      fanMode: dco_decode_opt_box_autoadd_fan_mode(arr[4]),
+      freqLevel: dco_decode_opt_box_autoadd_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  Device dco_decode_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return Device(
+      ip: dco_decode_String(arr[0]),
+      kind: dco_decode_device_kind(arr[1]),
+      macAddress: dco_decode_opt_String(arr[2]),
+      vendorHint: dco_decode_opt_String(arr[3]),
+      sysName: dco_decode_opt_String(arr[4]),
+      sysDescr: dco_decode_opt_String(arr[5]),
+      httpBanner: dco_decode_opt_String(arr[6]),
+      openPort: dco_decode_opt_box_autoadd_u_16(arr[7]),
+      lastUpdated: dco_decode_u_64(arr[8]),
+    );
+  }
+
+  @protected
+  DeviceKind dco_decode_device_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeviceKind.values[raw as int];
+  }
+
+  @protected
+  Drift dco_decode_drift(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Drift(
+      field: dco_decode_drift_field(arr[0]),
+      expected: dco_decode_String(arr[1]),
+      actual: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  DriftField dco_decode_drift_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DriftField.values[raw as int];
+  }
+
+  @protected
+  DriftReport dco_decode_drift_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DriftReport(
+      ip: dco_decode_String(arr[0]),
+      minerId: dco_decode_opt_box_autoadd_miner_id(arr[1]),
+      policy: dco_decode_String(arr[2]),
+      drifts: dco_decode_list_drift(arr[3]),
+      detectedAt: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  ErrorKind dco_decode_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ErrorKind_AuthFailed();
+      case 1:
+        return ErrorKind_Unreachable();
+      case 2:
+        return ErrorKind_Timeout();
+      case 3:
+        return ErrorKind_Unsupported();
+      case 4:
+        return ErrorKind_RejectedByMiner(code: dco_decode_String(raw[1]));
+      case 5:
+        return ErrorKind_ParseFailed();
+      case 6:
+        return ErrorKind_PartialSuccess();
+      case 7:
+        return ErrorKind_Other();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FanMode dco_decode_fan_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return FanMode_Auto();
+      case 1:
+        return FanMode_Fixed(percent: dco_decode_u_32(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AuthFailure> dco_decode_list_auth_failure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_auth_failure).toList();
+  }
+
+  @protected
+  List<BackupResult> dco_decode_list_backup_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_backup_result).toList();
+  }
+
+  @protected
+  List<CommandAttempt> dco_decode_list_command_attempt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_command_attempt).toList();
+  }
+
+  @protected
+  List<CommandResult> dco_decode_list_command_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_command_result).toList();
+  }
+
+  @protected
+  List<ConfigChange> dco_decode_list_config_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_config_change).toList();
+  }
+
+  @protected
+  List<CredentialSet> dco_decode_list_credential_set(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_credential_set).toList();
+  }
+
+  @protected
+  List<DesiredState> dco_decode_list_desired_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_desired_state).toList();
+  }
+
+  @protected
+  List<Drift> dco_decode_list_drift(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_drift).toList();
+  }
+
+  @protected
+  List<DriftReport> dco_decode_list_drift_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_drift_report).toList();
+  }
+
+  @protected
+  List<ErrorKind> dco_decode_list_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_error_kind).toList();
+  }
+
+  @protected
+  List<LocalInterface> dco_decode_list_local_interface(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_local_interface).toList();
+  }
+
+  @protected
+  List<Miner> dco_decode_list_miner(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_miner).toList();
+  }
+
+  @protected
+  List<MinerLocation> dco_decode_list_miner_location(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_miner_location).toList();
+  }
+
+  @protected
+  List<MinerTags> dco_decode_list_miner_tags(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_miner_tags).toList();
+  }
+
+  @protected
+  List<NamedRange> dco_decode_list_named_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_named_range).toList();
+  }
+
+  @protected
+  List<double?> dco_decode_list_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_f_64).toList();
+  }
+
+  @protected
+  List<int?> dco_decode_list_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_u_32).toList();
+  }
+
+  @protected
+  List<OutletLink> dco_decode_list_outlet_link(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_outlet_link).toList();
+  }
+
+  @protected
+  List<PoolConfig> dco_decode_list_pool_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pool_config).toList();
+  }
+
+  @protected
+  List<PoolDiff> dco_decode_list_pool_diff(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pool_diff).toList();
+  }
+
+  @protected
+  List<PoolSlotChange> dco_decode_list_pool_slot_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pool_slot_change).toList();
+  }
+
+  @protected
+  List<PoolTemplate> dco_decode_list_pool_template(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pool_template).toList();
+  }
+
+  @protected
+  Uint16List dco_decode_list_prim_u_16_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint16List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<RotationResult> dco_decode_list_rotation_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rotation_result).toList();
+  }
+
+  @protected
+  List<SnapshotInfo> dco_decode_list_snapshot_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_snapshot_info).toList();
+  }
+
+  @protected
+  List<WorkerPreview> dco_decode_list_worker_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_worker_preview).toList();
+  }
+
+  @protected
+  LocalInterface dco_decode_local_interface(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LocalInterface(
+      name: dco_decode_String(arr[0]),
+      ip: dco_decode_String(arr[1]),
+      range: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  Miner dco_decode_miner(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return Miner(
+      id: dco_decode_opt_box_autoadd_miner_id(arr[0]),
+      ip: dco_decode_String(arr[1]),
+      model: dco_decode_opt_String(arr[2]),
+      status: dco_decode_miner_status(arr[3]),
+      stats: dco_decode_miner_stats(arr[4]),
+      lastUpdated: dco_decode_u_64(arr[5]),
+      vendorHint: dco_decode_opt_String(arr[6]),
+      outlet: dco_decode_opt_box_autoadd_pdu_outlet(arr[7]),
+    );
+  }
+
+  @protected
+  MinerCommand dco_decode_miner_command(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return MinerCommand_Reboot();
+      case 1:
+        return MinerCommand_BlinkLed();
+      case 2:
+        return MinerCommand_StopBlink();
+      case 3:
+        return MinerCommand_SetPools(
+          pools: dco_decode_list_pool_config(raw[1]),
+        );
+      case 4:
+        return MinerCommand_ApplyConfig(
+          pools: dco_decode_opt_list_pool_config(raw[1]),
+          powerMode: dco_decode_opt_box_autoadd_power_mode(raw[2]),
+          fanMode: dco_decode_opt_box_autoadd_fan_mode(raw[3]),
+          freqLevel: dco_decode_opt_box_autoadd_u_32(raw[4]),
+        );
+      case 5:
+        return MinerCommand_RestoreConfig(
+          snapshotId: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  MinerCredentials dco_decode_miner_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MinerCredentials(
+      username: dco_decode_String(arr[0]),
+      password: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  MinerId dco_decode_miner_id(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return MinerId(field0: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  MinerLocation dco_decode_miner_location(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MinerLocation(
+      minerId: dco_decode_miner_id(arr[0]),
+      rack: dco_decode_String(arr[1]),
+      position: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  MinerStats dco_decode_miner_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 28)
+      throw Exception('unexpected arr length: expect 28 but see ${arr.length}');
+    return MinerStats(
+      hashrateRt: dco_decode_f_64(arr[0]),
+      hashrateAvg: dco_decode_f_64(arr[1]),
+      tempOutletMin: dco_decode_list_opt_box_autoadd_f_64(arr[2]),
+      tempOutletMax: dco_decode_list_opt_box_autoadd_f_64(arr[3]),
+      tempInletMin: dco_decode_list_opt_box_autoadd_f_64(arr[4]),
+      tempInletMax: dco_decode_list_opt_box_autoadd_f_64(arr[5]),
+      fanSpeeds: dco_decode_list_opt_box_autoadd_u_32(arr[6]),
+      uptime: dco_decode_u_64(arr[7]),
+      pool1: dco_decode_opt_String(arr[8]),
+      worker1: dco_decode_opt_String(arr[9]),
+      pool2: dco_decode_opt_String(arr[10]),
+      worker2: dco_decode_opt_String(arr[11]),
+      pool3: dco_decode_opt_String(arr[12]),
+      worker3: dco_decode_opt_String(arr[13]),
+      model: dco_decode_opt_String(arr[14]),
+      firmware: dco_decode_opt_String(arr[15]),
+      software: dco_decode_opt_String(arr[16]),
+      hardware: dco_decode_opt_String(arr[17]),
+      macAddress: dco_decode_opt_String(arr[18]),
+      serialNumber: dco_decode_opt_String(arr[19]),
+      hostname: dco_decode_opt_String(arr[20]),
+      networkMode: dco_decode_opt_box_autoadd_network_mode(arr[21]),
+      netmask: dco_decode_opt_String(arr[22]),
+      gateway: dco_decode_opt_String(arr[23]),
+      dnsServers: dco_decode_list_String(arr[24]),
+      powerMode: dco_decode_opt_box_autoadd_u_8(arr[25]),
+      fanMode: dco_decode_opt_box_autoadd_fan_mode(arr[26]),
+      freqLevel: dco_decode_opt_box_autoadd_u_32(arr[27]),
+    );
+  }
+
+  @protected
+  MinerStatus dco_decode_miner_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MinerStatus.values[raw as int];
+  }
+
+  @protected
+  MinerTags dco_decode_miner_tags(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MinerTags(
+      minerId: dco_decode_miner_id(arr[0]),
+      tags: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  MinerVendor dco_decode_miner_vendor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MinerVendor.values[raw as int];
+  }
+
+  @protected
+  NamedRange dco_decode_named_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return NamedRange(
+      name: dco_decode_String(arr[0]),
+      spec: dco_decode_String(arr[1]),
+      source: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  NetworkMode dco_decode_network_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NetworkMode.values[raw as int];
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  ErrorKind? dco_decode_opt_box_autoadd_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_error_kind(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  FanMode? dco_decode_opt_box_autoadd_fan_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_fan_mode(raw);
+  }
+
+  @protected
+  MinerCredentials? dco_decode_opt_box_autoadd_miner_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_miner_credentials(raw);
+  }
+
+  @protected
+  MinerId? dco_decode_opt_box_autoadd_miner_id(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_miner_id(raw);
+  }
+
+  @protected
+  MinerVendor? dco_decode_opt_box_autoadd_miner_vendor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_miner_vendor(raw);
+  }
+
+  @protected
+  NetworkMode? dco_decode_opt_box_autoadd_network_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_network_mode(raw);
+  }
+
+  @protected
+  PduOutlet? dco_decode_opt_box_autoadd_pdu_outlet(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pdu_outlet(raw);
+  }
+
+  @protected
+  PoolConfig? dco_decode_opt_box_autoadd_pool_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pool_config(raw);
+  }
+
+  @protected
+  PowerMode? dco_decode_opt_box_autoadd_power_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_power_mode(raw);
+  }
+
+  @protected
+  RetryPolicy? dco_decode_opt_box_autoadd_retry_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_retry_policy(raw);
+  }
+
+  @protected
+  SnapshotInfo? dco_decode_opt_box_autoadd_snapshot_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_snapshot_info(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  }
+
+  @protected
+  List<PoolConfig>? dco_decode_opt_list_pool_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_pool_config(raw);
+  }
+
+  @protected
+  OutletLink dco_decode_outlet_link(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OutletLink(
+      minerId: dco_decode_miner_id(arr[0]),
+      outlet: dco_decode_pdu_outlet(arr[1]),
+    );
+  }
+
+  @protected
+  PasswordPolicy dco_decode_password_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PasswordPolicy_Generate(length: dco_decode_u_32(raw[1]));
+      case 1:
+        return PasswordPolicy_Fixed(password: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  PduOutlet dco_decode_pdu_outlet(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PduOutlet(
+      pduIp: dco_decode_String(arr[0]),
+      outlet: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  PoolConfig dco_decode_pool_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PoolConfig(
+      url: dco_decode_String(arr[0]),
+      worker: dco_decode_String(arr[1]),
+      password: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  PoolDiff dco_decode_pool_diff(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PoolDiff(
+      ip: dco_decode_String(arr[0]),
+      minerId: dco_decode_opt_box_autoadd_miner_id(arr[1]),
+      changes: dco_decode_list_pool_slot_change(arr[2]),
+      passwordsCompared: dco_decode_bool(arr[3]),
+      error: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  PoolSlotChange dco_decode_pool_slot_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PoolSlotChange(
+      slot: dco_decode_u_32(arr[0]),
+      current: dco_decode_opt_box_autoadd_pool_config(arr[1]),
+      intended: dco_decode_opt_box_autoadd_pool_config(arr[2]),
+    );
+  }
+
+  @protected
+  PoolTemplate dco_decode_pool_template(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PoolTemplate(
+      name: dco_decode_String(arr[0]),
+      pools: dco_decode_list_pool_config(arr[1]),
+    );
+  }
+
+  @protected
+  PowerMode dco_decode_power_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PowerMode.values[raw as int];
+  }
+
+  @protected
+  RetryPolicy dco_decode_retry_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RetryPolicy(
+      maxAttempts: dco_decode_u_32(arr[0]),
+      initialBackoffMs: dco_decode_u_64(arr[1]),
+      maxBackoffMs: dco_decode_u_64(arr[2]),
+      retryOn: dco_decode_list_error_kind(arr[3]),
+      minUptimeSecs: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  RotationOutcome dco_decode_rotation_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RotationOutcome.values[raw as int];
+  }
+
+  @protected
+  RotationResult dco_decode_rotation_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RotationResult(
+      ip: dco_decode_String(arr[0]),
+      minerId: dco_decode_opt_box_autoadd_miner_id(arr[1]),
+      outcome: dco_decode_rotation_outcome(arr[2]),
+      message: dco_decode_opt_String(arr[3]),
+      errorKind: dco_decode_opt_box_autoadd_error_kind(arr[4]),
+    );
+  }
+
+  @protected
+  ScanEvent dco_decode_scan_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ScanEvent_Started(totalIps: dco_decode_usize(raw[1]));
+      case 1:
+        return ScanEvent_Found(dco_decode_miner(raw[1]));
+      case 2:
+        return ScanEvent_Progress(
+          scanned: dco_decode_usize(raw[1]),
+          total: dco_decode_usize(raw[2]),
+        );
+      case 3:
+        return ScanEvent_Complete(
+          found: dco_decode_usize(raw[1]),
+          failed: dco_decode_usize(raw[2]),
+        );
+      case 4:
+        return ScanEvent_Cancelled(
+          scanned: dco_decode_usize(raw[1]),
+          found: dco_decode_usize(raw[2]),
+        );
+      case 5:
+        return ScanEvent_MinerMoved(
+          id: dco_decode_miner_id(raw[1]),
+          fromIp: dco_decode_String(raw[2]),
+          toIp: dco_decode_String(raw[3]),
+        );
+      case 6:
+        return ScanEvent_IpTakenOver(
+          ip: dco_decode_String(raw[1]),
+          previous: dco_decode_miner_id(raw[2]),
+          current: dco_decode_miner_id(raw[3]),
+        );
+      case 7:
+        return ScanEvent_NonMiner(dco_decode_device(raw[1]));
+      case 8:
+        return ScanEvent_DuplicateIp(
+          ip: dco_decode_String(raw[1]),
+          macAddresses: dco_decode_list_String(raw[2]),
+          serialNumbers: dco_decode_list_String(raw[3]),
+        );
+      case 9:
+        return ScanEvent_FactoryDefaultIp(
+          ip: dco_decode_String(raw[1]),
+          id: dco_decode_opt_box_autoadd_miner_id(raw[2]),
+          macAddress: dco_decode_opt_String(raw[3]),
+        );
+      case 10:
+        return ScanEvent_NetworkMismatch(
+          ip: dco_decode_String(raw[1]),
+          id: dco_decode_opt_box_autoadd_miner_id(raw[2]),
+          netmask: dco_decode_opt_String(raw[3]),
+          gateway: dco_decode_opt_String(raw[4]),
+          expectedSubnet: dco_decode_opt_String(raw[5]),
+          reason: dco_decode_String(raw[6]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SnapshotInfo dco_decode_snapshot_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return SnapshotInfo(
+      id: dco_decode_String(arr[0]),
+      minerId: dco_decode_miner_id(arr[1]),
+      ip: dco_decode_String(arr[2]),
+      vendor: dco_decode_miner_vendor(arr[3]),
+      model: dco_decode_opt_String(arr[4]),
+      takenAt: dco_decode_u_64(arr[5]),
+      note: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  VaultStatus dco_decode_vault_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return VaultStatus(
+      exists: dco_decode_bool(arr[0]),
+      unlocked: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  WorkerPreview dco_decode_worker_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WorkerPreview(
+      ip: dco_decode_String(arr[0]),
+      minerId: dco_decode_opt_box_autoadd_miner_id(arr[1]),
+      workers: dco_decode_list_String(arr[2]),
+      error: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<ScanEvent> sse_decode_StreamSink_scan_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AppSettings sse_decode_app_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_antminerCredentials = sse_decode_miner_credentials(deserializer);
+    var var_whatsminerCredentials = sse_decode_miner_credentials(deserializer);
+    var var_scanThreadCount = sse_decode_u_32(deserializer);
+    var var_monitorInterval = sse_decode_u_64(deserializer);
+    var var_namedRanges = sse_decode_list_named_range(deserializer);
+    var var_scanTimeoutMs = sse_decode_u_64(deserializer);
+    var var_scanPorts = sse_decode_list_prim_u_16_strict(deserializer);
+    var var_scanDevices = sse_decode_bool(deserializer);
+    var var_snmpCommunity = sse_decode_String(deserializer);
+    var var_outletLinks = sse_decode_list_outlet_link(deserializer);
+    var var_factoryDefaultIps = sse_decode_list_String(deserializer);
+    var var_detectIpConflicts = sse_decode_bool(deserializer);
+    var var_credentialSets = sse_decode_list_credential_set(deserializer);
+    var var_minerTags = sse_decode_list_miner_tags(deserializer);
+    var var_minerLocations = sse_decode_list_miner_location(deserializer);
+    var var_poolTemplates = sse_decode_list_pool_template(deserializer);
+    var var_desiredStates = sse_decode_list_desired_state(deserializer);
+    return AppSettings(
+      antminerCredentials: var_antminerCredentials,
+      whatsminerCredentials: var_whatsminerCredentials,
+      scanThreadCount: var_scanThreadCount,
+      monitorInterval: var_monitorInterval,
+      namedRanges: var_namedRanges,
+      scanTimeoutMs: var_scanTimeoutMs,
+      scanPorts: var_scanPorts,
+      scanDevices: var_scanDevices,
+      snmpCommunity: var_snmpCommunity,
+      outletLinks: var_outletLinks,
+      factoryDefaultIps: var_factoryDefaultIps,
+      detectIpConflicts: var_detectIpConflicts,
+      credentialSets: var_credentialSets,
+      minerTags: var_minerTags,
+      minerLocations: var_minerLocations,
+      poolTemplates: var_poolTemplates,
+      desiredStates: var_desiredStates,
+    );
+  }
+
+  @protected
+  AuthFailure sse_decode_auth_failure(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ip = sse_decode_String(deserializer);
+    var var_minerId = sse_decode_opt_box_autoadd_miner_id(deserializer);
+    var var_tried = sse_decode_u_32(deserializer);
+    var var_failedAt = sse_decode_u_64(deserializer);
+    return AuthFailure(
+      ip: var_ip,
+      minerId: var_minerId,
+      tried: var_tried,
+      failedAt: var_failedAt,
+    );
+  }
+
+  @protected
+  BackupResult sse_decode_backup_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ip = sse_decode_String(deserializer);
+    var var_minerId = sse_decode_opt_box_autoadd_miner_id(deserializer);
+    var var_snapshot = sse_decode_opt_box_autoadd_snapshot_info(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return BackupResult(
+      ip: var_ip,
+      minerId: var_minerId,
+      snapshot: var_snapshot,
+      error: var_error,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AppSettings sse_decode_box_autoadd_app_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_app_settings(deserializer));
+  }
+
+  @protected
+  ErrorKind sse_decode_box_autoadd_error_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_error_kind(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  FanMode sse_decode_box_autoadd_fan_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fan_mode(deserializer));
+  }
+
+  @protected
+  MinerCommand sse_decode_box_autoadd_miner_command(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_miner_command(deserializer));
+  }
+
+  @protected
+  MinerCredentials sse_decode_box_autoadd_miner_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_miner_credentials(deserializer));
+  }
+
+  @protected
+  MinerId sse_decode_box_autoadd_miner_id(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_miner_id(deserializer));
+  }
+
+  @protected
+  MinerVendor sse_decode_box_autoadd_miner_vendor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_miner_vendor(deserializer));
+  }
+
+  @protected
+  NetworkMode sse_decode_box_autoadd_network_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_network_mode(deserializer));
+  }
+
+  @protected
+  PasswordPolicy sse_decode_box_autoadd_password_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_password_policy(deserializer));
+  }
+
+  @protected
+  PduOutlet sse_decode_box_autoadd_pdu_outlet(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pdu_outlet(deserializer));
+  }
+
+  @protected
+  PoolConfig sse_decode_box_autoadd_pool_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pool_config(deserializer));
+  }
+
+  @protected
+  PowerMode sse_decode_box_autoadd_power_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_power_mode(deserializer));
+  }
+
+  @protected
+  RetryPolicy sse_decode_box_autoadd_retry_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_retry_policy(deserializer));
+  }
+
+  @protected
+  SnapshotInfo sse_decode_box_autoadd_snapshot_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_snapshot_info(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_8(deserializer));
+  }
+
+  @protected
+  CommandAttempt sse_decode_command_attempt(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_attempt = sse_decode_u_32(deserializer);
+    var var_startedAt = sse_decode_u_64(deserializer);
+    var var_durationMs = sse_decode_u_64(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    var var_errorKind = sse_decode_opt_box_autoadd_error_kind(deserializer);
+    return CommandAttempt(
+      attempt: var_attempt,
+      startedAt: var_startedAt,
+      durationMs: var_durationMs,
+      error: var_error,
+      errorKind: var_errorKind,
+    );
+  }
+
+  @protected
+  CommandResult sse_decode_command_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ip = sse_decode_String(deserializer);
+    var var_minerId = sse_decode_opt_box_autoadd_miner_id(deserializer);
+    var var_success = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    var var_errorKind = sse_decode_opt_box_autoadd_error_kind(deserializer);
+    var var_retryable = sse_decode_bool(deserializer);
+    var var_suggestedAction = sse_decode_opt_String(deserializer);
+    var var_attempts = sse_decode_list_command_attempt(deserializer);
+    var var_skipped = sse_decode_opt_String(deserializer);
+    return CommandResult(
+      ip: var_ip,
+      minerId: var_minerId,
+      success: var_success,
+      error: var_error,
+      errorKind: var_errorKind,
+      retryable: var_retryable,
+      suggestedAction: var_suggestedAction,
+      attempts: var_attempts,
+      skipped: var_skipped,
+    );
+  }
+
+  @protected
+  ConfigChange sse_decode_config_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_before = sse_decode_opt_String(deserializer);
+    var var_after = sse_decode_opt_String(deserializer);
+    return ConfigChange(key: var_key, before: var_before, after: var_after);
+  }
+
+  @protected
+  CredentialScope sse_decode_credential_scope(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return CredentialScope_All();
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return CredentialScope_Range(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return CredentialScope_Tag(var_field0);
+      case 3:
+        var var_field0 = sse_decode_miner_id(deserializer);
+        return CredentialScope_Miner(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CredentialSet sse_decode_credential_set(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_scope = sse_decode_credential_scope(deserializer);
+    var var_vendor = sse_decode_opt_box_autoadd_miner_vendor(deserializer);
+    var var_priority = sse_decode_i_32(deserializer);
+    var var_credentials = sse_decode_miner_credentials(deserializer);
+    return CredentialSet(
+      name: var_name,
+      scope: var_scope,
+      vendor: var_vendor,
+      priority: var_priority,
+      credentials: var_credentials,
+    );
+  }
+
+  @protected
+  DesiredState sse_decode_desired_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_scope = sse_decode_credential_scope(deserializer);
+    var var_pools = sse_decode_opt_list_pool_config(deserializer);
+    var var_powerMode = sse_decode_opt_box_autoadd_power_mode(deserializer);
+    var var_fanMode = sse_decode_opt_box_autoadd_fan_mode(deserializer);
+    var var_freqLevel = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return DesiredState(
+      name: var_name,
+      scope: var_scope,
+      pools: var_pools,
+      powerMode: var_powerMode,
+      fanMode: var_fanMode,
+      freqLevel: var_freqLevel,
+    );
+  }
+
+  @protected
+  Device sse_decode_device(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ip = sse_decode_String(deserializer);
+    var var_kind = sse_decode_device_kind(deserializer);
+    var var_macAddress = sse_decode_opt_String(deserializer);
+    var var_vendorHint = sse_decode_opt_String(deserializer);
+    var var_sysName = sse_decode_opt_String(deserializer);
+    var var_sysDescr = sse_decode_opt_String(deserializer);
+    var var_httpBanner = sse_decode_opt_String(deserializer);
+    var var_openPort = sse_decode_opt_box_autoadd_u_16(deserializer);
+    var var_lastUpdated = sse_decode_u_64(deserializer);
+    return Device(
+      ip: var_ip,
+      kind: var_kind,
+      macAddress: var_macAddress,
+      vendorHint: var_vendorHint,
+      sysName: var_sysName,
+      sysDescr: var_sysDescr,
+      httpBanner: var_httpBanner,
+      openPort: var_openPort,
+      lastUpdated: var_lastUpdated,
+    );
+  }
+
+  @protected
+  DeviceKind sse_decode_device_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DeviceKind.values[inner];
+  }
+
+  @protected
+  Drift sse_decode_drift(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_drift_field(deserializer);
+    var var_expected = sse_decode_String(deserializer);
+    var var_actual = sse_decode_String(deserializer);
+    return Drift(field: var_field, expected: var_expected, actual: var_actual);
+  }
+
+  @protected
+  DriftField sse_decode_drift_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DriftField.values[inner];
+  }
+
+  @protected
+  DriftReport sse_decode_drift_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ip = sse_decode_String(deserializer);
+    var var_minerId = sse_decode_opt_box_autoadd_miner_id(deserializer);
+    var var_policy = sse_decode_String(deserializer);
+    var var_drifts = sse_decode_list_drift(deserializer);
+    var var_detectedAt = sse_decode_u_64(deserializer);
+    return DriftReport(
+      ip: var_ip,
+      minerId: var_minerId,
+      policy: var_policy,
+      drifts: var_drifts,
+      detectedAt: var_detectedAt,
+    );
+  }
+
+  @protected
+  ErrorKind sse_decode_error_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ErrorKind_AuthFailed();
+      case 1:
+        return ErrorKind_Unreachable();
+      case 2:
+        return ErrorKind_Timeout();
+      case 3:
+        return ErrorKind_Unsupported();
+      case 4:
+        var var_code = sse_decode_String(deserializer);
+        return ErrorKind_RejectedByMiner(code: var_code);
+      case 5:
+        return ErrorKind_ParseFailed();
+      case 6:
+        return ErrorKind_PartialSuccess();
+      case 7:
+        return ErrorKind_Other();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FanMode sse_decode_fan_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return FanMode_Auto();
+      case 1:
+        var var_percent = sse_decode_u_32(deserializer);
+        return FanMode_Fixed(percent: var_percent);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AuthFailure> sse_decode_list_auth_failure(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AuthFailure>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_auth_failure(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BackupResult> sse_decode_list_backup_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BackupResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_backup_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CommandAttempt> sse_decode_list_command_attempt(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CommandAttempt>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_command_attempt(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CommandResult> sse_decode_list_command_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CommandResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_command_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConfigChange> sse_decode_list_config_change(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConfigChange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_config_change(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CredentialSet> sse_decode_list_credential_set(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CredentialSet>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_credential_set(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DesiredState> sse_decode_list_desired_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DesiredState>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_desired_state(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Drift> sse_decode_list_drift(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Drift>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_drift(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DriftReport> sse_decode_list_drift_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DriftReport>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_drift_report(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ErrorKind> sse_decode_list_error_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ErrorKind>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_error_kind(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LocalInterface> sse_decode_list_local_interface(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LocalInterface>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_local_interface(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Miner> sse_decode_list_miner(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Miner>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_miner(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MinerLocation> sse_decode_list_miner_location(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MinerLocation>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_miner_location(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MinerTags> sse_decode_list_miner_tags(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MinerTags>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_miner_tags(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NamedRange> sse_decode_list_named_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NamedRange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_named_range(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<double?> sse_decode_list_opt_box_autoadd_f_64(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <double?>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_opt_box_autoadd_f_64(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int?> sse_decode_list_opt_box_autoadd_u_32(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <int?>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_opt_box_autoadd_u_32(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OutletLink> sse_decode_list_outlet_link(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OutletLink>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_outlet_link(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PoolConfig> sse_decode_list_pool_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PoolConfig>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pool_config(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PoolDiff> sse_decode_list_pool_diff(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PoolDiff>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pool_diff(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PoolSlotChange> sse_decode_list_pool_slot_change(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PoolSlotChange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pool_slot_change(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PoolTemplate> sse_decode_list_pool_template(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PoolTemplate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pool_template(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint16List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<RotationResult> sse_decode_list_rotation_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RotationResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rotation_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SnapshotInfo> sse_decode_list_snapshot_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SnapshotInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_snapshot_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WorkerPreview> sse_decode_list_worker_preview(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WorkerPreview>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_worker_preview(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LocalInterface sse_decode_local_interface(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_ip = sse_decode_String(deserializer);
+    var var_range = sse_decode_String(deserializer);
+    return LocalInterface(name: var_name, ip: var_ip, range: var_range);
+  }
+
+  @protected
+  Miner sse_decode_miner(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_opt_box_autoadd_miner_id(deserializer);
+    var var_ip = sse_decode_String(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_status = sse_decode_miner_status(deserializer);
+    var var_stats = sse_decode_miner_stats(deserializer);
+    var var_lastUpdated = sse_decode_u_64(deserializer);
+    var var_vendorHint = sse_decode_opt_String(deserializer);
+    var var_outlet = sse_decode_opt_box_autoadd_pdu_outlet(deserializer);
+    return Miner(
+      id: var_id,
+      ip: var_ip,
+      model: var_model,
+      status: var_status,
+      stats: var_stats,
+      lastUpdated: var_lastUpdated,
+      vendorHint: var_vendorHint,
+      outlet: var_outlet,
+    );
+  }
+
+  @protected
+  MinerCommand sse_decode_miner_command(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return MinerCommand_Reboot();
+      case 1:
+        return MinerCommand_BlinkLed();
+      case 2:
+        return MinerCommand_StopBlink();
+      case 3:
+        var var_pools = sse_decode_list_pool_config(deserializer);
+        return MinerCommand_SetPools(pools: var_pools);
+      case 4:
+        var var_pools = sse_decode_opt_list_pool_config(deserializer);
+        var var_powerMode = sse_decode_opt_box_autoadd_power_mode(deserializer);
+        var var_fanMode = sse_decode_opt_box_autoadd_fan_mode(deserializer);
+        var var_freqLevel = sse_decode_opt_box_autoadd_u_32(deserializer);
+        return MinerCommand_ApplyConfig(
+          pools: var_pools,
+          powerMode: var_powerMode,
+          fanMode: var_fanMode,
+          freqLevel: var_freqLevel,
+        );
+      case 5:
+        var var_snapshotId = sse_decode_String(deserializer);
+        return MinerCommand_RestoreConfig(snapshotId: var_snapshotId);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  MinerCredentials sse_decode_miner_credentials(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_username = sse_decode_String(deserializer);
+    var var_password = sse_decode_String(deserializer);
+    return MinerCredentials(username: var_username, password: var_password);
+  }
+
+  @protected
+  MinerId sse_decode_miner_id(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    return MinerId(field0: var_field0);
+  }
+
+  @protected
+  MinerLocation sse_decode_miner_location(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_minerId = sse_decode_miner_id(deserializer);
+    var var_rack = sse_decode_String(deserializer);
+    var var_position = sse_decode_String(deserializer);
+    return MinerLocation(
+      minerId: var_minerId,
+      rack: var_rack,
+      position: var_position,
+    );
+  }
+
+  @protected
+  MinerStats sse_decode_miner_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hashrateRt = sse_decode_f_64(deserializer);
+    var var_hashrateAvg = sse_decode_f_64(deserializer);
+    var var_tempOutletMin = sse_decode_list_opt_box_autoadd_f_64(deserializer);
+    var var_tempOutletMax = sse_decode_list_opt_box_autoadd_f_64(deserializer);
+    var var_tempInletMin = sse_decode_list_opt_box_autoadd_f_64(deserializer);
+    var var_tempInletMax = sse_decode_list_opt_box_autoadd_f_64(deserializer);
+    var var_fanSpeeds = sse_decode_list_opt_box_autoadd_u_32(deserializer);
+    var var_uptime = sse_decode_u_64(deserializer);
+    var var_pool1 = sse_decode_opt_String(deserializer);
+    var var_worker1 = sse_decode_opt_String(deserializer);
+    var var_pool2 = sse_decode_opt_String(deserializer);
+    var var_worker2 = sse_decode_opt_String(deserializer);
+    var var_pool3 = sse_decode_opt_String(deserializer);
+    var var_worker3 = sse_decode_opt_String(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_firmware = sse_decode_opt_String(deserializer);
+    var var_software = sse_decode_opt_String(deserializer);
+    var var_hardware = sse_decode_opt_String(deserializer);
+    var var_macAddress = sse_decode_opt_String(deserializer);
+    var var_serialNumber = sse_decode_opt_String(deserializer);
+    var var_hostname = sse_decode_opt_String(deserializer);
+    var var_networkMode = sse_decode_opt_box_autoadd_network_mode(deserializer);
+    var var_netmask = sse_decode_opt_String(deserializer);
+    var var_gateway = sse_decode_opt_String(deserializer);
+    var var_dnsServers = sse_decode_list_String(deserializer);
+    var var_powerMode = sse_decode_opt_box_autoadd_u_8(deserializer);
+    var var_fanMode = sse_decode_opt_box_autoadd_fan_mode(deserializer);
+    var var_freqLevel = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return MinerStats(
+      hashrateRt: var_hashrateRt,
+      hashrateAvg: var_hashrateAvg,
+      tempOutletMin: var_tempOutletMin,
+      tempOutletMax: var_tempOutletMax,
+      tempInletMin: var_tempInletMin,
+      tempInletMax: var_tempInletMax,
+      fanSpeeds: var_fanSpeeds,
+      uptime: var_uptime,
+      pool1: var_pool1,
+      worker1: var_worker1,
+      pool2: var_pool2,
+      worker2: var_worker2,
+      pool3: var_pool3,
+      worker3: var_worker3,
+      model: var_model,
+      firmware: var_firmware,
+      software: var_software,
+      hardware: var_hardware,
+      macAddress: var_macAddress,
+      serialNumber: var_serialNumber,
+      hostname: var_hostname,
+      networkMode: var_networkMode,
+      netmask: var_netmask,
+      gateway: var_gateway,
+      dnsServers: var_dnsServers,
+      powerMode: var_powerMode,
+      fanMode: var_fanMode,
+      freqLevel: var_freqLevel,
+    );
+  }
+
+  @protected
+  MinerStatus sse_decode_miner_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MinerStatus.values[inner];
+  }
+
+  @protected
+  MinerTags sse_decode_miner_tags(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_minerId = sse_decode_miner_id(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    return MinerTags(minerId: var_minerId, tags: var_tags);
+  }
+
+  @protected
+  MinerVendor sse_decode_miner_vendor(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MinerVendor.values[inner];
+  }
+
+  @protected
+  NamedRange sse_decode_named_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_spec = sse_decode_String(deserializer);
+    var var_source = sse_decode_opt_String(deserializer);
+    return NamedRange(name: var_name, spec: var_spec, source: var_source);
+  }
+
+  @protected
+  NetworkMode sse_decode_network_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NetworkMode.values[inner];
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ErrorKind? sse_decode_opt_box_autoadd_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_error_kind(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FanMode? sse_decode_opt_box_autoadd_fan_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_fan_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MinerCredentials? sse_decode_opt_box_autoadd_miner_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_miner_credentials(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MinerId? sse_decode_opt_box_autoadd_miner_id(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_miner_id(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MinerVendor? sse_decode_opt_box_autoadd_miner_vendor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_miner_vendor(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  NetworkMode? sse_decode_opt_box_autoadd_network_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_network_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PduOutlet? sse_decode_opt_box_autoadd_pdu_outlet(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pdu_outlet(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PoolConfig? sse_decode_opt_box_autoadd_pool_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pool_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PowerMode? sse_decode_opt_box_autoadd_power_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_power_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RetryPolicy? sse_decode_opt_box_autoadd_retry_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_retry_policy(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  AppSettings dco_decode_box_autoadd_app_settings(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_app_settings(raw);
-  }
+  SnapshotInfo? sse_decode_opt_box_autoadd_snapshot_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
 
-  @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_snapshot_info(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  MinerCommand dco_decode_box_autoadd_miner_command(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_miner_command(raw);
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  MinerCredentials dco_decode_box_autoadd_miner_credentials(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_miner_credentials(raw);
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_8(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  int dco_decode_box_autoadd_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  List<PoolConfig>? sse_decode_opt_list_pool_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_pool_config(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  CommandResult dco_decode_command_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return CommandResult(
-      ip: dco_decode_String(arr[0]),
-      success: dco_decode_bool(arr[1]),
-      error: dco_decode_opt_String(arr[2]),
-    );
+  OutletLink sse_decode_outlet_link(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_minerId = sse_decode_miner_id(deserializer);
+    var var_outlet = sse_decode_pdu_outlet(deserializer);
+    return OutletLink(minerId: var_minerId, outlet: var_outlet);
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  PasswordPolicy sse_decode_password_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_length = sse_decode_u_32(deserializer);
+        return PasswordPolicy_Generate(length: var_length);
+      case 1:
+        var var_password = sse_decode_String(deserializer);
+        return PasswordPolicy_Fixed(password: var_password);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  PduOutlet sse_decode_pdu_outlet(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pduIp = sse_decode_String(deserializer);
+    var var_outlet = sse_decode_u_32(deserializer);
+    return PduOutlet(pduIp: var_pduIp, outlet: var_outlet);
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  PoolConfig sse_decode_pool_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_worker = sse_decode_String(deserializer);
+    var var_password = sse_decode_String(deserializer);
+    return PoolConfig(url: var_url, worker: var_worker, password: var_password);
   }
 
   @protected
-  List<CommandResult> dco_decode_list_command_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_command_result).toList();
+  PoolDiff sse_decode_pool_diff(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ip = sse_decode_String(deserializer);
+    var var_minerId = sse_decode_opt_box_autoadd_miner_id(deserializer);
+    var var_changes = sse_decode_list_pool_slot_change(deserializer);
+    var var_passwordsCompared = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return PoolDiff(
+      ip: var_ip,
+      minerId: var_minerId,
+      changes: var_changes,
+      passwordsCompared: var_passwordsCompared,
+      error: var_error,
+    );
   }
 
   @protected
-  List<Miner> dco_decode_list_miner(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_miner).toList();
+  PoolSlotChange sse_decode_pool_slot_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_slot = sse_decode_u_32(deserializer);
+    var var_current = sse_decode_opt_box_autoadd_pool_config(deserializer);
+    var var_intended = sse_decode_opt_box_autoadd_pool_config(deserializer);
+    return PoolSlotChange(
+      slot: var_slot,
+      current: var_current,
+      intended: var_intended,
+    );
   }
 
   @protected
-  List<double?> dco_decode_list_opt_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_f_64).toList();
+  PoolTemplate sse_decode_pool_template(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_pools = sse_decode_list_pool_config(deserializer);
+    return PoolTemplate(name: var_name, pools: var_pools);
   }
 
   @protected
-  List<int?> dco_decode_list_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_u_32).toList();
+  PowerMode sse_decode_power_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PowerMode.values[inner];
   }
 
   @protected
-  List<PoolConfig> dco_decode_list_pool_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_pool_config).toList();
+  RetryPolicy sse_decode_retry_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxAttempts = sse_decode_u_32(deserializer);
+    var var_initialBackoffMs = sse_decode_u_64(deserializer);
+    var var_maxBackoffMs = sse_decode_u_64(deserializer);
+    var var_retryOn = sse_decode_list_error_kind(deserializer);
+    var var_minUptimeSecs = sse_decode_u_64(deserializer);
+    return RetryPolicy(
+      maxAttempts: var_maxAttempts,
+      initialBackoffMs: var_initialBackoffMs,
+      maxBackoffMs: var_maxBackoffMs,
+      retryOn: var_retryOn,
+      minUptimeSecs: var_minUptimeSecs,
+    );
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  RotationOutcome sse_decode_rotation_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RotationOutcome.values[inner];
   }
 
   @protected
-  Miner dco_decode_miner(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return Miner(
-      ip: dco_decode_String(arr[0]),
-      model: dco_decode_opt_String(arr[1]),
-      status: dco_decode_miner_status(arr[2]),
-      stats: dco_decode_miner_stats(arr[3]),
-      lastUpdated: dco_decode_u_64(arr[4]),
+  RotationResult sse_decode_rotation_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ip = sse_decode_String(deserializer);
+    var var_minerId = sse_decode_opt_box_autoadd_miner_id(deserializer);
+    var var_outcome = sse_decode_rotation_outcome(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    var var_errorKind = sse_decode_opt_box_autoadd_error_kind(deserializer);
+    return RotationResult(
+      ip: var_ip,
+      minerId: var_minerId,
+      outcome: var_outcome,
+      message: var_message,
+      errorKind: var_errorKind,
     );
   }
 
   @protected
-  MinerCommand dco_decode_miner_command(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
+  ScanEvent sse_decode_scan_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
       case 0:
-        return MinerCommand_Reboot();
+        var var_totalIps = sse_decode_usize(deserializer);
+        return ScanEvent_Started(totalIps: var_totalIps);
       case 1:
-        return MinerCommand_BlinkLed();
+        var var_field0 = sse_decode_miner(deserializer);
+        return ScanEvent_Found(var_field0);
       case 2:
-        return MinerCommand_StopBlink();
+        var var_scanned = sse_decode_usize(deserializer);
+        var var_total = sse_decode_usize(deserializer);
+        return ScanEvent_Progress(scanned: var_scanned, total: var_total);
       case 3:
-        return MinerCommand_SetPools(
-          pools: dco_decode_list_pool_config(raw[1]),
+        var var_found = sse_decode_usize(deserializer);
+        var var_failed = sse_decode_usize(deserializer);
+        return ScanEvent_Complete(found: var_found, failed: var_failed);
+      case 4:
+        var var_scanned = sse_decode_usize(deserializer);
+        var var_found = sse_decode_usize(deserializer);
+        return ScanEvent_Cancelled(scanned: var_scanned, found: var_found);
+      case 5:
+        var var_id = sse_decode_miner_id(deserializer);
+        var var_fromIp = sse_decode_String(deserializer);
+        var var_toIp = sse_decode_String(deserializer);
+        return ScanEvent_MinerMoved(
+          id: var_id,
+          fromIp: var_fromIp,
+          toIp: var_toIp,
+        );
+      case 6:
+        var var_ip = sse_decode_String(deserializer);
+        var var_previous = sse_decode_miner_id(deserializer);
+        var var_current = sse_decode_miner_id(deserializer);
+        return ScanEvent_IpTakenOver(
+          ip: var_ip,
+          previous: var_previous,
+          current: var_current,
+        );
+      case 7:
+        var var_field0 = sse_decode_device(deserializer);
+        return ScanEvent_NonMiner(var_field0);
+      case 8:
+        var var_ip = sse_decode_String(deserializer);
+        var var_macAddresses = sse_decode_list_String(deserializer);
+        var var_serialNumbers = sse_decode_list_String(deserializer);
+        return ScanEvent_DuplicateIp(
+          ip: var_ip,
+          macAddresses: var_macAddresses,
+          serialNumbers: var_serialNumbers,
+        );
+      case 9:
+        var var_ip = sse_decode_String(deserializer);
+        var var_id = sse_decode_opt_box_autoadd_miner_id(deserializer);
+        var var_macAddress = sse_decode_opt_String(deserializer);
+        return ScanEvent_FactoryDefaultIp(
+          ip: var_ip,
+          id: var_id,
+          macAddress: var_macAddress,
+        );
+      case 10:
+        var var_ip = sse_decode_String(deserializer);
+        var var_id = sse_decode_opt_box_autoadd_miner_id(deserializer);
+        var var_netmask = sse_decode_opt_String(deserializer);
+        var var_gateway = sse_decode_opt_String(deserializer);
+        var var_expectedSubnet = sse_decode_opt_String(deserializer);
+        var var_reason = sse_decode_String(deserializer);
+        return ScanEvent_NetworkMismatch(
+          ip: var_ip,
+          id: var_id,
+          netmask: var_netmask,
+          gateway: var_gateway,
+          expectedSubnet: var_expectedSubnet,
+          reason: var_reason,
         );
       default:
-        throw Exception("unreachable");
+        throw UnimplementedError('');
     }
   }
 
   @protected
-  MinerCredentials dco_decode_miner_credentials(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return MinerCredentials(
-      username: dco_decode_String(arr[0]),
-      password: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  MinerStats dco_decode_miner_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 20)
-      throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
-    return MinerStats(
-      hashrateRt: dco_decode_f_64(arr[0]),
-      hashrateAvg: dco_decode_f_64(arr[1]),
-      tempOutletMin: dco_decode_list_opt_box_autoadd_f_64(arr[2]),
-      tempOutletMax: dco_decode_list_opt_box_autoadd_f_64(arr[3]),
-      tempInletMin: dco_decode_list_opt_box_autoadd_f_64(arr[4]),
-      tempInletMax: dco_decode_list_opt_box_autoadd_f_64(arr[5]),
-      fanSpeeds: dco_decode_list_opt_box_autoadd_u_32(arr[6]),
-      uptime: dco_decode_u_64(arr[7]),
-      pool1: dco_decode_opt_String(arr[8]),
-      worker1: dco_decode_opt_String(arr[9]),
-      pool2: dco_decode_opt_String(arr[10]),
-      worker2: dco_decode_opt_String(arr[11]),
-      pool3: dco_decode_opt_String(arr[12]),
-      worker3: dco_decode_opt_String(arr[13]),
-      model: dco_decode_opt_String(arr[14]),
-      firmware: dco_decode_opt_String(arr[15]),
-      software: dco_decode_opt_String(arr[16]),
-      hardware: dco_decode_opt_String(arr[17]),
-      macAddress: dco_decode_opt_String(arr[18]),
-      powerMode: dco_decode_opt_box_autoadd_u_8(arr[19]),
+  SnapshotInfo sse_decode_snapshot_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_minerId = sse_decode_miner_id(deserializer);
+    var var_ip = sse_decode_String(deserializer);
+    var var_vendor = sse_decode_miner_vendor(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_takenAt = sse_decode_u_64(deserializer);
+    var var_note = sse_decode_opt_String(deserializer);
+    return SnapshotInfo(
+      id: var_id,
+      minerId: var_minerId,
+      ip: var_ip,
+      vendor: var_vendor,
+      model: var_model,
+      takenAt: var_takenAt,
+      note: var_note,
     );
   }
 
   @protected
-  MinerStatus dco_decode_miner_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MinerStatus.values[raw as int];
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
-  }
-
-  @protected
-  MinerCredentials? dco_decode_opt_box_autoadd_miner_credentials(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_miner_credentials(raw);
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  PoolConfig dco_decode_pool_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return PoolConfig(
-      url: dco_decode_String(arr[0]),
-      worker: dco_decode_String(arr[1]),
-      password: dco_decode_String(arr[2]),
-    );
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  PowerMode dco_decode_power_mode(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PowerMode.values[raw as int];
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  VaultStatus sse_decode_vault_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_exists = sse_decode_bool(deserializer);
+    var var_unlocked = sse_decode_bool(deserializer);
+    return VaultStatus(exists: var_exists, unlocked: var_unlocked);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  WorkerPreview sse_decode_worker_preview(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ip = sse_decode_String(deserializer);
+    var var_minerId = sse_decode_opt_box_autoadd_miner_id(deserializer);
+    var var_workers = sse_decode_list_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return WorkerPreview(
+      ip: var_ip,
+      minerId: var_minerId,
+      workers: var_workers,
+      error: var_error,
+    );
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_StreamSink_scan_event_Sse(
+    RustStreamSink<ScanEvent> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scan_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  AppSettings sse_decode_app_settings(SseDeserializer deserializer) {
+  void sse_encode_app_settings(AppSettings self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_antminerCredentials = sse_decode_miner_credentials(deserializer);
-    var var_whatsminerCredentials = sse_decode_miner_credentials(deserializer);
-    var var_scanThreadCount = sse_decode_u_32(deserializer);
-    var var_monitorInterval = sse_decode_u_64(deserializer);
-    return AppSettings(
-      antminerCredentials: var_antminerCredentials,
-      whatsminerCredentials: var_whatsminerCredentials,
-      scanThreadCount: var_scanThreadCount,
-      monitorInterval: var_monitorInterval,
-    );
+    sse_encode_miner_credentials(self.antminerCredentials, serializer);
+    sse_encode_miner_credentials(self.whatsminerCredentials, serializer);
+    sse_encode_u_32(self.scanThreadCount, serializer);
+    sse_encode_u_64(self.monitorInterval, serializer);
+    sse_encode_list_named_range(self.namedRanges, serializer);
+    sse_encode_u_64(self.scanTimeoutMs, serializer);
+    sse_encode_list_prim_u_16_strict(self.scanPorts, serializer);
+    sse_encode_bool(self.scanDevices, serializer);
+    sse_encode_String(self.snmpCommunity, serializer);
+    sse_encode_list_outlet_link(self.outletLinks, serializer);
+    sse_encode_list_String(self.factoryDefaultIps, serializer);
+    sse_encode_bool(self.detectIpConflicts, serializer);
+    sse_encode_list_credential_set(self.credentialSets, serializer);
+    sse_encode_list_miner_tags(self.minerTags, serializer);
+    sse_encode_list_miner_location(self.minerLocations, serializer);
+    sse_encode_list_pool_template(self.poolTemplates, serializer);
+    sse_encode_list_desired_state(self.desiredStates, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_auth_failure(AuthFailure self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_String(self.ip, serializer);
+    sse_encode_opt_box_autoadd_miner_id(self.minerId, serializer);
+    sse_encode_u_32(self.tried, serializer);
+    sse_encode_u_64(self.failedAt, serializer);
   }
 
   @protected
-  AppSettings sse_decode_box_autoadd_app_settings(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_backup_result(BackupResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_app_settings(deserializer));
+    sse_encode_String(self.ip, serializer);
+    sse_encode_opt_box_autoadd_miner_id(self.minerId, serializer);
+    sse_encode_opt_box_autoadd_snapshot_info(self.snapshot, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+  void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_64(deserializer));
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  MinerCommand sse_decode_box_autoadd_miner_command(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_app_settings(
+    AppSettings self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_miner_command(deserializer));
+    sse_encode_app_settings(self, serializer);
   }
 
   @protected
-  MinerCredentials sse_decode_box_autoadd_miner_credentials(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_error_kind(
+    ErrorKind self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_miner_credentials(deserializer));
+    sse_encode_error_kind(self, serializer);
   }
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
+    sse_encode_f_64(self, serializer);
   }
 
   @protected
-  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_fan_mode(FanMode self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_8(deserializer));
+    sse_encode_fan_mode(self, serializer);
   }
 
   @protected
-  CommandResult sse_decode_command_result(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_miner_command(
+    MinerCommand self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ip = sse_decode_String(deserializer);
-    var var_success = sse_decode_bool(deserializer);
-    var var_error = sse_decode_opt_String(deserializer);
-    return CommandResult(ip: var_ip, success: var_success, error: var_error);
+    sse_encode_miner_command(self, serializer);
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_miner_credentials(
+    MinerCredentials self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    sse_encode_miner_credentials(self, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_miner_id(MinerId self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_miner_id(self, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_miner_vendor(
+    MinerVendor self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    sse_encode_miner_vendor(self, serializer);
   }
 
   @protected
-  List<CommandResult> sse_decode_list_command_result(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_network_mode(
+    NetworkMode self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <CommandResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_command_result(deserializer));
-    }
-    return ans_;
+    sse_encode_network_mode(self, serializer);
   }
 
   @protected
-  List<Miner> sse_decode_list_miner(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_password_policy(
+    PasswordPolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Miner>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_miner(deserializer));
-    }
-    return ans_;
+    sse_encode_password_policy(self, serializer);
   }
 
   @protected
-  List<double?> sse_decode_list_opt_box_autoadd_f_64(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_pdu_outlet(
+    PduOutlet self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <double?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_f_64(deserializer));
-    }
-    return ans_;
+    sse_encode_pdu_outlet(self, serializer);
   }
 
   @protected
-  List<int?> sse_decode_list_opt_box_autoadd_u_32(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_pool_config(
+    PoolConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <int?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_u_32(deserializer));
-    }
-    return ans_;
+    sse_encode_pool_config(self, serializer);
   }
 
   @protected
-  List<PoolConfig> sse_decode_list_pool_config(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_power_mode(
+    PowerMode self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_power_mode(self, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PoolConfig>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_pool_config(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_box_autoadd_retry_policy(
+    RetryPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_retry_policy(self, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_snapshot_info(
+    SnapshotInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_snapshot_info(self, serializer);
   }
 
   @protected
-  Miner sse_decode_miner(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ip = sse_decode_String(deserializer);
-    var var_model = sse_decode_opt_String(deserializer);
-    var var_status = sse_decode_miner_status(deserializer);
-    var var_stats = sse_decode_miner_stats(deserializer);
-    var var_lastUpdated = sse_decode_u_64(deserializer);
-    return Miner(
-      ip: var_ip,
-      model: var_model,
-      status: var_status,
-      stats: var_stats,
-      lastUpdated: var_lastUpdated,
-    );
+    sse_encode_u_16(self, serializer);
   }
 
   @protected
-  MinerCommand sse_decode_miner_command(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return MinerCommand_Reboot();
-      case 1:
-        return MinerCommand_BlinkLed();
-      case 2:
-        return MinerCommand_StopBlink();
-      case 3:
-        var var_pools = sse_decode_list_pool_config(deserializer);
-        return MinerCommand_SetPools(pools: var_pools);
-      default:
-        throw UnimplementedError('');
-    }
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self, serializer);
   }
 
   @protected
-  MinerCredentials sse_decode_miner_credentials(SseDeserializer deserializer) {
+  void sse_encode_command_attempt(
+    CommandAttempt self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_username = sse_decode_String(deserializer);
-    var var_password = sse_decode_String(deserializer);
-    return MinerCredentials(username: var_username, password: var_password);
+    sse_encode_u_32(self.attempt, serializer);
+    sse_encode_u_64(self.startedAt, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+    sse_encode_opt_String(self.error, serializer);
+    sse_encode_opt_box_autoadd_error_kind(self.errorKind, serializer);
   }
 
   @protected
-  MinerStats sse_decode_miner_stats(SseDeserializer deserializer) {
+  void sse_encode_command_result(CommandResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_hashrateRt = sse_decode_f_64(deserializer);
-    var var_hashrateAvg = sse_decode_f_64(deserializer);
-    var var_tempOutletMin = sse_decode_list_opt_box_autoadd_f_64(deserializer);
-    var var_tempOutletMax = sse_decode_list_opt_box_autoadd_f_64(deserializer);
-    var var_tempInletMin = sse_decode_list_opt_box_autoadd_f_64(deserializer);
-    var var_tempInletMax = sse_decode_list_opt_box_autoadd_f_64(deserializer);
-    var var_fanSpeeds = sse_decode_list_opt_box_autoadd_u_32(deserializer);
-    var var_uptime = sse_decode_u_64(deserializer);
-    var var_pool1 = sse_decode_opt_String(deserializer);
-    var var_worker1 = sse_decode_opt_String(deserializer);
-    var var_pool2 = sse_decode_opt_String(deserializer);
-    var var_worker2 = sse_decode_opt_String(deserializer);
-    var var_pool3 = sse_decode_opt_String(deserializer);
-    var var_worker3 = sse_decode_opt_String(deserializer);
-    var var_model = sse_decode_opt_String(deserializer);
-    var var_firmware = sse_decode_opt_String(deserializer);
-    var var_software = sse_decode_opt_String(deserializer);
-    var var_hardware = sse_decode_opt_String(deserializer);
-    var var_macAddress = sse_decode_opt_String(deserializer);
-    var var_powerMode = sse_decode_opt_box_autoadd_u_8(deserializer);
-    return MinerStats(
-      hashrateRt: var_hashrateRt,
-      hashrateAvg: var_hashrateAvg,
-      tempOutletMin: var_tempOutletMin,
-      tempOutletMax: var_tempOutletMax,
-      tempInletMin: var_tempInletMin,
-      tempInletMax: var_tempInletMax,
-      fanSpeeds: var_fanSpeeds,
-      uptime: var_uptime,
-      pool1: var_pool1,
-      worker1: var_worker1,
-      pool2: var_pool2,
-      worker2: var_worker2,
-      pool3: var_pool3,
-      worker3: var_worker3,
-      model: var_model,
-      firmware: var_firmware,
-      software: var_software,
-      hardware: var_hardware,
-      macAddress: var_macAddress,
-      powerMode: var_powerMode,
-    );
+    sse_encode_String(self.ip, serializer);
+    sse_encode_opt_box_autoadd_miner_id(self.minerId, serializer);
+    sse_encode_bool(self.success, serializer);
+    sse_encode_opt_String(self.error, serializer);
+    sse_encode_opt_box_autoadd_error_kind(self.errorKind, serializer);
+    sse_encode_bool(self.retryable, serializer);
+    sse_encode_opt_String(self.suggestedAction, serializer);
+    sse_encode_list_command_attempt(self.attempts, serializer);
+    sse_encode_opt_String(self.skipped, serializer);
+  }
+
+  @protected
+  void sse_encode_config_change(ConfigChange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_opt_String(self.before, serializer);
+    sse_encode_opt_String(self.after, serializer);
   }
 
   @protected
-  MinerStatus sse_decode_miner_status(SseDeserializer deserializer) {
+  void sse_encode_credential_scope(
+    CredentialScope self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return MinerStatus.values[inner];
+    switch (self) {
+      case CredentialScope_All():
+        sse_encode_i_32(0, serializer);
+      case CredentialScope_Range(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case CredentialScope_Tag(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(field0, serializer);
+      case CredentialScope_Miner(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_miner_id(field0, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_credential_set(CredentialSet self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_credential_scope(self.scope, serializer);
+    sse_encode_opt_box_autoadd_miner_vendor(self.vendor, serializer);
+    sse_encode_i_32(self.priority, serializer);
+    sse_encode_miner_credentials(self.credentials, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_desired_state(DesiredState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_credential_scope(self.scope, serializer);
+    sse_encode_opt_list_pool_config(self.pools, serializer);
+    sse_encode_opt_box_autoadd_power_mode(self.powerMode, serializer);
+    sse_encode_opt_box_autoadd_fan_mode(self.fanMode, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.freqLevel, serializer);
   }
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+  void sse_encode_device(Device self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.ip, serializer);
+    sse_encode_device_kind(self.kind, serializer);
+    sse_encode_opt_String(self.macAddress, serializer);
+    sse_encode_opt_String(self.vendorHint, serializer);
+    sse_encode_opt_String(self.sysName, serializer);
+    sse_encode_opt_String(self.sysDescr, serializer);
+    sse_encode_opt_String(self.httpBanner, serializer);
+    sse_encode_opt_box_autoadd_u_16(self.openPort, serializer);
+    sse_encode_u_64(self.lastUpdated, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_device_kind(DeviceKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  MinerCredentials? sse_decode_opt_box_autoadd_miner_credentials(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_drift(Drift self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_drift_field(self.field, serializer);
+    sse_encode_String(self.expected, serializer);
+    sse_encode_String(self.actual, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_miner_credentials(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_drift_field(DriftField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_drift_report(DriftReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.ip, serializer);
+    sse_encode_opt_box_autoadd_miner_id(self.minerId, serializer);
+    sse_encode_String(self.policy, serializer);
+    sse_encode_list_drift(self.drifts, serializer);
+    sse_encode_u_64(self.detectedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_error_kind(ErrorKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ErrorKind_AuthFailed():
+        sse_encode_i_32(0, serializer);
+      case ErrorKind_Unreachable():
+        sse_encode_i_32(1, serializer);
+      case ErrorKind_Timeout():
+        sse_encode_i_32(2, serializer);
+      case ErrorKind_Unsupported():
+        sse_encode_i_32(3, serializer);
+      case ErrorKind_RejectedByMiner(code: final code):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(code, serializer);
+      case ErrorKind_ParseFailed():
+        sse_encode_i_32(5, serializer);
+      case ErrorKind_PartialSuccess():
+        sse_encode_i_32(6, serializer);
+      case ErrorKind_Other():
+        sse_encode_i_32(7, serializer);
     }
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_fan_mode(FanMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case FanMode_Auto():
+        sse_encode_i_32(0, serializer);
+      case FanMode_Fixed(percent: final percent):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(percent, serializer);
     }
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_8(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
     }
   }
 
   @protected
-  PoolConfig sse_decode_pool_config(SseDeserializer deserializer) {
+  void sse_encode_list_auth_failure(
+    List<AuthFailure> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_url = sse_decode_String(deserializer);
-    var var_worker = sse_decode_String(deserializer);
-    var var_password = sse_decode_String(deserializer);
-    return PoolConfig(url: var_url, worker: var_worker, password: var_password);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_auth_failure(item, serializer);
+    }
   }
 
   @protected
-  PowerMode sse_decode_power_mode(SseDeserializer deserializer) {
+  void sse_encode_list_backup_result(
+    List<BackupResult> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return PowerMode.values[inner];
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_backup_result(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_command_attempt(
+    List<CommandAttempt> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_command_attempt(item, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_command_result(
+    List<CommandResult> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_command_result(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_list_config_change(
+    List<ConfigChange> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_config_change(item, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_list_credential_set(
+    List<CredentialSet> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_credential_set(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_desired_state(
+    List<DesiredState> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_desired_state(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_drift(List<Drift> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_drift(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_app_settings(AppSettings self, SseSerializer serializer) {
+  void sse_encode_list_drift_report(
+    List<DriftReport> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_miner_credentials(self.antminerCredentials, serializer);
-    sse_encode_miner_credentials(self.whatsminerCredentials, serializer);
-    sse_encode_u_32(self.scanThreadCount, serializer);
-    sse_encode_u_64(self.monitorInterval, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_drift_report(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_list_error_kind(
+    List<ErrorKind> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_error_kind(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_app_settings(
-    AppSettings self,
+  void sse_encode_list_local_interface(
+    List<LocalInterface> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_app_settings(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_local_interface(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+  void sse_encode_list_miner(List<Miner> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_miner(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_miner_command(
-    MinerCommand self,
+  void sse_encode_list_miner_location(
+    List<MinerLocation> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_miner_command(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_miner_location(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_miner_credentials(
-    MinerCredentials self,
+  void sse_encode_list_miner_tags(
+    List<MinerTags> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_miner_credentials(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_miner_tags(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+  void sse_encode_list_named_range(
+    List<NamedRange> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_named_range(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
+  void sse_encode_list_opt_box_autoadd_f_64(
+    List<double?> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_8(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_opt_box_autoadd_f_64(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_command_result(CommandResult self, SseSerializer serializer) {
+  void sse_encode_list_opt_box_autoadd_u_32(
+    List<int?> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.ip, serializer);
-    sse_encode_bool(self.success, serializer);
-    sse_encode_opt_String(self.error, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_opt_box_autoadd_u_32(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_list_outlet_link(
+    List<OutletLink> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_outlet_link(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_list_pool_config(
+    List<PoolConfig> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pool_config(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_list_pool_diff(
+    List<PoolDiff> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_String(item, serializer);
+      sse_encode_pool_diff(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_command_result(
-    List<CommandResult> self,
+  void sse_encode_list_pool_slot_change(
+    List<PoolSlotChange> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_command_result(item, serializer);
+      sse_encode_pool_slot_change(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_pool_template(
+    List<PoolTemplate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pool_template(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_miner(List<Miner> self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_16_strict(
+    Uint16List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint16List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_miner(item, serializer);
-    }
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void sse_encode_list_opt_box_autoadd_f_64(
-    List<double?> self,
+  void sse_encode_list_rotation_result(
+    List<RotationResult> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_opt_box_autoadd_f_64(item, serializer);
+      sse_encode_rotation_result(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_opt_box_autoadd_u_32(
-    List<int?> self,
+  void sse_encode_list_snapshot_info(
+    List<SnapshotInfo> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_opt_box_autoadd_u_32(item, serializer);
+      sse_encode_snapshot_info(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_pool_config(
-    List<PoolConfig> self,
+  void sse_encode_list_worker_preview(
+    List<WorkerPreview> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_pool_config(item, serializer);
+      sse_encode_worker_preview(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_local_interface(
+    LocalInterface self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.ip, serializer);
+    sse_encode_String(self.range, serializer);
   }
 
   @protected
   void sse_encode_miner(Miner self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_miner_id(self.id, serializer);
     sse_encode_String(self.ip, serializer);
     sse_encode_opt_String(self.model, serializer);
     sse_encode_miner_status(self.status, serializer);
     sse_encode_miner_stats(self.stats, serializer);
     sse_encode_u_64(self.lastUpdated, serializer);
+    sse_encode_opt_String(self.vendorHint, serializer);
+    sse_encode_opt_box_autoadd_pdu_outlet(self.outlet, serializer);
   }
 
   @protected
@@ -1421,6 +5073,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case MinerCommand_SetPools(pools: final pools):
         sse_encode_i_32(3, serializer);
         sse_encode_list_pool_config(pools, serializer);
+      case MinerCommand_ApplyConfig(
+        pools: final pools,
+        powerMode: final powerMode,
+        fanMode: final fanMode,
+        freqLevel: final freqLevel,
+      ):
+        sse_encode_i_32(4, serializer);
+        sse_encode_opt_list_pool_config(pools, serializer);
+        sse_encode_opt_box_autoadd_power_mode(powerMode, serializer);
+        sse_encode_opt_box_autoadd_fan_mode(fanMode, serializer);
+        sse_encode_opt_box_autoadd_u_32(freqLevel, serializer);
+      case MinerCommand_RestoreConfig(snapshotId: final snapshotId):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(snapshotId, serializer);
     }
   }
 
@@ -1434,6 +5100,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.password, serializer);
   }
 
+  @protected
+  void sse_encode_miner_id(MinerId self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.field0, serializer);
+  }
+
+  @protected
+  void sse_encode_miner_location(MinerLocation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_miner_id(self.minerId, serializer);
+    sse_encode_String(self.rack, serializer);
+    sse_encode_String(self.position, serializer);
+  }
+
   @protected
   void sse_encode_miner_stats(MinerStats self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1456,7 +5136,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.software, serializer);
     sse_encode_opt_String(self.hardware, serializer);
     sse_encode_opt_String(self.macAddress, serializer);
+    sse_encode_opt_String(self.serialNumber, serializer);
+    sse_encode_opt_String(self.hostname, serializer);
+    sse_encode_opt_box_autoadd_network_mode(self.networkMode, serializer);
+    sse_encode_opt_String(self.netmask, serializer);
+    sse_encode_opt_String(self.gateway, serializer);
+    sse_encode_list_String(self.dnsServers, serializer);
     sse_encode_opt_box_autoadd_u_8(self.powerMode, serializer);
+    sse_encode_opt_box_autoadd_fan_mode(self.fanMode, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.freqLevel, serializer);
   }
 
   @protected
@@ -1465,6 +5153,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_miner_tags(MinerTags self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_miner_id(self.minerId, serializer);
+    sse_encode_list_String(self.tags, serializer);
+  }
+
+  @protected
+  void sse_encode_miner_vendor(MinerVendor self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_named_range(NamedRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.spec, serializer);
+    sse_encode_opt_String(self.source, serializer);
+  }
+
+  @protected
+  void sse_encode_network_mode(NetworkMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1475,6 +5190,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_error_kind(
+    ErrorKind? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_error_kind(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1485,6 +5213,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_fan_mode(
+    FanMode? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_fan_mode(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_miner_credentials(
     MinerCredentials? self,
@@ -1498,6 +5239,120 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_miner_id(
+    MinerId? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_miner_id(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_miner_vendor(
+    MinerVendor? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_miner_vendor(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_network_mode(
+    NetworkMode? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_network_mode(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_pdu_outlet(
+    PduOutlet? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_pdu_outlet(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_pool_config(
+    PoolConfig? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_pool_config(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_power_mode(
+    PowerMode? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_power_mode(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_retry_policy(
+    RetryPolicy? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_retry_policy(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_snapshot_info(
+    SnapshotInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_snapshot_info(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1518,6 +5373,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_pool_config(
+    List<PoolConfig>? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_pool_config(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_outlet_link(OutletLink self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_miner_id(self.minerId, serializer);
+    sse_encode_pdu_outlet(self.outlet, serializer);
+  }
+
+  @protected
+  void sse_encode_password_policy(
+    PasswordPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case PasswordPolicy_Generate(length: final length):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_32(length, serializer);
+      case PasswordPolicy_Fixed(password: final password):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(password, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_pdu_outlet(PduOutlet self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.pduIp, serializer);
+    sse_encode_u_32(self.outlet, serializer);
+  }
+
   @protected
   void sse_encode_pool_config(PoolConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1526,12 +5424,169 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.password, serializer);
   }
 
+  @protected
+  void sse_encode_pool_diff(PoolDiff self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.ip, serializer);
+    sse_encode_opt_box_autoadd_miner_id(self.minerId, serializer);
+    sse_encode_list_pool_slot_change(self.changes, serializer);
+    sse_encode_bool(self.passwordsCompared, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_pool_slot_change(
+    PoolSlotChange self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.slot, serializer);
+    sse_encode_opt_box_autoadd_pool_config(self.current, serializer);
+    sse_encode_opt_box_autoadd_pool_config(self.intended, serializer);
+  }
+
+  @protected
+  void sse_encode_pool_template(PoolTemplate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_list_pool_config(self.pools, serializer);
+  }
+
   @protected
   void sse_encode_power_mode(PowerMode self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_retry_policy(RetryPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxAttempts, serializer);
+    sse_encode_u_64(self.initialBackoffMs, serializer);
+    sse_encode_u_64(self.maxBackoffMs, serializer);
+    sse_encode_list_error_kind(self.retryOn, serializer);
+    sse_encode_u_64(self.minUptimeSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_rotation_outcome(
+    RotationOutcome self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_rotation_result(
+    RotationResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.ip, serializer);
+    sse_encode_opt_box_autoadd_miner_id(self.minerId, serializer);
+    sse_encode_rotation_outcome(self.outcome, serializer);
+    sse_encode_opt_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_error_kind(self.errorKind, serializer);
+  }
+
+  @protected
+  void sse_encode_scan_event(ScanEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ScanEvent_Started(totalIps: final totalIps):
+        sse_encode_i_32(0, serializer);
+        sse_encode_usize(totalIps, serializer);
+      case ScanEvent_Found(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_miner(field0, serializer);
+      case ScanEvent_Progress(scanned: final scanned, total: final total):
+        sse_encode_i_32(2, serializer);
+        sse_encode_usize(scanned, serializer);
+        sse_encode_usize(total, serializer);
+      case ScanEvent_Complete(found: final found, failed: final failed):
+        sse_encode_i_32(3, serializer);
+        sse_encode_usize(found, serializer);
+        sse_encode_usize(failed, serializer);
+      case ScanEvent_Cancelled(scanned: final scanned, found: final found):
+        sse_encode_i_32(4, serializer);
+        sse_encode_usize(scanned, serializer);
+        sse_encode_usize(found, serializer);
+      case ScanEvent_MinerMoved(
+        id: final id,
+        fromIp: final fromIp,
+        toIp: final toIp,
+      ):
+        sse_encode_i_32(5, serializer);
+        sse_encode_miner_id(id, serializer);
+        sse_encode_String(fromIp, serializer);
+        sse_encode_String(toIp, serializer);
+      case ScanEvent_IpTakenOver(
+        ip: final ip,
+        previous: final previous,
+        current: final current,
+      ):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(ip, serializer);
+        sse_encode_miner_id(previous, serializer);
+        sse_encode_miner_id(current, serializer);
+      case ScanEvent_NonMiner(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_device(field0, serializer);
+      case ScanEvent_DuplicateIp(
+        ip: final ip,
+        macAddresses: final macAddresses,
+        serialNumbers: final serialNumbers,
+      ):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(ip, serializer);
+        sse_encode_list_String(macAddresses, serializer);
+        sse_encode_list_String(serialNumbers, serializer);
+      case ScanEvent_FactoryDefaultIp(
+        ip: final ip,
+        id: final id,
+        macAddress: final macAddress,
+      ):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(ip, serializer);
+        sse_encode_opt_box_autoadd_miner_id(id, serializer);
+        sse_encode_opt_String(macAddress, serializer);
+      case ScanEvent_NetworkMismatch(
+        ip: final ip,
+        id: final id,
+        netmask: final netmask,
+        gateway: final gateway,
+        expectedSubnet: final expectedSubnet,
+        reason: final reason,
+      ):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(ip, serializer);
+        sse_encode_opt_box_autoadd_miner_id(id, serializer);
+        sse_encode_opt_String(netmask, serializer);
+        sse_encode_opt_String(gateway, serializer);
+        sse_encode_opt_String(expectedSubnet, serializer);
+        sse_encode_String(reason, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_snapshot_info(SnapshotInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_miner_id(self.minerId, serializer);
+    sse_encode_String(self.ip, serializer);
+    sse_encode_miner_vendor(self.vendor, serializer);
+    sse_encode_opt_String(self.model, serializer);
+    sse_encode_u_64(self.takenAt, serializer);
+    sse_encode_opt_String(self.note, serializer);
+  }
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1554,4 +5609,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
+  @protected
+  void sse_encode_vault_status(VaultStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.exists, serializer);
+    sse_encode_bool(self.unlocked, serializer);
+  }
+
+  @protected
+  void sse_encode_worker_preview(WorkerPreview self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.ip, serializer);
+    sse_encode_opt_box_autoadd_miner_id(self.minerId, serializer);
+    sse_encode_list_String(self.workers, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
 }
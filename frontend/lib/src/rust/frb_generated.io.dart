@@ -3,19 +3,34 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/backup.dart';
 import 'api/commands.dart';
+import 'api/credentials.dart';
+import 'api/devices.dart';
 import 'api/models.dart';
 import 'api/monitor.dart';
+import 'api/policy.dart';
+import 'api/pools.dart';
 import 'api/scanner.dart';
 import 'api/settings.dart';
 import 'api/simple.dart';
+import 'api/vault.dart';
+import 'backup.dart';
+import 'client/credentials.dart';
+import 'client/pools.dart';
+import 'client/retry.dart';
+import 'client/rotation.dart';
 import 'core/config.dart';
+import 'core/error.dart';
 import 'core/models.dart';
+import 'core/vault.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
+import 'monitor/drift.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
+import 'scanner.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -28,63 +43,210 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<ScanEvent> dco_decode_StreamSink_scan_event_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
   @protected
   AppSettings dco_decode_app_settings(dynamic raw);
 
+  @protected
+  AuthFailure dco_decode_auth_failure(dynamic raw);
+
+  @protected
+  BackupResult dco_decode_backup_result(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
   @protected
   AppSettings dco_decode_box_autoadd_app_settings(dynamic raw);
 
+  @protected
+  ErrorKind dco_decode_box_autoadd_error_kind(dynamic raw);
+
   @protected
   double dco_decode_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  FanMode dco_decode_box_autoadd_fan_mode(dynamic raw);
+
   @protected
   MinerCommand dco_decode_box_autoadd_miner_command(dynamic raw);
 
   @protected
   MinerCredentials dco_decode_box_autoadd_miner_credentials(dynamic raw);
 
+  @protected
+  MinerId dco_decode_box_autoadd_miner_id(dynamic raw);
+
+  @protected
+  MinerVendor dco_decode_box_autoadd_miner_vendor(dynamic raw);
+
+  @protected
+  NetworkMode dco_decode_box_autoadd_network_mode(dynamic raw);
+
+  @protected
+  PasswordPolicy dco_decode_box_autoadd_password_policy(dynamic raw);
+
+  @protected
+  PduOutlet dco_decode_box_autoadd_pdu_outlet(dynamic raw);
+
+  @protected
+  PoolConfig dco_decode_box_autoadd_pool_config(dynamic raw);
+
+  @protected
+  PowerMode dco_decode_box_autoadd_power_mode(dynamic raw);
+
+  @protected
+  RetryPolicy dco_decode_box_autoadd_retry_policy(dynamic raw);
+
+  @protected
+  SnapshotInfo dco_decode_box_autoadd_snapshot_info(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
   int dco_decode_box_autoadd_u_8(dynamic raw);
 
+  @protected
+  CommandAttempt dco_decode_command_attempt(dynamic raw);
+
   @protected
   CommandResult dco_decode_command_result(dynamic raw);
 
+  @protected
+  ConfigChange dco_decode_config_change(dynamic raw);
+
+  @protected
+  CredentialScope dco_decode_credential_scope(dynamic raw);
+
+  @protected
+  CredentialSet dco_decode_credential_set(dynamic raw);
+
+  @protected
+  DesiredState dco_decode_desired_state(dynamic raw);
+
+  @protected
+  Device dco_decode_device(dynamic raw);
+
+  @protected
+  DeviceKind dco_decode_device_kind(dynamic raw);
+
+  @protected
+  Drift dco_decode_drift(dynamic raw);
+
+  @protected
+  DriftField dco_decode_drift_field(dynamic raw);
+
+  @protected
+  DriftReport dco_decode_drift_report(dynamic raw);
+
+  @protected
+  ErrorKind dco_decode_error_kind(dynamic raw);
+
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  FanMode dco_decode_fan_mode(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AuthFailure> dco_decode_list_auth_failure(dynamic raw);
+
+  @protected
+  List<BackupResult> dco_decode_list_backup_result(dynamic raw);
+
+  @protected
+  List<CommandAttempt> dco_decode_list_command_attempt(dynamic raw);
+
   @protected
   List<CommandResult> dco_decode_list_command_result(dynamic raw);
 
+  @protected
+  List<ConfigChange> dco_decode_list_config_change(dynamic raw);
+
+  @protected
+  List<CredentialSet> dco_decode_list_credential_set(dynamic raw);
+
+  @protected
+  List<DesiredState> dco_decode_list_desired_state(dynamic raw);
+
+  @protected
+  List<Drift> dco_decode_list_drift(dynamic raw);
+
+  @protected
+  List<DriftReport> dco_decode_list_drift_report(dynamic raw);
+
+  @protected
+  List<ErrorKind> dco_decode_list_error_kind(dynamic raw);
+
+  @protected
+  List<LocalInterface> dco_decode_list_local_interface(dynamic raw);
+
   @protected
   List<Miner> dco_decode_list_miner(dynamic raw);
 
+  @protected
+  List<MinerLocation> dco_decode_list_miner_location(dynamic raw);
+
+  @protected
+  List<MinerTags> dco_decode_list_miner_tags(dynamic raw);
+
+  @protected
+  List<NamedRange> dco_decode_list_named_range(dynamic raw);
+
   @protected
   List<double?> dco_decode_list_opt_box_autoadd_f_64(dynamic raw);
 
   @protected
   List<int?> dco_decode_list_opt_box_autoadd_u_32(dynamic raw);
 
+  @protected
+  List<OutletLink> dco_decode_list_outlet_link(dynamic raw);
+
   @protected
   List<PoolConfig> dco_decode_list_pool_config(dynamic raw);
 
+  @protected
+  List<PoolDiff> dco_decode_list_pool_diff(dynamic raw);
+
+  @protected
+  List<PoolSlotChange> dco_decode_list_pool_slot_change(dynamic raw);
+
+  @protected
+  List<PoolTemplate> dco_decode_list_pool_template(dynamic raw);
+
+  @protected
+  Uint16List dco_decode_list_prim_u_16_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<RotationResult> dco_decode_list_rotation_result(dynamic raw);
+
+  @protected
+  List<SnapshotInfo> dco_decode_list_snapshot_info(dynamic raw);
+
+  @protected
+  List<WorkerPreview> dco_decode_list_worker_preview(dynamic raw);
+
+  @protected
+  LocalInterface dco_decode_local_interface(dynamic raw);
+
   @protected
   Miner dco_decode_miner(dynamic raw);
 
@@ -94,6 +256,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   MinerCredentials dco_decode_miner_credentials(dynamic raw);
 
+  @protected
+  MinerId dco_decode_miner_id(dynamic raw);
+
+  @protected
+  MinerLocation dco_decode_miner_location(dynamic raw);
+
   @protected
   MinerStats dco_decode_miner_stats(dynamic raw);
 
@@ -101,296 +269,1070 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   MinerStatus dco_decode_miner_status(dynamic raw);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  MinerTags dco_decode_miner_tags(dynamic raw);
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+  MinerVendor dco_decode_miner_vendor(dynamic raw);
 
   @protected
-  MinerCredentials? dco_decode_opt_box_autoadd_miner_credentials(dynamic raw);
+  NamedRange dco_decode_named_range(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  NetworkMode dco_decode_network_mode(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+  String? dco_decode_opt_String(dynamic raw);
 
   @protected
-  PoolConfig dco_decode_pool_config(dynamic raw);
+  ErrorKind? dco_decode_opt_box_autoadd_error_kind(dynamic raw);
 
   @protected
-  PowerMode dco_decode_power_mode(dynamic raw);
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  FanMode? dco_decode_opt_box_autoadd_fan_mode(dynamic raw);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  MinerCredentials? dco_decode_opt_box_autoadd_miner_credentials(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  MinerId? dco_decode_opt_box_autoadd_miner_id(dynamic raw);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  MinerVendor? dco_decode_opt_box_autoadd_miner_vendor(dynamic raw);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  NetworkMode? dco_decode_opt_box_autoadd_network_mode(dynamic raw);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  PduOutlet? dco_decode_opt_box_autoadd_pdu_outlet(dynamic raw);
 
   @protected
-  AppSettings sse_decode_app_settings(SseDeserializer deserializer);
+  PoolConfig? dco_decode_opt_box_autoadd_pool_config(dynamic raw);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  PowerMode? dco_decode_opt_box_autoadd_power_mode(dynamic raw);
 
   @protected
-  AppSettings sse_decode_box_autoadd_app_settings(SseDeserializer deserializer);
+  RetryPolicy? dco_decode_opt_box_autoadd_retry_policy(dynamic raw);
 
   @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+  SnapshotInfo? dco_decode_opt_box_autoadd_snapshot_info(dynamic raw);
 
   @protected
-  MinerCommand sse_decode_box_autoadd_miner_command(
-    SseDeserializer deserializer,
-  );
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
 
   @protected
-  MinerCredentials sse_decode_box_autoadd_miner_credentials(
-    SseDeserializer deserializer,
-  );
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+  List<PoolConfig>? dco_decode_opt_list_pool_config(dynamic raw);
 
   @protected
-  CommandResult sse_decode_command_result(SseDeserializer deserializer);
+  OutletLink dco_decode_outlet_link(dynamic raw);
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  PasswordPolicy dco_decode_password_policy(dynamic raw);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  PduOutlet dco_decode_pdu_outlet(dynamic raw);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  PoolConfig dco_decode_pool_config(dynamic raw);
 
   @protected
-  List<CommandResult> sse_decode_list_command_result(
-    SseDeserializer deserializer,
-  );
+  PoolDiff dco_decode_pool_diff(dynamic raw);
 
   @protected
-  List<Miner> sse_decode_list_miner(SseDeserializer deserializer);
+  PoolSlotChange dco_decode_pool_slot_change(dynamic raw);
 
   @protected
-  List<double?> sse_decode_list_opt_box_autoadd_f_64(
-    SseDeserializer deserializer,
-  );
+  PoolTemplate dco_decode_pool_template(dynamic raw);
 
   @protected
-  List<int?> sse_decode_list_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  PowerMode dco_decode_power_mode(dynamic raw);
 
   @protected
-  List<PoolConfig> sse_decode_list_pool_config(SseDeserializer deserializer);
+  RetryPolicy dco_decode_retry_policy(dynamic raw);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  RotationOutcome dco_decode_rotation_outcome(dynamic raw);
 
   @protected
-  Miner sse_decode_miner(SseDeserializer deserializer);
+  RotationResult dco_decode_rotation_result(dynamic raw);
 
   @protected
-  MinerCommand sse_decode_miner_command(SseDeserializer deserializer);
+  ScanEvent dco_decode_scan_event(dynamic raw);
 
   @protected
-  MinerCredentials sse_decode_miner_credentials(SseDeserializer deserializer);
+  SnapshotInfo dco_decode_snapshot_info(dynamic raw);
 
   @protected
-  MinerStats sse_decode_miner_stats(SseDeserializer deserializer);
+  int dco_decode_u_16(dynamic raw);
 
   @protected
-  MinerStatus sse_decode_miner_status(SseDeserializer deserializer);
+  int dco_decode_u_32(dynamic raw);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  BigInt dco_decode_u_64(dynamic raw);
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+  int dco_decode_u_8(dynamic raw);
 
   @protected
-  MinerCredentials? sse_decode_opt_box_autoadd_miner_credentials(
-    SseDeserializer deserializer,
-  );
+  void dco_decode_unit(dynamic raw);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  BigInt dco_decode_usize(dynamic raw);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+  VaultStatus dco_decode_vault_status(dynamic raw);
 
   @protected
-  PoolConfig sse_decode_pool_config(SseDeserializer deserializer);
+  WorkerPreview dco_decode_worker_preview(dynamic raw);
 
   @protected
-  PowerMode sse_decode_power_mode(SseDeserializer deserializer);
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  RustStreamSink<ScanEvent> sse_decode_StreamSink_scan_event_Sse(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  String sse_decode_String(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  AppSettings sse_decode_app_settings(SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  AuthFailure sse_decode_auth_failure(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
+  BackupResult sse_decode_backup_result(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_app_settings(AppSettings self, SseSerializer serializer);
+  AppSettings sse_decode_box_autoadd_app_settings(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  ErrorKind sse_decode_box_autoadd_error_kind(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_box_autoadd_app_settings(
-    AppSettings self,
-    SseSerializer serializer,
-  );
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+  FanMode sse_decode_box_autoadd_fan_mode(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_box_autoadd_miner_command(
-    MinerCommand self,
-    SseSerializer serializer,
+  MinerCommand sse_decode_box_autoadd_miner_command(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_miner_credentials(
-    MinerCredentials self,
-    SseSerializer serializer,
+  MinerCredentials sse_decode_box_autoadd_miner_credentials(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  MinerId sse_decode_box_autoadd_miner_id(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+  MinerVendor sse_decode_box_autoadd_miner_vendor(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_command_result(CommandResult self, SseSerializer serializer);
+  NetworkMode sse_decode_box_autoadd_network_mode(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  PasswordPolicy sse_decode_box_autoadd_password_policy(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  PduOutlet sse_decode_box_autoadd_pdu_outlet(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  PoolConfig sse_decode_box_autoadd_pool_config(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_list_command_result(
-    List<CommandResult> self,
-    SseSerializer serializer,
-  );
+  PowerMode sse_decode_box_autoadd_power_mode(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_list_miner(List<Miner> self, SseSerializer serializer);
+  RetryPolicy sse_decode_box_autoadd_retry_policy(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_list_opt_box_autoadd_f_64(
-    List<double?> self,
-    SseSerializer serializer,
+  SnapshotInfo sse_decode_box_autoadd_snapshot_info(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_opt_box_autoadd_u_32(
-    List<int?> self,
-    SseSerializer serializer,
-  );
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_list_pool_config(
-    List<PoolConfig> self,
-    SseSerializer serializer,
-  );
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_miner(Miner self, SseSerializer serializer);
+  CommandAttempt sse_decode_command_attempt(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_miner_command(MinerCommand self, SseSerializer serializer);
+  CommandResult sse_decode_command_result(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_miner_credentials(
-    MinerCredentials self,
-    SseSerializer serializer,
-  );
+  ConfigChange sse_decode_config_change(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_miner_stats(MinerStats self, SseSerializer serializer);
+  CredentialScope sse_decode_credential_scope(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_miner_status(MinerStatus self, SseSerializer serializer);
+  CredentialSet sse_decode_credential_set(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  DesiredState sse_decode_desired_state(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+  Device sse_decode_device(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_miner_credentials(
-    MinerCredentials? self,
-    SseSerializer serializer,
-  );
+  DeviceKind sse_decode_device_kind(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+  Drift sse_decode_drift(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+  DriftField sse_decode_drift_field(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_pool_config(PoolConfig self, SseSerializer serializer);
+  DriftReport sse_decode_drift_report(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_power_mode(PowerMode self, SseSerializer serializer);
+  ErrorKind sse_decode_error_kind(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+  double sse_decode_f_64(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+  FanMode sse_decode_fan_mode(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  int sse_decode_i_32(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AuthFailure> sse_decode_list_auth_failure(SseDeserializer deserializer);
+
+  @protected
+  List<BackupResult> sse_decode_list_backup_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CommandAttempt> sse_decode_list_command_attempt(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CommandResult> sse_decode_list_command_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ConfigChange> sse_decode_list_config_change(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CredentialSet> sse_decode_list_credential_set(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DesiredState> sse_decode_list_desired_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Drift> sse_decode_list_drift(SseDeserializer deserializer);
+
+  @protected
+  List<DriftReport> sse_decode_list_drift_report(SseDeserializer deserializer);
+
+  @protected
+  List<ErrorKind> sse_decode_list_error_kind(SseDeserializer deserializer);
+
+  @protected
+  List<LocalInterface> sse_decode_list_local_interface(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Miner> sse_decode_list_miner(SseDeserializer deserializer);
+
+  @protected
+  List<MinerLocation> sse_decode_list_miner_location(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MinerTags> sse_decode_list_miner_tags(SseDeserializer deserializer);
+
+  @protected
+  List<NamedRange> sse_decode_list_named_range(SseDeserializer deserializer);
+
+  @protected
+  List<double?> sse_decode_list_opt_box_autoadd_f_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<int?> sse_decode_list_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  List<OutletLink> sse_decode_list_outlet_link(SseDeserializer deserializer);
+
+  @protected
+  List<PoolConfig> sse_decode_list_pool_config(SseDeserializer deserializer);
+
+  @protected
+  List<PoolDiff> sse_decode_list_pool_diff(SseDeserializer deserializer);
+
+  @protected
+  List<PoolSlotChange> sse_decode_list_pool_slot_change(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PoolTemplate> sse_decode_list_pool_template(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<RotationResult> sse_decode_list_rotation_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SnapshotInfo> sse_decode_list_snapshot_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WorkerPreview> sse_decode_list_worker_preview(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LocalInterface sse_decode_local_interface(SseDeserializer deserializer);
+
+  @protected
+  Miner sse_decode_miner(SseDeserializer deserializer);
+
+  @protected
+  MinerCommand sse_decode_miner_command(SseDeserializer deserializer);
+
+  @protected
+  MinerCredentials sse_decode_miner_credentials(SseDeserializer deserializer);
+
+  @protected
+  MinerId sse_decode_miner_id(SseDeserializer deserializer);
+
+  @protected
+  MinerLocation sse_decode_miner_location(SseDeserializer deserializer);
+
+  @protected
+  MinerStats sse_decode_miner_stats(SseDeserializer deserializer);
+
+  @protected
+  MinerStatus sse_decode_miner_status(SseDeserializer deserializer);
+
+  @protected
+  MinerTags sse_decode_miner_tags(SseDeserializer deserializer);
+
+  @protected
+  MinerVendor sse_decode_miner_vendor(SseDeserializer deserializer);
+
+  @protected
+  NamedRange sse_decode_named_range(SseDeserializer deserializer);
+
+  @protected
+  NetworkMode sse_decode_network_mode(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  ErrorKind? sse_decode_opt_box_autoadd_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FanMode? sse_decode_opt_box_autoadd_fan_mode(SseDeserializer deserializer);
+
+  @protected
+  MinerCredentials? sse_decode_opt_box_autoadd_miner_credentials(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MinerId? sse_decode_opt_box_autoadd_miner_id(SseDeserializer deserializer);
+
+  @protected
+  MinerVendor? sse_decode_opt_box_autoadd_miner_vendor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NetworkMode? sse_decode_opt_box_autoadd_network_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PduOutlet? sse_decode_opt_box_autoadd_pdu_outlet(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PoolConfig? sse_decode_opt_box_autoadd_pool_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PowerMode? sse_decode_opt_box_autoadd_power_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RetryPolicy? sse_decode_opt_box_autoadd_retry_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SnapshotInfo? sse_decode_opt_box_autoadd_snapshot_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+  @protected
+  List<PoolConfig>? sse_decode_opt_list_pool_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutletLink sse_decode_outlet_link(SseDeserializer deserializer);
+
+  @protected
+  PasswordPolicy sse_decode_password_policy(SseDeserializer deserializer);
+
+  @protected
+  PduOutlet sse_decode_pdu_outlet(SseDeserializer deserializer);
+
+  @protected
+  PoolConfig sse_decode_pool_config(SseDeserializer deserializer);
+
+  @protected
+  PoolDiff sse_decode_pool_diff(SseDeserializer deserializer);
+
+  @protected
+  PoolSlotChange sse_decode_pool_slot_change(SseDeserializer deserializer);
+
+  @protected
+  PoolTemplate sse_decode_pool_template(SseDeserializer deserializer);
+
+  @protected
+  PowerMode sse_decode_power_mode(SseDeserializer deserializer);
+
+  @protected
+  RetryPolicy sse_decode_retry_policy(SseDeserializer deserializer);
+
+  @protected
+  RotationOutcome sse_decode_rotation_outcome(SseDeserializer deserializer);
+
+  @protected
+  RotationResult sse_decode_rotation_result(SseDeserializer deserializer);
+
+  @protected
+  ScanEvent sse_decode_scan_event(SseDeserializer deserializer);
+
+  @protected
+  SnapshotInfo sse_decode_snapshot_info(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  VaultStatus sse_decode_vault_status(SseDeserializer deserializer);
+
+  @protected
+  WorkerPreview sse_decode_worker_preview(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_scan_event_Sse(
+    RustStreamSink<ScanEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_app_settings(AppSettings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_auth_failure(AuthFailure self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_result(BackupResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_app_settings(
+    AppSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_error_kind(
+    ErrorKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_fan_mode(FanMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_miner_command(
+    MinerCommand self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_miner_credentials(
+    MinerCredentials self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_miner_id(MinerId self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_miner_vendor(
+    MinerVendor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_network_mode(
+    NetworkMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_password_policy(
+    PasswordPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_pdu_outlet(
+    PduOutlet self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_pool_config(
+    PoolConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_power_mode(
+    PowerMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_retry_policy(
+    RetryPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_snapshot_info(
+    SnapshotInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_command_attempt(
+    CommandAttempt self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_command_result(CommandResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_config_change(ConfigChange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_credential_scope(
+    CredentialScope self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_credential_set(CredentialSet self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_desired_state(DesiredState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_device(Device self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_device_kind(DeviceKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_drift(Drift self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_drift_field(DriftField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_drift_report(DriftReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_error_kind(ErrorKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fan_mode(FanMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_auth_failure(
+    List<AuthFailure> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_backup_result(
+    List<BackupResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_command_attempt(
+    List<CommandAttempt> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_command_result(
+    List<CommandResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_config_change(
+    List<ConfigChange> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_credential_set(
+    List<CredentialSet> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_desired_state(
+    List<DesiredState> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_drift(List<Drift> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_drift_report(
+    List<DriftReport> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_error_kind(
+    List<ErrorKind> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_local_interface(
+    List<LocalInterface> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_miner(List<Miner> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_miner_location(
+    List<MinerLocation> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_miner_tags(
+    List<MinerTags> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_named_range(
+    List<NamedRange> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_opt_box_autoadd_f_64(
+    List<double?> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_opt_box_autoadd_u_32(
+    List<int?> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_outlet_link(
+    List<OutletLink> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_pool_config(
+    List<PoolConfig> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_pool_diff(List<PoolDiff> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pool_slot_change(
+    List<PoolSlotChange> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_pool_template(
+    List<PoolTemplate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_16_strict(
+    Uint16List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_rotation_result(
+    List<RotationResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_snapshot_info(
+    List<SnapshotInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_worker_preview(
+    List<WorkerPreview> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_local_interface(
+    LocalInterface self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_miner(Miner self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_miner_command(MinerCommand self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_miner_credentials(
+    MinerCredentials self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_miner_id(MinerId self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_miner_location(MinerLocation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_miner_stats(MinerStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_miner_status(MinerStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_miner_tags(MinerTags self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_miner_vendor(MinerVendor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_named_range(NamedRange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_network_mode(NetworkMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_error_kind(
+    ErrorKind? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_fan_mode(
+    FanMode? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_miner_credentials(
+    MinerCredentials? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_miner_id(
+    MinerId? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_miner_vendor(
+    MinerVendor? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_network_mode(
+    NetworkMode? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_pdu_outlet(
+    PduOutlet? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_pool_config(
+    PoolConfig? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_power_mode(
+    PowerMode? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_retry_policy(
+    RetryPolicy? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_snapshot_info(
+    SnapshotInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_pool_config(
+    List<PoolConfig>? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_outlet_link(OutletLink self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_password_policy(
+    PasswordPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_pdu_outlet(PduOutlet self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pool_config(PoolConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pool_diff(PoolDiff self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pool_slot_change(
+    PoolSlotChange self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_pool_template(PoolTemplate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_power_mode(PowerMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_retry_policy(RetryPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rotation_outcome(
+    RotationOutcome self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_rotation_result(
+    RotationResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_scan_event(ScanEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_snapshot_info(SnapshotInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_vault_status(VaultStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_worker_preview(WorkerPreview self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -0,0 +1,76 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../core/models.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// One setting that differs from the policy, both sides formatted for display
+class Drift {
+  final DriftField field;
+  final String expected;
+  final String actual;
+
+  const Drift({
+    required this.field,
+    required this.expected,
+    required this.actual,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ expected.hashCode ^ actual.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Drift &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          expected == other.expected &&
+          actual == other.actual;
+}
+
+/// A setting a policy can pin
+enum DriftField { pools, powerMode, fanMode, freqLevel }
+
+/// Drift of one miner from its policy
+class DriftReport {
+  final String ip;
+  final MinerId? minerId;
+
+  /// Name of the `DesiredState` the miner was compared with
+  final String policy;
+  final List<Drift> drifts;
+
+  /// Unix timestamp of the poll that found it
+  final BigInt detectedAt;
+
+  const DriftReport({
+    required this.ip,
+    this.minerId,
+    required this.policy,
+    required this.drifts,
+    required this.detectedAt,
+  });
+
+  @override
+  int get hashCode =>
+      ip.hashCode ^
+      minerId.hashCode ^
+      policy.hashCode ^
+      drifts.hashCode ^
+      detectedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DriftReport &&
+          runtimeType == other.runtimeType &&
+          ip == other.ip &&
+          minerId == other.minerId &&
+          policy == other.policy &&
+          drifts == other.drifts &&
+          detectedAt == other.detectedAt;
+}
@@ -0,0 +1,76 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'core/models.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'scanner.freezed.dart';
+
+@freezed
+sealed class ScanEvent with _$ScanEvent {
+  const ScanEvent._();
+
+  /// Scan has started
+  const factory ScanEvent.started({required BigInt totalIps}) =
+      ScanEvent_Started;
+  /// Found a miner
+  const factory ScanEvent.found(Miner field0) = ScanEvent_Found;
+  /// Progress update
+  const factory ScanEvent.progress({
+    required BigInt scanned,
+    required BigInt total,
+  }) = ScanEvent_Progress;
+  /// Scan completed
+  const factory ScanEvent.complete({
+    required BigInt found,
+    required BigInt failed,
+  }) = ScanEvent_Complete;
+  /// Scan was stopped through its `ScanHandle` before finishing
+  const factory ScanEvent.cancelled({
+    required BigInt scanned,
+    required BigInt found,
+  }) = ScanEvent_Cancelled;
+  /// A known miner was found at a different address than last time
+  const factory ScanEvent.minerMoved({
+    required MinerId id,
+    required String fromIp,
+    required String toIp,
+  }) = ScanEvent_MinerMoved;
+  /// A different device now answers at an address that belonged to `previous`
+  const factory ScanEvent.ipTakenOver({
+    required String ip,
+    required MinerId previous,
+    required MinerId current,
+  }) = ScanEvent_IpTakenOver;
+  /// Something answered that is not a miner: its MAC belongs to a
+  /// non-miner vendor, it didn't speak the CGMiner API on an open port, or
+  /// (with `scan_devices`) it answered the SNMP probe
+  const factory ScanEvent.nonMiner(Device field0) = ScanEvent_NonMiner;
+  /// `ip` answered as more than one device during the scan: two devices
+  /// share the address.  Lists every MAC and serial seen there.
+  const factory ScanEvent.duplicateIp({
+    required String ip,
+    required List<String> macAddresses,
+    required List<String> serialNumbers,
+  }) = ScanEvent_DuplicateIp;
+  /// A miner sits on one of the factory-default addresses in the settings
+  const factory ScanEvent.factoryDefaultIp({
+    required String ip,
+    MinerId? id,
+    String? macAddress,
+  }) = ScanEvent_FactoryDefaultIp;
+  /// The miner's configured netmask or gateway doesn't fit the subnet it
+  /// was found in.  `expected_subnet` is the local subnet it is on, or for
+  /// a routed miner the scanned CIDR block it was found in, if any.
+  const factory ScanEvent.networkMismatch({
+    required String ip,
+    MinerId? id,
+    String? netmask,
+    String? gateway,
+    String? expectedSubnet,
+    required String reason,
+  }) = ScanEvent_NetworkMismatch;
+}
@@ -0,0 +1,3449 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'scanner.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+  'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models',
+);
+
+/// @nodoc
+mixin _$ScanEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ScanEventCopyWith<$Res> {
+  factory $ScanEventCopyWith(
+    ScanEvent value,
+    $Res Function(ScanEvent) then,
+  ) = _$ScanEventCopyWithImpl<$Res, ScanEvent>;
+}
+
+/// @nodoc
+class _$ScanEventCopyWithImpl<$Res, $Val extends ScanEvent>
+    implements $ScanEventCopyWith<$Res> {
+  _$ScanEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_StartedImplCopyWith<$Res> {
+  factory _$$ScanEvent_StartedImplCopyWith(
+    _$ScanEvent_StartedImpl value,
+    $Res Function(_$ScanEvent_StartedImpl) then,
+  ) = __$$ScanEvent_StartedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt totalIps});
+}
+
+/// @nodoc
+class __$$ScanEvent_StartedImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_StartedImpl>
+    implements _$$ScanEvent_StartedImplCopyWith<$Res> {
+  __$$ScanEvent_StartedImplCopyWithImpl(
+    _$ScanEvent_StartedImpl _value,
+    $Res Function(_$ScanEvent_StartedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? totalIps = null}) {
+    return _then(
+      _$ScanEvent_StartedImpl(
+        totalIps: null == totalIps
+            ? _value.totalIps
+            : totalIps // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_StartedImpl extends ScanEvent_Started {
+  const _$ScanEvent_StartedImpl({required this.totalIps}) : super._();
+
+  @override
+  final BigInt totalIps;
+
+  @override
+  String toString() {
+    return 'ScanEvent.started(totalIps: $totalIps)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_StartedImpl &&
+            (identical(other.totalIps, totalIps) ||
+                other.totalIps == totalIps));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, totalIps);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_StartedImplCopyWith<_$ScanEvent_StartedImpl> get copyWith =>
+      __$$ScanEvent_StartedImplCopyWithImpl<_$ScanEvent_StartedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return started(totalIps);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return started?.call(totalIps);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (started != null) {
+      return started(totalIps);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return started(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return started?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (started != null) {
+      return started(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_Started extends ScanEvent {
+  const factory ScanEvent_Started({required final BigInt totalIps}) =
+      _$ScanEvent_StartedImpl;
+  const ScanEvent_Started._() : super._();
+
+  BigInt get totalIps;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_StartedImplCopyWith<_$ScanEvent_StartedImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_FoundImplCopyWith<$Res> {
+  factory _$$ScanEvent_FoundImplCopyWith(
+    _$ScanEvent_FoundImpl value,
+    $Res Function(_$ScanEvent_FoundImpl) then,
+  ) = __$$ScanEvent_FoundImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Miner field0});
+}
+
+/// @nodoc
+class __$$ScanEvent_FoundImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_FoundImpl>
+    implements _$$ScanEvent_FoundImplCopyWith<$Res> {
+  __$$ScanEvent_FoundImplCopyWithImpl(
+    _$ScanEvent_FoundImpl _value,
+    $Res Function(_$ScanEvent_FoundImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$ScanEvent_FoundImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as Miner,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_FoundImpl extends ScanEvent_Found {
+  const _$ScanEvent_FoundImpl(this.field0) : super._();
+
+  @override
+  final Miner field0;
+
+  @override
+  String toString() {
+    return 'ScanEvent.found(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_FoundImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_FoundImplCopyWith<_$ScanEvent_FoundImpl> get copyWith =>
+      __$$ScanEvent_FoundImplCopyWithImpl<_$ScanEvent_FoundImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return found(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return found?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (found != null) {
+      return found(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return found(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return found?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (found != null) {
+      return found(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_Found extends ScanEvent {
+  const factory ScanEvent_Found(final Miner field0) = _$ScanEvent_FoundImpl;
+  const ScanEvent_Found._() : super._();
+
+  Miner get field0;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_FoundImplCopyWith<_$ScanEvent_FoundImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_ProgressImplCopyWith<$Res> {
+  factory _$$ScanEvent_ProgressImplCopyWith(
+    _$ScanEvent_ProgressImpl value,
+    $Res Function(_$ScanEvent_ProgressImpl) then,
+  ) = __$$ScanEvent_ProgressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt scanned, BigInt total});
+}
+
+/// @nodoc
+class __$$ScanEvent_ProgressImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_ProgressImpl>
+    implements _$$ScanEvent_ProgressImplCopyWith<$Res> {
+  __$$ScanEvent_ProgressImplCopyWithImpl(
+    _$ScanEvent_ProgressImpl _value,
+    $Res Function(_$ScanEvent_ProgressImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? scanned = null, Object? total = null}) {
+    return _then(
+      _$ScanEvent_ProgressImpl(
+        scanned: null == scanned
+            ? _value.scanned
+            : scanned // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        total: null == total
+            ? _value.total
+            : total // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_ProgressImpl extends ScanEvent_Progress {
+  const _$ScanEvent_ProgressImpl({required this.scanned, required this.total})
+    : super._();
+
+  @override
+  final BigInt scanned;
+  @override
+  final BigInt total;
+
+  @override
+  String toString() {
+    return 'ScanEvent.progress(scanned: $scanned, total: $total)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_ProgressImpl &&
+            (identical(other.scanned, scanned) || other.scanned == scanned) &&
+            (identical(other.total, total) || other.total == total));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, scanned, total);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_ProgressImplCopyWith<_$ScanEvent_ProgressImpl> get copyWith =>
+      __$$ScanEvent_ProgressImplCopyWithImpl<_$ScanEvent_ProgressImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return progress(scanned, total);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return progress?.call(scanned, total);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (progress != null) {
+      return progress(scanned, total);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return progress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return progress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (progress != null) {
+      return progress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_Progress extends ScanEvent {
+  const factory ScanEvent_Progress({
+    required final BigInt scanned,
+    required final BigInt total,
+  }) = _$ScanEvent_ProgressImpl;
+  const ScanEvent_Progress._() : super._();
+
+  BigInt get scanned;
+  BigInt get total;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_ProgressImplCopyWith<_$ScanEvent_ProgressImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_CompleteImplCopyWith<$Res> {
+  factory _$$ScanEvent_CompleteImplCopyWith(
+    _$ScanEvent_CompleteImpl value,
+    $Res Function(_$ScanEvent_CompleteImpl) then,
+  ) = __$$ScanEvent_CompleteImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt found, BigInt failed});
+}
+
+/// @nodoc
+class __$$ScanEvent_CompleteImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_CompleteImpl>
+    implements _$$ScanEvent_CompleteImplCopyWith<$Res> {
+  __$$ScanEvent_CompleteImplCopyWithImpl(
+    _$ScanEvent_CompleteImpl _value,
+    $Res Function(_$ScanEvent_CompleteImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? found = null, Object? failed = null}) {
+    return _then(
+      _$ScanEvent_CompleteImpl(
+        found: null == found
+            ? _value.found
+            : found // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        failed: null == failed
+            ? _value.failed
+            : failed // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_CompleteImpl extends ScanEvent_Complete {
+  const _$ScanEvent_CompleteImpl({required this.found, required this.failed})
+    : super._();
+
+  @override
+  final BigInt found;
+  @override
+  final BigInt failed;
+
+  @override
+  String toString() {
+    return 'ScanEvent.complete(found: $found, failed: $failed)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_CompleteImpl &&
+            (identical(other.found, found) || other.found == found) &&
+            (identical(other.failed, failed) || other.failed == failed));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, found, failed);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_CompleteImplCopyWith<_$ScanEvent_CompleteImpl> get copyWith =>
+      __$$ScanEvent_CompleteImplCopyWithImpl<_$ScanEvent_CompleteImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return complete(found, failed);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return complete?.call(found, failed);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (complete != null) {
+      return complete(found, failed);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return complete(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return complete?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (complete != null) {
+      return complete(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_Complete extends ScanEvent {
+  const factory ScanEvent_Complete({
+    required final BigInt found,
+    required final BigInt failed,
+  }) = _$ScanEvent_CompleteImpl;
+  const ScanEvent_Complete._() : super._();
+
+  BigInt get found;
+  BigInt get failed;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_CompleteImplCopyWith<_$ScanEvent_CompleteImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_CancelledImplCopyWith<$Res> {
+  factory _$$ScanEvent_CancelledImplCopyWith(
+    _$ScanEvent_CancelledImpl value,
+    $Res Function(_$ScanEvent_CancelledImpl) then,
+  ) = __$$ScanEvent_CancelledImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt scanned, BigInt found});
+}
+
+/// @nodoc
+class __$$ScanEvent_CancelledImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_CancelledImpl>
+    implements _$$ScanEvent_CancelledImplCopyWith<$Res> {
+  __$$ScanEvent_CancelledImplCopyWithImpl(
+    _$ScanEvent_CancelledImpl _value,
+    $Res Function(_$ScanEvent_CancelledImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? scanned = null, Object? found = null}) {
+    return _then(
+      _$ScanEvent_CancelledImpl(
+        scanned: null == scanned
+            ? _value.scanned
+            : scanned // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+        found: null == found
+            ? _value.found
+            : found // ignore: cast_nullable_to_non_nullable
+                  as BigInt,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_CancelledImpl extends ScanEvent_Cancelled {
+  const _$ScanEvent_CancelledImpl({required this.scanned, required this.found})
+    : super._();
+
+  @override
+  final BigInt scanned;
+  @override
+  final BigInt found;
+
+  @override
+  String toString() {
+    return 'ScanEvent.cancelled(scanned: $scanned, found: $found)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_CancelledImpl &&
+            (identical(other.scanned, scanned) || other.scanned == scanned) &&
+            (identical(other.found, found) || other.found == found));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, scanned, found);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_CancelledImplCopyWith<_$ScanEvent_CancelledImpl> get copyWith =>
+      __$$ScanEvent_CancelledImplCopyWithImpl<_$ScanEvent_CancelledImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return cancelled(scanned, found);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return cancelled?.call(scanned, found);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(scanned, found);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return cancelled(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return cancelled?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_Cancelled extends ScanEvent {
+  const factory ScanEvent_Cancelled({
+    required final BigInt scanned,
+    required final BigInt found,
+  }) = _$ScanEvent_CancelledImpl;
+  const ScanEvent_Cancelled._() : super._();
+
+  BigInt get scanned;
+  BigInt get found;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_CancelledImplCopyWith<_$ScanEvent_CancelledImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_MinerMovedImplCopyWith<$Res> {
+  factory _$$ScanEvent_MinerMovedImplCopyWith(
+    _$ScanEvent_MinerMovedImpl value,
+    $Res Function(_$ScanEvent_MinerMovedImpl) then,
+  ) = __$$ScanEvent_MinerMovedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({MinerId id, String fromIp, String toIp});
+}
+
+/// @nodoc
+class __$$ScanEvent_MinerMovedImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_MinerMovedImpl>
+    implements _$$ScanEvent_MinerMovedImplCopyWith<$Res> {
+  __$$ScanEvent_MinerMovedImplCopyWithImpl(
+    _$ScanEvent_MinerMovedImpl _value,
+    $Res Function(_$ScanEvent_MinerMovedImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? id = null, Object? fromIp = null, Object? toIp = null}) {
+    return _then(
+      _$ScanEvent_MinerMovedImpl(
+        id: null == id
+            ? _value.id
+            : id // ignore: cast_nullable_to_non_nullable
+                  as MinerId,
+        fromIp: null == fromIp
+            ? _value.fromIp
+            : fromIp // ignore: cast_nullable_to_non_nullable
+                  as String,
+        toIp: null == toIp
+            ? _value.toIp
+            : toIp // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_MinerMovedImpl extends ScanEvent_MinerMoved {
+  const _$ScanEvent_MinerMovedImpl({
+    required this.id,
+    required this.fromIp,
+    required this.toIp,
+  }) : super._();
+
+  @override
+  final MinerId id;
+  @override
+  final String fromIp;
+  @override
+  final String toIp;
+
+  @override
+  String toString() {
+    return 'ScanEvent.minerMoved(id: $id, fromIp: $fromIp, toIp: $toIp)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_MinerMovedImpl &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.fromIp, fromIp) || other.fromIp == fromIp) &&
+            (identical(other.toIp, toIp) || other.toIp == toIp));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, id, fromIp, toIp);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_MinerMovedImplCopyWith<_$ScanEvent_MinerMovedImpl>
+  get copyWith =>
+      __$$ScanEvent_MinerMovedImplCopyWithImpl<_$ScanEvent_MinerMovedImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return minerMoved(id, fromIp, toIp);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return minerMoved?.call(id, fromIp, toIp);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (minerMoved != null) {
+      return minerMoved(id, fromIp, toIp);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return minerMoved(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return minerMoved?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (minerMoved != null) {
+      return minerMoved(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_MinerMoved extends ScanEvent {
+  const factory ScanEvent_MinerMoved({
+    required final MinerId id,
+    required final String fromIp,
+    required final String toIp,
+  }) = _$ScanEvent_MinerMovedImpl;
+  const ScanEvent_MinerMoved._() : super._();
+
+  MinerId get id;
+  String get fromIp;
+  String get toIp;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_MinerMovedImplCopyWith<_$ScanEvent_MinerMovedImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_IpTakenOverImplCopyWith<$Res> {
+  factory _$$ScanEvent_IpTakenOverImplCopyWith(
+    _$ScanEvent_IpTakenOverImpl value,
+    $Res Function(_$ScanEvent_IpTakenOverImpl) then,
+  ) = __$$ScanEvent_IpTakenOverImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String ip, MinerId previous, MinerId current});
+}
+
+/// @nodoc
+class __$$ScanEvent_IpTakenOverImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_IpTakenOverImpl>
+    implements _$$ScanEvent_IpTakenOverImplCopyWith<$Res> {
+  __$$ScanEvent_IpTakenOverImplCopyWithImpl(
+    _$ScanEvent_IpTakenOverImpl _value,
+    $Res Function(_$ScanEvent_IpTakenOverImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? ip = null,
+    Object? previous = null,
+    Object? current = null,
+  }) {
+    return _then(
+      _$ScanEvent_IpTakenOverImpl(
+        ip: null == ip
+            ? _value.ip
+            : ip // ignore: cast_nullable_to_non_nullable
+                  as String,
+        previous: null == previous
+            ? _value.previous
+            : previous // ignore: cast_nullable_to_non_nullable
+                  as MinerId,
+        current: null == current
+            ? _value.current
+            : current // ignore: cast_nullable_to_non_nullable
+                  as MinerId,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_IpTakenOverImpl extends ScanEvent_IpTakenOver {
+  const _$ScanEvent_IpTakenOverImpl({
+    required this.ip,
+    required this.previous,
+    required this.current,
+  }) : super._();
+
+  @override
+  final String ip;
+  @override
+  final MinerId previous;
+  @override
+  final MinerId current;
+
+  @override
+  String toString() {
+    return 'ScanEvent.ipTakenOver(ip: $ip, previous: $previous, current: $current)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_IpTakenOverImpl &&
+            (identical(other.ip, ip) || other.ip == ip) &&
+            (identical(other.previous, previous) ||
+                other.previous == previous) &&
+            (identical(other.current, current) || other.current == current));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, ip, previous, current);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_IpTakenOverImplCopyWith<_$ScanEvent_IpTakenOverImpl>
+  get copyWith =>
+      __$$ScanEvent_IpTakenOverImplCopyWithImpl<_$ScanEvent_IpTakenOverImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return ipTakenOver(ip, previous, current);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return ipTakenOver?.call(ip, previous, current);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (ipTakenOver != null) {
+      return ipTakenOver(ip, previous, current);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return ipTakenOver(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return ipTakenOver?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (ipTakenOver != null) {
+      return ipTakenOver(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_IpTakenOver extends ScanEvent {
+  const factory ScanEvent_IpTakenOver({
+    required final String ip,
+    required final MinerId previous,
+    required final MinerId current,
+  }) = _$ScanEvent_IpTakenOverImpl;
+  const ScanEvent_IpTakenOver._() : super._();
+
+  String get ip;
+  MinerId get previous;
+  MinerId get current;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_IpTakenOverImplCopyWith<_$ScanEvent_IpTakenOverImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_NonMinerImplCopyWith<$Res> {
+  factory _$$ScanEvent_NonMinerImplCopyWith(
+    _$ScanEvent_NonMinerImpl value,
+    $Res Function(_$ScanEvent_NonMinerImpl) then,
+  ) = __$$ScanEvent_NonMinerImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Device field0});
+}
+
+/// @nodoc
+class __$$ScanEvent_NonMinerImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_NonMinerImpl>
+    implements _$$ScanEvent_NonMinerImplCopyWith<$Res> {
+  __$$ScanEvent_NonMinerImplCopyWithImpl(
+    _$ScanEvent_NonMinerImpl _value,
+    $Res Function(_$ScanEvent_NonMinerImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({Object? field0 = null}) {
+    return _then(
+      _$ScanEvent_NonMinerImpl(
+        null == field0
+            ? _value.field0
+            : field0 // ignore: cast_nullable_to_non_nullable
+                  as Device,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_NonMinerImpl extends ScanEvent_NonMiner {
+  const _$ScanEvent_NonMinerImpl(this.field0) : super._();
+
+  @override
+  final Device field0;
+
+  @override
+  String toString() {
+    return 'ScanEvent.nonMiner(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_NonMinerImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_NonMinerImplCopyWith<_$ScanEvent_NonMinerImpl> get copyWith =>
+      __$$ScanEvent_NonMinerImplCopyWithImpl<_$ScanEvent_NonMinerImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return nonMiner(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return nonMiner?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (nonMiner != null) {
+      return nonMiner(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return nonMiner(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return nonMiner?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (nonMiner != null) {
+      return nonMiner(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_NonMiner extends ScanEvent {
+  const factory ScanEvent_NonMiner(final Device field0) =
+      _$ScanEvent_NonMinerImpl;
+  const ScanEvent_NonMiner._() : super._();
+
+  Device get field0;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_NonMinerImplCopyWith<_$ScanEvent_NonMinerImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_DuplicateIpImplCopyWith<$Res> {
+  factory _$$ScanEvent_DuplicateIpImplCopyWith(
+    _$ScanEvent_DuplicateIpImpl value,
+    $Res Function(_$ScanEvent_DuplicateIpImpl) then,
+  ) = __$$ScanEvent_DuplicateIpImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String ip, List<String> macAddresses, List<String> serialNumbers});
+}
+
+/// @nodoc
+class __$$ScanEvent_DuplicateIpImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_DuplicateIpImpl>
+    implements _$$ScanEvent_DuplicateIpImplCopyWith<$Res> {
+  __$$ScanEvent_DuplicateIpImplCopyWithImpl(
+    _$ScanEvent_DuplicateIpImpl _value,
+    $Res Function(_$ScanEvent_DuplicateIpImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? ip = null,
+    Object? macAddresses = null,
+    Object? serialNumbers = null,
+  }) {
+    return _then(
+      _$ScanEvent_DuplicateIpImpl(
+        ip: null == ip
+            ? _value.ip
+            : ip // ignore: cast_nullable_to_non_nullable
+                  as String,
+        macAddresses: null == macAddresses
+            ? _value._macAddresses
+            : macAddresses // ignore: cast_nullable_to_non_nullable
+                  as List<String>,
+        serialNumbers: null == serialNumbers
+            ? _value._serialNumbers
+            : serialNumbers // ignore: cast_nullable_to_non_nullable
+                  as List<String>,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_DuplicateIpImpl extends ScanEvent_DuplicateIp {
+  const _$ScanEvent_DuplicateIpImpl({
+    required this.ip,
+    required final List<String> macAddresses,
+    required final List<String> serialNumbers,
+  }) : _macAddresses = macAddresses,
+       _serialNumbers = serialNumbers,
+       super._();
+
+  @override
+  final String ip;
+  final List<String> _macAddresses;
+  @override
+  List<String> get macAddresses {
+    if (_macAddresses is EqualUnmodifiableListView) return _macAddresses;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_macAddresses);
+  }
+
+  final List<String> _serialNumbers;
+  @override
+  List<String> get serialNumbers {
+    if (_serialNumbers is EqualUnmodifiableListView) return _serialNumbers;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_serialNumbers);
+  }
+
+  @override
+  String toString() {
+    return 'ScanEvent.duplicateIp(ip: $ip, macAddresses: $macAddresses, serialNumbers: $serialNumbers)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_DuplicateIpImpl &&
+            (identical(other.ip, ip) || other.ip == ip) &&
+            const DeepCollectionEquality().equals(
+              other._macAddresses,
+              _macAddresses,
+            ) &&
+            const DeepCollectionEquality().equals(
+              other._serialNumbers,
+              _serialNumbers,
+            ));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    ip,
+    const DeepCollectionEquality().hash(_macAddresses),
+    const DeepCollectionEquality().hash(_serialNumbers),
+  );
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_DuplicateIpImplCopyWith<_$ScanEvent_DuplicateIpImpl>
+  get copyWith =>
+      __$$ScanEvent_DuplicateIpImplCopyWithImpl<_$ScanEvent_DuplicateIpImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return duplicateIp(ip, macAddresses, serialNumbers);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return duplicateIp?.call(ip, macAddresses, serialNumbers);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (duplicateIp != null) {
+      return duplicateIp(ip, macAddresses, serialNumbers);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return duplicateIp(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return duplicateIp?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (duplicateIp != null) {
+      return duplicateIp(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_DuplicateIp extends ScanEvent {
+  const factory ScanEvent_DuplicateIp({
+    required final String ip,
+    required final List<String> macAddresses,
+    required final List<String> serialNumbers,
+  }) = _$ScanEvent_DuplicateIpImpl;
+  const ScanEvent_DuplicateIp._() : super._();
+
+  String get ip;
+  List<String> get macAddresses;
+  List<String> get serialNumbers;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_DuplicateIpImplCopyWith<_$ScanEvent_DuplicateIpImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_FactoryDefaultIpImplCopyWith<$Res> {
+  factory _$$ScanEvent_FactoryDefaultIpImplCopyWith(
+    _$ScanEvent_FactoryDefaultIpImpl value,
+    $Res Function(_$ScanEvent_FactoryDefaultIpImpl) then,
+  ) = __$$ScanEvent_FactoryDefaultIpImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String ip, MinerId? id, String? macAddress});
+}
+
+/// @nodoc
+class __$$ScanEvent_FactoryDefaultIpImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_FactoryDefaultIpImpl>
+    implements _$$ScanEvent_FactoryDefaultIpImplCopyWith<$Res> {
+  __$$ScanEvent_FactoryDefaultIpImplCopyWithImpl(
+    _$ScanEvent_FactoryDefaultIpImpl _value,
+    $Res Function(_$ScanEvent_FactoryDefaultIpImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? ip = null,
+    Object? id = freezed,
+    Object? macAddress = freezed,
+  }) {
+    return _then(
+      _$ScanEvent_FactoryDefaultIpImpl(
+        ip: null == ip
+            ? _value.ip
+            : ip // ignore: cast_nullable_to_non_nullable
+                  as String,
+        id: freezed == id
+            ? _value.id
+            : id // ignore: cast_nullable_to_non_nullable
+                  as MinerId?,
+        macAddress: freezed == macAddress
+            ? _value.macAddress
+            : macAddress // ignore: cast_nullable_to_non_nullable
+                  as String?,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_FactoryDefaultIpImpl extends ScanEvent_FactoryDefaultIp {
+  const _$ScanEvent_FactoryDefaultIpImpl({
+    required this.ip,
+    this.id,
+    this.macAddress,
+  }) : super._();
+
+  @override
+  final String ip;
+  @override
+  final MinerId? id;
+  @override
+  final String? macAddress;
+
+  @override
+  String toString() {
+    return 'ScanEvent.factoryDefaultIp(ip: $ip, id: $id, macAddress: $macAddress)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_FactoryDefaultIpImpl &&
+            (identical(other.ip, ip) || other.ip == ip) &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.macAddress, macAddress) ||
+                other.macAddress == macAddress));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, ip, id, macAddress);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_FactoryDefaultIpImplCopyWith<_$ScanEvent_FactoryDefaultIpImpl>
+  get copyWith =>
+      __$$ScanEvent_FactoryDefaultIpImplCopyWithImpl<_$ScanEvent_FactoryDefaultIpImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return factoryDefaultIp(ip, id, macAddress);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return factoryDefaultIp?.call(ip, id, macAddress);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (factoryDefaultIp != null) {
+      return factoryDefaultIp(ip, id, macAddress);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return factoryDefaultIp(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return factoryDefaultIp?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (factoryDefaultIp != null) {
+      return factoryDefaultIp(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_FactoryDefaultIp extends ScanEvent {
+  const factory ScanEvent_FactoryDefaultIp({
+    required final String ip,
+    final MinerId? id,
+    final String? macAddress,
+  }) = _$ScanEvent_FactoryDefaultIpImpl;
+  const ScanEvent_FactoryDefaultIp._() : super._();
+
+  String get ip;
+  MinerId? get id;
+  String? get macAddress;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_FactoryDefaultIpImplCopyWith<_$ScanEvent_FactoryDefaultIpImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ScanEvent_NetworkMismatchImplCopyWith<$Res> {
+  factory _$$ScanEvent_NetworkMismatchImplCopyWith(
+    _$ScanEvent_NetworkMismatchImpl value,
+    $Res Function(_$ScanEvent_NetworkMismatchImpl) then,
+  ) = __$$ScanEvent_NetworkMismatchImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({
+    String ip,
+    MinerId? id,
+    String? netmask,
+    String? gateway,
+    String? expectedSubnet,
+    String reason,
+  });
+}
+
+/// @nodoc
+class __$$ScanEvent_NetworkMismatchImplCopyWithImpl<$Res>
+    extends _$ScanEventCopyWithImpl<$Res, _$ScanEvent_NetworkMismatchImpl>
+    implements _$$ScanEvent_NetworkMismatchImplCopyWith<$Res> {
+  __$$ScanEvent_NetworkMismatchImplCopyWithImpl(
+    _$ScanEvent_NetworkMismatchImpl _value,
+    $Res Function(_$ScanEvent_NetworkMismatchImpl) _then,
+  ) : super(_value, _then);
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? ip = null,
+    Object? id = freezed,
+    Object? netmask = freezed,
+    Object? gateway = freezed,
+    Object? expectedSubnet = freezed,
+    Object? reason = null,
+  }) {
+    return _then(
+      _$ScanEvent_NetworkMismatchImpl(
+        ip: null == ip
+            ? _value.ip
+            : ip // ignore: cast_nullable_to_non_nullable
+                  as String,
+        id: freezed == id
+            ? _value.id
+            : id // ignore: cast_nullable_to_non_nullable
+                  as MinerId?,
+        netmask: freezed == netmask
+            ? _value.netmask
+            : netmask // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        gateway: freezed == gateway
+            ? _value.gateway
+            : gateway // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        expectedSubnet: freezed == expectedSubnet
+            ? _value.expectedSubnet
+            : expectedSubnet // ignore: cast_nullable_to_non_nullable
+                  as String?,
+        reason: null == reason
+            ? _value.reason
+            : reason // ignore: cast_nullable_to_non_nullable
+                  as String,
+      ),
+    );
+  }
+}
+
+/// @nodoc
+
+class _$ScanEvent_NetworkMismatchImpl extends ScanEvent_NetworkMismatch {
+  const _$ScanEvent_NetworkMismatchImpl({
+    required this.ip,
+    this.id,
+    this.netmask,
+    this.gateway,
+    this.expectedSubnet,
+    required this.reason,
+  }) : super._();
+
+  @override
+  final String ip;
+  @override
+  final MinerId? id;
+  @override
+  final String? netmask;
+  @override
+  final String? gateway;
+  @override
+  final String? expectedSubnet;
+  @override
+  final String reason;
+
+  @override
+  String toString() {
+    return 'ScanEvent.networkMismatch(ip: $ip, id: $id, netmask: $netmask, gateway: $gateway, expectedSubnet: $expectedSubnet, reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ScanEvent_NetworkMismatchImpl &&
+            (identical(other.ip, ip) || other.ip == ip) &&
+            (identical(other.id, id) || other.id == id) &&
+            (identical(other.netmask, netmask) || other.netmask == netmask) &&
+            (identical(other.gateway, gateway) || other.gateway == gateway) &&
+            (identical(other.expectedSubnet, expectedSubnet) ||
+                other.expectedSubnet == expectedSubnet) &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    runtimeType,
+    ip,
+    id,
+    netmask,
+    gateway,
+    expectedSubnet,
+    reason,
+  );
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ScanEvent_NetworkMismatchImplCopyWith<_$ScanEvent_NetworkMismatchImpl>
+  get copyWith =>
+      __$$ScanEvent_NetworkMismatchImplCopyWithImpl<_$ScanEvent_NetworkMismatchImpl>(
+        this,
+        _$identity,
+      );
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt totalIps) started,
+    required TResult Function(Miner field0) found,
+    required TResult Function(BigInt scanned, BigInt total) progress,
+    required TResult Function(BigInt found, BigInt failed) complete,
+    required TResult Function(BigInt scanned, BigInt found) cancelled,
+    required TResult Function(
+      MinerId id,
+      String fromIp,
+      String toIp,
+    )
+    minerMoved,
+    required TResult Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )
+    ipTakenOver,
+    required TResult Function(Device field0) nonMiner,
+    required TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )
+    duplicateIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )
+    factoryDefaultIp,
+    required TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )
+    networkMismatch,
+  }) {
+    return networkMismatch(ip, id, netmask, gateway, expectedSubnet, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt totalIps)? started,
+    TResult? Function(Miner field0)? found,
+    TResult? Function(BigInt scanned, BigInt total)? progress,
+    TResult? Function(BigInt found, BigInt failed)? complete,
+    TResult? Function(BigInt scanned, BigInt found)? cancelled,
+    TResult? Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult? Function(
+      String ip,
+      MinerId previous,
+      MinerId current,
+    )?
+    ipTakenOver,
+    TResult? Function(Device field0)? nonMiner,
+    TResult? Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult? Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+  }) {
+    return networkMismatch?.call(
+      ip, id, netmask, gateway, expectedSubnet, reason,
+    );
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt totalIps)? started,
+    TResult Function(Miner field0)? found,
+    TResult Function(BigInt scanned, BigInt total)? progress,
+    TResult Function(BigInt found, BigInt failed)? complete,
+    TResult Function(BigInt scanned, BigInt found)? cancelled,
+    TResult Function(MinerId id, String fromIp, String toIp)? minerMoved,
+    TResult Function(String ip, MinerId previous, MinerId current)? ipTakenOver,
+    TResult Function(Device field0)? nonMiner,
+    TResult Function(
+      String ip,
+      List<String> macAddresses,
+      List<String> serialNumbers,
+    )?
+    duplicateIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? macAddress,
+    )?
+    factoryDefaultIp,
+    TResult Function(
+      String ip,
+      MinerId? id,
+      String? netmask,
+      String? gateway,
+      String? expectedSubnet,
+      String reason,
+    )?
+    networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (networkMismatch != null) {
+      return networkMismatch(ip, id, netmask, gateway, expectedSubnet, reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ScanEvent_Started value) started,
+    required TResult Function(ScanEvent_Found value) found,
+    required TResult Function(ScanEvent_Progress value) progress,
+    required TResult Function(ScanEvent_Complete value) complete,
+    required TResult Function(ScanEvent_Cancelled value) cancelled,
+    required TResult Function(ScanEvent_MinerMoved value) minerMoved,
+    required TResult Function(ScanEvent_IpTakenOver value) ipTakenOver,
+    required TResult Function(ScanEvent_NonMiner value) nonMiner,
+    required TResult Function(ScanEvent_DuplicateIp value) duplicateIp,
+    required TResult Function(
+      ScanEvent_FactoryDefaultIp value,
+    )
+    factoryDefaultIp,
+    required TResult Function(ScanEvent_NetworkMismatch value) networkMismatch,
+  }) {
+    return networkMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ScanEvent_Started value)? started,
+    TResult? Function(ScanEvent_Found value)? found,
+    TResult? Function(ScanEvent_Progress value)? progress,
+    TResult? Function(ScanEvent_Complete value)? complete,
+    TResult? Function(ScanEvent_Cancelled value)? cancelled,
+    TResult? Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult? Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult? Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult? Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult? Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult? Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+  }) {
+    return networkMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ScanEvent_Started value)? started,
+    TResult Function(ScanEvent_Found value)? found,
+    TResult Function(ScanEvent_Progress value)? progress,
+    TResult Function(ScanEvent_Complete value)? complete,
+    TResult Function(ScanEvent_Cancelled value)? cancelled,
+    TResult Function(ScanEvent_MinerMoved value)? minerMoved,
+    TResult Function(ScanEvent_IpTakenOver value)? ipTakenOver,
+    TResult Function(ScanEvent_NonMiner value)? nonMiner,
+    TResult Function(ScanEvent_DuplicateIp value)? duplicateIp,
+    TResult Function(ScanEvent_FactoryDefaultIp value)? factoryDefaultIp,
+    TResult Function(ScanEvent_NetworkMismatch value)? networkMismatch,
+    required TResult orElse(),
+  }) {
+    if (networkMismatch != null) {
+      return networkMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ScanEvent_NetworkMismatch extends ScanEvent {
+  const factory ScanEvent_NetworkMismatch({
+    required final String ip,
+    final MinerId? id,
+    final String? netmask,
+    final String? gateway,
+    final String? expectedSubnet,
+    required final String reason,
+  }) = _$ScanEvent_NetworkMismatchImpl;
+  const ScanEvent_NetworkMismatch._() : super._();
+
+  String get ip;
+  MinerId? get id;
+  String? get netmask;
+  String? get gateway;
+  String? get expectedSubnet;
+  String get reason;
+
+  /// Create a copy of ScanEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ScanEvent_NetworkMismatchImplCopyWith<_$ScanEvent_NetworkMismatchImpl>
+  get copyWith => throw _privateConstructorUsedError;
+}
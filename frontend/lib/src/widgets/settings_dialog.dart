@@ -22,6 +22,9 @@ class _SettingsDialogState extends State<SettingsDialog> with SingleTickerProvid
   bool _obscureWhatsminerPass = true;
 
   bool _isLoading = true;
+
+  // Settings as loaded, so saving keeps the fields this dialog doesn't edit
+  AppSettings? _settings;
   
   // General settings
   double _scanThreadCount = 32;
@@ -54,6 +57,7 @@ class _SettingsDialogState extends State<SettingsDialog> with SingleTickerProvid
       
       if (mounted) {
         setState(() {
+          _settings = settings;
           _antminerUserCtrl.text = settings.antminerCredentials.username;
           _antminerPassCtrl.text = settings.antminerCredentials.password;
           _whatsminerUserCtrl.text = settings.whatsminerCredentials.username;
@@ -75,6 +79,9 @@ class _SettingsDialogState extends State<SettingsDialog> with SingleTickerProvid
   }
 
   Future<void> _saveSettings() async {
+    final loaded = _settings;
+    if (loaded == null) return;
+
     final settings = AppSettings(
       antminerCredentials: MinerCredentials(
         username: _antminerUserCtrl.text,
@@ -86,6 +93,19 @@ class _SettingsDialogState extends State<SettingsDialog> with SingleTickerProvid
       ),
       scanThreadCount: _scanThreadCount.toInt(),
       monitorInterval: BigInt.from(_monitorInterval.toInt()),
+      namedRanges: loaded.namedRanges,
+      scanTimeoutMs: loaded.scanTimeoutMs,
+      scanPorts: loaded.scanPorts,
+      scanDevices: loaded.scanDevices,
+      snmpCommunity: loaded.snmpCommunity,
+      outletLinks: loaded.outletLinks,
+      factoryDefaultIps: loaded.factoryDefaultIps,
+      detectIpConflicts: loaded.detectIpConflicts,
+      credentialSets: loaded.credentialSets,
+      minerTags: loaded.minerTags,
+      minerLocations: loaded.minerLocations,
+      poolTemplates: loaded.poolTemplates,
+      desiredStates: loaded.desiredStates,
     );
 
     try {
@@ -61,7 +61,14 @@ void main() {
             'command': command,
           });
           // Return success for all
-          return targetIps.map((ip) => CommandResult(ip: ip, success: true)).toList();
+          return targetIps
+              .map((ip) => CommandResult(
+                    ip: ip,
+                    success: true,
+                    retryable: false,
+                    attempts: const [],
+                  ))
+              .toList();
         },
       );
     });